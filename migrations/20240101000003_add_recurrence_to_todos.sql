@@ -0,0 +1,11 @@
+-- Due dates and RFC 5545 recurrence for todos
+ALTER TABLE todos
+    ADD COLUMN due_at TIMESTAMPTZ,
+    ADD COLUMN recurrence_rule TEXT,
+    ADD COLUMN time_zone VARCHAR(64) NOT NULL DEFAULT 'UTC',
+    ADD COLUMN series_id UUID,
+    ADD COLUMN occurrence_index INTEGER NOT NULL DEFAULT 1;
+
+-- Indexes for due date queries and series history
+CREATE INDEX idx_todos_user_due_at ON todos(user_id, due_at);
+CREATE INDEX idx_todos_series_id ON todos(series_id) WHERE series_id IS NOT NULL;
@@ -0,0 +1,19 @@
+-- Trigram matching for languages Postgres cannot tokenize (e.g. Japanese)
+CREATE EXTENSION IF NOT EXISTS pg_trgm;
+
+-- Full-text search vectors, with titles weighted above descriptions
+ALTER TABLE todos
+    ADD COLUMN search_vector TSVECTOR GENERATED ALWAYS AS (
+        setweight(to_tsvector('english', title), 'A') ||
+        setweight(to_tsvector('english', COALESCE(description, '')), 'B')
+    ) STORED,
+    ADD COLUMN search_vector_simple TSVECTOR GENERATED ALWAYS AS (
+        setweight(to_tsvector('simple', title), 'A') ||
+        setweight(to_tsvector('simple', COALESCE(description, '')), 'B')
+    ) STORED;
+
+-- Indexes for search
+CREATE INDEX idx_todos_search_vector ON todos USING GIN(search_vector);
+CREATE INDEX idx_todos_search_vector_simple ON todos USING GIN(search_vector_simple);
+CREATE INDEX idx_todos_title_trgm ON todos USING GIN(title gin_trgm_ops);
+CREATE INDEX idx_todos_description_trgm ON todos USING GIN(description gin_trgm_ops);
@@ -0,0 +1,4 @@
+-- Indexes for filtering and sorting todo lists
+CREATE INDEX idx_todos_user_created_at ON todos(user_id, created_at, id);
+CREATE INDEX idx_todos_user_updated_at ON todos(user_id, updated_at, id);
+CREATE INDEX idx_todos_user_title ON todos(user_id, title, id);
@@ -0,0 +1,6 @@
+-- Soft delete: trashed todos keep their row until restored or purged
+ALTER TABLE todos ADD COLUMN deleted_at TIMESTAMPTZ;
+
+-- Indexes for the trash listing and the purge job
+CREATE INDEX idx_todos_user_deleted_at ON todos(user_id, deleted_at) WHERE deleted_at IS NOT NULL;
+CREATE INDEX idx_todos_deleted_at ON todos(deleted_at) WHERE deleted_at IS NOT NULL;
@@ -0,0 +1,2 @@
+-- Optimistic concurrency control: bumped on every write, exposed as the ETag
+ALTER TABLE todos ADD COLUMN version INTEGER NOT NULL DEFAULT 1;
@@ -0,0 +1,17 @@
+-- Change history of todos, written in the same transaction as each change
+CREATE TYPE todo_action AS ENUM ('created', 'updated', 'completed', 'deleted', 'restored');
+
+CREATE TABLE todo_history (
+    id UUID PRIMARY KEY DEFAULT uuid_generate_v4(),
+    todo_id UUID NOT NULL REFERENCES todos(id) ON DELETE CASCADE,
+    user_id UUID NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+    actor_id UUID REFERENCES users(id) ON DELETE SET NULL,
+    action todo_action NOT NULL,
+    before JSONB,
+    after JSONB NOT NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+-- Indexes for the per-todo history and the user-wide activity feed
+CREATE INDEX idx_todo_history_todo_id ON todo_history(todo_id, created_at DESC);
+CREATE INDEX idx_todo_history_user_id ON todo_history(user_id, created_at DESC);
@@ -0,0 +1,15 @@
+-- Comments on todos; the body is stored as the Markdown source the author wrote
+CREATE TABLE comments (
+    id UUID PRIMARY KEY DEFAULT uuid_generate_v4(),
+    todo_id UUID NOT NULL REFERENCES todos(id) ON DELETE CASCADE,
+    author_id UUID NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+    body TEXT NOT NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    edited_at TIMESTAMPTZ
+);
+
+-- Keyset pagination of a todo's comments, oldest first
+CREATE INDEX idx_comments_todo_id ON comments(todo_id, created_at, id);
+
+-- Kept in step with the comments table by every comment insert and delete
+ALTER TABLE todos ADD COLUMN comment_count INTEGER NOT NULL DEFAULT 0;
@@ -0,0 +1,34 @@
+-- Attachment metadata; the bytes live in the object store under storage_key
+CREATE TABLE attachments (
+    id UUID PRIMARY KEY DEFAULT uuid_generate_v4(),
+    todo_id UUID NOT NULL REFERENCES todos(id) ON DELETE CASCADE,
+    user_id UUID NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+    file_name TEXT NOT NULL,
+    content_type TEXT NOT NULL,
+    size_bytes BIGINT NOT NULL CHECK (size_bytes >= 0),
+    storage_key TEXT NOT NULL UNIQUE,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+-- Indexes for listing a todo's attachments and summing a user's quota usage
+CREATE INDEX idx_attachments_todo_id ON attachments(todo_id, created_at);
+CREATE INDEX idx_attachments_user_id ON attachments(user_id);
+
+-- Objects whose attachment is gone, including rows removed by a cascading
+-- todo or user delete. Drained by the object cleanup job.
+CREATE TABLE object_deletions (
+    storage_key TEXT PRIMARY KEY,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE FUNCTION queue_attachment_object_deletion() RETURNS TRIGGER AS $$
+BEGIN
+    INSERT INTO object_deletions (storage_key) VALUES (OLD.storage_key)
+    ON CONFLICT DO NOTHING;
+    RETURN OLD;
+END;
+$$ LANGUAGE plpgsql;
+
+CREATE TRIGGER attachments_queue_object_deletion
+    AFTER DELETE ON attachments
+    FOR EACH ROW EXECUTE FUNCTION queue_attachment_object_deletion();
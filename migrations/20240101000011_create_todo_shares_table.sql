@@ -0,0 +1,13 @@
+-- Todos shared with other users; the owner keeps full control
+CREATE TYPE share_permission AS ENUM ('viewer', 'editor');
+
+CREATE TABLE todo_shares (
+    todo_id UUID NOT NULL REFERENCES todos(id) ON DELETE CASCADE,
+    user_id UUID NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+    permission share_permission NOT NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    PRIMARY KEY (todo_id, user_id)
+);
+
+-- "Shared with me" listing
+CREATE INDEX idx_todo_shares_user_id ON todo_shares(user_id, created_at DESC);
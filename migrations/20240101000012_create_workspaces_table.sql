@@ -0,0 +1,57 @@
+-- Workspaces group users and their todos; each workspace is one tenant
+CREATE TYPE workspace_role AS ENUM ('owner', 'admin', 'member');
+
+CREATE TABLE workspaces (
+    id UUID PRIMARY KEY DEFAULT uuid_generate_v4(),
+    name VARCHAR(100) NOT NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE TABLE workspace_members (
+    workspace_id UUID NOT NULL REFERENCES workspaces(id) ON DELETE CASCADE,
+    user_id UUID NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+    role workspace_role NOT NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    PRIMARY KEY (workspace_id, user_id)
+);
+
+-- A user's workspaces, the one joined first being their default
+CREATE INDEX idx_workspace_members_user_id ON workspace_members(user_id, created_at);
+
+-- Pending invitations; accepting one turns it into a membership
+CREATE TABLE workspace_invitations (
+    id UUID PRIMARY KEY DEFAULT uuid_generate_v4(),
+    workspace_id UUID NOT NULL REFERENCES workspaces(id) ON DELETE CASCADE,
+    email VARCHAR(255) NOT NULL,
+    role workspace_role NOT NULL,
+    invited_by UUID REFERENCES users(id) ON DELETE SET NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    UNIQUE (workspace_id, email)
+);
+
+CREATE INDEX idx_workspace_invitations_email ON workspace_invitations(email);
+
+-- Every existing user gets a personal workspace holding their todos
+CREATE TEMPORARY TABLE personal_workspaces AS
+    SELECT id AS user_id, uuid_generate_v4() AS workspace_id FROM users;
+
+INSERT INTO workspaces (id, name)
+    SELECT workspace_id, 'Personal' FROM personal_workspaces;
+
+INSERT INTO workspace_members (workspace_id, user_id, role)
+    SELECT workspace_id, user_id, 'owner' FROM personal_workspaces;
+
+ALTER TABLE todos ADD COLUMN workspace_id UUID REFERENCES workspaces(id) ON DELETE CASCADE;
+
+UPDATE todos SET workspace_id = p.workspace_id
+    FROM personal_workspaces p
+    WHERE p.user_id = todos.user_id;
+
+DROP TABLE personal_workspaces;
+
+-- New todos belong to the workspace of the request creating them
+ALTER TABLE todos
+    ALTER COLUMN workspace_id SET NOT NULL,
+    ALTER COLUMN workspace_id SET DEFAULT NULLIF(current_setting('app.workspace_id', true), '')::uuid;
+
+CREATE INDEX idx_todos_workspace_id ON todos(workspace_id);
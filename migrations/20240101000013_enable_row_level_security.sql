@@ -0,0 +1,44 @@
+-- Requests run as todo_tenant with app.workspace_id set to their workspace.
+-- Unlike the login role, which may own the tables or be a superuser,
+-- todo_tenant never bypasses row-level security.
+DO $$
+BEGIN
+    IF NOT EXISTS (SELECT 1 FROM pg_roles WHERE rolname = 'todo_tenant') THEN
+        CREATE ROLE todo_tenant NOLOGIN;
+    END IF;
+END
+$$;
+
+GRANT todo_tenant TO CURRENT_USER;
+GRANT USAGE ON SCHEMA public TO todo_tenant;
+GRANT SELECT, INSERT, UPDATE, DELETE ON ALL TABLES IN SCHEMA public TO todo_tenant;
+ALTER DEFAULT PRIVILEGES IN SCHEMA public
+    GRANT SELECT, INSERT, UPDATE, DELETE ON TABLES TO todo_tenant;
+
+-- Todos are only visible within their workspace
+ALTER TABLE todos ENABLE ROW LEVEL SECURITY;
+CREATE POLICY workspace_isolation ON todos
+    USING (workspace_id = NULLIF(current_setting('app.workspace_id', true), '')::uuid);
+
+-- Rows belonging to a todo are visible wherever the todo is
+ALTER TABLE todo_history ENABLE ROW LEVEL SECURITY;
+CREATE POLICY workspace_isolation ON todo_history
+    USING (EXISTS (SELECT 1 FROM todos WHERE todos.id = todo_history.todo_id));
+
+ALTER TABLE comments ENABLE ROW LEVEL SECURITY;
+CREATE POLICY workspace_isolation ON comments
+    USING (EXISTS (SELECT 1 FROM todos WHERE todos.id = comments.todo_id));
+
+ALTER TABLE attachments ENABLE ROW LEVEL SECURITY;
+CREATE POLICY workspace_isolation ON attachments
+    USING (EXISTS (SELECT 1 FROM todos WHERE todos.id = attachments.todo_id));
+
+ALTER TABLE todo_shares ENABLE ROW LEVEL SECURITY;
+CREATE POLICY workspace_isolation ON todo_shares
+    USING (EXISTS (SELECT 1 FROM todos WHERE todos.id = todo_shares.todo_id));
+
+-- Attachment quotas span all of a user's workspaces, so usage is summed by
+-- the table owner, past the policy
+CREATE FUNCTION attachment_bytes_used(uploader UUID) RETURNS BIGINT
+    LANGUAGE sql STABLE SECURITY DEFINER SET search_path = public
+    AS $$ SELECT COALESCE(SUM(size_bytes), 0)::BIGINT FROM attachments WHERE user_id = uploader $$;
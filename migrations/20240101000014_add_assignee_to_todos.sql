@@ -0,0 +1,8 @@
+-- The member responsible for a todo, separate from its creator
+ALTER TABLE todos ADD COLUMN assignee_id UUID REFERENCES users(id) ON DELETE SET NULL;
+
+-- "Assigned to me" listing
+CREATE INDEX idx_todos_assignee_id ON todos(assignee_id, created_at DESC)
+    WHERE deleted_at IS NULL;
+
+ALTER TYPE todo_action ADD VALUE 'assigned';
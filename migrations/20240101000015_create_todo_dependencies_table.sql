@@ -0,0 +1,15 @@
+-- "Blocked by" links: a todo can't be completed while its blockers are open
+CREATE TABLE todo_dependencies (
+    todo_id UUID NOT NULL REFERENCES todos(id) ON DELETE CASCADE,
+    blocker_id UUID NOT NULL REFERENCES todos(id) ON DELETE CASCADE,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    PRIMARY KEY (todo_id, blocker_id),
+    CHECK (todo_id <> blocker_id)
+);
+
+-- Walking the graph from blockers to the todos they block
+CREATE INDEX idx_todo_dependencies_blocker_id ON todo_dependencies(blocker_id);
+
+ALTER TABLE todo_dependencies ENABLE ROW LEVEL SECURITY;
+CREATE POLICY workspace_isolation ON todo_dependencies
+    USING (EXISTS (SELECT 1 FROM todos WHERE todos.id = todo_dependencies.todo_id));
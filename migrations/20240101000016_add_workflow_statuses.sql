@@ -0,0 +1,24 @@
+-- Todos move through workflow statuses; completed stays in step with
+-- whether the status is terminal
+ALTER TABLE todos ADD COLUMN status VARCHAR(50) NOT NULL DEFAULT 'todo';
+ALTER TABLE todos ADD COLUMN completed_at TIMESTAMPTZ;
+
+UPDATE todos SET status = 'done', completed_at = updated_at WHERE completed;
+
+-- Board columns and status filters
+CREATE INDEX idx_todos_status ON todos(user_id, status) WHERE deleted_at IS NULL;
+
+-- Custom workflows; workspaces without rows here use the default workflow
+CREATE TABLE workflow_statuses (
+    workspace_id UUID NOT NULL REFERENCES workspaces(id) ON DELETE CASCADE,
+    key VARCHAR(50) NOT NULL,
+    name VARCHAR(100) NOT NULL,
+    terminal BOOLEAN NOT NULL,
+    transitions TEXT[] NOT NULL,
+    position INTEGER NOT NULL,
+    PRIMARY KEY (workspace_id, key)
+);
+
+ALTER TABLE workflow_statuses ENABLE ROW LEVEL SECURITY;
+CREATE POLICY workspace_isolation ON workflow_statuses
+    USING (workspace_id = NULLIF(current_setting('app.workspace_id', true), '')::uuid);
@@ -0,0 +1,8 @@
+-- Order of the cards in a board column, lowest first. New todos go to the
+-- bottom of their column.
+ALTER TABLE todos ADD COLUMN position DOUBLE PRECISION NOT NULL DEFAULT 0;
+
+UPDATE todos SET position = EXTRACT(EPOCH FROM created_at);
+
+CREATE INDEX idx_todos_board ON todos(workspace_id, status, position)
+    WHERE deleted_at IS NULL;
@@ -0,0 +1,7 @@
+-- Priority of a todo, from none to urgent. sort=smart weighs it together
+-- with the due date.
+CREATE TYPE todo_priority AS ENUM ('none', 'low', 'medium', 'high', 'urgent');
+
+ALTER TABLE todos ADD COLUMN priority todo_priority NOT NULL DEFAULT 'none';
+
+CREATE INDEX idx_todos_priority ON todos(user_id, priority) WHERE deleted_at IS NULL;
@@ -0,0 +1,5 @@
+-- Free-form labels on a todo, stored normalized (lowercase, without the
+-- leading #, deduplicated). The GIN index serves tag filters.
+ALTER TABLE todos ADD COLUMN tags TEXT[] NOT NULL DEFAULT '{}';
+
+CREATE INDEX idx_todos_tags ON todos USING GIN (tags) WHERE deleted_at IS NULL;
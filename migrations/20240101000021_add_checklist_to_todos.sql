@@ -0,0 +1,3 @@
+-- Ordered checklist items ({id, text, checked}) kept with their todo, so
+-- changing one bumps the todo's version
+ALTER TABLE todos ADD COLUMN checklist JSONB NOT NULL DEFAULT '[]';
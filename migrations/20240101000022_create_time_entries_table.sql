@@ -0,0 +1,46 @@
+-- Time tracked on todos, by timer or entered by hand; a timer's entry has
+-- no end while it runs
+CREATE TABLE time_entries (
+    id UUID PRIMARY KEY DEFAULT uuid_generate_v4(),
+    todo_id UUID NOT NULL REFERENCES todos(id) ON DELETE CASCADE,
+    user_id UUID NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+    started_at TIMESTAMPTZ NOT NULL,
+    ended_at TIMESTAMPTZ,
+    note TEXT,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    CHECK (ended_at IS NULL OR ended_at > started_at)
+);
+
+CREATE INDEX idx_time_entries_todo_id ON time_entries(todo_id, started_at);
+CREATE INDEX idx_time_entries_user_id ON time_entries(user_id, started_at);
+
+-- A user has at most one running timer, whichever workspace it is in
+CREATE UNIQUE INDEX idx_time_entries_running ON time_entries(user_id) WHERE ended_at IS NULL;
+
+ALTER TABLE time_entries ENABLE ROW LEVEL SECURITY;
+CREATE POLICY workspace_isolation ON time_entries
+    USING (EXISTS (SELECT 1 FROM todos WHERE todos.id = time_entries.todo_id));
+
+-- Time reports span all of a user's workspaces, so their entries are read
+-- by the table owner, past the policy. Entries are clipped to the range,
+-- running ones ending now.
+CREATE FUNCTION tracked_time(tracker UUID, range_start TIMESTAMPTZ, range_end TIMESTAMPTZ)
+    RETURNS TABLE (
+        started_at TIMESTAMPTZ,
+        ended_at TIMESTAMPTZ,
+        workspace_id UUID,
+        workspace_name VARCHAR,
+        tags TEXT[]
+    )
+    LANGUAGE sql STABLE SECURITY DEFINER SET search_path = public
+    AS $$
+        SELECT GREATEST(e.started_at, range_start),
+               LEAST(COALESCE(e.ended_at, NOW()), range_end),
+               t.workspace_id, w.name, t.tags
+        FROM time_entries e
+        JOIN todos t ON t.id = e.todo_id
+        JOIN workspaces w ON w.id = t.workspace_id
+        WHERE e.user_id = tracker
+            AND e.started_at < range_end
+            AND COALESCE(e.ended_at, NOW()) > range_start
+    $$;
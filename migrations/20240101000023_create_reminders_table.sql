@@ -0,0 +1,70 @@
+-- Where a notification is delivered
+CREATE TYPE delivery_channel AS ENUM ('in_app', 'email', 'webhook');
+
+-- Reminders of a todo for one user, at a fixed time or a number of minutes
+-- before the todo is due. Moving the due date moves the reminder; it fires
+-- again if its time changes after firing.
+CREATE TABLE reminders (
+    id UUID PRIMARY KEY DEFAULT uuid_generate_v4(),
+    todo_id UUID NOT NULL REFERENCES todos(id) ON DELETE CASCADE,
+    user_id UUID NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+    remind_at TIMESTAMPTZ,
+    offset_minutes INTEGER,
+    channels delivery_channel[] NOT NULL,
+    webhook_url TEXT,
+    -- The reminder time it last fired for
+    fired_for TIMESTAMPTZ,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    CHECK ((remind_at IS NULL) <> (offset_minutes IS NULL))
+);
+
+CREATE INDEX idx_reminders_todo_id ON reminders(todo_id, user_id);
+
+ALTER TABLE reminders ENABLE ROW LEVEL SECURITY;
+CREATE POLICY workspace_isolation ON reminders
+    USING (EXISTS (SELECT 1 FROM todos WHERE todos.id = reminders.todo_id));
+
+-- Delivery log: one row per channel each time a reminder fires, retried
+-- until sent or out of attempts
+CREATE TYPE delivery_status AS ENUM ('pending', 'sent', 'failed');
+
+CREATE TABLE reminder_deliveries (
+    id UUID PRIMARY KEY DEFAULT uuid_generate_v4(),
+    reminder_id UUID NOT NULL REFERENCES reminders(id) ON DELETE CASCADE,
+    todo_id UUID NOT NULL REFERENCES todos(id) ON DELETE CASCADE,
+    user_id UUID NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+    channel delivery_channel NOT NULL,
+    -- The reminder time this delivery is for
+    scheduled_for TIMESTAMPTZ NOT NULL,
+    status delivery_status NOT NULL DEFAULT 'pending',
+    attempts INTEGER NOT NULL DEFAULT 0,
+    next_attempt_at TIMESTAMPTZ NOT NULL,
+    last_error TEXT,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    delivered_at TIMESTAMPTZ,
+    UNIQUE (reminder_id, scheduled_for, channel)
+);
+
+CREATE INDEX idx_reminder_deliveries_pending ON reminder_deliveries(next_attempt_at)
+    WHERE status = 'pending';
+
+ALTER TABLE reminder_deliveries ENABLE ROW LEVEL SECURITY;
+CREATE POLICY workspace_isolation ON reminder_deliveries
+    USING (EXISTS (SELECT 1 FROM todos WHERE todos.id = reminder_deliveries.todo_id));
+
+-- Notifications shown in the app. They belong to their user rather than a
+-- workspace and outlive the todo they are about.
+CREATE TYPE notification_event AS ENUM ('reminder');
+
+CREATE TABLE notifications (
+    id UUID PRIMARY KEY DEFAULT uuid_generate_v4(),
+    user_id UUID NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+    event notification_event NOT NULL,
+    todo_id UUID REFERENCES todos(id) ON DELETE SET NULL,
+    title TEXT NOT NULL,
+    body TEXT NOT NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    read_at TIMESTAMPTZ
+);
+
+CREATE INDEX idx_notifications_user_id ON notifications(user_id, created_at);
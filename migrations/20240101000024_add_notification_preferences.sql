@@ -0,0 +1,16 @@
+-- Events besides reminders that notify users
+ALTER TYPE notification_event ADD VALUE 'assigned';
+ALTER TYPE notification_event ADD VALUE 'comment';
+
+CREATE INDEX idx_notifications_unread ON notifications(user_id, created_at)
+    WHERE read_at IS NULL;
+
+-- Whether an event creates a notification in the user's inbox and whether it
+-- is also emailed. Without a row the event's defaults apply.
+CREATE TABLE notification_preferences (
+    user_id UUID NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+    event notification_event NOT NULL,
+    in_app BOOLEAN NOT NULL,
+    email BOOLEAN NOT NULL,
+    PRIMARY KEY (user_id, event)
+);
@@ -0,0 +1,19 @@
+-- Whether a todo is blocked is part of its representation. Blockers are
+-- completed, reopened, trashed and restored by many different writes, so
+-- the todos they block get a new version here rather than in each of them.
+CREATE FUNCTION bump_blocked_todos() RETURNS TRIGGER
+    LANGUAGE plpgsql
+    AS $$
+    BEGIN
+        UPDATE todos SET version = version + 1
+        WHERE id IN (SELECT todo_id FROM todo_dependencies WHERE blocker_id = NEW.id);
+        RETURN NULL;
+    END;
+    $$;
+
+CREATE TRIGGER todos_bump_blocked
+    AFTER UPDATE OF completed, deleted_at ON todos
+    FOR EACH ROW
+    WHEN (OLD.completed IS DISTINCT FROM NEW.completed
+          OR (OLD.deleted_at IS NULL) IS DISTINCT FROM (NEW.deleted_at IS NULL))
+    EXECUTE FUNCTION bump_blocked_todos();
@@ -0,0 +1,14 @@
+-- A series has one todo per occurrence, so completing an occurrence again
+-- after reopening it doesn't spawn the next one twice. Duplicates spawned
+-- before this constraint existed are split off into series of their own.
+UPDATE todos AS t
+SET series_id = t.id, occurrence_index = 1
+FROM (SELECT id, row_number() OVER (PARTITION BY series_id, occurrence_index
+                                    ORDER BY created_at, id) AS copy
+      FROM todos
+      WHERE series_id IS NOT NULL) AS d
+WHERE t.id = d.id AND d.copy > 1;
+
+DROP INDEX idx_todos_series_id;
+ALTER TABLE todos
+    ADD CONSTRAINT todos_series_occurrence_key UNIQUE (series_id, occurrence_index);
@@ -0,0 +1,6 @@
+-- Cancelled statuses are terminal without the todo having been done, so
+-- recurring todos cancelled there don't go on to their next occurrence
+ALTER TABLE workflow_statuses ADD COLUMN cancelled BOOLEAN NOT NULL DEFAULT FALSE;
+ALTER TABLE workflow_statuses ADD CHECK (terminal OR NOT cancelled);
+
+UPDATE workflow_statuses SET cancelled = TRUE WHERE key = 'cancelled' AND terminal;
@@ -0,0 +1,36 @@
+-- Projects group a workspace's todos; each can have workflow statuses of its
+-- own instead of the workspace's
+CREATE TABLE projects (
+    id UUID PRIMARY KEY DEFAULT uuid_generate_v4(),
+    workspace_id UUID NOT NULL REFERENCES workspaces(id) ON DELETE CASCADE,
+    name VARCHAR(100) NOT NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    UNIQUE (workspace_id, name),
+    -- Target of the foreign keys keeping todos and statuses in the project's workspace
+    UNIQUE (id, workspace_id)
+);
+
+ALTER TABLE projects ENABLE ROW LEVEL SECURITY;
+CREATE POLICY workspace_isolation ON projects
+    USING (workspace_id = NULLIF(current_setting('app.workspace_id', true), '')::uuid);
+
+-- A project with todos, trashed ones included, cannot be deleted
+ALTER TABLE todos ADD COLUMN project_id UUID;
+ALTER TABLE todos ADD CONSTRAINT todos_project_fkey
+    FOREIGN KEY (project_id, workspace_id) REFERENCES projects(id, workspace_id);
+
+CREATE INDEX idx_todos_project_id ON todos(project_id) WHERE project_id IS NOT NULL;
+
+-- Statuses without a project are the workspace's workflow; projects without
+-- statuses of their own use it
+ALTER TABLE workflow_statuses ADD COLUMN project_id UUID;
+ALTER TABLE workflow_statuses ADD CONSTRAINT workflow_statuses_project_fkey
+    FOREIGN KEY (project_id, workspace_id) REFERENCES projects(id, workspace_id)
+    ON DELETE CASCADE;
+
+ALTER TABLE workflow_statuses DROP CONSTRAINT workflow_statuses_pkey;
+CREATE UNIQUE INDEX idx_workflow_statuses_workspace_key
+    ON workflow_statuses(workspace_id, key) WHERE project_id IS NULL;
+CREATE UNIQUE INDEX idx_workflow_statuses_project_key
+    ON workflow_statuses(project_id, key) WHERE project_id IS NOT NULL;
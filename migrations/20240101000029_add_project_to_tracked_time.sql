@@ -0,0 +1,26 @@
+-- Time reports can be grouped by project; todos outside projects have none.
+DROP FUNCTION tracked_time(UUID, TIMESTAMPTZ, TIMESTAMPTZ);
+
+CREATE FUNCTION tracked_time(tracker UUID, range_start TIMESTAMPTZ, range_end TIMESTAMPTZ)
+    RETURNS TABLE (
+        started_at TIMESTAMPTZ,
+        ended_at TIMESTAMPTZ,
+        workspace_id UUID,
+        workspace_name VARCHAR,
+        project_id UUID,
+        project_name VARCHAR,
+        tags TEXT[]
+    )
+    LANGUAGE sql STABLE SECURITY DEFINER SET search_path = public
+    AS $$
+        SELECT GREATEST(e.started_at, range_start),
+               LEAST(COALESCE(e.ended_at, NOW()), range_end),
+               t.workspace_id, w.name, t.project_id, p.name, t.tags
+        FROM time_entries e
+        JOIN todos t ON t.id = e.todo_id
+        JOIN workspaces w ON w.id = t.workspace_id
+        LEFT JOIN projects p ON p.id = t.project_id
+        WHERE e.user_id = tracker
+            AND e.started_at < range_end
+            AND COALESCE(e.ended_at, NOW()) > range_start
+    $$;
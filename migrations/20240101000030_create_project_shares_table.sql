@@ -0,0 +1,29 @@
+-- Projects shared with members of the workspace; a share gives the same
+-- access to every todo in the project, whoever created it
+CREATE TABLE project_shares (
+    project_id UUID NOT NULL REFERENCES projects(id) ON DELETE CASCADE,
+    user_id UUID NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+    permission share_permission NOT NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    PRIMARY KEY (project_id, user_id)
+);
+
+CREATE INDEX idx_project_shares_user_id ON project_shares(user_id);
+
+ALTER TABLE project_shares ENABLE ROW LEVEL SECURITY;
+CREATE POLICY workspace_isolation ON project_shares
+    USING (EXISTS (SELECT 1 FROM projects WHERE projects.id = project_shares.project_id));
+
+-- Access each user has to a todo through its own share and its project's.
+-- The stronger permission wins, and the todo counts as shared from the
+-- earlier of the two. Runs with the caller's rights, so the policies apply.
+CREATE VIEW todo_access WITH (security_invoker = true) AS
+    SELECT todo_id, user_id, MAX(permission) AS permission, MIN(created_at) AS shared_at
+    FROM (
+        SELECT todo_id, user_id, permission, created_at FROM todo_shares
+        UNION ALL
+        SELECT t.id, s.user_id, s.permission, s.created_at
+        FROM project_shares s
+        JOIN todos t ON t.project_id = s.project_id
+    ) shares
+    GROUP BY todo_id, user_id;
@@ -0,0 +1,48 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+use crate::domain::entities::Attachment;
+
+/// `multipart/form-data` upload of a single file.
+#[derive(ToSchema)]
+pub struct UploadAttachmentRequest {
+    #[schema(value_type = String, format = Binary)]
+    pub file: Vec<u8>,
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct AttachmentResponse {
+    pub id: Uuid,
+    pub todo_id: Uuid,
+    pub uploaded_by: Uuid,
+    pub file_name: String,
+    /// Detected from the file's content
+    pub content_type: String,
+    pub size_bytes: i64,
+    pub created_at: DateTime<Utc>,
+}
+
+impl From<Attachment> for AttachmentResponse {
+    fn from(attachment: Attachment) -> Self {
+        Self {
+            id: attachment.id,
+            todo_id: attachment.todo_id.0,
+            uploaded_by: attachment.user_id,
+            file_name: attachment.file_name,
+            content_type: attachment.content_type,
+            size_bytes: attachment.size_bytes,
+            created_at: attachment.created_at,
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct AttachmentListResponse {
+    /// Oldest first
+    pub attachments: Vec<AttachmentResponse>,
+    /// Total size of your attachments across all todos
+    pub used_bytes: i64,
+    pub quota_bytes: i64,
+}
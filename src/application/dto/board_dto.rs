@@ -0,0 +1,45 @@
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+use super::todo_dto::TodoResponse;
+
+#[derive(Debug, Deserialize)]
+pub struct BoardQuery {
+    pub limit: Option<i64>,
+}
+
+impl BoardQuery {
+    /// Cards per column
+    pub fn limit(&self) -> i64 {
+        self.limit.unwrap_or(20).clamp(1, 100)
+    }
+}
+
+/// A workflow status and the todos in it, in board order.
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct BoardColumnResponse {
+    pub status: String,
+    pub name: String,
+    pub terminal: bool,
+    /// Todos in the column, across all pages
+    pub total: i64,
+    pub cards: Vec<TodoResponse>,
+    /// Whether more cards follow this page
+    pub has_more: bool,
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct BoardResponse {
+    /// In workflow order
+    pub columns: Vec<BoardColumnResponse>,
+}
+
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct MoveTodoRequest {
+    /// Column to move the todo to; its current status to reorder it
+    pub status: String,
+    /// Card to place the todo right below, in the target column of the
+    /// todo's project. The todo goes to the top of the column when omitted.
+    pub after_id: Option<Uuid>,
+}
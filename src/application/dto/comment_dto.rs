@@ -0,0 +1,71 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+use crate::domain::entities::{Comment, CommentCursor};
+
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct CreateCommentRequest {
+    /// Markdown, at most 10000 characters
+    pub body: String,
+}
+
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct UpdateCommentRequest {
+    /// Markdown, at most 10000 characters
+    pub body: String,
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct CommentResponse {
+    pub id: Uuid,
+    pub todo_id: Uuid,
+    pub author_id: Uuid,
+    /// Markdown source as written; sanitize before rendering as HTML
+    pub body: String,
+    pub created_at: DateTime<Utc>,
+    /// Set once the comment has been edited
+    pub edited_at: Option<DateTime<Utc>>,
+}
+
+impl From<Comment> for CommentResponse {
+    fn from(comment: Comment) -> Self {
+        Self {
+            id: comment.id,
+            todo_id: comment.todo_id.0,
+            author_id: comment.author_id,
+            body: comment.body.value().to_string(),
+            created_at: comment.created_at,
+            edited_at: comment.edited_at,
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct CommentListResponse {
+    /// Oldest first
+    pub comments: Vec<CommentResponse>,
+    pub per_page: i64,
+    /// Cursor for the following page, if there is one
+    pub next_cursor: Option<String>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+pub struct CommentListQuery {
+    pub cursor: Option<String>,
+    pub per_page: Option<i64>,
+}
+
+impl CommentListQuery {
+    pub fn per_page(&self) -> i64 {
+        self.per_page.unwrap_or(20).clamp(1, 100)
+    }
+
+    pub fn cursor(&self) -> Result<Option<CommentCursor>, String> {
+        self.cursor
+            .as_deref()
+            .map(CommentCursor::decode)
+            .transpose()
+    }
+}
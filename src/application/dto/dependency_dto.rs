@@ -0,0 +1,36 @@
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+use super::todo_dto::TodoResponse;
+
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct AddBlockerRequest {
+    /// Todo that has to be completed first
+    pub blocker_id: Uuid,
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct BlockerListResponse {
+    /// Oldest first
+    pub blockers: Vec<TodoResponse>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct NextTodosQuery {
+    pub limit: Option<usize>,
+}
+
+impl NextTodosQuery {
+    pub fn limit(&self) -> usize {
+        self.limit.unwrap_or(20).clamp(1, 100)
+    }
+}
+
+/// Open todos in an order they can be worked through: each comes after the
+/// todos blocking it. The leading todos with `blocked: false` can be started
+/// now.
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct NextTodosResponse {
+    pub todos: Vec<TodoResponse>,
+}
@@ -1,5 +1,29 @@
+pub mod attachment_dto;
 pub mod auth_dto;
+pub mod board_dto;
+pub mod comment_dto;
+pub mod dependency_dto;
+pub mod notification_dto;
+pub mod project_dto;
+pub mod reminder_dto;
+pub mod saved_filter_dto;
+pub mod share_dto;
+pub mod time_entry_dto;
 pub mod todo_dto;
+pub mod workflow_dto;
+pub mod workspace_dto;
 
+pub use attachment_dto::*;
 pub use auth_dto::*;
+pub use board_dto::*;
+pub use comment_dto::*;
+pub use dependency_dto::*;
+pub use notification_dto::*;
+pub use project_dto::*;
+pub use reminder_dto::*;
+pub use saved_filter_dto::*;
+pub use share_dto::*;
+pub use time_entry_dto::*;
 pub use todo_dto::*;
+pub use workflow_dto::*;
+pub use workspace_dto::*;
@@ -0,0 +1,114 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+use crate::domain::entities::{
+    Notification, NotificationCursor, NotificationEvent, NotificationPreference,
+};
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct NotificationResponse {
+    pub id: Uuid,
+    pub event: NotificationEvent,
+    /// The todo it is about; `null` once the todo is deleted
+    pub todo_id: Option<Uuid>,
+    pub title: String,
+    pub body: String,
+    pub created_at: DateTime<Utc>,
+    pub read_at: Option<DateTime<Utc>>,
+}
+
+impl From<Notification> for NotificationResponse {
+    fn from(notification: Notification) -> Self {
+        Self {
+            id: notification.id,
+            event: notification.event,
+            todo_id: notification.todo_id.map(|id| id.0),
+            title: notification.title,
+            body: notification.body,
+            created_at: notification.created_at,
+            read_at: notification.read_at,
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct NotificationListResponse {
+    /// Newest first
+    pub notifications: Vec<NotificationResponse>,
+    /// Unread notifications in all pages
+    pub unread_count: i64,
+    pub per_page: i64,
+    /// Cursor for the following page, if there is one
+    pub next_cursor: Option<String>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+pub struct NotificationListQuery {
+    #[serde(default)]
+    pub unread: bool,
+    pub cursor: Option<String>,
+    pub per_page: Option<i64>,
+}
+
+impl NotificationListQuery {
+    pub fn per_page(&self) -> i64 {
+        self.per_page.unwrap_or(20).clamp(1, 100)
+    }
+
+    pub fn cursor(&self) -> Result<Option<NotificationCursor>, String> {
+        self.cursor
+            .as_deref()
+            .map(NotificationCursor::decode)
+            .transpose()
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct MarkAllReadResponse {
+    /// Notifications that were unread
+    pub marked: u64,
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, ToSchema)]
+pub struct NotificationPreferenceDto {
+    pub event: NotificationEvent,
+    /// Whether the event creates a notification in the inbox
+    pub in_app: bool,
+    /// Whether the event is also emailed. For reminders, each reminder's own
+    /// channels apply too.
+    pub email: bool,
+}
+
+impl From<NotificationPreference> for NotificationPreferenceDto {
+    fn from(preference: NotificationPreference) -> Self {
+        Self {
+            event: preference.event,
+            in_app: preference.in_app,
+            email: preference.email,
+        }
+    }
+}
+
+impl From<NotificationPreferenceDto> for NotificationPreference {
+    fn from(dto: NotificationPreferenceDto) -> Self {
+        Self {
+            event: dto.event,
+            in_app: dto.in_app,
+            email: dto.email,
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct NotificationPreferencesResponse {
+    /// One per event, defaults included
+    pub preferences: Vec<NotificationPreferenceDto>,
+}
+
+/// Events left out keep their current preference.
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct UpdateNotificationPreferencesRequest {
+    pub preferences: Vec<NotificationPreferenceDto>,
+}
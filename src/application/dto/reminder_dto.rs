@@ -0,0 +1,104 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+use crate::domain::entities::{DeliveryChannel, DeliveryStatus, Reminder, ReminderDelivery};
+
+fn default_channels() -> Vec<DeliveryChannel> {
+    vec![DeliveryChannel::InApp]
+}
+
+/// A reminder at `remind_at`, or `offset_minutes` before the todo is due;
+/// exactly one must be set.
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct CreateReminderRequest {
+    /// In the future
+    pub remind_at: Option<DateTime<Utc>>,
+    /// Between 0 and 43200 (30 days); the todo must have a due date
+    pub offset_minutes: Option<i32>,
+    /// Default: `["in_app"]`
+    #[serde(default = "default_channels")]
+    pub channels: Vec<DeliveryChannel>,
+    /// HTTPS URL on a public host; required by, and only used by, the
+    /// webhook channel
+    pub webhook_url: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct ReminderResponse {
+    pub id: Uuid,
+    pub todo_id: Uuid,
+    pub remind_at: Option<DateTime<Utc>>,
+    pub offset_minutes: Option<i32>,
+    pub channels: Vec<DeliveryChannel>,
+    pub webhook_url: Option<String>,
+    /// When the reminder fires; `null` for an offset reminder of a todo
+    /// without a due date
+    pub fire_at: Option<DateTime<Utc>>,
+    /// Whether it has fired for `fire_at`
+    pub fired: bool,
+    pub created_at: DateTime<Utc>,
+}
+
+impl ReminderResponse {
+    pub fn new(reminder: Reminder, due_at: Option<DateTime<Utc>>) -> Self {
+        let fire_at = reminder.fire_at(due_at);
+        Self {
+            id: reminder.id,
+            todo_id: reminder.todo_id.0,
+            remind_at: reminder.remind_at,
+            offset_minutes: reminder.offset_minutes,
+            channels: reminder.channels,
+            webhook_url: reminder.webhook_url.map(|u| u.value().to_string()),
+            fired: fire_at.is_some() && reminder.fired_for == fire_at,
+            fire_at,
+            created_at: reminder.created_at,
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct ReminderListResponse {
+    /// Oldest first
+    pub reminders: Vec<ReminderResponse>,
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct ReminderDeliveryResponse {
+    pub id: Uuid,
+    pub channel: DeliveryChannel,
+    /// The reminder time this delivery is for
+    pub scheduled_for: DateTime<Utc>,
+    pub status: DeliveryStatus,
+    pub attempts: i32,
+    /// When the next attempt is due, while pending
+    pub next_attempt_at: Option<DateTime<Utc>>,
+    /// Error of the last failed attempt
+    pub last_error: Option<String>,
+    pub created_at: DateTime<Utc>,
+    pub delivered_at: Option<DateTime<Utc>>,
+}
+
+impl From<ReminderDelivery> for ReminderDeliveryResponse {
+    fn from(delivery: ReminderDelivery) -> Self {
+        Self {
+            id: delivery.id,
+            channel: delivery.channel,
+            scheduled_for: delivery.scheduled_for,
+            status: delivery.status,
+            attempts: delivery.attempts,
+            next_attempt_at: (delivery.status == DeliveryStatus::Pending)
+                .then_some(delivery.next_attempt_at),
+            last_error: delivery.last_error,
+            created_at: delivery.created_at,
+            delivered_at: delivery.delivered_at,
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct ReminderDeliveryListResponse {
+    /// Most recent first
+    pub deliveries: Vec<ReminderDeliveryResponse>,
+}
@@ -0,0 +1,81 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+use crate::application::dto::TodoListQuery;
+use crate::domain::entities::{FilterDefinition, SavedFilter, SortOrder, TodoSortField};
+
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct CreateSavedFilterRequest {
+    /// At most 100 characters, unique among your filters
+    pub name: String,
+    pub definition: FilterDefinition,
+}
+
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct UpdateSavedFilterRequest {
+    /// At most 100 characters, unique among your filters
+    pub name: String,
+    pub definition: FilterDefinition,
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct SavedFilterResponse {
+    pub id: Uuid,
+    pub name: String,
+    pub definition: FilterDefinition,
+    /// Matching todos that are not completed
+    pub open_count: i64,
+    /// Matching todos updated since the filter's todos were last listed
+    pub unread_count: i64,
+    pub last_viewed_at: Option<DateTime<Utc>>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl SavedFilterResponse {
+    pub fn new(filter: SavedFilter, open_count: i64, unread_count: i64) -> Self {
+        Self {
+            id: filter.id,
+            name: filter.name.value().to_string(),
+            definition: filter.definition,
+            open_count,
+            unread_count,
+            last_viewed_at: filter.last_viewed_at,
+            created_at: filter.created_at,
+            updated_at: filter.updated_at,
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct SavedFilterListResponse {
+    /// By name
+    pub filters: Vec<SavedFilterResponse>,
+}
+
+/// Sorting and paging of a saved filter's todos, as on the list endpoint.
+#[derive(Debug, Default, Deserialize)]
+pub struct FilterTodosQuery {
+    pub page: Option<i64>,
+    pub per_page: Option<i64>,
+    pub sort: Option<TodoSortField>,
+    pub order: Option<SortOrder>,
+    pub cursor: Option<String>,
+    pub include_total: Option<bool>,
+}
+
+impl From<FilterTodosQuery> for TodoListQuery {
+    fn from(query: FilterTodosQuery) -> Self {
+        Self {
+            page: query.page,
+            per_page: query.per_page,
+            sort: query.sort,
+            order: query.order,
+            cursor: query.cursor,
+            include_total: query.include_total,
+            ..Default::default()
+        }
+    }
+}
@@ -0,0 +1,95 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+use super::todo_dto::TodoResponse;
+use crate::domain::entities::{ProjectShare, SharePermission, Todo, TodoShare};
+
+/// Who to share a todo or project with, and how.
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct ShareTodoRequest {
+    /// Email of a registered user
+    pub email: String,
+    pub permission: SharePermission,
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct TodoShareResponse {
+    pub todo_id: Uuid,
+    pub user_id: Uuid,
+    pub email: String,
+    pub permission: SharePermission,
+    pub created_at: DateTime<Utc>,
+}
+
+impl From<TodoShare> for TodoShareResponse {
+    fn from(share: TodoShare) -> Self {
+        Self {
+            todo_id: share.todo_id.0,
+            user_id: share.user_id,
+            email: share.email,
+            permission: share.permission,
+            created_at: share.created_at,
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct TodoShareListResponse {
+    /// Oldest first
+    pub shares: Vec<TodoShareResponse>,
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct ProjectShareResponse {
+    pub project_id: Uuid,
+    pub user_id: Uuid,
+    pub email: String,
+    pub permission: SharePermission,
+    pub created_at: DateTime<Utc>,
+}
+
+impl From<ProjectShare> for ProjectShareResponse {
+    fn from(share: ProjectShare) -> Self {
+        Self {
+            project_id: share.project_id,
+            user_id: share.user_id,
+            email: share.email,
+            permission: share.permission,
+            created_at: share.created_at,
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct ProjectShareListResponse {
+    /// Oldest first
+    pub shares: Vec<ProjectShareResponse>,
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct SharedTodoResponse {
+    pub todo: TodoResponse,
+    /// Your permission on the todo, the stronger one when both the todo and
+    /// its project are shared with you
+    pub permission: SharePermission,
+}
+
+impl From<(Todo, SharePermission)> for SharedTodoResponse {
+    fn from((todo, permission): (Todo, SharePermission)) -> Self {
+        Self {
+            todo: TodoResponse::from(todo),
+            permission,
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct SharedTodoListResponse {
+    /// Most recently shared first
+    pub todos: Vec<SharedTodoResponse>,
+    pub total: i64,
+    pub page: i64,
+    pub per_page: i64,
+}
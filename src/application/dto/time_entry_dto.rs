@@ -0,0 +1,195 @@
+use chrono::{DateTime, NaiveDate, Utc};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+use crate::domain::entities::{ReportDimension, TimeEntry, TimeReport, TimeReportRow};
+
+/// Time worked on a todo, entered by hand.
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct CreateTimeEntryRequest {
+    pub started_at: DateTime<Utc>,
+    /// In the past, at most 24 hours after `started_at`
+    pub ended_at: DateTime<Utc>,
+    /// At most 1000 characters
+    pub note: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct TimeEntryResponse {
+    pub id: Uuid,
+    pub todo_id: Uuid,
+    pub user_id: Uuid,
+    pub started_at: DateTime<Utc>,
+    /// `null` while the timer runs
+    pub ended_at: Option<DateTime<Utc>>,
+    /// Up to now while the timer runs
+    pub duration_seconds: i64,
+    pub running: bool,
+    pub note: Option<String>,
+}
+
+impl TimeEntryResponse {
+    pub fn new(entry: TimeEntry, now: DateTime<Utc>) -> Self {
+        Self {
+            id: entry.id,
+            todo_id: entry.todo_id.0,
+            user_id: entry.user_id,
+            duration_seconds: entry.duration_seconds(now),
+            running: entry.is_running(),
+            started_at: entry.started_at,
+            ended_at: entry.ended_at,
+            note: entry.note.map(|n| n.value().to_string()),
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct TimeEntryListResponse {
+    /// Most recently started first
+    pub entries: Vec<TimeEntryResponse>,
+    pub total_seconds: i64,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum ReportFormat {
+    #[default]
+    Json,
+    Csv,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct TimeReportQuery {
+    /// First day of the report
+    pub from: NaiveDate,
+    /// Last day of the report, inclusive
+    pub to: NaiveDate,
+    /// IANA time zone the days are in (default: UTC)
+    pub time_zone: Option<String>,
+    /// Comma-separated dimensions: `day`, `workspace`, `project`, `tag`
+    /// (default: `day`)
+    pub group_by: Option<String>,
+    #[serde(default)]
+    pub format: ReportFormat,
+}
+
+/// Tracked time of one group. Dimensions the report is not grouped by are
+/// `null`, as are the project for time on todos outside projects and `tag`
+/// for time on untagged todos.
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct TimeReportRowResponse {
+    pub day: Option<NaiveDate>,
+    pub workspace_id: Option<Uuid>,
+    pub workspace_name: Option<String>,
+    pub project_id: Option<Uuid>,
+    pub project_name: Option<String>,
+    pub tag: Option<String>,
+    pub seconds: i64,
+}
+
+impl From<TimeReportRow> for TimeReportRowResponse {
+    fn from(row: TimeReportRow) -> Self {
+        let (workspace_id, workspace_name) = row.workspace.unzip();
+        let (project_id, project_name) = row.project.unzip();
+        Self {
+            day: row.day,
+            workspace_id,
+            workspace_name,
+            project_id,
+            project_name,
+            tag: row.tag.map(|t| t.value().to_string()),
+            seconds: row.seconds,
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct TimeReportResponse {
+    pub from: NaiveDate,
+    pub to: NaiveDate,
+    pub time_zone: String,
+    pub group_by: Vec<ReportDimension>,
+    /// All tracked time in the range, counted once even where tag rows
+    /// overlap
+    pub total_seconds: i64,
+    /// Ordered by day, workspace name, project name and tag
+    pub rows: Vec<TimeReportRowResponse>,
+}
+
+impl TimeReportResponse {
+    pub fn new(
+        report: TimeReport,
+        from: NaiveDate,
+        to: NaiveDate,
+        time_zone: String,
+        group_by: Vec<ReportDimension>,
+    ) -> Self {
+        Self {
+            from,
+            to,
+            time_zone,
+            group_by,
+            total_seconds: report.total_seconds,
+            rows: report.rows.into_iter().map(Into::into).collect(),
+        }
+    }
+
+    /// The rows as CSV: a column per grouped dimension, then `seconds` and
+    /// `hours` to two decimals.
+    pub fn to_csv(&self) -> String {
+        let mut header = Vec::new();
+        for dimension in &self.group_by {
+            match dimension {
+                ReportDimension::Workspace => header.extend(["workspace_id", "workspace"]),
+                ReportDimension::Project => header.extend(["project_id", "project"]),
+                _ => header.push(dimension.name()),
+            }
+        }
+        header.extend(["seconds", "hours"]);
+
+        let mut csv = header.join(",") + "\r\n";
+        for row in &self.rows {
+            let mut fields = Vec::new();
+            for dimension in &self.group_by {
+                match dimension {
+                    ReportDimension::Day => fields.push(row.day.map(|d| d.to_string())),
+                    ReportDimension::Workspace => {
+                        fields.push(row.workspace_id.map(|id| id.to_string()));
+                        fields.push(row.workspace_name.clone());
+                    }
+                    ReportDimension::Project => {
+                        fields.push(row.project_id.map(|id| id.to_string()));
+                        fields.push(row.project_name.clone());
+                    }
+                    ReportDimension::Tag => fields.push(row.tag.clone()),
+                }
+            }
+            fields.push(Some(row.seconds.to_string()));
+            fields.push(Some(format!("{:.2}", row.seconds as f64 / 3600.0)));
+
+            let line: Vec<String> = fields
+                .iter()
+                .map(|field| csv_field(field.as_deref().unwrap_or("")))
+                .collect();
+            csv += &line.join(",");
+            csv += "\r\n";
+        }
+        csv
+    }
+}
+
+/// Quotes a CSV field where needed. Text a spreadsheet would read as a
+/// formula is prefixed with `'`.
+fn csv_field(value: &str) -> String {
+    let value = if value.starts_with(['=', '+', '-', '@', '\t', '\r']) {
+        format!("'{}", value)
+    } else {
+        value.to_string()
+    };
+    if value.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value
+    }
+}
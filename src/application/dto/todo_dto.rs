@@ -3,50 +3,280 @@ use serde::{Deserialize, Serialize};
 use utoipa::ToSchema;
 use uuid::Uuid;
 
-use crate::domain::entities::Todo;
+use crate::domain::entities::{
+    ChecklistItem, QueryError, SearchLanguage, SortOrder, Todo, TodoAction, TodoCursor, TodoFilter,
+    TodoHistoryEntry, TodoPriority, TodoQuery, TodoSearchHit, TodoSort, TodoSortField, TodoStatus,
+    TodoTag,
+};
+use crate::shared::patch::Patch;
 
 #[derive(Debug, Deserialize, ToSchema)]
 pub struct CreateTodoRequest {
     pub title: String,
     pub description: Option<String>,
+    /// Defaults to `none`
+    pub priority: Option<TodoPriority>,
+    /// At most 20; a leading `#` is dropped
+    #[serde(default)]
+    pub tags: Vec<String>,
+    pub due_at: Option<DateTime<Utc>>,
+    /// RFC 5545 RRULE, e.g. `FREQ=WEEKLY;BYDAY=MO`
+    pub recurrence_rule: Option<String>,
+    /// IANA time zone used to expand the recurrence (default: UTC)
+    pub time_zone: Option<String>,
+    /// Project of the workspace; the todo starts in the initial status of
+    /// the project's workflow
+    pub project_id: Option<Uuid>,
 }
 
+/// A todo typed as one line of text, e.g. `Pay rent tomorrow 9am #finance
+/// !high every month`.
 #[derive(Debug, Deserialize, ToSchema)]
+pub struct QuickAddTodoRequest {
+    pub text: String,
+    /// The user's IANA time zone, used to read dates and times and to expand
+    /// the recurrence. No zone is stored per user, so clients should always
+    /// send it; without it `tomorrow 9am` is read in UTC.
+    pub time_zone: Option<String>,
+}
+
+/// Full replacement of a todo's editable fields. Omitted optional fields are
+/// cleared and `completed` defaults to `false`; an omitted `status` follows
+/// from `completed`.
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
 pub struct UpdateTodoRequest {
-    pub title: Option<String>,
+    pub title: String,
     pub description: Option<String>,
-    pub completed: Option<bool>,
+    /// Defaults to `none`
+    pub priority: Option<TodoPriority>,
+    #[serde(default)]
+    pub tags: Vec<String>,
+    /// Key of a status in the workflow the todo follows
+    pub status: Option<String>,
+    #[serde(default)]
+    pub completed: bool,
+    pub due_at: Option<DateTime<Utc>>,
+    pub recurrence_rule: Option<String>,
+    /// Defaults to UTC
+    pub time_zone: Option<String>,
+    /// The todo's status must be in the new project's workflow
+    pub project_id: Option<Uuid>,
+}
+
+impl From<&Todo> for UpdateTodoRequest {
+    fn from(todo: &Todo) -> Self {
+        Self {
+            title: todo.title.value().to_string(),
+            description: todo.description.clone(),
+            priority: Some(todo.priority),
+            tags: todo.tags.iter().map(|t| t.value().to_string()).collect(),
+            status: Some(todo.status.value().to_string()),
+            completed: todo.completed,
+            due_at: todo.due_at,
+            recurrence_rule: todo.recurrence_rule.as_ref().map(|r| r.value().to_string()),
+            time_zone: Some(todo.time_zone.value().to_string()),
+            project_id: todo.project_id,
+        }
+    }
+}
+
+/// JSON Merge Patch (RFC 7396) of a todo: omitted fields are left unchanged
+/// and `null` clears a field.
+#[derive(Debug, Default, Deserialize, ToSchema)]
+pub struct PatchTodoRequest {
+    #[serde(default)]
+    #[schema(value_type = Option<String>)]
+    pub title: Patch<String>,
+    #[serde(default)]
+    #[schema(value_type = Option<String>)]
+    pub description: Patch<String>,
+    /// `null` resets the priority to `none`
+    #[serde(default)]
+    #[schema(value_type = Option<TodoPriority>)]
+    pub priority: Patch<TodoPriority>,
+    /// Replaces all tags; `null` removes them
+    #[serde(default)]
+    #[schema(value_type = Option<Vec<String>>)]
+    pub tags: Patch<Vec<String>>,
+    /// Key of a status in the workflow the todo follows
+    #[serde(default)]
+    #[schema(value_type = Option<String>)]
+    pub status: Patch<String>,
+    /// `true` moves the todo to a terminal status and `false` reopens it,
+    /// unless `status` is given
+    #[serde(default)]
+    #[schema(value_type = Option<bool>)]
+    pub completed: Patch<bool>,
+    #[serde(default)]
+    #[schema(value_type = Option<DateTime<Utc>>)]
+    pub due_at: Patch<DateTime<Utc>>,
+    #[serde(default)]
+    #[schema(value_type = Option<String>)]
+    pub recurrence_rule: Patch<String>,
+    /// `null` resets the time zone to UTC
+    #[serde(default)]
+    #[schema(value_type = Option<String>)]
+    pub time_zone: Patch<String>,
+    /// `null` takes the todo out of its project. The todo's status must be
+    /// in the new project's workflow
+    #[serde(default)]
+    #[schema(value_type = Option<Uuid>)]
+    pub project_id: Patch<Uuid>,
+}
+
+impl From<UpdateTodoRequest> for PatchTodoRequest {
+    fn from(request: UpdateTodoRequest) -> Self {
+        Self {
+            title: Patch::Value(request.title),
+            description: request.description.into(),
+            priority: request.priority.into(),
+            tags: Patch::Value(request.tags),
+            status: request.status.map_or(Patch::Missing, Patch::Value),
+            completed: Patch::Value(request.completed),
+            due_at: request.due_at.into(),
+            recurrence_rule: request.recurrence_rule.into(),
+            time_zone: request.time_zone.into(),
+            project_id: request.project_id.into(),
+        }
+    }
+}
+
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct AssignTodoRequest {
+    /// A user the todo is visible to: its owner or a user it is shared with
+    pub assignee_id: Uuid,
+}
+
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct AddChecklistItemRequest {
+    /// At most 500 characters
+    pub text: String,
+    /// Index to insert the item at (default: after the last item)
+    pub position: Option<usize>,
+}
+
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct ReorderChecklistRequest {
+    /// Every item of the checklist, in the new order
+    pub item_ids: Vec<Uuid>,
+}
+
+/// How many of a todo's checklist items are checked.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+pub struct ChecklistProgress {
+    pub checked: usize,
+    pub total: usize,
 }
 
 #[derive(Debug, Serialize, Deserialize, ToSchema)]
 pub struct TodoResponse {
     pub id: Uuid,
+    /// Creator and owner
+    pub user_id: Uuid,
+    pub assignee_id: Option<Uuid>,
+    pub project_id: Option<Uuid>,
     pub title: String,
     pub description: Option<String>,
+    pub priority: TodoPriority,
+    pub tags: Vec<String>,
+    /// Steps of the todo, in order
+    pub checklist: Vec<ChecklistItem>,
+    pub checklist_progress: ChecklistProgress,
+    /// Key of the todo's status in the workflow of its project, or of the
+    /// workspace
+    pub status: String,
+    /// Whether the status is terminal
     pub completed: bool,
+    pub completed_at: Option<DateTime<Utc>>,
+    pub due_at: Option<DateTime<Utc>>,
+    pub recurrence_rule: Option<String>,
+    pub time_zone: String,
+    pub series_id: Option<Uuid>,
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
+    /// Set when the todo is in the trash
+    pub deleted_at: Option<DateTime<Utc>>,
+    /// Incremented on every change; also returned as the `ETag` header
+    pub version: i32,
+    pub comment_count: i32,
+    /// Whether an open todo blocks this one
+    pub blocked: bool,
+    /// Seconds of time tracked on the todo by everyone; running timers
+    /// count once they stop
+    pub tracked_seconds: i64,
 }
 
 impl From<Todo> for TodoResponse {
     fn from(todo: Todo) -> Self {
         Self {
             id: todo.id.0,
+            user_id: todo.user_id,
+            assignee_id: todo.assignee_id,
+            project_id: todo.project_id,
             title: todo.title.value().to_string(),
             description: todo.description,
+            priority: todo.priority,
+            tags: todo
+                .tags
+                .into_iter()
+                .map(|t| t.value().to_string())
+                .collect(),
+            checklist_progress: ChecklistProgress {
+                checked: todo.checklist.iter().filter(|item| item.checked).count(),
+                total: todo.checklist.len(),
+            },
+            checklist: todo.checklist,
+            status: todo.status.value().to_string(),
             completed: todo.completed,
+            completed_at: todo.completed_at,
+            due_at: todo.due_at,
+            recurrence_rule: todo.recurrence_rule.map(|r| r.value().to_string()),
+            time_zone: todo.time_zone.value().to_string(),
+            series_id: todo.series_id,
             created_at: todo.created_at,
             updated_at: todo.updated_at,
+            deleted_at: todo.deleted_at,
+            version: todo.version,
+            comment_count: todo.comment_count,
+            blocked: todo.blocked,
+            tracked_seconds: todo.tracked_seconds,
         }
     }
 }
 
+/// Query parameters of writes that can complete a todo.
+#[derive(Debug, Default, Deserialize)]
+pub struct TodoWriteQuery {
+    /// Complete the todo even while open todos block it
+    #[serde(default)]
+    pub force: bool,
+}
+
+/// Versions named by an `If-Match` header. A write only proceeds while the
+/// todo is at one of them.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct VersionPrecondition(pub Vec<i32>);
+
+impl VersionPrecondition {
+    pub fn matches(&self, version: i32) -> bool {
+        self.0.contains(&version)
+    }
+}
+
 #[derive(Debug, Serialize, Deserialize, ToSchema)]
 pub struct TodoListResponse {
     pub todos: Vec<TodoResponse>,
-    pub total: i64,
-    pub page: i64,
+    /// Omitted when `include_total=false`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub total: Option<i64>,
+    /// Omitted when paging by cursor
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub page: Option<i64>,
     pub per_page: i64,
+    /// Cursor for the following page, if there is one
+    pub next_cursor: Option<String>,
+    /// Cursor for the preceding page, if there is one
+    pub prev_cursor: Option<String>,
 }
 
 #[derive(Debug, Deserialize)]
@@ -68,3 +298,272 @@ impl PaginationQuery {
         (self.page() - 1) * self.per_page()
     }
 }
+
+#[derive(Debug, Default, Deserialize)]
+pub struct TodoListQuery {
+    pub page: Option<i64>,
+    pub per_page: Option<i64>,
+    pub completed: Option<bool>,
+    pub status: Option<String>,
+    pub priority: Option<TodoPriority>,
+    pub tag: Option<String>,
+    pub created_after: Option<DateTime<Utc>>,
+    pub created_before: Option<DateTime<Utc>>,
+    pub updated_after: Option<DateTime<Utc>>,
+    pub updated_before: Option<DateTime<Utc>>,
+    pub due_after: Option<DateTime<Utc>>,
+    pub due_before: Option<DateTime<Utc>>,
+    /// `me` or a user ID
+    pub assignee: Option<String>,
+    pub project_id: Option<Uuid>,
+    /// A `TodoQuery` expression
+    pub q: Option<String>,
+    pub sort: Option<TodoSortField>,
+    pub order: Option<SortOrder>,
+    pub cursor: Option<String>,
+    pub include_total: Option<bool>,
+}
+
+impl TodoListQuery {
+    pub fn pagination(&self) -> PaginationQuery {
+        PaginationQuery {
+            page: self.page,
+            per_page: self.per_page,
+        }
+    }
+
+    /// The filter for `user_id`'s listing, resolving `assignee=me` to them.
+    pub fn filter(&self, user_id: Uuid) -> Result<TodoFilter, String> {
+        let assignee_id = match self.assignee.as_deref().map(str::trim) {
+            None => None,
+            Some("me") => Some(user_id),
+            Some(assignee) => Some(
+                Uuid::parse_str(assignee)
+                    .map_err(|_| "assignee must be \"me\" or a user ID".to_string())?,
+            ),
+        };
+        Ok(TodoFilter {
+            completed: self.completed,
+            status: self.status.clone().map(TodoStatus::new).transpose()?,
+            priority: self.priority,
+            tags: self
+                .tag
+                .clone()
+                .map(TodoTag::new)
+                .transpose()?
+                .into_iter()
+                .collect(),
+            created_after: self.created_after,
+            created_before: self.created_before,
+            updated_after: self.updated_after,
+            updated_before: self.updated_before,
+            due_after: self.due_after,
+            due_before: self.due_before,
+            assignee_id,
+            project_id: self.project_id,
+            ..Default::default()
+        })
+    }
+
+    /// The parsed `q` expression, if one was given.
+    pub fn query(&self, user_id: Uuid) -> Result<Option<TodoQuery>, QueryError> {
+        self.q
+            .as_deref()
+            .filter(|q| !q.trim().is_empty())
+            .map(|q| TodoQuery::parse(q, user_id))
+            .transpose()
+    }
+
+    pub fn sort(&self) -> TodoSort {
+        TodoSort::new(self.sort, self.order)
+    }
+
+    /// Decodes the cursor, which must have been issued for the same sort.
+    pub fn cursor(&self) -> Result<Option<TodoCursor>, String> {
+        let Some(token) = &self.cursor else {
+            return Ok(None);
+        };
+        let cursor = TodoCursor::decode(token)?;
+        if cursor.sort != self.sort() {
+            return Err("Cursor does not match the requested sort".to_string());
+        }
+        Ok(Some(cursor))
+    }
+
+    pub fn include_total(&self) -> bool {
+        self.include_total.unwrap_or(true)
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct OccurrencePreviewResponse {
+    pub todo_id: Uuid,
+    pub recurrence_rule: Option<String>,
+    pub time_zone: String,
+    pub occurrences: Vec<DateTime<Utc>>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct OccurrencePreviewQuery {
+    pub count: Option<usize>,
+}
+
+impl OccurrencePreviewQuery {
+    pub fn count(&self) -> usize {
+        self.count.unwrap_or(5).clamp(1, 100)
+    }
+}
+
+#[derive(Debug, Deserialize)]
+pub struct TodoSearchQuery {
+    pub q: String,
+    pub lang: Option<SearchLanguage>,
+    pub page: Option<i64>,
+    pub per_page: Option<i64>,
+}
+
+impl TodoSearchQuery {
+    pub fn pagination(&self) -> PaginationQuery {
+        PaginationQuery {
+            page: self.page,
+            per_page: self.per_page,
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct TodoSearchResult {
+    pub todo: TodoResponse,
+    pub rank: f32,
+    /// Title with matches wrapped in `<mark>` tags
+    pub title_snippet: String,
+    /// Matching excerpt of the description, if it matched
+    pub description_snippet: Option<String>,
+}
+
+impl From<TodoSearchHit> for TodoSearchResult {
+    fn from(hit: TodoSearchHit) -> Self {
+        Self {
+            todo: TodoResponse::from(hit.todo),
+            rank: hit.rank,
+            title_snippet: hit.title_snippet,
+            description_snippet: hit.description_snippet,
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct TodoSearchResponse {
+    pub results: Vec<TodoSearchResult>,
+    pub total: i64,
+    pub page: i64,
+    pub per_page: i64,
+}
+
+/// Maximum number of operations accepted in one batch request.
+pub const MAX_BATCH_OPERATIONS: usize = 500;
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum BatchMode {
+    /// Nothing is written unless every operation succeeds
+    #[default]
+    AllOrNothing,
+    /// Successful operations are written even if others fail
+    BestEffort,
+}
+
+/// Merge patch of one todo, as in `PATCH /api/v1/todos/{id}`.
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct BatchUpdateOperation {
+    pub id: Uuid,
+    /// Complete the todo even while open todos block it
+    #[serde(default)]
+    pub force: bool,
+    #[serde(flatten)]
+    pub changes: PatchTodoRequest,
+}
+
+#[derive(Debug, Deserialize, ToSchema)]
+#[serde(tag = "op", rename_all = "snake_case")]
+pub enum BatchOperation {
+    Create(CreateTodoRequest),
+    Update(BatchUpdateOperation),
+    /// Moves the todo to the trash
+    Delete {
+        id: Uuid,
+    },
+}
+
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct TodoBatchRequest {
+    #[serde(default)]
+    pub mode: BatchMode,
+    /// Applied in order; at most 500
+    pub operations: Vec<BatchOperation>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum BatchOperationStatus {
+    Succeeded,
+    Failed,
+    /// Valid, but not written because another operation failed
+    RolledBack,
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct BatchOperationResult {
+    pub index: usize,
+    pub status: BatchOperationStatus,
+    /// The created or updated todo
+    pub todo: Option<TodoResponse>,
+    pub error: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct TodoBatchResponse {
+    /// Whether any changes were written
+    pub committed: bool,
+    pub succeeded: usize,
+    pub failed: usize,
+    pub results: Vec<BatchOperationResult>,
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct TodoHistoryResponse {
+    pub id: Uuid,
+    pub todo_id: Uuid,
+    /// User who made the change
+    pub actor_id: Option<Uuid>,
+    pub action: TodoAction,
+    /// Previous values of the changed fields; null for creations
+    #[schema(value_type = Option<Object>)]
+    pub before: Option<serde_json::Value>,
+    /// New values of the changed fields
+    #[schema(value_type = Object)]
+    pub after: serde_json::Value,
+    pub created_at: DateTime<Utc>,
+}
+
+impl From<TodoHistoryEntry> for TodoHistoryResponse {
+    fn from(entry: TodoHistoryEntry) -> Self {
+        Self {
+            id: entry.id,
+            todo_id: entry.todo_id.0,
+            actor_id: entry.actor_id,
+            action: entry.action,
+            before: entry.before,
+            after: entry.after,
+            created_at: entry.created_at,
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct TodoHistoryListResponse {
+    pub entries: Vec<TodoHistoryResponse>,
+    pub total: i64,
+    pub page: i64,
+    pub per_page: i64,
+}
@@ -0,0 +1,90 @@
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+use crate::domain::entities::{TodoStatus, Workflow, WorkflowStatus};
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct WorkflowStatusBody {
+    /// Lowercase letters, digits and underscores, e.g. `in_review`
+    pub key: String,
+    pub name: String,
+    /// Todos in a terminal status count as completed
+    #[serde(default)]
+    pub terminal: bool,
+    /// Terminal status for todos dropped rather than done; recurring todos
+    /// cancelled here have no next occurrence
+    #[serde(default)]
+    pub cancelled: bool,
+    /// Keys of the statuses todos can move on to
+    #[serde(default)]
+    pub transitions: Vec<String>,
+}
+
+impl From<&WorkflowStatus> for WorkflowStatusBody {
+    fn from(status: &WorkflowStatus) -> Self {
+        Self {
+            key: status.key.value().to_string(),
+            name: status.name.clone(),
+            terminal: status.terminal,
+            cancelled: status.cancelled,
+            transitions: status
+                .transitions
+                .iter()
+                .map(|key| key.value().to_string())
+                .collect(),
+        }
+    }
+}
+
+impl TryFrom<WorkflowStatusBody> for WorkflowStatus {
+    type Error = String;
+
+    fn try_from(body: WorkflowStatusBody) -> Result<Self, String> {
+        Ok(Self {
+            key: TodoStatus::new(body.key)?,
+            name: body.name.trim().to_string(),
+            terminal: body.terminal,
+            cancelled: body.cancelled,
+            transitions: body
+                .transitions
+                .into_iter()
+                .map(TodoStatus::new)
+                .collect::<Result<_, _>>()?,
+        })
+    }
+}
+
+/// Replacement of a workspace's or a project's workflow.
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct UpdateWorkflowRequest {
+    /// New todos start in the first status, which can't be terminal
+    pub statuses: Vec<WorkflowStatusBody>,
+}
+
+impl UpdateWorkflowRequest {
+    pub fn workflow(self) -> Result<Workflow, String> {
+        self.statuses
+            .into_iter()
+            .map(WorkflowStatus::try_from)
+            .collect::<Result<Vec<_>, _>>()
+            .and_then(Workflow::new)
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct WorkflowResponse {
+    /// In order; new todos start in the first
+    pub statuses: Vec<WorkflowStatusBody>,
+}
+
+impl From<&Workflow> for WorkflowResponse {
+    fn from(workflow: &Workflow) -> Self {
+        Self {
+            statuses: workflow
+                .statuses()
+                .iter()
+                .map(WorkflowStatusBody::from)
+                .collect(),
+        }
+    }
+}
@@ -0,0 +1,106 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+use crate::domain::entities::{Workspace, WorkspaceInvitation, WorkspaceMember, WorkspaceRole};
+
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct CreateWorkspaceRequest {
+    /// At most 100 characters
+    pub name: String,
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct WorkspaceResponse {
+    pub id: Uuid,
+    pub name: String,
+    /// Your role in the workspace
+    pub role: WorkspaceRole,
+    pub created_at: DateTime<Utc>,
+}
+
+impl From<(Workspace, WorkspaceRole)> for WorkspaceResponse {
+    fn from((workspace, role): (Workspace, WorkspaceRole)) -> Self {
+        Self {
+            id: workspace.id,
+            name: workspace.name.value().to_string(),
+            role,
+            created_at: workspace.created_at,
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct WorkspaceListResponse {
+    /// In the order you joined them; the first is used when no
+    /// `X-Workspace-Id` header is sent
+    pub workspaces: Vec<WorkspaceResponse>,
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct WorkspaceMemberResponse {
+    pub user_id: Uuid,
+    pub email: String,
+    pub role: WorkspaceRole,
+    pub joined_at: DateTime<Utc>,
+}
+
+impl From<WorkspaceMember> for WorkspaceMemberResponse {
+    fn from(member: WorkspaceMember) -> Self {
+        Self {
+            user_id: member.user_id,
+            email: member.email,
+            role: member.role,
+            joined_at: member.created_at,
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct WorkspaceMemberListResponse {
+    /// In the order they joined
+    pub members: Vec<WorkspaceMemberResponse>,
+}
+
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct UpdateMemberRequest {
+    pub role: WorkspaceRole,
+}
+
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct InviteMemberRequest {
+    pub email: String,
+    pub role: WorkspaceRole,
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct WorkspaceInvitationResponse {
+    pub id: Uuid,
+    pub workspace_id: Uuid,
+    pub workspace_name: String,
+    pub email: String,
+    pub role: WorkspaceRole,
+    pub invited_by: Option<Uuid>,
+    pub created_at: DateTime<Utc>,
+}
+
+impl From<WorkspaceInvitation> for WorkspaceInvitationResponse {
+    fn from(invitation: WorkspaceInvitation) -> Self {
+        Self {
+            id: invitation.id,
+            workspace_id: invitation.workspace_id,
+            workspace_name: invitation.workspace_name.value().to_string(),
+            email: invitation.email,
+            role: invitation.role,
+            invited_by: invitation.invited_by,
+            created_at: invitation.created_at,
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct WorkspaceInvitationListResponse {
+    /// Oldest first
+    pub invitations: Vec<WorkspaceInvitationResponse>,
+}
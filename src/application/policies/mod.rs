@@ -0,0 +1,3 @@
+pub mod todo_policy;
+
+pub use todo_policy::TodoPolicy;
@@ -0,0 +1,59 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+use uuid::Uuid;
+
+use crate::domain::entities::{Todo, TodoId, TodoPermission, TodoRole};
+use crate::domain::repositories::TodoRepository;
+use crate::shared::error::{AppError, AppResult};
+
+/// Decides what a user may do with a todo: its owner can do anything, and
+/// users it is shared with what their permission allows.
+#[derive(Clone)]
+pub struct TodoPolicy {
+    todo_repository: Arc<dyn TodoRepository>,
+}
+
+impl TodoPolicy {
+    pub fn new(todo_repository: Arc<dyn TodoRepository>) -> Self {
+        Self { todo_repository }
+    }
+
+    /// The active todo and the user's role on it. Todos the user can't see
+    /// are not found, so their existence is not revealed.
+    pub async fn role(&self, user_id: Uuid, todo_id: Uuid) -> AppResult<(Todo, TodoRole)> {
+        self.todo_repository
+            .find_accessible(todo_id.into(), user_id)
+            .await?
+            .ok_or_else(|| AppError::NotFound("Todo not found".to_string()))
+    }
+
+    /// The user's role on each active todo among `todo_ids` they can see.
+    /// Todos they can't see are left out.
+    pub async fn roles(
+        &self,
+        user_id: Uuid,
+        todo_ids: &[TodoId],
+    ) -> AppResult<HashMap<TodoId, (Todo, TodoRole)>> {
+        Ok(self
+            .todo_repository
+            .find_accessible_by_ids(todo_ids, user_id)
+            .await?
+            .into_iter()
+            .map(|(todo, role)| (todo.id, (todo, role)))
+            .collect())
+    }
+
+    /// The active todo, if the user may act on it with `permission`.
+    pub async fn authorize(
+        &self,
+        user_id: Uuid,
+        todo_id: Uuid,
+        permission: TodoPermission,
+    ) -> AppResult<Todo> {
+        let (todo, role) = self.role(user_id, todo_id).await?;
+        if !role.allows(permission) {
+            return Err(AppError::Forbidden);
+        }
+        Ok(todo)
+    }
+}
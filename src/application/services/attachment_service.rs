@@ -0,0 +1,158 @@
+use std::sync::Arc;
+
+use bytes::Bytes;
+use uuid::Uuid;
+
+use crate::application::dto::{AttachmentListResponse, AttachmentResponse};
+use crate::application::policies::TodoPolicy;
+use crate::domain::entities::{Attachment, Todo, TodoPermission};
+use crate::domain::repositories::{AttachmentRepository, TodoRepository};
+use crate::infrastructure::storage::{AttachmentLimits, ByteRange, ByteStream, ObjectStore};
+use crate::shared::error::{AppError, AppResult};
+
+pub struct AttachmentService {
+    attachment_repository: Arc<dyn AttachmentRepository>,
+    policy: TodoPolicy,
+    object_store: Arc<dyn ObjectStore>,
+    limits: AttachmentLimits,
+}
+
+impl AttachmentService {
+    pub fn new(
+        attachment_repository: Arc<dyn AttachmentRepository>,
+        todo_repository: Arc<dyn TodoRepository>,
+        object_store: Arc<dyn ObjectStore>,
+        limits: AttachmentLimits,
+    ) -> Self {
+        Self {
+            attachment_repository,
+            policy: TodoPolicy::new(todo_repository),
+            object_store,
+            limits,
+        }
+    }
+
+    pub async fn upload(
+        &self,
+        user_id: Uuid,
+        todo_id: Uuid,
+        file_name: &str,
+        data: Bytes,
+    ) -> AppResult<AttachmentResponse> {
+        let todo = self
+            .policy
+            .authorize(user_id, todo_id, TodoPermission::Edit)
+            .await?;
+        if data.len() as i64 > self.limits.max_file_bytes {
+            return Err(AppError::PayloadTooLarge(format!(
+                "Attachments cannot be larger than {} bytes",
+                self.limits.max_file_bytes
+            )));
+        }
+        if data.is_empty() {
+            return Err(AppError::Validation("Attachment is empty".to_string()));
+        }
+
+        let attachment = Attachment::new(todo.id, user_id, file_name, &data);
+        // Fail early rather than upload content that cannot be kept
+        let used = self
+            .attachment_repository
+            .total_size_by_user(user_id)
+            .await?;
+        if used + attachment.size_bytes > self.limits.quota_bytes {
+            return Err(AppError::PayloadTooLarge(format!(
+                "Attachment quota of {} bytes exceeded",
+                self.limits.quota_bytes
+            )));
+        }
+
+        self.object_store.put(&attachment.storage_key, data).await?;
+        match self
+            .attachment_repository
+            .create(&attachment, self.limits.quota_bytes)
+            .await
+        {
+            Ok(created) => Ok(AttachmentResponse::from(created)),
+            Err(e) => {
+                if let Err(cleanup) = self.object_store.delete(&attachment.storage_key).await {
+                    tracing::warn!(
+                        "Failed to delete object {} of rejected upload: {:?}",
+                        attachment.storage_key,
+                        cleanup
+                    );
+                }
+                Err(e)
+            }
+        }
+    }
+
+    pub async fn list(&self, user_id: Uuid, todo_id: Uuid) -> AppResult<AttachmentListResponse> {
+        let todo = self
+            .policy
+            .authorize(user_id, todo_id, TodoPermission::View)
+            .await?;
+        let attachments = self.attachment_repository.find_by_todo(todo.id).await?;
+        let used_bytes = self
+            .attachment_repository
+            .total_size_by_user(user_id)
+            .await?;
+
+        Ok(AttachmentListResponse {
+            attachments: attachments
+                .into_iter()
+                .map(AttachmentResponse::from)
+                .collect(),
+            used_bytes,
+            quota_bytes: self.limits.quota_bytes,
+        })
+    }
+
+    pub async fn find(
+        &self,
+        user_id: Uuid,
+        todo_id: Uuid,
+        attachment_id: Uuid,
+    ) -> AppResult<Attachment> {
+        let todo = self
+            .policy
+            .authorize(user_id, todo_id, TodoPermission::View)
+            .await?;
+        self.find_attachment(&todo, attachment_id).await
+    }
+
+    /// Streams the attachment's content, or only `range` of it.
+    pub async fn content(
+        &self,
+        attachment: &Attachment,
+        range: Option<ByteRange>,
+    ) -> AppResult<ByteStream> {
+        self.object_store
+            .get(&attachment.storage_key, range)
+            .await
+            .map_err(|e| match e {
+                AppError::NotFound(_) => {
+                    AppError::NotFound("Attachment content not found".to_string())
+                }
+                other => other,
+            })
+    }
+
+    /// Deletes the attachment. Its content is removed by the object cleanup job.
+    pub async fn delete(&self, user_id: Uuid, todo_id: Uuid, attachment_id: Uuid) -> AppResult<()> {
+        let todo = self
+            .policy
+            .authorize(user_id, todo_id, TodoPermission::Edit)
+            .await?;
+        let attachment = self.find_attachment(&todo, attachment_id).await?;
+        self.attachment_repository
+            .delete(attachment.id, attachment.todo_id)
+            .await
+    }
+
+    async fn find_attachment(&self, todo: &Todo, attachment_id: Uuid) -> AppResult<Attachment> {
+        self.attachment_repository
+            .find_by_id(attachment_id, todo.id)
+            .await?
+            .ok_or_else(|| AppError::NotFound("Attachment not found".to_string()))
+    }
+}
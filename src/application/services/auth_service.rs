@@ -1,21 +1,27 @@
 use std::sync::Arc;
 
 use crate::application::dto::{AuthResponse, LoginRequest, RefreshRequest, RegisterRequest};
-use crate::domain::entities::User;
-use crate::domain::repositories::UserRepository;
+use crate::domain::entities::{User, Workspace, WorkspaceName, PERSONAL_WORKSPACE_NAME};
+use crate::domain::repositories::{UserRepository, WorkspaceRepository};
 use crate::infrastructure::auth::jwt::{JwtConfig, TokenType};
 use crate::infrastructure::auth::password::{hash_password, verify_password};
 use crate::shared::error::{AppError, AppResult};
 
 pub struct AuthService {
     user_repository: Arc<dyn UserRepository>,
+    workspace_repository: Arc<dyn WorkspaceRepository>,
     jwt_config: JwtConfig,
 }
 
 impl AuthService {
-    pub fn new(user_repository: Arc<dyn UserRepository>, jwt_config: JwtConfig) -> Self {
+    pub fn new(
+        user_repository: Arc<dyn UserRepository>,
+        workspace_repository: Arc<dyn WorkspaceRepository>,
+        jwt_config: JwtConfig,
+    ) -> Self {
         Self {
             user_repository,
+            workspace_repository,
             jwt_config,
         }
     }
@@ -38,6 +44,13 @@ impl AuthService {
         let user = User::new(request.email, password_hash);
         let created_user = self.user_repository.create(&user).await?;
 
+        // Every user starts out with a workspace of their own
+        let name = WorkspaceName::new(PERSONAL_WORKSPACE_NAME.to_string())
+            .map_err(AppError::Validation)?;
+        self.workspace_repository
+            .create(&Workspace::new(name), created_user.id)
+            .await?;
+
         // Generate tokens
         self.generate_tokens(&created_user)
     }
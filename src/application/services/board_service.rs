@@ -0,0 +1,130 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+use uuid::Uuid;
+
+use crate::application::dto::{
+    BoardColumnResponse, BoardQuery, BoardResponse, PaginationQuery, TodoResponse,
+};
+use crate::domain::entities::{Todo, TodoStatus, Workflow, WorkflowStatus};
+use crate::domain::repositories::{ProjectRepository, TodoRepository, WorkflowRepository};
+use crate::shared::error::{AppError, AppResult};
+
+/// The todos of a project a user can see, in one column per status of the
+/// project's workflow.
+pub struct BoardService {
+    todo_repository: Arc<dyn TodoRepository>,
+    workflow_repository: Arc<dyn WorkflowRepository>,
+    project_repository: Arc<dyn ProjectRepository>,
+}
+
+impl BoardService {
+    pub fn new(
+        todo_repository: Arc<dyn TodoRepository>,
+        workflow_repository: Arc<dyn WorkflowRepository>,
+        project_repository: Arc<dyn ProjectRepository>,
+    ) -> Self {
+        Self {
+            todo_repository,
+            workflow_repository,
+            project_repository,
+        }
+    }
+
+    /// Every column with its first `query.limit()` cards.
+    pub async fn board(
+        &self,
+        user_id: Uuid,
+        project_id: Uuid,
+        query: BoardQuery,
+    ) -> AppResult<BoardResponse> {
+        let workflow = self.workflow(project_id).await?;
+        let totals = self.totals(user_id, project_id).await?;
+        let mut cards: HashMap<TodoStatus, Vec<Todo>> = HashMap::new();
+        for todo in self
+            .todo_repository
+            .find_board(user_id, project_id, query.limit())
+            .await?
+        {
+            cards.entry(todo.status.clone()).or_default().push(todo);
+        }
+
+        Ok(BoardResponse {
+            columns: workflow
+                .statuses()
+                .iter()
+                .map(|status| {
+                    let cards = cards.remove(&status.key).unwrap_or_default();
+                    let total = totals.get(&status.key).copied().unwrap_or(0);
+                    column(status, total, cards, 0)
+                })
+                .collect(),
+        })
+    }
+
+    /// One page of a single column.
+    pub async fn column(
+        &self,
+        user_id: Uuid,
+        project_id: Uuid,
+        status: String,
+        pagination: PaginationQuery,
+    ) -> AppResult<BoardColumnResponse> {
+        let workflow = self.workflow(project_id).await?;
+        let status = workflow
+            .get(&status)
+            .ok_or_else(|| AppError::NotFound("Status not found".to_string()))?;
+        let total = self
+            .totals(user_id, project_id)
+            .await?
+            .get(&status.key)
+            .copied()
+            .unwrap_or(0);
+        let cards = self
+            .todo_repository
+            .find_board_column(
+                user_id,
+                project_id,
+                &status.key,
+                pagination.per_page(),
+                pagination.offset(),
+            )
+            .await?;
+
+        Ok(column(status, total, cards, pagination.offset()))
+    }
+
+    /// The workflow of the project, which must be in the workspace.
+    async fn workflow(&self, project_id: Uuid) -> AppResult<Workflow> {
+        self.project_repository
+            .find_by_id(project_id)
+            .await?
+            .ok_or_else(|| AppError::NotFound("Project not found".to_string()))?;
+        self.workflow_repository.find(Some(project_id)).await
+    }
+
+    async fn totals(&self, user_id: Uuid, project_id: Uuid) -> AppResult<HashMap<TodoStatus, i64>> {
+        Ok(self
+            .todo_repository
+            .count_by_status(user_id, project_id)
+            .await?
+            .into_iter()
+            .collect())
+    }
+}
+
+/// A column holding `cards`, which start `offset` cards into it.
+fn column(
+    status: &WorkflowStatus,
+    total: i64,
+    cards: Vec<Todo>,
+    offset: i64,
+) -> BoardColumnResponse {
+    BoardColumnResponse {
+        status: status.key.value().to_string(),
+        name: status.name.clone(),
+        terminal: status.terminal,
+        total,
+        has_more: offset + (cards.len() as i64) < total,
+        cards: cards.into_iter().map(TodoResponse::from).collect(),
+    }
+}
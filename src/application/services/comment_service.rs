@@ -0,0 +1,138 @@
+use std::sync::Arc;
+use uuid::Uuid;
+
+use crate::application::dto::{
+    CommentListQuery, CommentListResponse, CommentResponse, CreateCommentRequest,
+    UpdateCommentRequest,
+};
+use crate::application::policies::TodoPolicy;
+use crate::application::services::Notifier;
+use crate::domain::entities::{
+    Comment, CommentBody, CommentCursor, Notification, Todo, TodoPermission,
+};
+use crate::domain::repositories::{CommentRepository, TodoRepository};
+use crate::shared::error::{AppError, AppResult};
+
+pub struct CommentService {
+    comment_repository: Arc<dyn CommentRepository>,
+    notifier: Notifier,
+    policy: TodoPolicy,
+}
+
+impl CommentService {
+    pub fn new(
+        comment_repository: Arc<dyn CommentRepository>,
+        todo_repository: Arc<dyn TodoRepository>,
+        notifier: Notifier,
+    ) -> Self {
+        Self {
+            comment_repository,
+            notifier,
+            policy: TodoPolicy::new(todo_repository),
+        }
+    }
+
+    /// Comments on a todo, notifying its creator and assignee other than
+    /// the author.
+    pub async fn create(
+        &self,
+        user_id: Uuid,
+        todo_id: Uuid,
+        request: CreateCommentRequest,
+    ) -> AppResult<CommentResponse> {
+        let todo = self
+            .policy
+            .authorize(user_id, todo_id, TodoPermission::Comment)
+            .await?;
+        let body = CommentBody::new(request.body).map_err(AppError::Validation)?;
+
+        let comment = Comment::new(todo.id, user_id, body);
+        let created = self.comment_repository.create(&comment).await?;
+
+        let mut recipients = vec![todo.user_id];
+        recipients.extend(todo.assignee_id);
+        recipients.dedup();
+        for recipient in recipients.into_iter().filter(|&id| id != user_id) {
+            self.notifier
+                .notify(Notification::comment(&todo, &created, recipient))
+                .await;
+        }
+        Ok(CommentResponse::from(created))
+    }
+
+    pub async fn list(
+        &self,
+        user_id: Uuid,
+        todo_id: Uuid,
+        query: CommentListQuery,
+    ) -> AppResult<CommentListResponse> {
+        let todo = self
+            .policy
+            .authorize(user_id, todo_id, TodoPermission::View)
+            .await?;
+        let cursor = query.cursor().map_err(AppError::Validation)?;
+        let per_page = query.per_page();
+
+        // Fetch one extra row to tell whether another page follows
+        let mut comments = self
+            .comment_repository
+            .find_by_todo(todo.id, cursor.as_ref(), per_page + 1)
+            .await?;
+        let has_next = comments.len() as i64 > per_page;
+        comments.truncate(per_page as usize);
+
+        let next_cursor = comments
+            .last()
+            .filter(|_| has_next)
+            .map(|c| CommentCursor::new(c).encode());
+
+        Ok(CommentListResponse {
+            comments: comments.into_iter().map(CommentResponse::from).collect(),
+            per_page,
+            next_cursor,
+        })
+    }
+
+    pub async fn update(
+        &self,
+        user_id: Uuid,
+        todo_id: Uuid,
+        comment_id: Uuid,
+        request: UpdateCommentRequest,
+    ) -> AppResult<CommentResponse> {
+        let (_, mut comment) = self.find_modifiable(user_id, todo_id, comment_id).await?;
+        let body = CommentBody::new(request.body).map_err(AppError::Validation)?;
+
+        comment.edit(body);
+        let updated = self.comment_repository.update(&comment).await?;
+        Ok(CommentResponse::from(updated))
+    }
+
+    pub async fn delete(&self, user_id: Uuid, todo_id: Uuid, comment_id: Uuid) -> AppResult<()> {
+        let (todo, comment) = self.find_modifiable(user_id, todo_id, comment_id).await?;
+        self.comment_repository.delete(comment.id, todo.id).await
+    }
+
+    /// The comment, if `user_id` may edit or delete it.
+    async fn find_modifiable(
+        &self,
+        user_id: Uuid,
+        todo_id: Uuid,
+        comment_id: Uuid,
+    ) -> AppResult<(Todo, Comment)> {
+        let todo = self
+            .policy
+            .authorize(user_id, todo_id, TodoPermission::View)
+            .await?;
+        let comment = self
+            .comment_repository
+            .find_by_id(comment_id, todo.id)
+            .await?
+            .ok_or_else(|| AppError::NotFound("Comment not found".to_string()))?;
+
+        if !comment.can_modify(user_id, todo.user_id) {
+            return Err(AppError::Forbidden);
+        }
+        Ok((todo, comment))
+    }
+}
@@ -0,0 +1,114 @@
+use std::sync::Arc;
+use uuid::Uuid;
+
+use crate::application::dto::{
+    AddBlockerRequest, BlockerListResponse, NextTodosQuery, NextTodosResponse, TodoResponse,
+};
+use crate::application::policies::TodoPolicy;
+use crate::domain::entities::{work_order, TodoPermission};
+use crate::domain::repositories::{TodoDependencyRepository, TodoRepository};
+use crate::shared::error::{AppError, AppResult};
+
+pub struct DependencyService {
+    dependency_repository: Arc<dyn TodoDependencyRepository>,
+    policy: TodoPolicy,
+}
+
+impl DependencyService {
+    pub fn new(
+        dependency_repository: Arc<dyn TodoDependencyRepository>,
+        todo_repository: Arc<dyn TodoRepository>,
+    ) -> Self {
+        Self {
+            dependency_repository,
+            policy: TodoPolicy::new(todo_repository),
+        }
+    }
+
+    pub async fn blockers(&self, user_id: Uuid, todo_id: Uuid) -> AppResult<BlockerListResponse> {
+        let todo = self
+            .policy
+            .authorize(user_id, todo_id, TodoPermission::View)
+            .await?;
+        let blockers = self.dependency_repository.find_blockers(todo.id).await?;
+
+        Ok(BlockerListResponse {
+            blockers: blockers.into_iter().map(TodoResponse::from).collect(),
+        })
+    }
+
+    /// Marks a todo as blocked by another one the user can see. Anyone who
+    /// may edit the todo can add blockers to it.
+    pub async fn add_blocker(
+        &self,
+        user_id: Uuid,
+        todo_id: Uuid,
+        request: AddBlockerRequest,
+    ) -> AppResult<TodoResponse> {
+        if request.blocker_id == todo_id {
+            return Err(AppError::Validation(
+                "A todo cannot block itself".to_string(),
+            ));
+        }
+        let todo = self
+            .policy
+            .authorize(user_id, todo_id, TodoPermission::Edit)
+            .await?;
+        let (blocker, _) = self
+            .policy
+            .role(user_id, request.blocker_id)
+            .await
+            .map_err(|e| match e {
+                AppError::NotFound(_) => AppError::NotFound("Blocker not found".to_string()),
+                e => e,
+            })?;
+
+        self.dependency_repository.add(todo.id, blocker.id).await?;
+        self.get(user_id, todo_id).await
+    }
+
+    pub async fn remove_blocker(
+        &self,
+        user_id: Uuid,
+        todo_id: Uuid,
+        blocker_id: Uuid,
+    ) -> AppResult<TodoResponse> {
+        let todo = self
+            .policy
+            .authorize(user_id, todo_id, TodoPermission::Edit)
+            .await?;
+        if !self
+            .dependency_repository
+            .remove(todo.id, blocker_id.into())
+            .await?
+        {
+            return Err(AppError::NotFound("Blocker not found".to_string()));
+        }
+        self.get(user_id, todo_id).await
+    }
+
+    /// The user's open todos, ordered so that blockers come before the
+    /// todos they block.
+    pub async fn next(&self, user_id: Uuid, query: NextTodosQuery) -> AppResult<NextTodosResponse> {
+        let (todos, dependencies) = self
+            .dependency_repository
+            .find_open_by_user(user_id)
+            .await?;
+
+        Ok(NextTodosResponse {
+            todos: work_order(todos, &dependencies)
+                .into_iter()
+                .take(query.limit())
+                .map(TodoResponse::from)
+                .collect(),
+        })
+    }
+
+    async fn get(&self, user_id: Uuid, todo_id: Uuid) -> AppResult<TodoResponse> {
+        let todo = self
+            .policy
+            .authorize(user_id, todo_id, TodoPermission::View)
+            .await?;
+        Ok(TodoResponse::from(todo))
+    }
+}
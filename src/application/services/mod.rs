@@ -1,5 +1,29 @@
+pub mod attachment_service;
 pub mod auth_service;
+pub mod board_service;
+pub mod comment_service;
+pub mod dependency_service;
+pub mod notification_service;
+pub mod project_service;
+pub mod reminder_service;
+pub mod saved_filter_service;
+pub mod share_service;
+pub mod time_entry_service;
 pub mod todo_service;
+pub mod workflow_service;
+pub mod workspace_service;
 
+pub use attachment_service::AttachmentService;
 pub use auth_service::AuthService;
+pub use board_service::BoardService;
+pub use comment_service::CommentService;
+pub use dependency_service::DependencyService;
+pub use notification_service::{NotificationService, Notifier};
+pub use project_service::ProjectService;
+pub use reminder_service::ReminderService;
+pub use saved_filter_service::SavedFilterService;
+pub use share_service::ShareService;
+pub use time_entry_service::TimeEntryService;
 pub use todo_service::TodoService;
+pub use workflow_service::WorkflowService;
+pub use workspace_service::WorkspaceService;
@@ -0,0 +1,185 @@
+use chrono::Utc;
+use std::collections::HashSet;
+use std::sync::Arc;
+use uuid::Uuid;
+
+use crate::application::dto::{
+    MarkAllReadResponse, NotificationListQuery, NotificationListResponse,
+    NotificationPreferenceDto, NotificationPreferencesResponse, NotificationResponse,
+    UpdateNotificationPreferencesRequest,
+};
+use crate::domain::entities::{
+    DeliveryChannel, Notification, NotificationCursor, NotificationEvent, NotificationPreference,
+};
+use crate::domain::repositories::{NotificationRepository, UserRepository};
+use crate::infrastructure::notifications::{NotificationChannels, Recipient};
+use crate::shared::error::{AppError, AppResult};
+
+pub struct NotificationService {
+    notification_repository: Arc<dyn NotificationRepository>,
+}
+
+impl NotificationService {
+    pub fn new(notification_repository: Arc<dyn NotificationRepository>) -> Self {
+        Self {
+            notification_repository,
+        }
+    }
+
+    pub async fn list(
+        &self,
+        user_id: Uuid,
+        query: NotificationListQuery,
+    ) -> AppResult<NotificationListResponse> {
+        let cursor = query.cursor().map_err(AppError::Validation)?;
+        let per_page = query.per_page();
+
+        // Fetch one extra row to tell whether another page follows
+        let mut notifications = self
+            .notification_repository
+            .find_by_user(user_id, query.unread, cursor.as_ref(), per_page + 1)
+            .await?;
+        let has_next = notifications.len() as i64 > per_page;
+        notifications.truncate(per_page as usize);
+
+        let next_cursor = notifications
+            .last()
+            .filter(|_| has_next)
+            .map(|n| NotificationCursor::new(n).encode());
+        let unread_count = self.notification_repository.count_unread(user_id).await?;
+
+        Ok(NotificationListResponse {
+            notifications: notifications
+                .into_iter()
+                .map(NotificationResponse::from)
+                .collect(),
+            unread_count,
+            per_page,
+            next_cursor,
+        })
+    }
+
+    pub async fn mark_read(&self, user_id: Uuid, id: Uuid) -> AppResult<NotificationResponse> {
+        let notification = self
+            .notification_repository
+            .mark_read(id, user_id, Utc::now())
+            .await?
+            .ok_or_else(|| AppError::NotFound("Notification not found".to_string()))?;
+        Ok(NotificationResponse::from(notification))
+    }
+
+    pub async fn mark_all_read(&self, user_id: Uuid) -> AppResult<MarkAllReadResponse> {
+        let marked = self
+            .notification_repository
+            .mark_all_read(user_id, Utc::now())
+            .await?;
+        Ok(MarkAllReadResponse { marked })
+    }
+
+    pub async fn preferences(&self, user_id: Uuid) -> AppResult<NotificationPreferencesResponse> {
+        let saved = self
+            .notification_repository
+            .find_preferences(user_id)
+            .await?;
+        let preferences = NotificationEvent::ALL
+            .into_iter()
+            .map(|event| {
+                saved
+                    .iter()
+                    .find(|p| p.event == event)
+                    .copied()
+                    .unwrap_or_else(|| NotificationPreference::default_for(event))
+            })
+            .map(NotificationPreferenceDto::from)
+            .collect();
+        Ok(NotificationPreferencesResponse { preferences })
+    }
+
+    pub async fn update_preferences(
+        &self,
+        user_id: Uuid,
+        request: UpdateNotificationPreferencesRequest,
+    ) -> AppResult<NotificationPreferencesResponse> {
+        let mut events = HashSet::new();
+        if !request.preferences.iter().all(|p| events.insert(p.event)) {
+            return Err(AppError::Validation(
+                "Each event can only be listed once".to_string(),
+            ));
+        }
+
+        let preferences: Vec<NotificationPreference> = request
+            .preferences
+            .into_iter()
+            .map(NotificationPreference::from)
+            .collect();
+        self.notification_repository
+            .save_preferences(user_id, &preferences)
+            .await?;
+        self.preferences(user_id).await
+    }
+}
+
+/// Notifies users of events, as their preferences allow. Notifying never
+/// fails the action that caused it; errors are logged instead.
+#[derive(Clone)]
+pub struct Notifier {
+    notification_repository: Arc<dyn NotificationRepository>,
+    user_repository: Arc<dyn UserRepository>,
+    channels: NotificationChannels,
+}
+
+impl Notifier {
+    pub fn new(
+        notification_repository: Arc<dyn NotificationRepository>,
+        user_repository: Arc<dyn UserRepository>,
+        channels: NotificationChannels,
+    ) -> Self {
+        Self {
+            notification_repository,
+            user_repository,
+            channels,
+        }
+    }
+
+    pub async fn notify(&self, notification: Notification) {
+        let id = notification.id;
+        if let Err(e) = self.try_notify(notification).await {
+            tracing::error!("Failed to send notification {}: {:?}", id, e);
+        }
+    }
+
+    async fn try_notify(&self, notification: Notification) -> AppResult<()> {
+        let preference = self
+            .notification_repository
+            .find_preference(notification.user_id, notification.event)
+            .await?
+            .unwrap_or_else(|| NotificationPreference::default_for(notification.event));
+
+        if preference.in_app {
+            self.notification_repository.create(&notification).await?;
+        }
+        if preference.email {
+            let (Some(user), Some(channel)) = (
+                self.user_repository
+                    .find_by_id(notification.user_id)
+                    .await?,
+                self.channels.get(DeliveryChannel::Email),
+            ) else {
+                return Ok(());
+            };
+            let recipient = Recipient {
+                user_id: user.id,
+                email: user.email,
+                webhook_url: None,
+            };
+            // Unlike reminder deliveries these emails are not retried, so
+            // the request that caused them need not wait for the mail service
+            tokio::spawn(async move {
+                if let Err(e) = channel.send(&recipient, &notification).await {
+                    tracing::warn!("Failed to email notification {}: {}", notification.id, e);
+                }
+            });
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,124 @@
+use std::sync::Arc;
+use uuid::Uuid;
+
+use crate::application::dto::{
+    CreateProjectRequest, ProjectListResponse, ProjectResponse, UpdateProjectRequest,
+    UpdateWorkflowRequest, WorkflowResponse,
+};
+use crate::domain::entities::{Project, ProjectName, WorkspaceRole};
+use crate::domain::repositories::{ProjectRepository, WorkflowRepository};
+use crate::shared::error::{AppError, AppResult};
+
+/// Projects of the workspace the request works in. Every member sees them;
+/// only owners and admins change them or their workflows.
+pub struct ProjectService {
+    project_repository: Arc<dyn ProjectRepository>,
+    workflow_repository: Arc<dyn WorkflowRepository>,
+}
+
+impl ProjectService {
+    pub fn new(
+        project_repository: Arc<dyn ProjectRepository>,
+        workflow_repository: Arc<dyn WorkflowRepository>,
+    ) -> Self {
+        Self {
+            project_repository,
+            workflow_repository,
+        }
+    }
+
+    pub async fn create(
+        &self,
+        workspace_id: Uuid,
+        role: WorkspaceRole,
+        request: CreateProjectRequest,
+    ) -> AppResult<ProjectResponse> {
+        if !role.can_configure() {
+            return Err(AppError::Forbidden);
+        }
+        let name = ProjectName::new(request.name).map_err(AppError::Validation)?;
+
+        let created = self
+            .project_repository
+            .create(&Project::new(workspace_id, name))
+            .await?;
+        Ok(ProjectResponse::from(created))
+    }
+
+    pub async fn list(&self) -> AppResult<ProjectListResponse> {
+        let projects = self.project_repository.find_all().await?;
+        Ok(ProjectListResponse {
+            projects: projects.into_iter().map(ProjectResponse::from).collect(),
+        })
+    }
+
+    pub async fn get(&self, id: Uuid) -> AppResult<ProjectResponse> {
+        let project = self.find(id).await?;
+        Ok(ProjectResponse::from(project))
+    }
+
+    pub async fn update(
+        &self,
+        role: WorkspaceRole,
+        id: Uuid,
+        request: UpdateProjectRequest,
+    ) -> AppResult<ProjectResponse> {
+        if !role.can_configure() {
+            return Err(AppError::Forbidden);
+        }
+        let mut project = self.find(id).await?;
+        let name = ProjectName::new(request.name).map_err(AppError::Validation)?;
+
+        project.rename(name);
+        let updated = self.project_repository.update(&project).await?;
+        Ok(ProjectResponse::from(updated))
+    }
+
+    /// Deletes a project once it has no todos left.
+    pub async fn delete(&self, role: WorkspaceRole, id: Uuid) -> AppResult<()> {
+        if !role.can_configure() {
+            return Err(AppError::Forbidden);
+        }
+        if !self.project_repository.delete(id).await? {
+            return Err(project_not_found());
+        }
+        Ok(())
+    }
+
+    /// The workflow the project's todos follow.
+    pub async fn workflow(&self, id: Uuid) -> AppResult<WorkflowResponse> {
+        self.find(id).await?;
+        let workflow = self.workflow_repository.find(Some(id)).await?;
+        Ok(WorkflowResponse::from(&workflow))
+    }
+
+    /// Gives the project a workflow of its own, only while none of its todos
+    /// is in a status the workflow drops.
+    pub async fn replace_workflow(
+        &self,
+        role: WorkspaceRole,
+        id: Uuid,
+        request: UpdateWorkflowRequest,
+    ) -> AppResult<WorkflowResponse> {
+        if !role.can_configure() {
+            return Err(AppError::Forbidden);
+        }
+        self.find(id).await?;
+        let workflow = request.workflow().map_err(AppError::Validation)?;
+        self.workflow_repository
+            .replace(Some(id), &workflow)
+            .await?;
+        Ok(WorkflowResponse::from(&workflow))
+    }
+
+    async fn find(&self, id: Uuid) -> AppResult<Project> {
+        self.project_repository
+            .find_by_id(id)
+            .await?
+            .ok_or_else(project_not_found)
+    }
+}
+
+fn project_not_found() -> AppError {
+    AppError::NotFound("Project not found".to_string())
+}
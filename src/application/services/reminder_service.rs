@@ -0,0 +1,137 @@
+use std::sync::Arc;
+
+use chrono::Utc;
+use uuid::Uuid;
+
+use crate::application::dto::{
+    CreateReminderRequest, ReminderDeliveryListResponse, ReminderListResponse, ReminderResponse,
+};
+use crate::application::policies::TodoPolicy;
+use crate::domain::entities::{
+    Reminder, ReminderTrigger, Todo, TodoPermission, WebhookUrl, MAX_REMINDERS_PER_TODO,
+};
+use crate::domain::repositories::{ReminderRepository, TodoRepository};
+use crate::shared::error::{AppError, AppResult};
+
+/// Users' own reminders of the todos they can see.
+pub struct ReminderService {
+    reminder_repository: Arc<dyn ReminderRepository>,
+    policy: TodoPolicy,
+}
+
+impl ReminderService {
+    pub fn new(
+        reminder_repository: Arc<dyn ReminderRepository>,
+        todo_repository: Arc<dyn TodoRepository>,
+    ) -> Self {
+        Self {
+            reminder_repository,
+            policy: TodoPolicy::new(todo_repository),
+        }
+    }
+
+    pub async fn create(
+        &self,
+        user_id: Uuid,
+        todo_id: Uuid,
+        request: CreateReminderRequest,
+    ) -> AppResult<ReminderResponse> {
+        let todo = self.find_todo(user_id, todo_id).await?;
+        let trigger = match (request.remind_at, request.offset_minutes) {
+            (Some(at), None) => ReminderTrigger::At(at),
+            (None, Some(minutes)) => ReminderTrigger::BeforeDue(minutes),
+            _ => {
+                return Err(AppError::Validation(
+                    "Set exactly one of remind_at and offset_minutes".to_string(),
+                ))
+            }
+        };
+        let webhook_url = request
+            .webhook_url
+            .map(WebhookUrl::new)
+            .transpose()
+            .map_err(AppError::Validation)?;
+
+        let existing = self
+            .reminder_repository
+            .find_by_todo(todo.id, user_id)
+            .await?;
+        if existing.len() >= MAX_REMINDERS_PER_TODO {
+            return Err(AppError::Validation(format!(
+                "A todo can have at most {} reminders",
+                MAX_REMINDERS_PER_TODO
+            )));
+        }
+
+        let reminder = Reminder::new(
+            &todo,
+            user_id,
+            trigger,
+            request.channels,
+            webhook_url,
+            Utc::now(),
+        )
+        .map_err(AppError::Validation)?;
+        let created = self.reminder_repository.create(&reminder).await?;
+        Ok(ReminderResponse::new(created, todo.due_at))
+    }
+
+    pub async fn list(&self, user_id: Uuid, todo_id: Uuid) -> AppResult<ReminderListResponse> {
+        let todo = self.find_todo(user_id, todo_id).await?;
+        let reminders = self
+            .reminder_repository
+            .find_by_todo(todo.id, user_id)
+            .await?;
+        Ok(ReminderListResponse {
+            reminders: reminders
+                .into_iter()
+                .map(|r| ReminderResponse::new(r, todo.due_at))
+                .collect(),
+        })
+    }
+
+    pub async fn delete(&self, user_id: Uuid, todo_id: Uuid, reminder_id: Uuid) -> AppResult<()> {
+        let todo = self.find_todo(user_id, todo_id).await?;
+        if !self
+            .reminder_repository
+            .delete(reminder_id, todo.id, user_id)
+            .await?
+        {
+            return Err(reminder_not_found());
+        }
+        Ok(())
+    }
+
+    /// The delivery log of one of the user's reminders.
+    pub async fn deliveries(
+        &self,
+        user_id: Uuid,
+        todo_id: Uuid,
+        reminder_id: Uuid,
+    ) -> AppResult<ReminderDeliveryListResponse> {
+        let todo = self.find_todo(user_id, todo_id).await?;
+        let reminder = self
+            .reminder_repository
+            .find_by_id(reminder_id, todo.id, user_id)
+            .await?
+            .ok_or_else(reminder_not_found)?;
+        let deliveries = self
+            .reminder_repository
+            .find_deliveries(reminder.id)
+            .await?;
+        Ok(ReminderDeliveryListResponse {
+            deliveries: deliveries.into_iter().map(Into::into).collect(),
+        })
+    }
+
+    /// Anyone who can see a todo can be reminded of it.
+    async fn find_todo(&self, user_id: Uuid, todo_id: Uuid) -> AppResult<Todo> {
+        self.policy
+            .authorize(user_id, todo_id, TodoPermission::View)
+            .await
+    }
+}
+
+fn reminder_not_found() -> AppError {
+    AppError::NotFound("Reminder not found".to_string())
+}
@@ -0,0 +1,146 @@
+use chrono::Utc;
+use std::sync::Arc;
+use uuid::Uuid;
+
+use crate::application::dto::{
+    CreateSavedFilterRequest, FilterTodosQuery, SavedFilterListResponse, SavedFilterResponse,
+    TodoListQuery, TodoListResponse, UpdateSavedFilterRequest,
+};
+use crate::application::services::{Notifier, TodoService};
+use crate::domain::entities::{FilterDefinition, SavedFilter, SavedFilterName};
+use crate::domain::repositories::{SavedFilterRepository, TodoRepository, WorkflowRepository};
+use crate::shared::error::{AppError, AppResult};
+
+/// Filters users save to list the same todos again, evaluated like the
+/// todo list endpoint's query parameters.
+pub struct SavedFilterService {
+    saved_filter_repository: Arc<dyn SavedFilterRepository>,
+    todo_repository: Arc<dyn TodoRepository>,
+    todo_service: TodoService,
+}
+
+impl SavedFilterService {
+    pub fn new(
+        saved_filter_repository: Arc<dyn SavedFilterRepository>,
+        todo_repository: Arc<dyn TodoRepository>,
+        workflow_repository: Arc<dyn WorkflowRepository>,
+        notifier: Notifier,
+    ) -> Self {
+        Self {
+            saved_filter_repository,
+            todo_service: TodoService::new(todo_repository.clone(), workflow_repository, notifier),
+            todo_repository,
+        }
+    }
+
+    pub async fn create(
+        &self,
+        user_id: Uuid,
+        request: CreateSavedFilterRequest,
+    ) -> AppResult<SavedFilterResponse> {
+        let name = SavedFilterName::new(request.name).map_err(AppError::Validation)?;
+        validate(&request.definition)?;
+
+        let filter = SavedFilter::new(user_id, name, request.definition);
+        let created = self.saved_filter_repository.create(&filter).await?;
+        self.respond(created).await
+    }
+
+    pub async fn list(&self, user_id: Uuid) -> AppResult<SavedFilterListResponse> {
+        let filters = self.saved_filter_repository.find_by_user(user_id).await?;
+        let mut responses = Vec::with_capacity(filters.len());
+        for filter in filters {
+            responses.push(self.respond(filter).await?);
+        }
+        Ok(SavedFilterListResponse { filters: responses })
+    }
+
+    pub async fn get(&self, user_id: Uuid, id: Uuid) -> AppResult<SavedFilterResponse> {
+        let filter = self.find(user_id, id).await?;
+        self.respond(filter).await
+    }
+
+    pub async fn update(
+        &self,
+        user_id: Uuid,
+        id: Uuid,
+        request: UpdateSavedFilterRequest,
+    ) -> AppResult<SavedFilterResponse> {
+        let mut filter = self.find(user_id, id).await?;
+        let name = SavedFilterName::new(request.name).map_err(AppError::Validation)?;
+        validate(&request.definition)?;
+
+        filter.redefine(name, request.definition);
+        let updated = self.saved_filter_repository.update(&filter).await?;
+        self.respond(updated).await
+    }
+
+    pub async fn delete(&self, user_id: Uuid, id: Uuid) -> AppResult<()> {
+        if !self.saved_filter_repository.delete(id, user_id).await? {
+            return Err(AppError::NotFound("Filter not found".to_string()));
+        }
+        Ok(())
+    }
+
+    /// Lists the filter's todos, which marks them all as read.
+    pub async fn todos(
+        &self,
+        user_id: Uuid,
+        id: Uuid,
+        query: FilterTodosQuery,
+    ) -> AppResult<TodoListResponse> {
+        let saved = self.find(user_id, id).await?;
+        let now = Utc::now();
+        let filter = saved.definition.filter(now).map_err(AppError::Validation)?;
+
+        let response = self
+            .todo_service
+            .list_matching(user_id, filter, &TodoListQuery::from(query))
+            .await?;
+        self.saved_filter_repository
+            .mark_viewed(saved.id, user_id, now)
+            .await?;
+        Ok(response)
+    }
+
+    async fn find(&self, user_id: Uuid, id: Uuid) -> AppResult<SavedFilter> {
+        self.saved_filter_repository
+            .find_by_id(id, user_id)
+            .await?
+            .ok_or_else(|| AppError::NotFound("Filter not found".to_string()))
+    }
+
+    /// The filter with its badge counts as of now.
+    async fn respond(&self, saved: SavedFilter) -> AppResult<SavedFilterResponse> {
+        let filter = saved
+            .definition
+            .filter(Utc::now())
+            .map_err(AppError::Validation)?;
+
+        let open_count = if filter.completed == Some(true) {
+            0
+        } else {
+            let mut open = filter.clone();
+            open.completed = Some(false);
+            self.todo_repository
+                .count_by_user(saved.user_id, &open)
+                .await?
+        };
+
+        let mut unread = filter;
+        unread.updated_after = saved.last_viewed_at;
+        let unread_count = self
+            .todo_repository
+            .count_by_user(saved.user_id, &unread)
+            .await?;
+
+        Ok(SavedFilterResponse::new(saved, open_count, unread_count))
+    }
+}
+
+fn validate(definition: &FilterDefinition) -> AppResult<()> {
+    definition
+        .filter(Utc::now())
+        .map(|_| ())
+        .map_err(AppError::Validation)
+}
@@ -0,0 +1,213 @@
+use std::sync::Arc;
+use uuid::Uuid;
+
+use crate::application::dto::{
+    PaginationQuery, ProjectShareListResponse, ProjectShareResponse, ShareTodoRequest,
+    SharedTodoListResponse, SharedTodoResponse, TodoShareListResponse, TodoShareResponse,
+};
+use crate::application::policies::TodoPolicy;
+use crate::domain::entities::{Project, TodoPermission, User, WorkspaceRole};
+use crate::domain::repositories::{
+    ProjectRepository, ProjectShareRepository, TodoRepository, TodoShareRepository, UserRepository,
+    WorkspaceRepository,
+};
+use crate::shared::error::{AppError, AppResult};
+
+/// Sharing of todos, by their owners, and of projects, by the workspace's
+/// owners and admins. A project share covers every todo in the project.
+pub struct ShareService {
+    share_repository: Arc<dyn TodoShareRepository>,
+    project_share_repository: Arc<dyn ProjectShareRepository>,
+    todo_repository: Arc<dyn TodoRepository>,
+    project_repository: Arc<dyn ProjectRepository>,
+    user_repository: Arc<dyn UserRepository>,
+    workspace_repository: Arc<dyn WorkspaceRepository>,
+    policy: TodoPolicy,
+}
+
+impl ShareService {
+    pub fn new(
+        share_repository: Arc<dyn TodoShareRepository>,
+        project_share_repository: Arc<dyn ProjectShareRepository>,
+        todo_repository: Arc<dyn TodoRepository>,
+        project_repository: Arc<dyn ProjectRepository>,
+        user_repository: Arc<dyn UserRepository>,
+        workspace_repository: Arc<dyn WorkspaceRepository>,
+    ) -> Self {
+        Self {
+            share_repository,
+            project_share_repository,
+            policy: TodoPolicy::new(todo_repository.clone()),
+            todo_repository,
+            project_repository,
+            user_repository,
+            workspace_repository,
+        }
+    }
+
+    /// Shares a todo with the member of `workspace_id` registered under
+    /// `request.email`, or changes the permission they already have.
+    pub async fn share(
+        &self,
+        user_id: Uuid,
+        workspace_id: Uuid,
+        todo_id: Uuid,
+        request: ShareTodoRequest,
+    ) -> AppResult<TodoShareResponse> {
+        let todo = self
+            .policy
+            .authorize(user_id, todo_id, TodoPermission::Share)
+            .await?;
+        let invitee = self.find_member(workspace_id, &request.email).await?;
+        if invitee.id == todo.user_id {
+            return Err(AppError::Validation(
+                "A todo cannot be shared with its owner".to_string(),
+            ));
+        }
+
+        let share = self
+            .share_repository
+            .upsert(todo.id, invitee.id, request.permission)
+            .await?;
+        Ok(TodoShareResponse::from(share))
+    }
+
+    pub async fn list(&self, user_id: Uuid, todo_id: Uuid) -> AppResult<TodoShareListResponse> {
+        let todo = self
+            .policy
+            .authorize(user_id, todo_id, TodoPermission::Share)
+            .await?;
+        let shares = self.share_repository.find_by_todo(todo.id).await?;
+
+        Ok(TodoShareListResponse {
+            shares: shares.into_iter().map(TodoShareResponse::from).collect(),
+        })
+    }
+
+    /// Revokes a share. Besides the owner, users can remove their own share.
+    pub async fn revoke(&self, user_id: Uuid, todo_id: Uuid, shared_with: Uuid) -> AppResult<()> {
+        let (todo, role) = self.policy.role(user_id, todo_id).await?;
+        if shared_with != user_id && !role.allows(TodoPermission::Share) {
+            return Err(AppError::Forbidden);
+        }
+
+        if !self.share_repository.delete(todo.id, shared_with).await? {
+            return Err(AppError::NotFound("Share not found".to_string()));
+        }
+        Ok(())
+    }
+
+    /// Todos other users have shared with `user_id`.
+    pub async fn shared_with_me(
+        &self,
+        user_id: Uuid,
+        pagination: PaginationQuery,
+    ) -> AppResult<SharedTodoListResponse> {
+        let todos = self
+            .todo_repository
+            .find_shared_with_user(user_id, pagination.per_page(), pagination.offset())
+            .await?;
+
+        let total = self.todo_repository.count_shared_with_user(user_id).await?;
+
+        Ok(SharedTodoListResponse {
+            todos: todos.into_iter().map(SharedTodoResponse::from).collect(),
+            total,
+            page: pagination.page(),
+            per_page: pagination.per_page(),
+        })
+    }
+
+    /// Shares a project, and so every todo in it, with the member of
+    /// `workspace_id` registered under `request.email`, or changes the
+    /// permission they already have.
+    pub async fn share_project(
+        &self,
+        workspace_id: Uuid,
+        role: WorkspaceRole,
+        project_id: Uuid,
+        request: ShareTodoRequest,
+    ) -> AppResult<ProjectShareResponse> {
+        if !role.can_configure() {
+            return Err(AppError::Forbidden);
+        }
+        let project = self.find_project(project_id).await?;
+        let invitee = self.find_member(workspace_id, &request.email).await?;
+
+        let share = self
+            .project_share_repository
+            .upsert(project.id, invitee.id, request.permission)
+            .await?;
+        Ok(ProjectShareResponse::from(share))
+    }
+
+    pub async fn list_project_shares(
+        &self,
+        role: WorkspaceRole,
+        project_id: Uuid,
+    ) -> AppResult<ProjectShareListResponse> {
+        if !role.can_configure() {
+            return Err(AppError::Forbidden);
+        }
+        let project = self.find_project(project_id).await?;
+        let shares = self
+            .project_share_repository
+            .find_by_project(project.id)
+            .await?;
+
+        Ok(ProjectShareListResponse {
+            shares: shares.into_iter().map(ProjectShareResponse::from).collect(),
+        })
+    }
+
+    /// Revokes a project share. Besides owners and admins, users can remove
+    /// their own share.
+    pub async fn revoke_project_share(
+        &self,
+        user_id: Uuid,
+        role: WorkspaceRole,
+        project_id: Uuid,
+        shared_with: Uuid,
+    ) -> AppResult<()> {
+        let project = self.find_project(project_id).await?;
+        if shared_with != user_id && !role.can_configure() {
+            return Err(AppError::Forbidden);
+        }
+
+        if !self
+            .project_share_repository
+            .delete(project.id, shared_with)
+            .await?
+        {
+            return Err(AppError::NotFound("Share not found".to_string()));
+        }
+        Ok(())
+    }
+
+    /// The member of `workspace_id` registered under `email`.
+    async fn find_member(&self, workspace_id: Uuid, email: &str) -> AppResult<User> {
+        let user = self
+            .user_repository
+            .find_by_email(email.trim())
+            .await?
+            .ok_or_else(|| AppError::NotFound("User not found".to_string()))?;
+        if self
+            .workspace_repository
+            .find_role(workspace_id, user.id)
+            .await?
+            .is_none()
+        {
+            return Err(AppError::Validation(
+                "Todos and projects can only be shared with members of the workspace".to_string(),
+            ));
+        }
+        Ok(user)
+    }
+
+    async fn find_project(&self, id: Uuid) -> AppResult<Project> {
+        self.project_repository
+            .find_by_id(id)
+            .await?
+            .ok_or_else(|| AppError::NotFound("Project not found".to_string()))
+    }
+}
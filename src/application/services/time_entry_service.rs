@@ -0,0 +1,167 @@
+use std::sync::Arc;
+
+use chrono::Utc;
+use uuid::Uuid;
+
+use crate::application::dto::{
+    CreateTimeEntryRequest, TimeEntryListResponse, TimeEntryResponse, TimeReportQuery,
+    TimeReportResponse,
+};
+use crate::application::policies::TodoPolicy;
+use crate::domain::entities::{
+    ReportDimension, ReportRange, TimeEntry, TimeEntryNote, TimeReport, TimeZoneName,
+    TodoPermission,
+};
+use crate::domain::repositories::{TimeEntryRepository, TodoRepository};
+use crate::shared::error::{AppError, AppResult};
+
+pub struct TimeEntryService {
+    time_entry_repository: Arc<dyn TimeEntryRepository>,
+    policy: TodoPolicy,
+}
+
+impl TimeEntryService {
+    pub fn new(
+        time_entry_repository: Arc<dyn TimeEntryRepository>,
+        todo_repository: Arc<dyn TodoRepository>,
+    ) -> Self {
+        Self {
+            time_entry_repository,
+            policy: TodoPolicy::new(todo_repository),
+        }
+    }
+
+    /// Starts a timer on the todo. Fails with `Conflict` while the user has
+    /// a timer running on any todo.
+    pub async fn start_timer(&self, user_id: Uuid, todo_id: Uuid) -> AppResult<TimeEntryResponse> {
+        let todo = self
+            .policy
+            .authorize(user_id, todo_id, TodoPermission::Edit)
+            .await?;
+
+        let entry = TimeEntry::start(todo.id, user_id);
+        let created = self.time_entry_repository.create(&entry).await?;
+        Ok(TimeEntryResponse::new(created, Utc::now()))
+    }
+
+    /// Stops the user's timer on the todo. Anyone who can see the todo can
+    /// stop their own timer, even after losing the right to edit it.
+    pub async fn stop_timer(&self, user_id: Uuid, todo_id: Uuid) -> AppResult<TimeEntryResponse> {
+        let todo = self
+            .policy
+            .authorize(user_id, todo_id, TodoPermission::View)
+            .await?;
+
+        let now = Utc::now();
+        let stopped = self
+            .time_entry_repository
+            .stop(user_id, todo.id, now)
+            .await?
+            .ok_or_else(|| AppError::NotFound("No timer is running on this todo".to_string()))?;
+        Ok(TimeEntryResponse::new(stopped, now))
+    }
+
+    pub async fn create(
+        &self,
+        user_id: Uuid,
+        todo_id: Uuid,
+        request: CreateTimeEntryRequest,
+    ) -> AppResult<TimeEntryResponse> {
+        let todo = self
+            .policy
+            .authorize(user_id, todo_id, TodoPermission::Edit)
+            .await?;
+        let note = request
+            .note
+            .map(TimeEntryNote::new)
+            .transpose()
+            .map_err(AppError::Validation)?;
+
+        let now = Utc::now();
+        let entry = TimeEntry::manual(
+            todo.id,
+            user_id,
+            request.started_at,
+            request.ended_at,
+            note,
+            now,
+        )
+        .map_err(AppError::Validation)?;
+        let created = self.time_entry_repository.create(&entry).await?;
+        Ok(TimeEntryResponse::new(created, now))
+    }
+
+    pub async fn list(&self, user_id: Uuid, todo_id: Uuid) -> AppResult<TimeEntryListResponse> {
+        let todo = self
+            .policy
+            .authorize(user_id, todo_id, TodoPermission::View)
+            .await?;
+
+        let now = Utc::now();
+        let entries: Vec<TimeEntryResponse> = self
+            .time_entry_repository
+            .find_by_todo(todo.id)
+            .await?
+            .into_iter()
+            .map(|entry| TimeEntryResponse::new(entry, now))
+            .collect();
+        Ok(TimeEntryListResponse {
+            total_seconds: entries.iter().map(|e| e.duration_seconds).sum(),
+            entries,
+        })
+    }
+
+    /// Deletes one of the user's own entries, running or not.
+    pub async fn delete(&self, user_id: Uuid, todo_id: Uuid, entry_id: Uuid) -> AppResult<()> {
+        let todo = self
+            .policy
+            .authorize(user_id, todo_id, TodoPermission::View)
+            .await?;
+        let entry = self
+            .time_entry_repository
+            .find_by_id(entry_id, todo.id)
+            .await?
+            .ok_or_else(|| AppError::NotFound("Time entry not found".to_string()))?;
+
+        if entry.user_id != user_id {
+            return Err(AppError::Forbidden);
+        }
+        self.time_entry_repository.delete(entry.id, todo.id).await?;
+        Ok(())
+    }
+
+    /// The user's tracked time over a range of days, across all their
+    /// workspaces.
+    pub async fn report(
+        &self,
+        user_id: Uuid,
+        query: &TimeReportQuery,
+    ) -> AppResult<TimeReportResponse> {
+        let time_zone = query
+            .time_zone
+            .clone()
+            .map(TimeZoneName::new)
+            .transpose()
+            .map_err(AppError::Validation)?
+            .unwrap_or_default();
+        let group_by = match query.group_by.as_deref() {
+            Some(list) => ReportDimension::parse_list(list).map_err(AppError::Validation)?,
+            None => vec![ReportDimension::Day],
+        };
+        let range =
+            ReportRange::new(query.from, query.to, time_zone.tz()).map_err(AppError::Validation)?;
+
+        let spans = self
+            .time_entry_repository
+            .tracked_spans(user_id, range.start(), range.end())
+            .await?;
+        let report = TimeReport::new(&spans, &range, &group_by);
+        Ok(TimeReportResponse::new(
+            report,
+            range.from,
+            range.to,
+            time_zone.value().to_string(),
+            group_by,
+        ))
+    }
+}
@@ -1,20 +1,47 @@
+use chrono::Utc;
+use std::collections::{HashMap, HashSet};
 use std::sync::Arc;
 use uuid::Uuid;
 
 use crate::application::dto::{
-    CreateTodoRequest, PaginationQuery, TodoListResponse, TodoResponse, UpdateTodoRequest,
+    AddChecklistItemRequest, AssignTodoRequest, BatchMode, BatchOperation, BatchOperationResult,
+    BatchOperationStatus, CreateTodoRequest, MoveTodoRequest, OccurrencePreviewQuery,
+    OccurrencePreviewResponse, PaginationQuery, PatchTodoRequest, QuickAddTodoRequest,
+    ReorderChecklistRequest, TodoBatchRequest, TodoBatchResponse, TodoHistoryListResponse,
+    TodoHistoryResponse, TodoListQuery, TodoListResponse, TodoResponse, TodoSearchQuery,
+    TodoSearchResponse, TodoSearchResult, UpdateTodoRequest, VersionPrecondition,
+    MAX_BATCH_OPERATIONS,
 };
-use crate::domain::entities::{Todo, TodoTitle};
-use crate::domain::repositories::TodoRepository;
-use crate::shared::error::{AppError, AppResult};
+use crate::application::policies::TodoPolicy;
+use crate::application::services::Notifier;
+use crate::domain::entities::{
+    parse_tags, ChecklistItem, Notification, QuickAdd, RecurrenceRule, SearchQuery, SortOrder,
+    TimeZoneName, Todo, TodoCursor, TodoFilter, TodoHistoryEntry, TodoId, TodoPermission,
+    TodoPriority, TodoSortField, TodoStatus, TodoTitle, Workflow, WorkflowStatus,
+};
+use crate::domain::repositories::{TodoChangeSet, TodoRepository, WorkflowRepository};
+use crate::shared::error::{AppError, AppResult, InputError};
+use crate::shared::patch::Patch;
 
 pub struct TodoService {
     todo_repository: Arc<dyn TodoRepository>,
+    workflow_repository: Arc<dyn WorkflowRepository>,
+    notifier: Notifier,
+    policy: TodoPolicy,
 }
 
 impl TodoService {
-    pub fn new(todo_repository: Arc<dyn TodoRepository>) -> Self {
-        Self { todo_repository }
+    pub fn new(
+        todo_repository: Arc<dyn TodoRepository>,
+        workflow_repository: Arc<dyn WorkflowRepository>,
+        notifier: Notifier,
+    ) -> Self {
+        Self {
+            policy: TodoPolicy::new(todo_repository.clone()),
+            todo_repository,
+            workflow_repository,
+            notifier,
+        }
     }
 
     pub async fn create(
@@ -22,72 +49,986 @@ impl TodoService {
         user_id: Uuid,
         request: CreateTodoRequest,
     ) -> AppResult<TodoResponse> {
-        let title = TodoTitle::new(request.title).map_err(AppError::Validation)?;
-        let todo = Todo::new(user_id, title, request.description);
-        let created = self.todo_repository.create(&todo).await?;
+        let workflow = self.workflow_repository.find(request.project_id).await?;
+        let todo = new_todo(user_id, request, &workflow)?;
+        let history = [TodoHistoryEntry::created(&todo, user_id)];
+        let created = self.todo_repository.create(&todo, &history).await?;
+        Ok(TodoResponse::from(created))
+    }
+
+    /// Creates a todo from one line of text, read in the request's time zone
+    /// (UTC when the client sends none).
+    pub async fn quick_add(
+        &self,
+        user_id: Uuid,
+        request: QuickAddTodoRequest,
+    ) -> AppResult<TodoResponse> {
+        let time_zone = request
+            .time_zone
+            .map(TimeZoneName::new)
+            .transpose()
+            .map_err(AppError::Validation)?
+            .unwrap_or_default();
+        let parsed = QuickAdd::parse(&request.text, Utc::now(), time_zone.tz())
+            .map_err(AppError::Validation)?;
+
+        let workflow = self.workflow_repository.find(None).await?;
+        let mut todo = Todo::new(user_id, parsed.title, None);
+        todo.status = workflow.initial().key.clone();
+        todo.priority = parsed.priority.unwrap_or_default();
+        todo.tags = parsed.tags;
+        todo.schedule(
+            parsed.due_at.into(),
+            parsed.recurrence_rule.into(),
+            Patch::Value(time_zone),
+        )
+        .map_err(AppError::Validation)?;
+
+        let history = [TodoHistoryEntry::created(&todo, user_id)];
+        let created = self.todo_repository.create(&todo, &history).await?;
         Ok(TodoResponse::from(created))
     }
 
     pub async fn get(&self, user_id: Uuid, todo_id: Uuid) -> AppResult<TodoResponse> {
         let todo = self
-            .todo_repository
-            .find_by_id(todo_id.into(), user_id)
-            .await?
-            .ok_or_else(|| AppError::NotFound("Todo not found".to_string()))?;
+            .policy
+            .authorize(user_id, todo_id, TodoPermission::View)
+            .await?;
 
         Ok(TodoResponse::from(todo))
     }
 
-    pub async fn list(
+    pub async fn list(&self, user_id: Uuid, query: TodoListQuery) -> AppResult<TodoListResponse> {
+        let mut filter = query.filter(user_id).map_err(AppError::Validation)?;
+        filter.query = query.query(user_id).map_err(|e| {
+            AppError::InvalidInput(InputError {
+                field: "q".to_string(),
+                position: e.position,
+                message: e.message,
+            })
+        })?;
+        self.list_matching(user_id, filter, &query).await
+    }
+
+    /// Lists the todos matching `filter`, sorted and paged as `query` asks;
+    /// the filter fields of `query` are ignored.
+    pub async fn list_matching(
         &self,
         user_id: Uuid,
-        pagination: PaginationQuery,
+        filter: TodoFilter,
+        query: &TodoListQuery,
     ) -> AppResult<TodoListResponse> {
-        let todos = self
+        filter.validate().map_err(AppError::Validation)?;
+        let sort = query.sort();
+        if sort.field == TodoSortField::Smart {
+            return self.list_smart(user_id, query, filter, sort.order).await;
+        }
+        let cursor = query.cursor().map_err(AppError::Validation)?;
+        let pagination = query.pagination();
+        let per_page = pagination.per_page();
+        let offset = if cursor.is_some() {
+            0
+        } else {
+            pagination.offset()
+        };
+
+        // Fetch one extra row to learn whether another page follows
+        let mut todos = self
             .todo_repository
-            .find_all_by_user(user_id, pagination.per_page(), pagination.offset())
+            .find_all_by_user(
+                user_id,
+                &filter,
+                sort,
+                cursor.as_ref(),
+                per_page + 1,
+                offset,
+            )
             .await?;
+        let has_more = todos.len() as i64 > per_page;
+        let backward = cursor.as_ref().is_some_and(|c| c.backward);
+        if has_more {
+            if backward {
+                todos.remove(0);
+            } else {
+                todos.pop();
+            }
+        }
+
+        let (has_next, has_prev) = match &cursor {
+            None => (has_more, pagination.page() > 1),
+            Some(c) if c.backward => (true, has_more),
+            Some(_) => (has_more, true),
+        };
+        let next_cursor = todos
+            .last()
+            .filter(|_| has_next)
+            .map(|t| TodoCursor::new(t, sort, false).encode());
+        let prev_cursor = todos
+            .first()
+            .filter(|_| has_prev)
+            .map(|t| TodoCursor::new(t, sort, true).encode());
+
+        let total = if query.include_total() {
+            Some(self.todo_repository.count_by_user(user_id, &filter).await?)
+        } else {
+            None
+        };
+
+        Ok(TodoListResponse {
+            todos: todos.into_iter().map(TodoResponse::from).collect(),
+            total,
+            page: cursor.is_none().then(|| pagination.page()),
+            per_page,
+            next_cursor,
+            prev_cursor,
+        })
+    }
+
+    /// Ranks matching open todos by `smart_score`, paging by offset: scores
+    /// change with time, so there are no cursors.
+    async fn list_smart(
+        &self,
+        user_id: Uuid,
+        query: &TodoListQuery,
+        mut filter: TodoFilter,
+        order: SortOrder,
+    ) -> AppResult<TodoListResponse> {
+        if filter.completed == Some(true) {
+            return Err(AppError::Validation(
+                "sort=smart only ranks open todos".to_string(),
+            ));
+        }
+        if query.cursor.is_some() {
+            return Err(AppError::Validation(
+                "sort=smart pages by page number, not cursor".to_string(),
+            ));
+        }
+        filter.completed = Some(false);
 
-        let total = self.todo_repository.count_by_user(user_id).await?;
+        let pagination = query.pagination();
+        let todos = self
+            .todo_repository
+            .find_smart(
+                user_id,
+                &filter,
+                Utc::now(),
+                order,
+                pagination.per_page(),
+                pagination.offset(),
+            )
+            .await?;
+        let total = if query.include_total() {
+            Some(self.todo_repository.count_by_user(user_id, &filter).await?)
+        } else {
+            None
+        };
 
         Ok(TodoListResponse {
             todos: todos.into_iter().map(TodoResponse::from).collect(),
             total,
+            page: Some(pagination.page()),
+            per_page: pagination.per_page(),
+            next_cursor: None,
+            prev_cursor: None,
+        })
+    }
+
+    pub async fn search(
+        &self,
+        user_id: Uuid,
+        request: TodoSearchQuery,
+    ) -> AppResult<TodoSearchResponse> {
+        let query = SearchQuery::parse(&request.q, request.lang).map_err(AppError::Validation)?;
+        let pagination = request.pagination();
+
+        let hits = self
+            .todo_repository
+            .search(user_id, &query, pagination.per_page(), pagination.offset())
+            .await?;
+
+        let total = self.todo_repository.count_search(user_id, &query).await?;
+
+        Ok(TodoSearchResponse {
+            results: hits.into_iter().map(TodoSearchResult::from).collect(),
+            total,
             page: pagination.page(),
             per_page: pagination.per_page(),
         })
     }
 
+    /// Replaces every editable field of a todo.
     pub async fn update(
         &self,
         user_id: Uuid,
         todo_id: Uuid,
         request: UpdateTodoRequest,
+        if_match: Option<VersionPrecondition>,
+        force: bool,
+    ) -> AppResult<TodoResponse> {
+        self.patch(user_id, todo_id, request.into(), if_match, force)
+            .await
+    }
+
+    /// Applies a JSON Merge Patch to a todo, moving it through the workflow
+    /// of its project, or of the workspace. A blocked todo is only completed
+    /// when `force` is set.
+    pub async fn patch(
+        &self,
+        user_id: Uuid,
+        todo_id: Uuid,
+        request: PatchTodoRequest,
+        if_match: Option<VersionPrecondition>,
+        force: bool,
+    ) -> AppResult<TodoResponse> {
+        let mut todo = self
+            .policy
+            .authorize(user_id, todo_id, TodoPermission::Edit)
+            .await?;
+        check_version(&todo, if_match.as_ref())?;
+
+        let workflow = self
+            .workflow_repository
+            .find(project_after(&todo, &request))
+            .await?;
+        let before = todo.clone();
+        let next = apply_patch(&workflow, &mut todo, request, force)?;
+        self.save(user_id, &before, todo, next).await
+    }
+
+    /// Applies a JSON Patch (RFC 6902) to the todo's `UpdateTodoRequest`
+    /// representation, then saves the result as a full replacement.
+    pub async fn json_patch(
+        &self,
+        user_id: Uuid,
+        todo_id: Uuid,
+        operations: json_patch::Patch,
+        if_match: Option<VersionPrecondition>,
+        force: bool,
+    ) -> AppResult<TodoResponse> {
+        let mut todo = self
+            .policy
+            .authorize(user_id, todo_id, TodoPermission::Edit)
+            .await?;
+        check_version(&todo, if_match.as_ref())?;
+
+        let mut document = serde_json::to_value(UpdateTodoRequest::from(&todo))
+            .map_err(|e| AppError::Internal(e.into()))?;
+        json_patch::patch(&mut document, &operations)
+            .map_err(|e| AppError::Validation(e.to_string()))?;
+        let replacement: UpdateTodoRequest = serde_json::from_value(document)
+            .map_err(|e| AppError::Validation(format!("Patched todo is invalid: {}", e)))?;
+
+        let replacement = PatchTodoRequest::from(replacement);
+        let workflow = self
+            .workflow_repository
+            .find(project_after(&todo, &replacement))
+            .await?;
+        let before = todo.clone();
+        let next = apply_patch(&workflow, &mut todo, replacement, force)?;
+        self.save(user_id, &before, todo, next).await
+    }
+
+    /// Assigns a todo to a user who can see it. Anyone who may edit the todo
+    /// can assign it; a new assignee other than themselves is notified.
+    pub async fn assign(
+        &self,
+        user_id: Uuid,
+        todo_id: Uuid,
+        request: AssignTodoRequest,
+        if_match: Option<VersionPrecondition>,
+    ) -> AppResult<TodoResponse> {
+        let mut todo = self
+            .policy
+            .authorize(user_id, todo_id, TodoPermission::Edit)
+            .await?;
+        check_version(&todo, if_match.as_ref())?;
+        match self.policy.role(request.assignee_id, todo_id).await {
+            Ok(_) => {}
+            Err(AppError::NotFound(_)) => {
+                return Err(AppError::Validation(
+                    "The assignee must have access to the todo".to_string(),
+                ))
+            }
+            Err(e) => return Err(e),
+        }
+
+        let before = todo.clone();
+        todo.assign(Some(request.assignee_id));
+        let notification = (before.assignee_id != todo.assignee_id
+            && request.assignee_id != user_id)
+            .then(|| Notification::assigned(&todo, request.assignee_id));
+        let response = self.save(user_id, &before, todo, None).await?;
+        if let Some(notification) = notification {
+            self.notifier.notify(notification).await;
+        }
+        Ok(response)
+    }
+
+    /// Clears a todo's assignee. Besides editors, the assignee can hand the
+    /// todo back.
+    pub async fn unassign(
+        &self,
+        user_id: Uuid,
+        todo_id: Uuid,
+        if_match: Option<VersionPrecondition>,
+    ) -> AppResult<TodoResponse> {
+        let (mut todo, role) = self.policy.role(user_id, todo_id).await?;
+        if !role.allows(TodoPermission::Edit) && todo.assignee_id != Some(user_id) {
+            return Err(AppError::Forbidden);
+        }
+        check_version(&todo, if_match.as_ref())?;
+
+        let before = todo.clone();
+        todo.assign(None);
+        self.save(user_id, &before, todo, None).await
+    }
+
+    pub async fn add_checklist_item(
+        &self,
+        user_id: Uuid,
+        todo_id: Uuid,
+        request: AddChecklistItemRequest,
+        if_match: Option<VersionPrecondition>,
+    ) -> AppResult<TodoResponse> {
+        let mut todo = self
+            .policy
+            .authorize(user_id, todo_id, TodoPermission::Edit)
+            .await?;
+        check_version(&todo, if_match.as_ref())?;
+        let item = ChecklistItem::new(request.text).map_err(AppError::Validation)?;
+
+        let before = todo.clone();
+        todo.add_checklist_item(item, request.position)
+            .map_err(AppError::Validation)?;
+        self.save(user_id, &before, todo, None).await
+    }
+
+    pub async fn reorder_checklist(
+        &self,
+        user_id: Uuid,
+        todo_id: Uuid,
+        request: ReorderChecklistRequest,
+        if_match: Option<VersionPrecondition>,
+    ) -> AppResult<TodoResponse> {
+        let mut todo = self
+            .policy
+            .authorize(user_id, todo_id, TodoPermission::Edit)
+            .await?;
+        check_version(&todo, if_match.as_ref())?;
+
+        let before = todo.clone();
+        todo.reorder_checklist(&request.item_ids)
+            .map_err(AppError::Validation)?;
+        self.save(user_id, &before, todo, None).await
+    }
+
+    /// Checks or unchecks a checklist item. Like any other change, this
+    /// bumps the todo's version.
+    pub async fn toggle_checklist_item(
+        &self,
+        user_id: Uuid,
+        todo_id: Uuid,
+        item_id: Uuid,
+        if_match: Option<VersionPrecondition>,
+    ) -> AppResult<TodoResponse> {
+        let mut todo = self
+            .policy
+            .authorize(user_id, todo_id, TodoPermission::Edit)
+            .await?;
+        check_version(&todo, if_match.as_ref())?;
+
+        let before = todo.clone();
+        if !todo.toggle_checklist_item(item_id) {
+            return Err(checklist_item_not_found());
+        }
+        self.save(user_id, &before, todo, None).await
+    }
+
+    pub async fn remove_checklist_item(
+        &self,
+        user_id: Uuid,
+        todo_id: Uuid,
+        item_id: Uuid,
+        if_match: Option<VersionPrecondition>,
+    ) -> AppResult<TodoResponse> {
+        let mut todo = self
+            .policy
+            .authorize(user_id, todo_id, TodoPermission::Edit)
+            .await?;
+        check_version(&todo, if_match.as_ref())?;
+
+        let before = todo.clone();
+        if !todo.remove_checklist_item(item_id) {
+            return Err(checklist_item_not_found());
+        }
+        self.save(user_id, &before, todo, None).await
+    }
+
+    /// Moves a todo on the board: into the `request.status` column, right
+    /// below `request.after_id` or at the top. Like any status change the
+    /// move has to follow the workflow.
+    pub async fn move_card(
+        &self,
+        user_id: Uuid,
+        todo_id: Uuid,
+        request: MoveTodoRequest,
+        if_match: Option<VersionPrecondition>,
+        force: bool,
+    ) -> AppResult<TodoResponse> {
+        let mut todo = self
+            .policy
+            .authorize(user_id, todo_id, TodoPermission::Edit)
+            .await?;
+        check_version(&todo, if_match.as_ref())?;
+        let status = TodoStatus::new(request.status).map_err(AppError::Validation)?;
+        if let Some(after_id) = request.after_id {
+            if after_id == todo_id {
+                return Err(AppError::Validation(
+                    "A todo cannot be placed below itself".to_string(),
+                ));
+            }
+            let (after, _) = self.policy.role(user_id, after_id).await?;
+            if after.status != status || after.project_id != todo.project_id {
+                return Err(AppError::Validation(
+                    "after_id must be a card in the target column".to_string(),
+                ));
+            }
+        }
+
+        let workflow = self.workflow_repository.find(todo.project_id).await?;
+        let target = workflow
+            .target(&todo, Some(status), None)
+            .map_err(AppError::Validation)?;
+        let before = todo.clone();
+        todo.update(None, Patch::Missing, target, force)
+            .map_err(AppError::Validation)?;
+        let next = next_occurrence(&workflow, &before, &todo);
+
+        let mut history: Vec<_> = TodoHistoryEntry::changed(&before, &todo, user_id)
+            .into_iter()
+            .collect();
+        history.extend(
+            next.iter()
+                .map(|next| TodoHistoryEntry::created(next, user_id)),
+        );
+        let moved = self
+            .todo_repository
+            .move_to(&todo, request.after_id.map(TodoId), next.as_ref(), &history)
+            .await?;
+        Ok(TodoResponse::from(moved))
+    }
+
+    /// Saves an updated todo, together with the next occurrence it spawned,
+    /// recording both in the history.
+    async fn save(
+        &self,
+        actor_id: Uuid,
+        before: &Todo,
+        todo: Todo,
+        next: Option<Todo>,
     ) -> AppResult<TodoResponse> {
+        let mut history: Vec<_> = TodoHistoryEntry::changed(before, &todo, actor_id)
+            .into_iter()
+            .collect();
+        let updated = match next {
+            Some(next) => {
+                history.push(TodoHistoryEntry::created(&next, actor_id));
+                self.todo_repository
+                    .complete_occurrence(&todo, &next, &history)
+                    .await?
+            }
+            None => self.todo_repository.update(&todo, &history).await?,
+        };
+        Ok(TodoResponse::from(updated))
+    }
+
+    /// Applies `request.operations` in order and writes the resulting changes
+    /// in one transaction. In all-or-nothing mode a single failed operation
+    /// discards every change.
+    pub async fn batch(
+        &self,
+        user_id: Uuid,
+        request: TodoBatchRequest,
+    ) -> AppResult<TodoBatchResponse> {
+        if request.operations.is_empty() {
+            return Err(AppError::Validation(
+                "A batch must contain at least one operation".to_string(),
+            ));
+        }
+        if request.operations.len() > MAX_BATCH_OPERATIONS {
+            return Err(AppError::Validation(format!(
+                "A batch cannot contain more than {} operations",
+                MAX_BATCH_OPERATIONS
+            )));
+        }
+
+        let ids: Vec<TodoId> = request
+            .operations
+            .iter()
+            .filter_map(|operation| match operation {
+                BatchOperation::Create(_) => None,
+                BatchOperation::Update(update) => Some(update.id.into()),
+                BatchOperation::Delete { id } => Some((*id).into()),
+            })
+            .collect();
+        // Each operation needs the same permission as its single-todo request
+        let roles = self.policy.roles(user_id, &ids).await?;
+        let authorize = |id: &TodoId, permission: TodoPermission| match roles.get(id) {
+            Some((_, role)) if role.allows(permission) => Ok(()),
+            Some(_) => Err(AppError::Forbidden),
+            None => Err(AppError::NotFound("Todo not found".to_string())),
+        };
+        let mut todos: HashMap<TodoId, Todo> = roles
+            .iter()
+            .map(|(id, (todo, _))| (*id, todo.clone()))
+            .collect();
+        let originals = todos.clone();
+
+        // The workflows of every project the operations touch
+        let mut project_ids: HashSet<Option<Uuid>> =
+            todos.values().map(|todo| todo.project_id).collect();
+        project_ids.insert(None);
+        for operation in &request.operations {
+            match operation {
+                BatchOperation::Create(create) => {
+                    project_ids.insert(create.project_id);
+                }
+                BatchOperation::Update(update) => {
+                    if let Patch::Value(project_id) = update.changes.project_id {
+                        project_ids.insert(Some(project_id));
+                    }
+                }
+                BatchOperation::Delete { .. } => {}
+            }
+        }
+        let mut workflows = HashMap::with_capacity(project_ids.len());
+        for project_id in project_ids {
+            let workflow = self.workflow_repository.find(project_id).await?;
+            workflows.insert(project_id, workflow);
+        }
+
+        // Operations see the effects of earlier ones in the same batch
+        let mut changes = TodoChangeSet::default();
+        let mut updated_ids = Vec::new();
+        let mut outcomes = Vec::with_capacity(request.operations.len());
+        for operation in request.operations {
+            let outcome = match operation {
+                BatchOperation::Create(create) => {
+                    let workflow = &workflows[&create.project_id];
+                    new_todo(user_id, create, workflow).map(|todo| {
+                        changes.created.push(todo.clone());
+                        Some(todo)
+                    })
+                }
+                BatchOperation::Update(update) => {
+                    let id = TodoId(update.id);
+                    let todo = authorize(&id, TodoPermission::Edit).and_then(|()| {
+                        todos
+                            .get_mut(&id)
+                            .filter(|todo| !todo.is_trashed())
+                            .ok_or_else(|| AppError::NotFound("Todo not found".to_string()))
+                    });
+                    match todo {
+                        Ok(todo) => {
+                            let mut changed = todo.clone();
+                            let workflow = &workflows[&project_after(todo, &update.changes)];
+                            apply_patch(workflow, &mut changed, update.changes, update.force).map(
+                                |next| {
+                                    changes.created.extend(next);
+                                    if !updated_ids.contains(&id) {
+                                        updated_ids.push(id);
+                                    }
+                                    *todo = changed.clone();
+                                    // The save checks the loaded version and bumps it once
+                                    changed.version += 1;
+                                    Some(changed)
+                                },
+                            )
+                        }
+                        Err(e) => Err(e),
+                    }
+                }
+                BatchOperation::Delete { id } => {
+                    let id = TodoId(id);
+                    let todo = authorize(&id, TodoPermission::Delete).and_then(|()| {
+                        todos
+                            .get_mut(&id)
+                            .filter(|todo| !todo.is_trashed())
+                            .ok_or_else(|| AppError::NotFound("Todo not found".to_string()))
+                    });
+                    match todo {
+                        Ok(todo) => {
+                            todo.trash();
+                            changes.trashed.push(id);
+                            Ok(None)
+                        }
+                        Err(e) => Err(e),
+                    }
+                }
+            };
+            outcomes.push(outcome);
+        }
+
+        // One history entry per todo, covering all of its operations
+        changes.history = changes
+            .created
+            .iter()
+            .map(|todo| TodoHistoryEntry::created(todo, user_id))
+            .collect();
+        let mut touched = updated_ids.clone();
+        touched.extend(
+            changes
+                .trashed
+                .iter()
+                .filter(|id| !updated_ids.contains(id)),
+        );
+        changes.history.extend(
+            touched
+                .iter()
+                .filter_map(|id| TodoHistoryEntry::changed(&originals[id], &todos[id], user_id)),
+        );
+        changes.updated = updated_ids
+            .iter()
+            .filter_map(|id| todos.remove(id))
+            .collect();
+
+        let failed = outcomes.iter().filter(|outcome| outcome.is_err()).count();
+        let rolled_back = failed > 0 && request.mode == BatchMode::AllOrNothing;
+        let committed = !rolled_back && !changes.is_empty();
+        if committed {
+            self.todo_repository
+                .apply_changes(user_id, &changes)
+                .await?;
+        }
+
+        let results = outcomes
+            .into_iter()
+            .enumerate()
+            .map(|(index, outcome)| match outcome {
+                Ok(todo) if rolled_back => BatchOperationResult {
+                    index,
+                    status: BatchOperationStatus::RolledBack,
+                    todo: todo.map(TodoResponse::from),
+                    error: None,
+                },
+                Ok(todo) => BatchOperationResult {
+                    index,
+                    status: BatchOperationStatus::Succeeded,
+                    todo: todo.map(TodoResponse::from),
+                    error: None,
+                },
+                Err(e) => BatchOperationResult {
+                    index,
+                    status: BatchOperationStatus::Failed,
+                    todo: None,
+                    error: Some(match e {
+                        AppError::NotFound(message) | AppError::Validation(message) => message,
+                        other => other.to_string(),
+                    }),
+                },
+            })
+            .collect::<Vec<_>>();
+
+        Ok(TodoBatchResponse {
+            committed,
+            succeeded: if rolled_back {
+                0
+            } else {
+                results.len() - failed
+            },
+            failed,
+            results,
+        })
+    }
+
+    pub async fn preview_occurrences(
+        &self,
+        user_id: Uuid,
+        todo_id: Uuid,
+        query: OccurrencePreviewQuery,
+    ) -> AppResult<OccurrencePreviewResponse> {
+        let todo = self
+            .policy
+            .authorize(user_id, todo_id, TodoPermission::View)
+            .await?;
+
+        Ok(OccurrencePreviewResponse {
+            todo_id: todo.id.0,
+            occurrences: todo.upcoming_occurrences(query.count()),
+            recurrence_rule: todo.recurrence_rule.map(|r| r.value().to_string()),
+            time_zone: todo.time_zone.value().to_string(),
+        })
+    }
+
+    /// Moves a todo to the trash.
+    pub async fn delete(
+        &self,
+        user_id: Uuid,
+        todo_id: Uuid,
+        if_match: Option<VersionPrecondition>,
+    ) -> AppResult<()> {
+        let mut todo = self
+            .policy
+            .authorize(user_id, todo_id, TodoPermission::Delete)
+            .await?;
+        check_version(&todo, if_match.as_ref())?;
+
+        let before = todo.clone();
+        todo.trash();
+        let history: Vec<_> = TodoHistoryEntry::changed(&before, &todo, user_id)
+            .into_iter()
+            .collect();
+        self.todo_repository.update(&todo, &history).await?;
+        Ok(())
+    }
+
+    pub async fn list_trash(
+        &self,
+        user_id: Uuid,
+        pagination: PaginationQuery,
+    ) -> AppResult<TodoListResponse> {
+        let todos = self
+            .todo_repository
+            .find_trashed_by_user(user_id, pagination.per_page(), pagination.offset())
+            .await?;
+
+        let total = self.todo_repository.count_trashed_by_user(user_id).await?;
+
+        Ok(TodoListResponse {
+            todos: todos.into_iter().map(TodoResponse::from).collect(),
+            total: Some(total),
+            page: Some(pagination.page()),
+            per_page: pagination.per_page(),
+            next_cursor: None,
+            prev_cursor: None,
+        })
+    }
+
+    pub async fn restore(&self, user_id: Uuid, todo_id: Uuid) -> AppResult<TodoResponse> {
         let mut todo = self
             .todo_repository
-            .find_by_id(todo_id.into(), user_id)
+            .find_trashed_by_id(todo_id.into(), user_id)
             .await?
-            .ok_or_else(|| AppError::NotFound("Todo not found".to_string()))?;
+            .ok_or_else(|| AppError::NotFound("Todo not found in trash".to_string()))?;
+
+        let before = todo.clone();
+        todo.restore();
+        let history: Vec<_> = TodoHistoryEntry::changed(&before, &todo, user_id)
+            .into_iter()
+            .collect();
+        let restored = self.todo_repository.update(&todo, &history).await?;
+        Ok(TodoResponse::from(restored))
+    }
 
-        let title = match request.title {
-            Some(t) => Some(TodoTitle::new(t).map_err(AppError::Validation)?),
-            None => None,
+    /// Changes to one todo, newest first. Trashed todos keep their history,
+    /// visible to their owner only.
+    pub async fn history(
+        &self,
+        user_id: Uuid,
+        todo_id: Uuid,
+        pagination: PaginationQuery,
+    ) -> AppResult<TodoHistoryListResponse> {
+        let owner_id = match self
+            .policy
+            .authorize(user_id, todo_id, TodoPermission::View)
+            .await
+        {
+            Ok(todo) => todo.user_id,
+            Err(AppError::NotFound(_)) => self
+                .todo_repository
+                .find_trashed_by_id(todo_id.into(), user_id)
+                .await?
+                .map(|todo| todo.user_id)
+                .ok_or_else(|| AppError::NotFound("Todo not found".to_string()))?,
+            Err(e) => return Err(e),
         };
 
-        todo.update(title, request.description, request.completed);
+        let entries = self
+            .todo_repository
+            .find_history_by_todo(
+                todo_id.into(),
+                owner_id,
+                pagination.per_page(),
+                pagination.offset(),
+            )
+            .await?;
 
-        let updated = self.todo_repository.update(&todo).await?;
-        Ok(TodoResponse::from(updated))
+        let total = self
+            .todo_repository
+            .count_history_by_todo(todo_id.into(), owner_id)
+            .await?;
+
+        Ok(TodoHistoryListResponse {
+            entries: entries.into_iter().map(TodoHistoryResponse::from).collect(),
+            total,
+            page: pagination.page(),
+            per_page: pagination.per_page(),
+        })
+    }
+
+    /// Changes to all of the user's todos, newest first.
+    pub async fn activity(
+        &self,
+        user_id: Uuid,
+        pagination: PaginationQuery,
+    ) -> AppResult<TodoHistoryListResponse> {
+        let entries = self
+            .todo_repository
+            .find_history_by_user(user_id, pagination.per_page(), pagination.offset())
+            .await?;
+
+        let total = self.todo_repository.count_history_by_user(user_id).await?;
+
+        Ok(TodoHistoryListResponse {
+            entries: entries.into_iter().map(TodoHistoryResponse::from).collect(),
+            total,
+            page: pagination.page(),
+            per_page: pagination.per_page(),
+        })
     }
 
-    pub async fn delete(&self, user_id: Uuid, todo_id: Uuid) -> AppResult<()> {
-        // Check if todo exists
+    /// Permanently deletes a todo that is already in the trash.
+    pub async fn delete_permanently(&self, user_id: Uuid, todo_id: Uuid) -> AppResult<()> {
         self.todo_repository
-            .find_by_id(todo_id.into(), user_id)
+            .find_trashed_by_id(todo_id.into(), user_id)
             .await?
-            .ok_or_else(|| AppError::NotFound("Todo not found".to_string()))?;
+            .ok_or_else(|| AppError::NotFound("Todo not found in trash".to_string()))?;
 
         self.todo_repository.delete(todo_id.into(), user_id).await
     }
 }
+
+fn checklist_item_not_found() -> AppError {
+    AppError::NotFound("Checklist item not found".to_string())
+}
+
+fn check_version(todo: &Todo, if_match: Option<&VersionPrecondition>) -> AppResult<()> {
+    match if_match {
+        Some(precondition) if !precondition.matches(todo.version) => Err(
+            AppError::PreconditionFailed("Todo has been modified".to_string()),
+        ),
+        _ => Ok(()),
+    }
+}
+
+/// A todo in the workflow's initial status.
+fn new_todo(user_id: Uuid, request: CreateTodoRequest, workflow: &Workflow) -> AppResult<Todo> {
+    let title = TodoTitle::new(request.title).map_err(AppError::Validation)?;
+    let mut todo = Todo::new(user_id, title, request.description);
+    todo.project_id = request.project_id;
+    todo.status = workflow.initial().key.clone();
+    todo.priority = request.priority.unwrap_or_default();
+    todo.tags = parse_tags(request.tags).map_err(AppError::Validation)?;
+    let (recurrence_rule, time_zone) =
+        parse_recurrence(request.recurrence_rule.into(), request.time_zone.into())?;
+    todo.schedule(request.due_at.into(), recurrence_rule, time_zone)
+        .map_err(AppError::Validation)?;
+    Ok(todo)
+}
+
+/// The project `todo` is in once `request` is applied.
+fn project_after(todo: &Todo, request: &PatchTodoRequest) -> Option<Uuid> {
+    let mut project_id = todo.project_id;
+    request.project_id.clone().apply(&mut project_id);
+    project_id
+}
+
+/// Applies `request` to `todo`, following `workflow`, the workflow of the
+/// project the todo ends up in. Completing an occurrence of a recurring todo
+/// returns the next occurrence, which the caller must save as well.
+fn apply_patch(
+    workflow: &Workflow,
+    todo: &mut Todo,
+    request: PatchTodoRequest,
+    force: bool,
+) -> AppResult<Option<Todo>> {
+    let project_id = project_after(todo, &request);
+    let title = request
+        .title
+        .required("title")
+        .map_err(AppError::Validation)?
+        .map(TodoTitle::new)
+        .transpose()
+        .map_err(AppError::Validation)?;
+    let status = request
+        .status
+        .required("status")
+        .map_err(AppError::Validation)?
+        .map(TodoStatus::new)
+        .transpose()
+        .map_err(AppError::Validation)?;
+    let completed = request
+        .completed
+        .required("completed")
+        .map_err(AppError::Validation)?;
+    if project_id != todo.project_id && workflow.get(todo.status.value()).is_none() {
+        return Err(AppError::Validation(format!(
+            "Status {} is not in the workflow of the todo's new project",
+            todo.status.value()
+        )));
+    }
+    let status = workflow
+        .target(todo, status, completed)
+        .map_err(AppError::Validation)?;
+
+    let priority = match request.priority {
+        Patch::Missing => None,
+        Patch::Null => Some(TodoPriority::default()),
+        Patch::Value(priority) => Some(priority),
+    };
+    let tags = match request.tags {
+        Patch::Missing => None,
+        Patch::Null => Some(Vec::new()),
+        Patch::Value(tags) => Some(parse_tags(tags).map_err(AppError::Validation)?),
+    };
+
+    let (recurrence_rule, time_zone) =
+        parse_recurrence(request.recurrence_rule, request.time_zone)?;
+    let before = todo.clone();
+
+    if let Some(priority) = priority.filter(|p| *p != todo.priority) {
+        todo.prioritize(priority);
+    }
+    if let Some(tags) = tags.filter(|t| *t != todo.tags) {
+        todo.retag(tags);
+    }
+    if project_id != todo.project_id {
+        todo.move_to_project(project_id);
+    }
+
+    todo.update(title, request.description, status, force)
+        .map_err(AppError::Validation)?;
+    todo.schedule(request.due_at, recurrence_rule, time_zone)
+        .map_err(AppError::Validation)?;
+
+    Ok(next_occurrence(workflow, &before, todo))
+}
+
+/// The occurrence following `todo` if the change from `before` completed
+/// a recurring todo, in the workflow's initial status. Cancelled
+/// occurrences have no successor.
+fn next_occurrence(workflow: &Workflow, before: &Todo, todo: &Todo) -> Option<Todo> {
+    let done = workflow
+        .get(todo.status.value())
+        .is_some_and(WorkflowStatus::is_done);
+    if before.completed || !done {
+        return None;
+    }
+    todo.next_occurrence().map(|mut next| {
+        next.status = workflow.initial().key.clone();
+        next
+    })
+}
+
+fn parse_recurrence(
+    recurrence_rule: Patch<String>,
+    time_zone: Patch<String>,
+) -> AppResult<(Patch<RecurrenceRule>, Patch<TimeZoneName>)> {
+    let recurrence_rule = recurrence_rule
+        .try_map(RecurrenceRule::new)
+        .map_err(AppError::Validation)?;
+    let time_zone = time_zone
+        .try_map(TimeZoneName::new)
+        .map_err(AppError::Validation)?;
+    Ok((recurrence_rule, time_zone))
+}
@@ -0,0 +1,39 @@
+use std::sync::Arc;
+
+use crate::application::dto::{UpdateWorkflowRequest, WorkflowResponse};
+use crate::domain::entities::WorkspaceRole;
+use crate::domain::repositories::WorkflowRepository;
+use crate::shared::error::{AppError, AppResult};
+
+/// The workflow of the workspace the request works in.
+pub struct WorkflowService {
+    workflow_repository: Arc<dyn WorkflowRepository>,
+}
+
+impl WorkflowService {
+    pub fn new(workflow_repository: Arc<dyn WorkflowRepository>) -> Self {
+        Self {
+            workflow_repository,
+        }
+    }
+
+    pub async fn get(&self) -> AppResult<WorkflowResponse> {
+        let workflow = self.workflow_repository.find(None).await?;
+        Ok(WorkflowResponse::from(&workflow))
+    }
+
+    /// Replaces the workflow. Only owners and admins can change it, and only
+    /// while no todo is in a status it drops.
+    pub async fn replace(
+        &self,
+        role: WorkspaceRole,
+        request: UpdateWorkflowRequest,
+    ) -> AppResult<WorkflowResponse> {
+        if !role.can_configure() {
+            return Err(AppError::Forbidden);
+        }
+        let workflow = request.workflow().map_err(AppError::Validation)?;
+        self.workflow_repository.replace(None, &workflow).await?;
+        Ok(WorkflowResponse::from(&workflow))
+    }
+}
@@ -0,0 +1,267 @@
+use std::sync::Arc;
+use uuid::Uuid;
+
+use crate::application::dto::{
+    CreateWorkspaceRequest, InviteMemberRequest, UpdateMemberRequest,
+    WorkspaceInvitationListResponse, WorkspaceInvitationResponse, WorkspaceListResponse,
+    WorkspaceMemberListResponse, WorkspaceMemberResponse, WorkspaceResponse,
+};
+use crate::domain::entities::{User, Workspace, WorkspaceInvitation, WorkspaceName, WorkspaceRole};
+use crate::domain::repositories::{UserRepository, WorkspaceRepository};
+use crate::shared::error::{AppError, AppResult};
+
+pub struct WorkspaceService {
+    workspace_repository: Arc<dyn WorkspaceRepository>,
+    user_repository: Arc<dyn UserRepository>,
+}
+
+impl WorkspaceService {
+    pub fn new(
+        workspace_repository: Arc<dyn WorkspaceRepository>,
+        user_repository: Arc<dyn UserRepository>,
+    ) -> Self {
+        Self {
+            workspace_repository,
+            user_repository,
+        }
+    }
+
+    pub async fn list(&self, user_id: Uuid) -> AppResult<WorkspaceListResponse> {
+        let workspaces = self.workspace_repository.find_by_user(user_id).await?;
+
+        Ok(WorkspaceListResponse {
+            workspaces: workspaces
+                .into_iter()
+                .map(WorkspaceResponse::from)
+                .collect(),
+        })
+    }
+
+    /// Creates a workspace owned by `user_id`.
+    pub async fn create(
+        &self,
+        user_id: Uuid,
+        request: CreateWorkspaceRequest,
+    ) -> AppResult<WorkspaceResponse> {
+        let name = WorkspaceName::new(request.name).map_err(AppError::Validation)?;
+        let workspace = Workspace::new(name);
+        let created = self
+            .workspace_repository
+            .create(&workspace, user_id)
+            .await?;
+        Ok(WorkspaceResponse::from((created, WorkspaceRole::Owner)))
+    }
+
+    pub async fn members(
+        &self,
+        user_id: Uuid,
+        workspace_id: Uuid,
+    ) -> AppResult<WorkspaceMemberListResponse> {
+        self.find_role(user_id, workspace_id).await?;
+        let members = self.workspace_repository.find_members(workspace_id).await?;
+
+        Ok(WorkspaceMemberListResponse {
+            members: members
+                .into_iter()
+                .map(WorkspaceMemberResponse::from)
+                .collect(),
+        })
+    }
+
+    pub async fn update_member(
+        &self,
+        user_id: Uuid,
+        workspace_id: Uuid,
+        member_id: Uuid,
+        request: UpdateMemberRequest,
+    ) -> AppResult<WorkspaceMemberResponse> {
+        let role = self.find_role(user_id, workspace_id).await?;
+        let current = self.find_member_role(workspace_id, member_id).await?;
+        if !role.can_assign(current) || !role.can_assign(request.role) {
+            return Err(AppError::Forbidden);
+        }
+        if current == WorkspaceRole::Owner && request.role != WorkspaceRole::Owner {
+            self.ensure_other_owner(workspace_id).await?;
+        }
+
+        let member = self
+            .workspace_repository
+            .update_member_role(workspace_id, member_id, request.role)
+            .await?
+            .ok_or_else(|| AppError::NotFound("Member not found".to_string()))?;
+        Ok(WorkspaceMemberResponse::from(member))
+    }
+
+    /// Removes a member. Besides owners and admins, members can remove
+    /// themselves to leave the workspace.
+    pub async fn remove_member(
+        &self,
+        user_id: Uuid,
+        workspace_id: Uuid,
+        member_id: Uuid,
+    ) -> AppResult<()> {
+        let role = self.find_role(user_id, workspace_id).await?;
+        let current = self.find_member_role(workspace_id, member_id).await?;
+        if member_id != user_id && !role.can_assign(current) {
+            return Err(AppError::Forbidden);
+        }
+        if current == WorkspaceRole::Owner {
+            self.ensure_other_owner(workspace_id).await?;
+        }
+
+        if !self
+            .workspace_repository
+            .remove_member(workspace_id, member_id)
+            .await?
+        {
+            return Err(AppError::NotFound("Member not found".to_string()));
+        }
+        Ok(())
+    }
+
+    /// Invites `request.email` to the workspace. Whoever registers with that
+    /// email can accept the invitation.
+    pub async fn invite(
+        &self,
+        user_id: Uuid,
+        workspace_id: Uuid,
+        request: InviteMemberRequest,
+    ) -> AppResult<WorkspaceInvitationResponse> {
+        let role = self.find_role(user_id, workspace_id).await?;
+        if !role.can_assign(request.role) {
+            return Err(AppError::Forbidden);
+        }
+        let email = request.email.trim().to_string();
+        if email.is_empty() {
+            return Err(AppError::Validation("Email cannot be empty".to_string()));
+        }
+        if let Some(invitee) = self.user_repository.find_by_email(&email).await? {
+            if self
+                .workspace_repository
+                .find_role(workspace_id, invitee.id)
+                .await?
+                .is_some()
+            {
+                return Err(AppError::Conflict(
+                    "User is already a member of the workspace".to_string(),
+                ));
+            }
+        }
+
+        let workspace = self
+            .workspace_repository
+            .find_by_id(workspace_id)
+            .await?
+            .ok_or_else(|| AppError::NotFound("Workspace not found".to_string()))?;
+        let invitation = WorkspaceInvitation::new(&workspace, email, request.role, user_id);
+        let created = self
+            .workspace_repository
+            .create_invitation(&invitation)
+            .await?;
+        Ok(WorkspaceInvitationResponse::from(created))
+    }
+
+    /// Pending invitations addressed to the user's email.
+    pub async fn invitations(&self, user_id: Uuid) -> AppResult<WorkspaceInvitationListResponse> {
+        let user = self.find_user(user_id).await?;
+        let invitations = self
+            .workspace_repository
+            .find_invitations_by_email(&user.email)
+            .await?;
+
+        Ok(WorkspaceInvitationListResponse {
+            invitations: invitations
+                .into_iter()
+                .map(WorkspaceInvitationResponse::from)
+                .collect(),
+        })
+    }
+
+    pub async fn accept_invitation(
+        &self,
+        user_id: Uuid,
+        invitation_id: Uuid,
+    ) -> AppResult<WorkspaceResponse> {
+        let user = self.find_user(user_id).await?;
+        let invitation = self
+            .workspace_repository
+            .find_invitation(invitation_id)
+            .await?
+            .filter(|invitation| invitation.email == user.email)
+            .ok_or_else(|| AppError::NotFound("Invitation not found".to_string()))?;
+
+        let member = self
+            .workspace_repository
+            .accept_invitation(&invitation, user_id)
+            .await?;
+        let workspace = self
+            .workspace_repository
+            .find_by_id(invitation.workspace_id)
+            .await?
+            .ok_or_else(|| AppError::NotFound("Workspace not found".to_string()))?;
+        Ok(WorkspaceResponse::from((workspace, member.role)))
+    }
+
+    /// Discards an invitation, either declined by the invitee or withdrawn
+    /// by a manager of the workspace.
+    pub async fn delete_invitation(&self, user_id: Uuid, invitation_id: Uuid) -> AppResult<()> {
+        let user = self.find_user(user_id).await?;
+        let invitation = self
+            .workspace_repository
+            .find_invitation(invitation_id)
+            .await?
+            .ok_or_else(|| AppError::NotFound("Invitation not found".to_string()))?;
+
+        if invitation.email != user.email {
+            let role = self
+                .workspace_repository
+                .find_role(invitation.workspace_id, user_id)
+                .await?
+                .ok_or_else(|| AppError::NotFound("Invitation not found".to_string()))?;
+            if !role.can_assign(invitation.role) {
+                return Err(AppError::Forbidden);
+            }
+        }
+
+        self.workspace_repository
+            .delete_invitation(invitation.id)
+            .await
+    }
+
+    /// The user's role in the workspace. Workspaces they are not a member of
+    /// are not found.
+    async fn find_role(&self, user_id: Uuid, workspace_id: Uuid) -> AppResult<WorkspaceRole> {
+        self.workspace_repository
+            .find_role(workspace_id, user_id)
+            .await?
+            .ok_or_else(|| AppError::NotFound("Workspace not found".to_string()))
+    }
+
+    async fn find_member_role(
+        &self,
+        workspace_id: Uuid,
+        member_id: Uuid,
+    ) -> AppResult<WorkspaceRole> {
+        self.workspace_repository
+            .find_role(workspace_id, member_id)
+            .await?
+            .ok_or_else(|| AppError::NotFound("Member not found".to_string()))
+    }
+
+    async fn find_user(&self, user_id: Uuid) -> AppResult<User> {
+        self.user_repository
+            .find_by_id(user_id)
+            .await?
+            .ok_or_else(|| AppError::NotFound("User not found".to_string()))
+    }
+
+    /// Fails unless the workspace keeps an owner after one steps down.
+    async fn ensure_other_owner(&self, workspace_id: Uuid) -> AppResult<()> {
+        if self.workspace_repository.count_owners(workspace_id).await? < 2 {
+            return Err(AppError::Validation(
+                "A workspace must keep at least one owner".to_string(),
+            ));
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,110 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use super::todo::TodoId;
+
+/// Used when an upload has no usable file name.
+const DEFAULT_FILE_NAME: &str = "attachment";
+
+/// Longest file name kept, in characters.
+const MAX_FILE_NAME_LENGTH: usize = 255;
+
+#[derive(Debug, Clone, Serialize, Deserialize, sqlx::FromRow)]
+pub struct Attachment {
+    pub id: Uuid,
+    pub todo_id: TodoId,
+    /// Uploader, whose quota the attachment counts against
+    pub user_id: Uuid,
+    pub file_name: String,
+    /// Detected from the content, never taken from the client
+    pub content_type: String,
+    pub size_bytes: i64,
+    /// Key of the content in the object store
+    pub storage_key: String,
+    pub created_at: DateTime<Utc>,
+}
+
+impl Attachment {
+    /// Describes `data` uploaded as `file_name`. The file name is reduced to
+    /// its last path component and the content type is sniffed from `data`.
+    pub fn new(todo_id: TodoId, user_id: Uuid, file_name: &str, data: &[u8]) -> Self {
+        let id = Uuid::new_v4();
+        Self {
+            id,
+            todo_id,
+            user_id,
+            file_name: sanitize_file_name(file_name),
+            content_type: sniff_content_type(data).to_string(),
+            size_bytes: data.len() as i64,
+            storage_key: format!("attachments/{}/{}", user_id, id),
+            created_at: Utc::now(),
+        }
+    }
+}
+
+/// The last path component of `name` without control characters, or a
+/// default name if nothing is left.
+pub fn sanitize_file_name(name: &str) -> String {
+    let base = name.rsplit(['/', '\\']).next().unwrap_or_default();
+    let cleaned: String = base
+        .chars()
+        .filter(|c| !c.is_control())
+        .take(MAX_FILE_NAME_LENGTH)
+        .collect();
+    let cleaned = cleaned.trim();
+    if cleaned.is_empty() || cleaned == "." || cleaned == ".." {
+        DEFAULT_FILE_NAME.to_string()
+    } else {
+        cleaned.to_string()
+    }
+}
+
+/// Media type of `data` from its magic bytes. Anything unrecognised is plain
+/// text if it is valid UTF-8 and opaque binary otherwise.
+pub fn sniff_content_type(data: &[u8]) -> &'static str {
+    if let Some(kind) = infer::get(data) {
+        return kind.mime_type();
+    }
+    if std::str::from_utf8(data).is_ok() {
+        "text/plain; charset=utf-8"
+    } else {
+        "application/octet-stream"
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sanitize_file_name() {
+        assert_eq!(sanitize_file_name("report.pdf"), "report.pdf");
+        assert_eq!(sanitize_file_name("../../etc/passwd"), "passwd");
+        assert_eq!(sanitize_file_name("C:\\Users\\me\\shot.png"), "shot.png");
+        assert_eq!(sanitize_file_name("bad\r\nname.txt"), "badname.txt");
+        assert_eq!(sanitize_file_name("dir/"), "attachment");
+        assert_eq!(sanitize_file_name(".."), "attachment");
+        assert_eq!(sanitize_file_name(&"a".repeat(300)).len(), 255);
+    }
+
+    #[test]
+    fn test_sniff_content_type() {
+        let png = b"\x89PNG\r\n\x1a\n\0\0\0\rIHDR";
+        assert_eq!(sniff_content_type(png), "image/png");
+        assert_eq!(sniff_content_type(b"%PDF-1.7\n"), "application/pdf");
+        assert_eq!(
+            sniff_content_type("メモ".as_bytes()),
+            "text/plain; charset=utf-8"
+        );
+        assert_eq!(
+            sniff_content_type(&[0xff, 0xfe, 0x00, 0x01]),
+            "application/octet-stream"
+        );
+
+        // The client's file name has no say in the type
+        let attachment = Attachment::new(TodoId::new(), Uuid::new_v4(), "fake.png", b"hello");
+        assert_eq!(attachment.content_type, "text/plain; charset=utf-8");
+        assert_eq!(attachment.size_bytes, 5);
+    }
+}
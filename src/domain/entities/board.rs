@@ -0,0 +1,47 @@
+use chrono::{DateTime, Utc};
+
+/// Board position of a todo created at `created_at`: below every card
+/// placed before it.
+pub fn initial_position(created_at: DateTime<Utc>) -> f64 {
+    created_at.timestamp_micros() as f64 / 1_000_000.0
+}
+
+/// Position for a card dropped between the cards at `above` and `below`,
+/// either of which may be missing at the ends of a column. `None` when the
+/// two are too close to fit a card between them; the column then has to be
+/// renumbered.
+pub fn position_between(above: Option<f64>, below: Option<f64>) -> Option<f64> {
+    let position = match (above, below) {
+        (Some(above), Some(below)) => above + (below - above) / 2.0,
+        (Some(above), None) => above + 1.0,
+        (None, Some(below)) => below - 1.0,
+        (None, None) => 0.0,
+    };
+    let fits =
+        above.is_none_or(|above| above < position) && below.is_none_or(|below| position < below);
+    fits.then_some(position)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_position_between() {
+        assert_eq!(position_between(None, None), Some(0.0));
+        assert_eq!(position_between(Some(3.0), None), Some(4.0));
+        assert_eq!(position_between(None, Some(3.0)), Some(2.0));
+        assert_eq!(position_between(Some(1.0), Some(2.0)), Some(1.5));
+
+        // Repeated drops between the same cards eventually run out of room
+        let above = 1.0;
+        let mut below = 2.0;
+        let mut drops = 0;
+        while let Some(position) = position_between(Some(above), Some(below)) {
+            below = position;
+            drops += 1;
+        }
+        assert!(drops > 40);
+        assert_eq!(position_between(Some(2.0), Some(2.0)), None);
+    }
+}
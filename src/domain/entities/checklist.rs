@@ -0,0 +1,39 @@
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+/// Most checklist items a todo can have.
+pub const MAX_CHECKLIST_ITEMS: usize = 100;
+/// Longest checklist item text, in characters.
+pub const MAX_CHECKLIST_TEXT_LENGTH: usize = 500;
+
+/// A lightweight step of a todo, stored with the todo itself.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+pub struct ChecklistItem {
+    pub id: Uuid,
+    pub text: String,
+    pub checked: bool,
+}
+
+impl ChecklistItem {
+    pub fn new(text: String) -> Result<Self, String> {
+        let text = text.trim().to_string();
+        if text.is_empty() {
+            return Err("Checklist item text cannot be empty".to_string());
+        }
+        if text.chars().count() > MAX_CHECKLIST_TEXT_LENGTH {
+            return Err(format!(
+                "Checklist item text cannot be longer than {} characters",
+                MAX_CHECKLIST_TEXT_LENGTH
+            ));
+        }
+        if text.chars().any(char::is_control) {
+            return Err("Checklist item text cannot contain control characters".to_string());
+        }
+        Ok(Self {
+            id: Uuid::new_v4(),
+            text,
+            checked: false,
+        })
+    }
+}
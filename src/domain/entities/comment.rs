@@ -0,0 +1,149 @@
+use base64::engine::general_purpose::URL_SAFE_NO_PAD;
+use base64::Engine;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::Type;
+use uuid::Uuid;
+
+use super::todo::TodoId;
+
+/// Maximum length of a comment body, in characters.
+pub const MAX_COMMENT_LENGTH: usize = 10_000;
+
+/// Markdown source of a comment. It is stored as written, apart from
+/// normalized line endings, and never rendered server-side; clients must
+/// sanitize the HTML they render from it.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Type)]
+#[sqlx(transparent)]
+pub struct CommentBody(String);
+
+impl CommentBody {
+    pub fn new(body: String) -> Result<Self, String> {
+        let body = body.replace("\r\n", "\n").replace('\r', "\n");
+        if body.trim().is_empty() {
+            return Err("Comment cannot be empty".to_string());
+        }
+        if body.chars().count() > MAX_COMMENT_LENGTH {
+            return Err(format!(
+                "Comment cannot be longer than {} characters",
+                MAX_COMMENT_LENGTH
+            ));
+        }
+        if body
+            .chars()
+            .any(|c| c.is_control() && c != '\n' && c != '\t')
+        {
+            return Err("Comment cannot contain control characters".to_string());
+        }
+        Ok(Self(body))
+    }
+
+    pub fn value(&self) -> &str {
+        &self.0
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, sqlx::FromRow)]
+pub struct Comment {
+    pub id: Uuid,
+    pub todo_id: TodoId,
+    pub author_id: Uuid,
+    pub body: CommentBody,
+    pub created_at: DateTime<Utc>,
+    /// Set once the body has been edited
+    pub edited_at: Option<DateTime<Utc>>,
+}
+
+impl Comment {
+    pub fn new(todo_id: TodoId, author_id: Uuid, body: CommentBody) -> Self {
+        Self {
+            id: Uuid::new_v4(),
+            todo_id,
+            author_id,
+            body,
+            created_at: Utc::now(),
+            edited_at: None,
+        }
+    }
+
+    pub fn edit(&mut self, body: CommentBody) {
+        self.body = body;
+        self.edited_at = Some(Utc::now());
+    }
+
+    /// Only the author and the owner of the todo may edit or delete a comment.
+    pub fn can_modify(&self, user_id: Uuid, todo_owner_id: Uuid) -> bool {
+        user_id == self.author_id || user_id == todo_owner_id
+    }
+}
+
+/// A keyset pagination position in a todo's comments, which are listed
+/// oldest first: the comments after this one come next.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CommentCursor {
+    pub created_at: DateTime<Utc>,
+    pub id: Uuid,
+}
+
+impl CommentCursor {
+    pub fn new(comment: &Comment) -> Self {
+        Self {
+            created_at: comment.created_at,
+            id: comment.id,
+        }
+    }
+
+    /// Opaque URL-safe token for clients.
+    pub fn encode(&self) -> String {
+        let json = serde_json::to_vec(self).unwrap_or_default();
+        URL_SAFE_NO_PAD.encode(json)
+    }
+
+    pub fn decode(token: &str) -> Result<Self, String> {
+        let invalid = || "Invalid cursor".to_string();
+        let json = URL_SAFE_NO_PAD.decode(token).map_err(|_| invalid())?;
+        serde_json::from_slice(&json).map_err(|_| invalid())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_comment_body_validation() {
+        // Markdown is kept as written, with normalized line endings
+        let body = CommentBody::new("**Done**\r\n<script>alert(1)</script>".to_string()).unwrap();
+        assert_eq!(body.value(), "**Done**\n<script>alert(1)</script>");
+
+        assert_eq!(
+            CommentBody::new(" \n ".to_string()).unwrap_err(),
+            "Comment cannot be empty"
+        );
+        assert!(CommentBody::new("a".repeat(MAX_COMMENT_LENGTH)).is_ok());
+        assert!(CommentBody::new("a".repeat(MAX_COMMENT_LENGTH + 1)).is_err());
+        assert_eq!(
+            CommentBody::new("nul\0byte".to_string()).unwrap_err(),
+            "Comment cannot contain control characters"
+        );
+    }
+
+    #[test]
+    fn test_comment_permissions() {
+        let owner = Uuid::new_v4();
+        let author = Uuid::new_v4();
+        let comment = Comment::new(
+            TodoId::new(),
+            author,
+            CommentBody::new("Looks good".to_string()).unwrap(),
+        );
+
+        assert!(comment.can_modify(author, owner));
+        assert!(comment.can_modify(owner, owner));
+        assert!(!comment.can_modify(Uuid::new_v4(), owner));
+
+        let cursor = CommentCursor::new(&comment);
+        assert_eq!(CommentCursor::decode(&cursor.encode()).unwrap(), cursor);
+        assert!(CommentCursor::decode("not a cursor").is_err());
+    }
+}
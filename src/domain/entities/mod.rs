@@ -1,5 +1,59 @@
+pub mod attachment;
+pub mod board;
+pub mod checklist;
+pub mod comment;
+pub mod notification;
+pub mod priority;
+pub mod project;
+pub mod quick_add;
+pub mod recurrence;
+pub mod reminder;
+pub mod saved_filter;
+pub mod search;
+pub mod tag;
+pub mod time_entry;
 pub mod todo;
+pub mod todo_dependency;
+pub mod todo_filter;
+pub mod todo_history;
+pub mod todo_query;
+pub mod todo_share;
 pub mod user;
+pub mod workflow;
+pub mod workspace;
 
+pub use attachment::Attachment;
+pub use board::{initial_position, position_between};
+pub use checklist::{ChecklistItem, MAX_CHECKLIST_ITEMS, MAX_CHECKLIST_TEXT_LENGTH};
+pub use comment::{Comment, CommentBody, CommentCursor, MAX_COMMENT_LENGTH};
+pub use notification::{
+    Notification, NotificationCursor, NotificationEvent, NotificationPreference,
+};
+pub use priority::{smart_score, TodoPriority};
+pub use project::{Project, ProjectName};
+pub use quick_add::QuickAdd;
+pub use recurrence::{RecurrenceRule, TimeZoneName};
+pub use reminder::{
+    retry_delay, DeliveryChannel, DeliveryStatus, PendingDelivery, Reminder, ReminderDelivery,
+    ReminderTrigger, WebhookUrl, MAX_DELIVERY_ATTEMPTS, MAX_REMINDERS_PER_TODO,
+    MAX_REMINDER_OFFSET_MINUTES,
+};
+pub use saved_filter::{FilterDefinition, SavedFilter, SavedFilterName, MAX_DUE_WITHIN_DAYS};
+pub use search::{SearchLanguage, SearchQuery, SearchTerm, TodoSearchHit};
+pub use tag::{parse_tags, TodoTag, MAX_TAGS};
+pub use time_entry::{
+    ReportDimension, ReportRange, TimeEntry, TimeEntryNote, TimeReport, TimeReportRow, TrackedSpan,
+    MAX_MANUAL_ENTRY_HOURS, MAX_REPORT_DAYS, MAX_TIME_ENTRY_NOTE_LENGTH,
+};
 pub use todo::{Todo, TodoId, TodoTitle};
+pub use todo_dependency::{work_order, TodoDependency};
+pub use todo_filter::{CursorKey, SortOrder, TodoCursor, TodoFilter, TodoSort, TodoSortField};
+pub use todo_history::{TodoAction, TodoHistoryEntry};
+pub use todo_query::{Comparison, DateField, QueryError, TodoQuery};
+pub use todo_share::{ProjectShare, SharePermission, TodoPermission, TodoRole, TodoShare};
 pub use user::User;
+pub use workflow::{TodoStatus, Workflow, WorkflowStatus, MAX_WORKFLOW_STATUSES};
+pub use workspace::{
+    Workspace, WorkspaceInvitation, WorkspaceMember, WorkspaceName, WorkspaceRole,
+    PERSONAL_WORKSPACE_NAME,
+};
@@ -0,0 +1,240 @@
+use base64::engine::general_purpose::URL_SAFE_NO_PAD;
+use base64::Engine;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::Type;
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+use super::comment::Comment;
+use super::reminder::PendingDelivery;
+use super::todo::{Todo, TodoId};
+
+/// Characters of a comment quoted in the notification about it.
+const COMMENT_EXCERPT_LENGTH: usize = 200;
+
+/// What a notification is about.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, ToSchema, Type)]
+#[serde(rename_all = "snake_case")]
+#[sqlx(type_name = "notification_event", rename_all = "snake_case")]
+pub enum NotificationEvent {
+    /// A reminder of a todo fired
+    Reminder,
+    /// The user was assigned a todo
+    Assigned,
+    /// Someone commented on a todo the user created or is assigned
+    Comment,
+}
+
+impl NotificationEvent {
+    pub const ALL: [Self; 3] = [Self::Reminder, Self::Assigned, Self::Comment];
+}
+
+/// Where a user wants to hear about one event.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, sqlx::FromRow)]
+pub struct NotificationPreference {
+    pub event: NotificationEvent,
+    /// Whether the event creates a notification in the inbox
+    pub in_app: bool,
+    /// Whether the event is also emailed
+    pub email: bool,
+}
+
+impl NotificationPreference {
+    /// The preference of a user who has not set one. Reminders go wherever
+    /// each reminder asks; other events only reach the inbox.
+    pub fn default_for(event: NotificationEvent) -> Self {
+        Self {
+            event,
+            in_app: true,
+            email: event == NotificationEvent::Reminder,
+        }
+    }
+}
+
+/// A message for one user, shown in the app or sent on another channel.
+#[derive(Debug, Clone, Serialize, Deserialize, sqlx::FromRow)]
+pub struct Notification {
+    pub id: Uuid,
+    pub user_id: Uuid,
+    pub event: NotificationEvent,
+    /// The todo it is about; cleared when the todo is deleted
+    pub todo_id: Option<TodoId>,
+    pub title: String,
+    pub body: String,
+    pub created_at: DateTime<Utc>,
+    pub read_at: Option<DateTime<Utc>>,
+}
+
+impl Notification {
+    pub fn new(
+        user_id: Uuid,
+        event: NotificationEvent,
+        todo_id: Option<TodoId>,
+        title: String,
+        body: String,
+    ) -> Self {
+        Self {
+            id: Uuid::new_v4(),
+            user_id,
+            event,
+            todo_id,
+            title,
+            body,
+            created_at: Utc::now(),
+            read_at: None,
+        }
+    }
+
+    /// The notification a reminder delivery sends. It takes the delivery's
+    /// id, so retries of the delivery send the same notification.
+    pub fn reminder(delivery: &PendingDelivery) -> Self {
+        let body = match delivery.due_at {
+            Some(due_at) => format!(
+                "{} is due at {}",
+                delivery.title.value(),
+                due_at.format("%Y-%m-%d %H:%M UTC")
+            ),
+            None => format!("Reminder about {}", delivery.title.value()),
+        };
+        Self {
+            id: delivery.id,
+            ..Self::new(
+                delivery.user_id,
+                NotificationEvent::Reminder,
+                Some(delivery.todo_id),
+                format!("Reminder: {}", delivery.title.value()),
+                body,
+            )
+        }
+    }
+
+    /// Tells the assignee of `todo` that it is theirs.
+    pub fn assigned(todo: &Todo, assignee_id: Uuid) -> Self {
+        let body = match todo.due_at {
+            Some(due_at) => format!(
+                "You were assigned {}, due at {}",
+                todo.title.value(),
+                due_at.format("%Y-%m-%d %H:%M UTC")
+            ),
+            None => format!("You were assigned {}", todo.title.value()),
+        };
+        Self::new(
+            assignee_id,
+            NotificationEvent::Assigned,
+            Some(todo.id),
+            format!("Assigned: {}", todo.title.value()),
+            body,
+        )
+    }
+
+    /// Tells `recipient_id` about a comment on `todo`, quoting its start.
+    pub fn comment(todo: &Todo, comment: &Comment, recipient_id: Uuid) -> Self {
+        let text = comment.body.value();
+        let body = match text.char_indices().nth(COMMENT_EXCERPT_LENGTH) {
+            Some((end, _)) => format!("{}…", &text[..end]),
+            None => text.to_string(),
+        };
+        Self::new(
+            recipient_id,
+            NotificationEvent::Comment,
+            Some(todo.id),
+            format!("New comment on {}", todo.title.value()),
+            body,
+        )
+    }
+
+    pub fn is_read(&self) -> bool {
+        self.read_at.is_some()
+    }
+}
+
+/// A keyset pagination position in a user's notifications, which are listed
+/// newest first: the notifications before this one come next.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct NotificationCursor {
+    pub created_at: DateTime<Utc>,
+    pub id: Uuid,
+}
+
+impl NotificationCursor {
+    pub fn new(notification: &Notification) -> Self {
+        Self {
+            created_at: notification.created_at,
+            id: notification.id,
+        }
+    }
+
+    /// Opaque URL-safe token for clients.
+    pub fn encode(&self) -> String {
+        let json = serde_json::to_vec(self).unwrap_or_default();
+        URL_SAFE_NO_PAD.encode(json)
+    }
+
+    pub fn decode(token: &str) -> Result<Self, String> {
+        let invalid = || "Invalid cursor".to_string();
+        let json = URL_SAFE_NO_PAD.decode(token).map_err(|_| invalid())?;
+        serde_json::from_slice(&json).map_err(|_| invalid())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::entities::{CommentBody, TodoTitle};
+
+    #[test]
+    fn test_event_notifications() {
+        let owner = Uuid::new_v4();
+        let assignee = Uuid::new_v4();
+        let todo = Todo::new(owner, TodoTitle::new("Ship it".to_string()).unwrap(), None);
+
+        let assigned = Notification::assigned(&todo, assignee);
+        assert_eq!(assigned.user_id, assignee);
+        assert_eq!(assigned.event, NotificationEvent::Assigned);
+        assert_eq!(assigned.todo_id, Some(todo.id));
+        assert_eq!(assigned.title, "Assigned: Ship it");
+        assert_eq!(assigned.body, "You were assigned Ship it");
+        assert!(!assigned.is_read());
+
+        // Long comments are cut at a character boundary
+        let body = CommentBody::new("é".repeat(COMMENT_EXCERPT_LENGTH + 1)).unwrap();
+        let comment = Comment::new(todo.id, assignee, body);
+        let notification = Notification::comment(&todo, &comment, owner);
+        assert_eq!(notification.user_id, owner);
+        assert_eq!(notification.title, "New comment on Ship it");
+        assert_eq!(
+            notification.body,
+            format!("{}…", "é".repeat(COMMENT_EXCERPT_LENGTH))
+        );
+
+        let short = Comment::new(
+            todo.id,
+            assignee,
+            CommentBody::new("LGTM".to_string()).unwrap(),
+        );
+        assert_eq!(Notification::comment(&todo, &short, owner).body, "LGTM");
+    }
+
+    #[test]
+    fn test_notification_defaults_and_cursor() {
+        let reminder = NotificationPreference::default_for(NotificationEvent::Reminder);
+        assert!(reminder.in_app && reminder.email);
+        let comment = NotificationPreference::default_for(NotificationEvent::Comment);
+        assert!(comment.in_app && !comment.email);
+
+        let notification = Notification::new(
+            Uuid::new_v4(),
+            NotificationEvent::Comment,
+            None,
+            "Title".to_string(),
+            "Body".to_string(),
+        );
+        let cursor = NotificationCursor::new(&notification);
+        assert_eq!(
+            NotificationCursor::decode(&cursor.encode()).unwrap(),
+            cursor
+        );
+        assert!(NotificationCursor::decode("not a cursor").is_err());
+    }
+}
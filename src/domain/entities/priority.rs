@@ -0,0 +1,160 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::Type;
+use utoipa::ToSchema;
+
+use super::todo::Todo;
+
+/// How important a todo is, from `none` up to `urgent`.
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    Default,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    Hash,
+    Serialize,
+    Deserialize,
+    ToSchema,
+    Type,
+)]
+#[serde(rename_all = "snake_case")]
+#[sqlx(type_name = "todo_priority", rename_all = "snake_case")]
+pub enum TodoPriority {
+    #[default]
+    None,
+    Low,
+    Medium,
+    High,
+    Urgent,
+}
+
+impl TodoPriority {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::None => "none",
+            Self::Low => "low",
+            Self::Medium => "medium",
+            Self::High => "high",
+            Self::Urgent => "urgent",
+        }
+    }
+
+    /// Points the priority adds to the smart score.
+    pub fn weight(self) -> f64 {
+        match self {
+            Self::None => 0.0,
+            Self::Low => 10.0,
+            Self::Medium => 20.0,
+            Self::High => 30.0,
+            Self::Urgent => 50.0,
+        }
+    }
+}
+
+/// Points of a todo due right now.
+pub const DUE_NOW_POINTS: f64 = 40.0;
+/// Due dates further out than this add nothing.
+pub const DUE_WINDOW_DAYS: f64 = 14.0;
+/// Most points added for being overdue, one per day.
+pub const MAX_OVERDUE_POINTS: f64 = 10.0;
+pub const AGE_POINTS_PER_DAY: f64 = 0.5;
+pub const MAX_AGE_POINTS: f64 = 10.0;
+
+/// Score of an open todo for `sort=smart`; higher scores come first. Like
+/// an Eisenhower matrix it weighs importance and urgency, adding up:
+///
+/// - priority: 0 for none, 10 for low, 20 for medium, 30 for high and 50
+///   for urgent;
+/// - due date: 40 when due now, falling linearly to 0 for todos due 14 or
+///   more days out. Overdue todos get 40 plus 1 per day overdue, up to 50.
+///   Todos without a due date get 0;
+/// - age: 0.5 per day since the todo was created, up to 10, so that
+///   neglected todos surface eventually.
+///
+/// An urgent todo thus outranks an unprioritized one due today, and a high
+/// priority todo due tomorrow outranks an urgent one due in two weeks.
+///
+/// Lists are ranked by the database, which computes the same score from
+/// these weights; ties go to the todo due soonest, then to the oldest.
+pub fn smart_score(todo: &Todo, now: DateTime<Utc>) -> f64 {
+    let days = |duration: chrono::Duration| duration.num_seconds() as f64 / 86_400.0;
+
+    let due = match todo.due_at {
+        Some(due_at) if due_at <= now => {
+            DUE_NOW_POINTS + days(now - due_at).min(MAX_OVERDUE_POINTS)
+        }
+        Some(due_at) => DUE_NOW_POINTS * (1.0 - days(due_at - now) / DUE_WINDOW_DAYS).max(0.0),
+        None => 0.0,
+    };
+    let age = (days(now - todo.created_at).max(0.0) * AGE_POINTS_PER_DAY).min(MAX_AGE_POINTS);
+
+    todo.priority.weight() + due + age
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::entities::TodoTitle;
+    use chrono::Duration;
+    use uuid::Uuid;
+
+    fn todo(title: &str, priority: TodoPriority, due_in_days: Option<i64>) -> Todo {
+        let mut todo = Todo::new(
+            Uuid::new_v4(),
+            TodoTitle::new(title.to_string()).unwrap(),
+            None,
+        );
+        todo.priority = priority;
+        todo.due_at = due_in_days.map(|days| todo.created_at + Duration::days(days));
+        todo
+    }
+
+    #[test]
+    fn test_priority_order() {
+        assert!(TodoPriority::Urgent > TodoPriority::High);
+        assert!(TodoPriority::Low > TodoPriority::None);
+        assert_eq!(TodoPriority::default(), TodoPriority::None);
+    }
+
+    #[test]
+    fn test_smart_score() {
+        let at_creation = |todo: Todo| smart_score(&todo, todo.created_at);
+        let plain = todo("Plain", TodoPriority::None, None);
+        assert_eq!(at_creation(plain.clone()), 0.0);
+
+        // Each component on its own
+        assert_eq!(
+            at_creation(todo("Urgent", TodoPriority::Urgent, None)),
+            50.0
+        );
+        assert_eq!(
+            at_creation(todo("Today", TodoPriority::None, Some(0))),
+            40.0
+        );
+        assert_eq!(
+            at_creation(todo("In a week", TodoPriority::None, Some(7))),
+            20.0
+        );
+        assert_eq!(
+            at_creation(todo("Far off", TodoPriority::None, Some(30))),
+            0.0
+        );
+        let now = plain.created_at;
+        assert_eq!(smart_score(&plain, now + Duration::days(4)), 2.0);
+        assert_eq!(smart_score(&plain, now + Duration::days(60)), 10.0);
+
+        // Overdue points are capped
+        assert_eq!(
+            at_creation(todo("Overdue", TodoPriority::None, Some(-3))),
+            43.0
+        );
+        assert_eq!(
+            at_creation(todo("Ancient", TodoPriority::None, Some(-90))),
+            50.0
+        );
+    }
+}
@@ -0,0 +1,71 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::Type;
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, ToSchema, Type)]
+#[sqlx(transparent)]
+pub struct ProjectName(String);
+
+impl ProjectName {
+    pub fn new(name: String) -> Result<Self, String> {
+        let name = name.trim().to_string();
+        if name.is_empty() {
+            return Err("Project name cannot be empty".to_string());
+        }
+        if name.chars().count() > 100 {
+            return Err("Project name cannot be longer than 100 characters".to_string());
+        }
+        Ok(Self(name))
+    }
+
+    pub fn value(&self) -> &str {
+        &self.0
+    }
+}
+
+/// A group of todos within a workspace. Its todos follow the project's own
+/// workflow if it has one, else the workspace's.
+#[derive(Debug, Clone, Serialize, Deserialize, sqlx::FromRow)]
+pub struct Project {
+    pub id: Uuid,
+    pub workspace_id: Uuid,
+    pub name: ProjectName,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl Project {
+    pub fn new(workspace_id: Uuid, name: ProjectName) -> Self {
+        let now = Utc::now();
+        Self {
+            id: Uuid::new_v4(),
+            workspace_id,
+            name,
+            created_at: now,
+            updated_at: now,
+        }
+    }
+
+    pub fn rename(&mut self, name: ProjectName) {
+        self.name = name;
+        self.updated_at = Utc::now();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_project_name_is_trimmed_and_bounded() {
+        assert_eq!(
+            ProjectName::new("  Launch ".to_string()).unwrap().value(),
+            "Launch"
+        );
+        assert!(ProjectName::new(" ".to_string()).is_err());
+        assert!(ProjectName::new("x".repeat(101)).is_err());
+        assert!(ProjectName::new("é".repeat(100)).is_ok());
+    }
+}
@@ -0,0 +1,1161 @@
+use std::ops::Range;
+
+use chrono::{DateTime, Datelike, Days, Months, NaiveDate, NaiveTime, Utc, Weekday};
+use chrono_tz::Tz;
+
+use super::priority::TodoPriority;
+use super::recurrence::{resolve_local, weekday_code, RecurrenceRule};
+use super::tag::TodoTag;
+use super::todo::TodoTitle;
+
+const MAX_QUICK_ADD_LENGTH: usize = 500;
+/// How far ahead a due date implied by a time or recurrence is looked for.
+const MAX_LOOKAHEAD_DAYS: usize = 400;
+
+/// Words that belong to the date or time following them, as in `on friday`.
+const CONNECTORS: [&str; 4] = ["on", "at", "by", "due"];
+/// Japanese particles that belong to the date or time before them, as in
+/// `金曜までに`. Longest first.
+const PARTICLES: [&str; 4] = ["までに", "まで", "に", "の"];
+
+/// A todo typed as one line, e.g. `Pay rent tomorrow 9am #finance !high
+/// every month` or `明日9時に家賃を払う #家計 !高 毎月`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct QuickAdd {
+    pub title: TodoTitle,
+    pub due_at: Option<DateTime<Utc>>,
+    pub tags: Vec<TodoTag>,
+    pub priority: Option<TodoPriority>,
+    pub recurrence_rule: Option<RecurrenceRule>,
+}
+
+impl QuickAdd {
+    /// Picks the schedule, tags and priority out of `text`; what is left is
+    /// the title. Dates and times are read in `tz` relative to `now`.
+    ///
+    /// - Tags: `#finance`, `#家計`.
+    /// - Priority: `!low`, `!medium`, `!high`, `!urgent`, `!低`, `!中`, `!高`,
+    ///   `!緊急`.
+    /// - Dates: `today`, `tomorrow`, `day after tomorrow`, `in 3 days`,
+    ///   `in 2 weeks`, `in a month`, `friday` or `on fri` (the first one
+    ///   after today), `next friday` (the one in next week), `next week` (its
+    ///   Monday), `next month` (its first day), `nov 5`, `5th november`,
+    ///   `2026-11-05`, `2026/11/05`; `今日`, `明日`, `明後日`, `3日後`,
+    ///   `2週間後`, `1ヶ月後`, `金曜日`, `来週の金曜`, `来週`, `来月`, `11月5日`,
+    ///   `2026年11月5日`. Dates without a year are the next such day.
+    /// - Times: `9am`, `9:30 pm`, `21:00`, `noon`; `9時`, `9時半`,
+    ///   `午後3時15分`, `正午`.
+    /// - Recurrence: `every day`, `every 2 weeks`, `every other month`,
+    ///   `every monday`, `every weekday`; `毎日`, `毎週`, `毎月`, `毎週月曜`,
+    ///   `毎月25日`, `平日`, `3日ごと`.
+    ///
+    /// Words like `on` and `at` before a date or time, and particles like
+    /// `に` after one, are dropped with it. A date without a time is due at
+    /// 23:59; a time or recurrence without a date is due at its first
+    /// occurrence after `now`. Only the first date, time, recurrence and
+    /// priority count; repeats, and anything not understood, stay in the
+    /// title.
+    pub fn parse(text: &str, now: DateTime<Utc>, tz: Tz) -> Result<Self, String> {
+        if text.chars().count() > MAX_QUICK_ADD_LENGTH {
+            return Err(format!(
+                "Quick-add text cannot be longer than {} characters",
+                MAX_QUICK_ADD_LENGTH
+            ));
+        }
+        let today = now.with_timezone(&tz).date_naive();
+        let mut found = Found::default();
+        let mut spans: Vec<Span> = Vec::new();
+
+        // English phrases, matched word by word
+        let words = words(text);
+        let mut i = 0;
+        while i < words.len() {
+            let tail: Vec<&str> = words[i..].iter().map(|(_, w)| w.as_str()).collect();
+            if let Some((count, piece)) = english_piece(&tail, today) {
+                let schedule = piece.is_schedule();
+                if found.add(piece) {
+                    let range = words[i].0.start..words[i + count - 1].0.end;
+                    spans.push(Span { range, schedule });
+                    i += count;
+                    continue;
+                }
+            }
+            i += 1;
+        }
+
+        // Tags, priorities, numeric dates and times and Japanese phrases,
+        // which need no spaces around them
+        let mut index = 0;
+        while let Some(c) = text[index..].chars().next() {
+            if let Some(span) = spans.iter().find(|s| s.range.contains(&index)) {
+                index = span.range.end;
+                continue;
+            }
+            let previous = text[..index].chars().next_back();
+            if let Some((length, piece)) = piece_at(&text[index..], previous, today) {
+                let mut end = index + length;
+                let schedule = piece.is_schedule();
+                let free = !spans
+                    .iter()
+                    .any(|s| s.range.start < end && index < s.range.end);
+                let splits_number = text[..end].chars().next_back().is_some_and(is_digit)
+                    && text[end..].chars().next().is_some_and(is_digit);
+                if free && !splits_number && found.add(piece) {
+                    if schedule {
+                        let rest = &text[end..];
+                        end += PARTICLES
+                            .iter()
+                            .find(|p| rest.starts_with(*p))
+                            .map_or(0, |p| p.len());
+                    }
+                    spans.push(Span {
+                        range: index..end,
+                        schedule,
+                    });
+                    index = end;
+                    continue;
+                }
+            }
+            index += c.len_utf8();
+        }
+
+        for pair in words.windows(2) {
+            let ((range, word), (next, _)) = (&pair[0], &pair[1]);
+            let next_is_schedule = spans
+                .iter()
+                .any(|s| s.schedule && s.range.start <= next.start && next.end <= s.range.end);
+            if CONNECTORS.contains(&word.as_str()) && next_is_schedule {
+                spans.push(Span {
+                    range: range.clone(),
+                    schedule: false,
+                });
+            }
+        }
+
+        let kept: String = text
+            .char_indices()
+            .filter(|(i, _)| !spans.iter().any(|s| s.range.contains(i)))
+            .map(|(_, c)| c)
+            .collect();
+        let title = kept
+            .split_whitespace()
+            .collect::<Vec<_>>()
+            .join(" ")
+            .trim_matches([',', '、', ' '])
+            .to_string();
+
+        let due_at = found.due_at(now, tz);
+        let recurrence_rule = found
+            .repeat
+            .as_ref()
+            .map(|repeat| RecurrenceRule::new(repeat.rule()))
+            .transpose()?;
+        Ok(Self {
+            title: TodoTitle::new(title)?,
+            due_at,
+            tags: found.tags,
+            priority: found.priority,
+            recurrence_rule,
+        })
+    }
+}
+
+/// Part of the text that was understood and is left out of the title.
+struct Span {
+    range: Range<usize>,
+    /// Whether it is a date, time or recurrence.
+    schedule: bool,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Piece {
+    Date(NaiveDate),
+    Time(NaiveTime),
+    Repeat(Repeat),
+    Tag(TodoTag),
+    Priority(TodoPriority),
+}
+
+impl Piece {
+    fn is_schedule(&self) -> bool {
+        matches!(self, Self::Date(_) | Self::Time(_) | Self::Repeat(_))
+    }
+}
+
+#[derive(Debug, Default)]
+struct Found {
+    date: Option<NaiveDate>,
+    time: Option<NaiveTime>,
+    repeat: Option<Repeat>,
+    tags: Vec<TodoTag>,
+    priority: Option<TodoPriority>,
+}
+
+impl Found {
+    /// Records `piece`, unless one of its kind was found before.
+    fn add(&mut self, piece: Piece) -> bool {
+        fn set<T>(slot: &mut Option<T>, value: T) -> bool {
+            if slot.is_some() {
+                return false;
+            }
+            *slot = Some(value);
+            true
+        }
+        match piece {
+            Piece::Date(date) => set(&mut self.date, date),
+            Piece::Time(time) => set(&mut self.time, time),
+            Piece::Repeat(repeat) => set(&mut self.repeat, repeat),
+            Piece::Priority(priority) => set(&mut self.priority, priority),
+            Piece::Tag(tag) => {
+                if !self.tags.contains(&tag) {
+                    self.tags.push(tag);
+                }
+                true
+            }
+        }
+    }
+
+    fn due_at(&self, now: DateTime<Utc>, tz: Tz) -> Option<DateTime<Utc>> {
+        let time = self
+            .time
+            .unwrap_or_else(|| NaiveTime::from_hms_opt(23, 59, 0).unwrap());
+        let at = |date: NaiveDate| resolve_local(tz, date.and_time(time));
+        if let Some(date) = self.date {
+            return at(date);
+        }
+        if self.time.is_none() && self.repeat.is_none() {
+            return None;
+        }
+        now.with_timezone(&tz)
+            .date_naive()
+            .iter_days()
+            .take(MAX_LOOKAHEAD_DAYS)
+            .filter(|date| self.repeat.as_ref().is_none_or(|r| r.matches(*date)))
+            .filter_map(at)
+            .find(|due_at| *due_at > now)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Unit {
+    Day,
+    Week,
+    Month,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Repeat {
+    unit: Unit,
+    interval: u32,
+    weekdays: Vec<Weekday>,
+    month_day: Option<u32>,
+}
+
+impl Repeat {
+    fn every(unit: Unit, interval: u32) -> Self {
+        Self {
+            unit,
+            interval,
+            weekdays: Vec::new(),
+            month_day: None,
+        }
+    }
+
+    fn on(weekdays: &[Weekday]) -> Self {
+        Self {
+            weekdays: weekdays.to_vec(),
+            ..Self::every(Unit::Week, 1)
+        }
+    }
+
+    fn rule(&self) -> String {
+        let freq = match self.unit {
+            Unit::Day => "DAILY",
+            Unit::Week => "WEEKLY",
+            Unit::Month => "MONTHLY",
+        };
+        let mut rule = format!("FREQ={};INTERVAL={}", freq, self.interval);
+        if !self.weekdays.is_empty() {
+            let days: Vec<_> = self.weekdays.iter().map(|d| weekday_code(*d)).collect();
+            rule.push_str(&format!(";BYDAY={}", days.join(",")));
+        }
+        if let Some(day) = self.month_day {
+            rule.push_str(&format!(";BYMONTHDAY={}", day));
+        }
+        rule
+    }
+
+    /// Whether `date` can be the first occurrence.
+    fn matches(&self, date: NaiveDate) -> bool {
+        (self.weekdays.is_empty() || self.weekdays.contains(&date.weekday()))
+            && self.month_day.is_none_or(|day| date.day() == day)
+    }
+}
+
+const WEEKDAYS: [Weekday; 7] = [
+    Weekday::Mon,
+    Weekday::Tue,
+    Weekday::Wed,
+    Weekday::Thu,
+    Weekday::Fri,
+    Weekday::Sat,
+    Weekday::Sun,
+];
+
+/// The whitespace-separated words of `text` with their byte ranges,
+/// lowercased and without trailing punctuation.
+fn words(text: &str) -> Vec<(Range<usize>, String)> {
+    let mut words = Vec::new();
+    let mut start = None;
+    for (i, c) in text.char_indices().chain([(text.len(), ' ')]) {
+        match (start, c.is_whitespace()) {
+            (None, false) => start = Some(i),
+            (Some(s), true) => {
+                let word = text[s..i].trim_end_matches([',', '.', ';', ':', '?']);
+                words.push((s..i, word.to_lowercase()));
+                start = None;
+            }
+            _ => {}
+        }
+    }
+    words
+}
+
+/// An English date, time or recurrence at the start of `words`, with the
+/// number of words it takes.
+fn english_piece(words: &[&str], today: NaiveDate) -> Option<(usize, Piece)> {
+    if let Some((count, repeat)) = english_repeat(words) {
+        return Some((count, Piece::Repeat(repeat)));
+    }
+    if let Some((count, date)) = english_date(words, today) {
+        return Some((count, Piece::Date(date)));
+    }
+    english_time(words).map(|(count, time)| (count, Piece::Time(time)))
+}
+
+fn english_repeat(words: &[&str]) -> Option<(usize, Repeat)> {
+    match words {
+        ["every", "weekday", ..] => Some((2, Repeat::on(&WEEKDAYS[..5]))),
+        ["every", "other", unit, ..] => Some((3, Repeat::every(english_unit(unit)?, 2))),
+        ["every", n, unit, ..] if english_number(n).is_some() => {
+            Some((3, Repeat::every(english_unit(unit)?, english_number(n)?)))
+        }
+        ["every", word, ..] => match english_weekday(word) {
+            Some(weekday) => Some((2, Repeat::on(&[weekday]))),
+            None => Some((2, Repeat::every(english_unit(word)?, 1))),
+        },
+        _ => None,
+    }
+}
+
+fn english_date(words: &[&str], today: NaiveDate) -> Option<(usize, NaiveDate)> {
+    match words {
+        ["today", ..] => Some((1, today)),
+        ["tomorrow" | "tmr" | "tmrw", ..] => Some((1, today + Days::new(1))),
+        ["the", "day", "after", "tomorrow", ..] => Some((4, today + Days::new(2))),
+        ["day", "after", "tomorrow", ..] => Some((3, today + Days::new(2))),
+        ["next", "week", ..] => Some((2, in_next_week(today, Weekday::Mon))),
+        ["next", "month", ..] => Some((2, first_of_next_month(today)?)),
+        ["next", word, ..] => Some((2, in_next_week(today, english_weekday(word)?))),
+        ["this", word, ..] => Some((2, upcoming(today, english_weekday(word)?))),
+        ["in", n, unit, ..] => Some((3, shift(today, english_number(n)?, english_unit(unit)?)?)),
+        ["on", word, ..] if english_weekday(word).is_some() => {
+            Some((2, upcoming(today, english_weekday(word)?)))
+        }
+        // Abbreviations like `sun` and `sat` are common words on their own
+        [word, ..] if word.len() > 5 && english_weekday(word).is_some() => {
+            Some((1, upcoming(today, english_weekday(word)?)))
+        }
+        [first, second, ..] => {
+            let (month, day) = match (english_month(first), english_month(second)) {
+                (Some(month), _) => (month, ordinal(second)?),
+                (None, Some(month)) => (month, ordinal(first)?),
+                (None, None) => return None,
+            };
+            Some((2, next_date(today, month, day)?))
+        }
+        _ => None,
+    }
+}
+
+fn english_time(words: &[&str]) -> Option<(usize, NaiveTime)> {
+    match words {
+        ["noon", ..] => Some((1, NaiveTime::from_hms_opt(12, 0, 0)?)),
+        [clock, meridiem @ ("am" | "pm"), ..] => Some((2, twelve_hour(clock, meridiem)?)),
+        [word, ..] => {
+            let split = word.len().checked_sub(2)?;
+            let (clock, meridiem) = (word.get(..split)?, word.get(split..)?);
+            Some((1, twelve_hour(clock, meridiem)?))
+        }
+        _ => None,
+    }
+}
+
+/// `9` or `9:30` with `am` or `pm`.
+fn twelve_hour(clock: &str, meridiem: &str) -> Option<NaiveTime> {
+    let (hour, minute) = match clock.split_once(':') {
+        Some((hour, minute)) if minute.len() == 2 => (hour, minute.parse().ok()?),
+        Some(_) => return None,
+        None => (clock, 0),
+    };
+    let hour: u32 = hour.parse().ok().filter(|h| (1..=12).contains(h))?;
+    let hour = match meridiem {
+        "am" => hour % 12,
+        "pm" => hour % 12 + 12,
+        _ => return None,
+    };
+    NaiveTime::from_hms_opt(hour, minute, 0)
+}
+
+fn english_number(word: &str) -> Option<u32> {
+    match word {
+        "a" | "an" | "one" => Some(1),
+        "two" => Some(2),
+        "three" => Some(3),
+        _ => word.parse().ok().filter(|n| *n > 0),
+    }
+}
+
+fn english_unit(word: &str) -> Option<Unit> {
+    match word {
+        "day" | "days" => Some(Unit::Day),
+        "week" | "weeks" => Some(Unit::Week),
+        "month" | "months" => Some(Unit::Month),
+        _ => None,
+    }
+}
+
+fn english_weekday(word: &str) -> Option<Weekday> {
+    let index = match word {
+        "mon" | "monday" => 0,
+        "tue" | "tues" | "tuesday" => 1,
+        "wed" | "wednesday" => 2,
+        "thu" | "thur" | "thurs" | "thursday" => 3,
+        "fri" | "friday" => 4,
+        "sat" | "saturday" => 5,
+        "sun" | "sunday" => 6,
+        _ => return None,
+    };
+    Some(WEEKDAYS[index])
+}
+
+fn english_month(word: &str) -> Option<u32> {
+    const MONTHS: [&str; 12] = [
+        "january",
+        "february",
+        "march",
+        "april",
+        "may",
+        "june",
+        "july",
+        "august",
+        "september",
+        "october",
+        "november",
+        "december",
+    ];
+    let index = MONTHS
+        .iter()
+        .position(|m| *m == word || (word.len() == 3 && m.starts_with(word)))
+        .or_else(|| (word == "sept").then_some(8))?;
+    Some(index as u32 + 1)
+}
+
+/// `5`, `5th`, `1st`, `2nd` or `3rd`.
+fn ordinal(word: &str) -> Option<u32> {
+    let digits = ["st", "nd", "rd", "th"]
+        .iter()
+        .find_map(|suffix| word.strip_suffix(suffix))
+        .unwrap_or(word);
+    digits.parse().ok().filter(|d| (1..=31).contains(d))
+}
+
+/// A tag, priority, numeric date or time, or Japanese phrase at the start
+/// of `text`, with its length in bytes. `previous` is the character before.
+fn piece_at(text: &str, previous: Option<char>, today: NaiveDate) -> Option<(usize, Piece)> {
+    let first = text.chars().next()?;
+    let after_word = previous.is_some_and(|c| c.is_ascii_alphanumeric());
+    if after_word || previous.is_some_and(is_digit) && is_digit(first) {
+        return None;
+    }
+
+    let mut reader = Reader::new(text);
+    let piece = if let Some(tag) = reader.attempt(tag) {
+        Piece::Tag(tag)
+    } else if let Some(priority) = reader.attempt(priority) {
+        Piece::Priority(priority)
+    } else if let Some(repeat) = reader.attempt(japanese_repeat) {
+        Piece::Repeat(repeat)
+    } else if let Some(date) = reader.attempt(|r| japanese_date(r, today)) {
+        Piece::Date(date)
+    } else if let Some(date) = reader.attempt(numeric_date) {
+        Piece::Date(date)
+    } else if let Some(time) = reader.attempt(japanese_time) {
+        Piece::Time(time)
+    } else {
+        Piece::Time(reader.attempt(clock_time)?)
+    };
+    Some((reader.read, piece))
+}
+
+fn tag(r: &mut Reader) -> Option<TodoTag> {
+    r.one_of(&["#", "＃"])?;
+    let length = r
+        .rest
+        .find(|c: char| !(c.is_alphanumeric() || matches!(c, '_' | '-' | '/')))
+        .unwrap_or(r.rest.len());
+    let tag = TodoTag::new(r.rest[..length].to_string()).ok()?;
+    r.advance(length);
+    Some(tag)
+}
+
+fn priority(r: &mut Reader) -> Option<TodoPriority> {
+    r.one_of(&["!", "！"])?;
+    let index = r.one_of(&[
+        "urgent", "high", "medium", "med", "low", "none", "緊急", "高", "中", "低",
+    ])?;
+    if r.rest.starts_with(|c: char| c.is_ascii_alphanumeric()) {
+        return None;
+    }
+    Some(match index {
+        0 | 6 => TodoPriority::Urgent,
+        1 | 7 => TodoPriority::High,
+        2 | 3 | 8 => TodoPriority::Medium,
+        4 | 9 => TodoPriority::Low,
+        _ => TodoPriority::None,
+    })
+}
+
+fn japanese_repeat(r: &mut Reader) -> Option<Repeat> {
+    if r.one_of(&["毎日"]).is_some() {
+        return Some(Repeat::every(Unit::Day, 1));
+    }
+    if r.one_of(&["毎平日", "平日"]).is_some() {
+        return Some(Repeat::on(&WEEKDAYS[..5]));
+    }
+    if r.one_of(&["毎週"]).is_some() {
+        r.one_of(&["の"]);
+        return Some(match r.attempt(japanese_weekday) {
+            Some(weekday) => Repeat::on(&[weekday]),
+            None => Repeat::every(Unit::Week, 1),
+        });
+    }
+    if let Some(weekday) = r.attempt(|r| {
+        r.one_of(&["毎"])?;
+        japanese_weekday(r)
+    }) {
+        return Some(Repeat::on(&[weekday]));
+    }
+    if r.one_of(&["毎月"]).is_some() {
+        let month_day = r.attempt(|r| {
+            r.one_of(&["の"]);
+            let (day, _) = r.number()?;
+            r.one_of(&["日"])?;
+            (1..=31).contains(&day).then_some(day)
+        });
+        return Some(Repeat {
+            month_day,
+            ..Repeat::every(Unit::Month, 1)
+        });
+    }
+    let (interval, _) = r.number().filter(|(n, _)| *n > 0)?;
+    let unit = japanese_unit(r)?;
+    r.one_of(&["ごと", "毎"])?;
+    Some(Repeat::every(unit, interval))
+}
+
+fn japanese_date(r: &mut Reader, today: NaiveDate) -> Option<NaiveDate> {
+    const RELATIVE: [(&str, u64); 9] = [
+        ("明後日", 2),
+        ("あさって", 2),
+        ("今日中", 0),
+        ("明日中", 1),
+        ("本日", 0),
+        ("今日", 0),
+        ("きょう", 0),
+        ("明日", 1),
+        ("あした", 1),
+    ];
+    let words: Vec<&str> = RELATIVE.iter().map(|(word, _)| *word).collect();
+    if let Some(index) = r.one_of(&words) {
+        return Some(today + Days::new(RELATIVE[index].1));
+    }
+    if r.one_of(&["来週"]).is_some() {
+        return Some(
+            match r.attempt(|r| {
+                r.one_of(&["の"]);
+                japanese_weekday(r)
+            }) {
+                Some(weekday) => in_next_week(today, weekday),
+                None => in_next_week(today, Weekday::Mon),
+            },
+        );
+    }
+    if r.one_of(&["来月"]).is_some() {
+        return first_of_next_month(today);
+    }
+    if let Some(weekday) = r.attempt(japanese_weekday) {
+        return Some(upcoming(today, weekday));
+    }
+    let (first, _) = r.number()?;
+    if let Some(unit) = r.attempt(|r| {
+        let unit = japanese_unit(r)?;
+        r.one_of(&["後"])?;
+        Some(unit)
+    }) {
+        return shift(today, first, unit);
+    }
+    let year = r.attempt(|r| r.one_of(&["年"]));
+    let month = match year {
+        Some(_) => r.number()?.0,
+        None => first,
+    };
+    r.one_of(&["月"])?;
+    let (day, _) = r.number()?;
+    r.one_of(&["日"])?;
+    match year {
+        Some(_) => NaiveDate::from_ymd_opt(first as i32, month, day),
+        None => next_date(today, month, day),
+    }
+}
+
+/// `2026-11-05` or `2026/11/05`.
+fn numeric_date(r: &mut Reader) -> Option<NaiveDate> {
+    let (year, 4) = r.number()? else {
+        return None;
+    };
+    let separators = ["-", "/"];
+    let separator = r.one_of(&separators)?;
+    let (month, _) = r.number()?;
+    r.one_of(&separators[separator..=separator])?;
+    let (day, _) = r.number()?;
+    NaiveDate::from_ymd_opt(year as i32, month, day)
+}
+
+fn japanese_time(r: &mut Reader) -> Option<NaiveTime> {
+    if r.one_of(&["正午"]).is_some() {
+        return NaiveTime::from_hms_opt(12, 0, 0);
+    }
+    let meridiem = r.one_of(&["午前", "午後"]);
+    let (hour, _) = r.number()?;
+    r.one_of(&["時"])?;
+    // 1時間 is an hour long, not one o'clock
+    if r.rest.starts_with('間') {
+        return None;
+    }
+    let minute = if r.one_of(&["半"]).is_some() {
+        30
+    } else {
+        r.attempt(|r| {
+            let (minute, _) = r.number()?;
+            r.one_of(&["分"])?;
+            Some(minute)
+        })
+        .unwrap_or(0)
+    };
+    let hour = match meridiem {
+        Some(_) if hour > 12 => return None,
+        Some(0) => hour % 12,
+        Some(_) => hour % 12 + 12,
+        None => hour,
+    };
+    NaiveTime::from_hms_opt(hour, minute, 0)
+}
+
+/// `21:00` or `9:30`.
+fn clock_time(r: &mut Reader) -> Option<NaiveTime> {
+    let (hour, 1..=2) = r.number()? else {
+        return None;
+    };
+    r.one_of(&[":", "："])?;
+    let (minute, 2) = r.number()? else {
+        return None;
+    };
+    NaiveTime::from_hms_opt(hour, minute, 0)
+}
+
+fn japanese_weekday(r: &mut Reader) -> Option<Weekday> {
+    let index = r.one_of(&["月", "火", "水", "木", "金", "土", "日"])?;
+    r.one_of(&["曜"])?;
+    r.one_of(&["日"]);
+    Some(WEEKDAYS[index])
+}
+
+fn japanese_unit(r: &mut Reader) -> Option<Unit> {
+    match r.one_of(&["日", "週間", "週", "ヶ月", "か月", "カ月", "ヵ月"])? {
+        0 => Some(Unit::Day),
+        1 | 2 => Some(Unit::Week),
+        _ => Some(Unit::Month),
+    }
+}
+
+/// Reads a phrase from the start of a string.
+#[derive(Clone, Copy)]
+struct Reader<'a> {
+    rest: &'a str,
+    /// Bytes read so far.
+    read: usize,
+}
+
+impl<'a> Reader<'a> {
+    fn new(text: &'a str) -> Self {
+        Self {
+            rest: text,
+            read: 0,
+        }
+    }
+
+    fn advance(&mut self, length: usize) {
+        self.rest = &self.rest[length..];
+        self.read += length;
+    }
+
+    /// Runs `read`, keeping what it read only if it succeeds.
+    fn attempt<T>(&mut self, read: impl FnOnce(&mut Self) -> Option<T>) -> Option<T> {
+        let mut trial = *self;
+        let value = read(&mut trial)?;
+        *self = trial;
+        Some(value)
+    }
+
+    /// Reads the first of `options` the text starts with, ignoring ASCII
+    /// case, and returns its index.
+    fn one_of(&mut self, options: &[&str]) -> Option<usize> {
+        let index = options.iter().position(|option| {
+            self.rest
+                .get(..option.len())
+                .is_some_and(|start| start.eq_ignore_ascii_case(option))
+        })?;
+        self.advance(options[index].len());
+        Some(index)
+    }
+
+    /// Reads a number in ASCII or full-width digits, returning its value and
+    /// number of digits.
+    fn number(&mut self) -> Option<(u32, usize)> {
+        let mut value: u32 = 0;
+        let mut digits = 0;
+        let mut length = 0;
+        for c in self.rest.chars().take_while(|c| is_digit(*c)) {
+            let digit = c.to_digit(10).unwrap_or_else(|| c as u32 - '０' as u32);
+            value = value.checked_mul(10)?.checked_add(digit)?;
+            digits += 1;
+            length += c.len_utf8();
+        }
+        if digits == 0 {
+            return None;
+        }
+        self.advance(length);
+        Some((value, digits))
+    }
+}
+
+fn is_digit(c: char) -> bool {
+    c.is_ascii_digit() || ('０'..='９').contains(&c)
+}
+
+/// The first `weekday` after `today`.
+fn upcoming(today: NaiveDate, weekday: Weekday) -> NaiveDate {
+    let days = (weekday.num_days_from_monday() + 6 - today.weekday().num_days_from_monday()) % 7;
+    today + Days::new(u64::from(days) + 1)
+}
+
+/// `weekday` in the week after `today`'s, weeks starting on Monday.
+fn in_next_week(today: NaiveDate, weekday: Weekday) -> NaiveDate {
+    let monday = today - Days::new(u64::from(today.weekday().num_days_from_monday()));
+    monday + Days::new(7 + u64::from(weekday.num_days_from_monday()))
+}
+
+fn first_of_next_month(today: NaiveDate) -> Option<NaiveDate> {
+    today.with_day(1)?.checked_add_months(Months::new(1))
+}
+
+/// The first `month`/`day` from `today` on.
+fn next_date(today: NaiveDate, month: u32, day: u32) -> Option<NaiveDate> {
+    (today.year()..=today.year() + 4)
+        .filter_map(|year| NaiveDate::from_ymd_opt(year, month, day))
+        .find(|date| *date >= today)
+}
+
+fn shift(today: NaiveDate, n: u32, unit: Unit) -> Option<NaiveDate> {
+    match unit {
+        Unit::Day => today.checked_add_days(Days::new(u64::from(n))),
+        Unit::Week => today.checked_add_days(Days::new(7 * u64::from(n))),
+        Unit::Month => today.checked_add_months(Months::new(n)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::NaiveDateTime;
+    use chrono_tz::Asia::Tokyo;
+
+    /// Wednesday 2026-10-21, 10:00 in Tokyo.
+    fn now() -> DateTime<Utc> {
+        "2026-10-21T01:00:00Z".parse().unwrap()
+    }
+
+    fn tokyo(local: &str) -> DateTime<Utc> {
+        let local = NaiveDateTime::parse_from_str(local, "%Y-%m-%d %H:%M").unwrap();
+        resolve_local(Tokyo, local).unwrap()
+    }
+
+    /// Parses each text and checks its title, local due time and RRULE.
+    fn check(cases: &[(&str, &str, Option<&str>, Option<&str>)]) {
+        for (text, title, due_at, rule) in cases {
+            let parsed =
+                QuickAdd::parse(text, now(), Tokyo).unwrap_or_else(|e| panic!("{:?}: {}", text, e));
+            assert_eq!(parsed.title.value(), *title, "title of {:?}", text);
+            assert_eq!(parsed.due_at, due_at.map(tokyo), "due date of {:?}", text);
+            assert_eq!(
+                parsed.recurrence_rule.as_ref().map(RecurrenceRule::value),
+                *rule,
+                "rule of {:?}",
+                text
+            );
+        }
+    }
+
+    #[test]
+    fn test_english_phrases() {
+        check(&[
+            ("Call mom", "Call mom", None, None),
+            (
+                "Submit report today",
+                "Submit report",
+                Some("2026-10-21 23:59"),
+                None,
+            ),
+            (
+                "Pay rent tomorrow",
+                "Pay rent",
+                Some("2026-10-22 23:59"),
+                None,
+            ),
+            ("Pay rent tmrw", "Pay rent", Some("2026-10-22 23:59"), None),
+            (
+                "Pay rent day after tomorrow",
+                "Pay rent",
+                Some("2026-10-23 23:59"),
+                None,
+            ),
+            (
+                "Dentist on friday at 3pm",
+                "Dentist",
+                Some("2026-10-23 15:00"),
+                None,
+            ),
+            ("Dentist on fri", "Dentist", Some("2026-10-23 23:59"), None),
+            ("Retro wednesday", "Retro", Some("2026-10-28 23:59"), None),
+            (
+                "Review this friday",
+                "Review",
+                Some("2026-10-23 23:59"),
+                None,
+            ),
+            (
+                "Review next friday",
+                "Review",
+                Some("2026-10-30 23:59"),
+                None,
+            ),
+            (
+                "Plan sprint next week",
+                "Plan sprint",
+                Some("2026-10-26 23:59"),
+                None,
+            ),
+            (
+                "Close books next month",
+                "Close books",
+                Some("2026-11-01 23:59"),
+                None,
+            ),
+            (
+                "Ship it in 3 days",
+                "Ship it",
+                Some("2026-10-24 23:59"),
+                None,
+            ),
+            (
+                "Renew passport in 2 weeks",
+                "Renew passport",
+                Some("2026-11-04 23:59"),
+                None,
+            ),
+            (
+                "Check in in a month",
+                "Check in",
+                Some("2026-11-21 23:59"),
+                None,
+            ),
+            (
+                "File taxes 2026-11-01 14:00",
+                "File taxes",
+                Some("2026-11-01 14:00"),
+                None,
+            ),
+            (
+                "File taxes by 2026/11/01",
+                "File taxes",
+                Some("2026-11-01 23:59"),
+                None,
+            ),
+            (
+                "Book flights Nov 5",
+                "Book flights",
+                Some("2026-11-05 23:59"),
+                None,
+            ),
+            (
+                "Book flights 5th november",
+                "Book flights",
+                Some("2026-11-05 23:59"),
+                None,
+            ),
+            ("Pay bill oct 1", "Pay bill", Some("2027-10-01 23:59"), None),
+            ("Lunch at noon", "Lunch", Some("2026-10-21 12:00"), None),
+            (
+                "Late call 11pm",
+                "Late call",
+                Some("2026-10-21 23:00"),
+                None,
+            ),
+            (
+                "Early call 7am",
+                "Early call",
+                Some("2026-10-22 07:00"),
+                None,
+            ),
+            (
+                "Standup 9:30 am tomorrow",
+                "Standup",
+                Some("2026-10-22 09:30"),
+                None,
+            ),
+            (
+                "Deploy due 2026-10-30 21:00",
+                "Deploy",
+                Some("2026-10-30 21:00"),
+                None,
+            ),
+            (
+                "Water plants every day 8am",
+                "Water plants",
+                Some("2026-10-22 08:00"),
+                Some("FREQ=DAILY"),
+            ),
+            (
+                "Team sync every monday at 10am",
+                "Team sync",
+                Some("2026-10-26 10:00"),
+                Some("FREQ=WEEKLY;BYDAY=MO"),
+            ),
+            (
+                "Gym every 2 days",
+                "Gym",
+                Some("2026-10-21 23:59"),
+                Some("FREQ=DAILY;INTERVAL=2"),
+            ),
+            (
+                "Payroll every other month",
+                "Payroll",
+                Some("2026-10-21 23:59"),
+                Some("FREQ=MONTHLY;INTERVAL=2"),
+            ),
+            (
+                "Timesheet every weekday 5pm",
+                "Timesheet",
+                Some("2026-10-21 17:00"),
+                Some("FREQ=WEEKLY;BYDAY=MO,TU,WE,TH,FR"),
+            ),
+            (
+                "Pay rent tomorrow 9am every month",
+                "Pay rent",
+                Some("2026-10-22 09:00"),
+                Some("FREQ=MONTHLY"),
+            ),
+            // Common words that only look like dates stay in the title
+            ("Enjoy the sun", "Enjoy the sun", None, None),
+            ("Meet at the office", "Meet at the office", None, None),
+            ("Write weekly report", "Write weekly report", None, None),
+            ("Review 2026-02-30", "Review 2026-02-30", None, None),
+            ("Put in 5 boxes", "Put in 5 boxes", None, None),
+            // Only the first date counts
+            (
+                "Call tomorrow and friday",
+                "Call and friday",
+                Some("2026-10-22 23:59"),
+                None,
+            ),
+        ]);
+    }
+
+    #[test]
+    fn test_japanese_phrases() {
+        check(&[
+            (
+                "今日中に報告書を提出",
+                "報告書を提出",
+                Some("2026-10-21 23:59"),
+                None,
+            ),
+            (
+                "明日家賃を払う",
+                "家賃を払う",
+                Some("2026-10-22 23:59"),
+                None,
+            ),
+            (
+                "明後日の午後3時に歯医者",
+                "歯医者",
+                Some("2026-10-23 15:00"),
+                None,
+            ),
+            (
+                "金曜日までにレポート",
+                "レポート",
+                Some("2026-10-23 23:59"),
+                None,
+            ),
+            ("水曜 振り返り", "振り返り", Some("2026-10-28 23:59"), None),
+            ("来週の金曜に会議", "会議", Some("2026-10-30 23:59"), None),
+            (
+                "来週月曜 10時半 定例",
+                "定例",
+                Some("2026-10-26 10:30"),
+                None,
+            ),
+            ("来週 計画", "計画", Some("2026-10-26 23:59"), None),
+            ("来月 締め", "締め", Some("2026-11-01 23:59"), None),
+            (
+                "3日後にパスポート更新",
+                "パスポート更新",
+                Some("2026-10-24 23:59"),
+                None,
+            ),
+            (
+                "2週間後 旅行の準備",
+                "旅行の準備",
+                Some("2026-11-04 23:59"),
+                None,
+            ),
+            ("1ヶ月後 点検", "点検", Some("2026-11-21 23:59"), None),
+            (
+                "11月5日にフライト予約",
+                "フライト予約",
+                Some("2026-11-05 23:59"),
+                None,
+            ),
+            ("2027年1月10日 更新", "更新", Some("2027-01-10 23:59"), None),
+            ("2026-11-01に提出", "提出", Some("2026-11-01 23:59"), None),
+            ("正午にランチ", "ランチ", Some("2026-10-21 12:00"), None),
+            ("９時に朝会", "朝会", Some("2026-10-22 09:00"), None),
+            ("明日21:00 電話", "電話", Some("2026-10-22 21:00"), None),
+            ("午前11時15分 面談", "面談", Some("2026-10-21 11:15"), None),
+            (
+                "毎日8時 水やり",
+                "水やり",
+                Some("2026-10-22 08:00"),
+                Some("FREQ=DAILY"),
+            ),
+            (
+                "毎週月曜日の10時に定例",
+                "定例",
+                Some("2026-10-26 10:00"),
+                Some("FREQ=WEEKLY;BYDAY=MO"),
+            ),
+            (
+                "毎金曜 週報",
+                "週報",
+                Some("2026-10-23 23:59"),
+                Some("FREQ=WEEKLY;BYDAY=FR"),
+            ),
+            (
+                "毎月25日 家賃",
+                "家賃",
+                Some("2026-10-25 23:59"),
+                Some("FREQ=MONTHLY;BYMONTHDAY=25"),
+            ),
+            (
+                "平日17時 日報",
+                "日報",
+                Some("2026-10-21 17:00"),
+                Some("FREQ=WEEKLY;BYDAY=MO,TU,WE,TH,FR"),
+            ),
+            (
+                "2週間ごとに 1on1",
+                "1on1",
+                Some("2026-10-21 23:59"),
+                Some("FREQ=WEEKLY;INTERVAL=2"),
+            ),
+            // Durations and bare days are not dates
+            (
+                "1時間の会議 明日",
+                "1時間の会議",
+                Some("2026-10-22 23:59"),
+                None,
+            ),
+            ("5日に支払い", "5日に支払い", None, None),
+        ]);
+    }
+
+    #[test]
+    fn test_tags_and_priority() {
+        let parse = |text: &str| QuickAdd::parse(text, now(), Tokyo).unwrap();
+        let tags = |parsed: &QuickAdd| {
+            parsed
+                .tags
+                .iter()
+                .map(|t| t.value().to_string())
+                .collect::<Vec<_>>()
+        };
+
+        let parsed = parse("Pay rent tomorrow 9am #finance !high every month");
+        assert_eq!(parsed.title.value(), "Pay rent");
+        assert_eq!(tags(&parsed), vec!["finance"]);
+        assert_eq!(parsed.priority, Some(TodoPriority::High));
+        assert_eq!(parsed.due_at, Some(tokyo("2026-10-22 09:00")));
+
+        let parsed = parse("明日9時に家賃を払う #家計 !高 毎月");
+        assert_eq!(parsed.title.value(), "家賃を払う");
+        assert_eq!(tags(&parsed), vec!["家計"]);
+        assert_eq!(parsed.priority, Some(TodoPriority::High));
+        assert_eq!(parsed.due_at, Some(tokyo("2026-10-22 09:00")));
+        assert_eq!(
+            parsed.recurrence_rule.as_ref().map(RecurrenceRule::value),
+            Some("FREQ=MONTHLY")
+        );
+
+        let parsed = parse("Read #books #Books #work/q4 !MED");
+        assert_eq!(parsed.title.value(), "Read");
+        assert_eq!(tags(&parsed), vec!["books", "work/q4"]);
+        assert_eq!(parsed.priority, Some(TodoPriority::Medium));
+
+        // Only the first priority counts; C# and !highway are not markers
+        let parsed = parse("Fix C# build !urgent !low !highway");
+        assert_eq!(parsed.title.value(), "Fix C# build !low !highway");
+        assert!(parsed.tags.is_empty());
+        assert_eq!(parsed.priority, Some(TodoPriority::Urgent));
+
+        let parsed = parse("報告書＃仕事 ！緊急");
+        assert_eq!(parsed.title.value(), "報告書");
+        assert_eq!(tags(&parsed), vec!["仕事"]);
+        assert_eq!(parsed.priority, Some(TodoPriority::Urgent));
+
+        assert_eq!(parse("Call mom").priority, None);
+    }
+
+    #[test]
+    fn test_quick_add_errors() {
+        let parse = |text: &str| QuickAdd::parse(text, now(), Tokyo);
+
+        assert_eq!(
+            parse("tomorrow 9am #home").unwrap_err(),
+            "Title cannot be empty"
+        );
+        assert!(parse("").is_err());
+        assert!(parse(&"a".repeat(501)).is_err());
+    }
+}
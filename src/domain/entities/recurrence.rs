@@ -0,0 +1,592 @@
+use std::collections::VecDeque;
+use std::fmt;
+
+use chrono::{
+    DateTime, Datelike, Duration, NaiveDate, NaiveDateTime, NaiveTime, TimeZone, Utc, Weekday,
+};
+use chrono_tz::Tz;
+use serde::{Deserialize, Serialize};
+use sqlx::Type;
+use utoipa::ToSchema;
+
+/// Upper bound on the number of empty periods scanned while looking for the
+/// next occurrence, so rules that can never match (e.g. BYMONTHDAY=31 every
+/// 12 months starting in February) terminate.
+const MAX_EMPTY_PERIODS: u32 = 1000;
+
+/// Largest accepted `INTERVAL`; anything beyond this is almost certainly a
+/// typo and would push the second occurrence centuries out.
+const MAX_INTERVAL: u32 = 1000;
+
+/// An IANA time zone name (e.g. `Asia/Tokyo`) used to expand recurrences in
+/// local time, so a 09:00 weekly todo stays at 09:00 across DST changes.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, ToSchema, Type)]
+#[sqlx(transparent)]
+pub struct TimeZoneName(String);
+
+impl TimeZoneName {
+    pub fn new(name: String) -> Result<Self, String> {
+        let tz: Tz = name
+            .parse()
+            .map_err(|_| format!("Unknown time zone: {}", name))?;
+        Ok(Self(tz.name().to_string()))
+    }
+
+    pub fn value(&self) -> &str {
+        &self.0
+    }
+
+    pub fn tz(&self) -> Tz {
+        self.0.parse().unwrap_or(Tz::UTC)
+    }
+}
+
+impl Default for TimeZoneName {
+    fn default() -> Self {
+        Self("UTC".to_string())
+    }
+}
+
+/// A validated RFC 5545 RRULE, stored in its normalized text form.
+///
+/// Supported parts are `FREQ` (DAILY, WEEKLY, MONTHLY), `INTERVAL` (up to
+/// 1000), `COUNT`, `UNTIL`, `BYDAY` (with ordinals such as `-1FR` for MONTHLY)
+/// and `BYMONTHDAY`. A date-only or floating `UNTIL` is interpreted in UTC.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, ToSchema, Type)]
+#[sqlx(transparent)]
+pub struct RecurrenceRule(String);
+
+impl RecurrenceRule {
+    pub fn new(rule: String) -> Result<Self, String> {
+        let parsed = Rule::parse(&rule)?;
+        Ok(Self(parsed.to_string()))
+    }
+
+    pub fn value(&self) -> &str {
+        &self.0
+    }
+
+    /// Maximum number of occurrences in the series, if limited by `COUNT`.
+    pub fn count(&self) -> Option<u32> {
+        Rule::parse(&self.0).ok().and_then(|rule| rule.count)
+    }
+
+    /// Occurrences strictly after `start`, expanded in `tz` from the period
+    /// containing `start`. `start` is expected to be an occurrence itself.
+    pub fn occurrences_after(&self, start: DateTime<Utc>, tz: Tz) -> Occurrences {
+        Occurrences {
+            rule: Rule::parse(&self.0).ok(),
+            tz,
+            anchor: start.with_timezone(&tz).naive_local(),
+            after: start,
+            period: 0,
+            pending: VecDeque::new(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Frequency {
+    Daily,
+    Weekly,
+    Monthly,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct ByDay {
+    ordinal: Option<i32>,
+    weekday: Weekday,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Rule {
+    freq: Frequency,
+    interval: u32,
+    count: Option<u32>,
+    until: Option<DateTime<Utc>>,
+    by_day: Vec<ByDay>,
+    by_month_day: Vec<i32>,
+}
+
+impl Rule {
+    fn parse(input: &str) -> Result<Self, String> {
+        let input = input.trim();
+        let body = input
+            .strip_prefix("RRULE:")
+            .or_else(|| input.strip_prefix("rrule:"))
+            .unwrap_or(input);
+        if body.is_empty() {
+            return Err("Recurrence rule cannot be empty".to_string());
+        }
+
+        let mut freq = None;
+        let mut interval = None;
+        let mut count = None;
+        let mut until = None;
+        let mut by_day = Vec::new();
+        let mut by_month_day = Vec::new();
+
+        for part in body.split(';').filter(|p| !p.is_empty()) {
+            let (key, value) = part
+                .split_once('=')
+                .ok_or_else(|| format!("Invalid RRULE part: {}", part))?;
+            match key.to_ascii_uppercase().as_str() {
+                "FREQ" => {
+                    freq = Some(match value.to_ascii_uppercase().as_str() {
+                        "DAILY" => Frequency::Daily,
+                        "WEEKLY" => Frequency::Weekly,
+                        "MONTHLY" => Frequency::Monthly,
+                        _ => return Err(format!("Unsupported FREQ: {}", value)),
+                    })
+                }
+                "INTERVAL" => {
+                    let n = parse_positive(key, value)?;
+                    if n > MAX_INTERVAL {
+                        return Err(format!("INTERVAL cannot be more than {}", MAX_INTERVAL));
+                    }
+                    interval = Some(n);
+                }
+                "COUNT" => count = Some(parse_positive(key, value)?),
+                "UNTIL" => until = Some(parse_until(value)?),
+                "BYDAY" => {
+                    by_day = value
+                        .split(',')
+                        .map(parse_by_day)
+                        .collect::<Result<_, _>>()?
+                }
+                "BYMONTHDAY" => {
+                    by_month_day = value
+                        .split(',')
+                        .map(|d| match d.parse::<i32>() {
+                            Ok(n) if n != 0 && (-31..=31).contains(&n) => Ok(n),
+                            _ => Err(format!("Invalid BYMONTHDAY: {}", d)),
+                        })
+                        .collect::<Result<_, _>>()?
+                }
+                _ => return Err(format!("Unsupported RRULE part: {}", key)),
+            }
+        }
+
+        let freq = freq.ok_or_else(|| "RRULE must specify FREQ".to_string())?;
+        if count.is_some() && until.is_some() {
+            return Err("RRULE cannot specify both COUNT and UNTIL".to_string());
+        }
+        if freq != Frequency::Monthly {
+            if by_day.iter().any(|d| d.ordinal.is_some()) {
+                return Err("BYDAY ordinals are only allowed with FREQ=MONTHLY".to_string());
+            }
+            if !by_month_day.is_empty() {
+                return Err("BYMONTHDAY is only allowed with FREQ=MONTHLY".to_string());
+            }
+        }
+
+        Ok(Self {
+            freq,
+            interval: interval.unwrap_or(1),
+            count,
+            until,
+            by_day,
+            by_month_day,
+        })
+    }
+
+    /// Candidate local dates for the `period`-th period after the anchor's,
+    /// or `None` once the period lies beyond the representable date range.
+    fn dates_in_period(&self, anchor: NaiveDate, period: u32) -> Option<Vec<NaiveDate>> {
+        let step = i64::from(period) * i64::from(self.interval);
+        let mut dates = match self.freq {
+            Frequency::Daily => {
+                let date = anchor.checked_add_signed(Duration::try_days(step)?)?;
+                if self.by_day.is_empty() || self.by_day.iter().any(|d| d.weekday == date.weekday())
+                {
+                    vec![date]
+                } else {
+                    vec![]
+                }
+            }
+            Frequency::Weekly => {
+                let offset =
+                    |weekday: Weekday| Duration::days(i64::from(weekday.num_days_from_monday()));
+                let week_start = anchor
+                    .checked_sub_signed(offset(anchor.weekday()))?
+                    .checked_add_signed(Duration::try_weeks(step)?)?;
+                if self.by_day.is_empty() {
+                    vec![week_start.checked_add_signed(offset(anchor.weekday()))?]
+                } else {
+                    self.by_day
+                        .iter()
+                        .map(|d| week_start.checked_add_signed(offset(d.weekday)))
+                        .collect::<Option<_>>()?
+                }
+            }
+            Frequency::Monthly => {
+                let months = i64::from(anchor.year()) * 12 + i64::from(anchor.month0()) + step;
+                let first = i32::try_from(months.div_euclid(12)).ok().and_then(|y| {
+                    NaiveDate::from_ymd_opt(y, months.rem_euclid(12) as u32 + 1, 1)
+                })?;
+                let month_days: Vec<NaiveDate> = first
+                    .iter_days()
+                    .take_while(|d| d.month() == first.month())
+                    .collect();
+                self.monthly_dates(anchor, &month_days)
+            }
+        };
+        dates.sort();
+        dates.dedup();
+        Some(dates)
+    }
+
+    fn monthly_dates(&self, anchor: NaiveDate, month_days: &[NaiveDate]) -> Vec<NaiveDate> {
+        let len = month_days.len() as i32;
+        let matches_month_day = |date: &NaiveDate| {
+            let day = date.day() as i32;
+            self.by_month_day
+                .iter()
+                .any(|&n| n == day || n == day - len - 1)
+        };
+
+        if self.by_day.is_empty() {
+            return month_days
+                .iter()
+                .filter(|d| {
+                    if self.by_month_day.is_empty() {
+                        d.day() == anchor.day()
+                    } else {
+                        matches_month_day(d)
+                    }
+                })
+                .copied()
+                .collect();
+        }
+
+        let mut dates = Vec::new();
+        for by_day in &self.by_day {
+            let same_weekday: Vec<NaiveDate> = month_days
+                .iter()
+                .filter(|d| d.weekday() == by_day.weekday)
+                .copied()
+                .collect();
+            match by_day.ordinal {
+                None => dates.extend(same_weekday),
+                Some(n) => {
+                    let index = if n > 0 {
+                        n - 1
+                    } else {
+                        same_weekday.len() as i32 + n
+                    };
+                    if let Some(date) = usize::try_from(index)
+                        .ok()
+                        .and_then(|i| same_weekday.get(i))
+                    {
+                        dates.push(*date);
+                    }
+                }
+            }
+        }
+        if !self.by_month_day.is_empty() {
+            dates.retain(matches_month_day);
+        }
+        dates
+    }
+}
+
+impl fmt::Display for Rule {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let freq = match self.freq {
+            Frequency::Daily => "DAILY",
+            Frequency::Weekly => "WEEKLY",
+            Frequency::Monthly => "MONTHLY",
+        };
+        write!(f, "FREQ={}", freq)?;
+        if self.interval != 1 {
+            write!(f, ";INTERVAL={}", self.interval)?;
+        }
+        if !self.by_day.is_empty() {
+            let days: Vec<String> = self
+                .by_day
+                .iter()
+                .map(|d| {
+                    let code = weekday_code(d.weekday);
+                    match d.ordinal {
+                        Some(n) => format!("{}{}", n, code),
+                        None => code.to_string(),
+                    }
+                })
+                .collect();
+            write!(f, ";BYDAY={}", days.join(","))?;
+        }
+        if !self.by_month_day.is_empty() {
+            let days: Vec<String> = self.by_month_day.iter().map(|d| d.to_string()).collect();
+            write!(f, ";BYMONTHDAY={}", days.join(","))?;
+        }
+        if let Some(count) = self.count {
+            write!(f, ";COUNT={}", count)?;
+        }
+        if let Some(until) = self.until {
+            write!(f, ";UNTIL={}", until.format("%Y%m%dT%H%M%SZ"))?;
+        }
+        Ok(())
+    }
+}
+
+/// Iterator over the occurrences of a [`RecurrenceRule`], honouring `UNTIL`.
+/// `COUNT` is tracked by the caller, which knows the position in the series.
+pub struct Occurrences {
+    rule: Option<Rule>,
+    tz: Tz,
+    anchor: NaiveDateTime,
+    after: DateTime<Utc>,
+    period: u32,
+    pending: VecDeque<DateTime<Utc>>,
+}
+
+impl Iterator for Occurrences {
+    type Item = DateTime<Utc>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let mut empty_periods = 0;
+        loop {
+            if let Some(at) = self.pending.pop_front() {
+                return Some(at);
+            }
+            let rule = self.rule.as_ref()?;
+            if empty_periods >= MAX_EMPTY_PERIODS {
+                return None;
+            }
+            let until = rule.until;
+            let Some(dates) = rule.dates_in_period(self.anchor.date(), self.period) else {
+                self.rule = None;
+                return None;
+            };
+            self.period += 1;
+            empty_periods += 1;
+            for date in dates {
+                let Some(at) = resolve_local(self.tz, date.and_time(self.anchor.time())) else {
+                    continue;
+                };
+                if until.is_some_and(|until| at > until) {
+                    self.rule = None;
+                    break;
+                }
+                if at > self.after {
+                    self.pending.push_back(at);
+                }
+            }
+        }
+    }
+}
+
+/// Maps a local wall-clock time to UTC, taking the earlier instant for
+/// ambiguous times and skipping forward past DST gaps.
+pub(super) fn resolve_local(tz: Tz, local: NaiveDateTime) -> Option<DateTime<Utc>> {
+    tz.from_local_datetime(&local)
+        .earliest()
+        .or_else(|| {
+            tz.from_local_datetime(&(local + Duration::hours(1)))
+                .earliest()
+        })
+        .map(|dt| dt.with_timezone(&Utc))
+}
+
+fn parse_positive(key: &str, value: &str) -> Result<u32, String> {
+    match value.parse::<u32>() {
+        Ok(n) if n > 0 => Ok(n),
+        _ => Err(format!(
+            "{} must be a positive integer",
+            key.to_ascii_uppercase()
+        )),
+    }
+}
+
+fn parse_until(value: &str) -> Result<DateTime<Utc>, String> {
+    let trimmed = value.trim_end_matches(['Z', 'z']);
+    if let Ok(dt) = NaiveDateTime::parse_from_str(trimmed, "%Y%m%dT%H%M%S") {
+        return Ok(dt.and_utc());
+    }
+    NaiveDate::parse_from_str(trimmed, "%Y%m%d")
+        .map(|d| {
+            d.and_time(NaiveTime::from_hms_opt(23, 59, 59).unwrap())
+                .and_utc()
+        })
+        .map_err(|_| format!("Invalid UNTIL: {}", value))
+}
+
+fn parse_by_day(value: &str) -> Result<ByDay, String> {
+    let value = value.trim().to_ascii_uppercase();
+    if value.len() < 2 || !value.is_ascii() {
+        return Err(format!("Invalid BYDAY: {}", value));
+    }
+    let (ordinal, code) = value.split_at(value.len() - 2);
+    let weekday = match code {
+        "MO" => Weekday::Mon,
+        "TU" => Weekday::Tue,
+        "WE" => Weekday::Wed,
+        "TH" => Weekday::Thu,
+        "FR" => Weekday::Fri,
+        "SA" => Weekday::Sat,
+        "SU" => Weekday::Sun,
+        _ => return Err(format!("Invalid BYDAY: {}", value)),
+    };
+    let ordinal = if ordinal.is_empty() {
+        None
+    } else {
+        match ordinal.parse::<i32>() {
+            Ok(n) if n != 0 && (-5..=5).contains(&n) => Some(n),
+            _ => return Err(format!("Invalid BYDAY: {}", value)),
+        }
+    };
+    Ok(ByDay { ordinal, weekday })
+}
+
+pub(super) fn weekday_code(weekday: Weekday) -> &'static str {
+    match weekday {
+        Weekday::Mon => "MO",
+        Weekday::Tue => "TU",
+        Weekday::Wed => "WE",
+        Weekday::Thu => "TH",
+        Weekday::Fri => "FR",
+        Weekday::Sat => "SA",
+        Weekday::Sun => "SU",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn utc(s: &str) -> DateTime<Utc> {
+        DateTime::parse_from_rfc3339(s).unwrap().with_timezone(&Utc)
+    }
+
+    fn next_n(rule: &str, start: &str, tz: &str, n: usize) -> Vec<DateTime<Utc>> {
+        let rule = RecurrenceRule::new(rule.to_string()).unwrap();
+        let tz = TimeZoneName::new(tz.to_string()).unwrap().tz();
+        rule.occurrences_after(utc(start), tz).take(n).collect()
+    }
+
+    #[test]
+    fn test_recurrence_rule_validation() {
+        // Normalized form
+        let rule = RecurrenceRule::new("RRULE:freq=weekly;byday=mo,we;interval=1".to_string());
+        assert_eq!(rule.unwrap().value(), "FREQ=WEEKLY;BYDAY=MO,WE");
+
+        // Missing FREQ
+        let missing = RecurrenceRule::new("INTERVAL=2".to_string());
+        assert_eq!(missing.unwrap_err(), "RRULE must specify FREQ");
+
+        // COUNT and UNTIL together
+        let both = RecurrenceRule::new("FREQ=DAILY;COUNT=3;UNTIL=20260101".to_string());
+        assert_eq!(
+            both.unwrap_err(),
+            "RRULE cannot specify both COUNT and UNTIL"
+        );
+
+        // Unsupported frequency and parts
+        assert!(RecurrenceRule::new("FREQ=HOURLY".to_string()).is_err());
+        assert!(RecurrenceRule::new("FREQ=DAILY;BYHOUR=9".to_string()).is_err());
+        assert!(RecurrenceRule::new("FREQ=WEEKLY;BYDAY=1MO".to_string()).is_err());
+        assert!(RecurrenceRule::new("FREQ=DAILY;INTERVAL=0".to_string()).is_err());
+        assert!(RecurrenceRule::new("FREQ=WEEKLY;BYDAY=éa".to_string()).is_err());
+        assert!(RecurrenceRule::new("FREQ=MONTHLY;BYDAY=1日".to_string()).is_err());
+        assert_eq!(
+            RecurrenceRule::new("FREQ=DAILY;INTERVAL=4294967295".to_string()).unwrap_err(),
+            "INTERVAL cannot be more than 1000"
+        );
+    }
+
+    #[test]
+    fn test_occurrences_end_at_the_last_representable_date() {
+        // Stepping past chrono's date range ends the series instead of panicking
+        for rule in [
+            "FREQ=DAILY;INTERVAL=1000",
+            "FREQ=WEEKLY;INTERVAL=1000;BYDAY=MO,SU",
+        ] {
+            let rule = RecurrenceRule::new(rule.to_string()).unwrap();
+            let count = rule
+                .occurrences_after(utc("2026-01-05T09:00:00Z"), Tz::UTC)
+                .count();
+            assert!(count > 0);
+        }
+    }
+
+    #[test]
+    fn test_time_zone_validation() {
+        assert_eq!(
+            TimeZoneName::new("Asia/Tokyo".to_string()).unwrap().value(),
+            "Asia/Tokyo"
+        );
+        assert_eq!(
+            TimeZoneName::new("Mars/Olympus".to_string()).unwrap_err(),
+            "Unknown time zone: Mars/Olympus"
+        );
+    }
+
+    #[test]
+    fn test_weekly_by_day_occurrences() {
+        // Monday 2026-10-19 09:00 in Tokyo
+        let next = next_n(
+            "FREQ=WEEKLY;BYDAY=MO,TH",
+            "2026-10-19T00:00:00Z",
+            "Asia/Tokyo",
+            3,
+        );
+        assert_eq!(
+            next,
+            vec![
+                utc("2026-10-22T00:00:00Z"),
+                utc("2026-10-26T00:00:00Z"),
+                utc("2026-10-29T00:00:00Z"),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_daily_keeps_local_time_across_dst() {
+        // 09:00 in New York, DST ends on 2026-11-01
+        let next = next_n("FREQ=DAILY", "2026-10-31T13:00:00Z", "America/New_York", 2);
+        assert_eq!(
+            next,
+            vec![utc("2026-11-01T14:00:00Z"), utc("2026-11-02T14:00:00Z")]
+        );
+    }
+
+    #[test]
+    fn test_monthly_occurrences() {
+        // Last Friday of the month
+        let next = next_n("FREQ=MONTHLY;BYDAY=-1FR", "2026-10-30T10:00:00Z", "UTC", 2);
+        assert_eq!(
+            next,
+            vec![utc("2026-11-27T10:00:00Z"), utc("2026-12-25T10:00:00Z")]
+        );
+
+        // The 31st skips months that are too short
+        let next = next_n("FREQ=MONTHLY", "2026-10-31T10:00:00Z", "UTC", 2);
+        assert_eq!(
+            next,
+            vec![utc("2026-12-31T10:00:00Z"), utc("2027-01-31T10:00:00Z")]
+        );
+
+        // Last day of every month
+        let next = next_n(
+            "FREQ=MONTHLY;BYMONTHDAY=-1",
+            "2027-01-31T10:00:00Z",
+            "UTC",
+            1,
+        );
+        assert_eq!(next, vec![utc("2027-02-28T10:00:00Z")]);
+    }
+
+    #[test]
+    fn test_until_stops_occurrences() {
+        let next = next_n(
+            "FREQ=DAILY;INTERVAL=2;UNTIL=20261023T000000Z",
+            "2026-10-18T12:00:00Z",
+            "UTC",
+            10,
+        );
+        assert_eq!(
+            next,
+            vec![utc("2026-10-20T12:00:00Z"), utc("2026-10-22T12:00:00Z")]
+        );
+    }
+}
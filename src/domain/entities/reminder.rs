@@ -0,0 +1,356 @@
+use chrono::{DateTime, Duration, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::Type;
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+use super::todo::{Todo, TodoId, TodoTitle};
+
+/// Most reminders a user can set on one todo.
+pub const MAX_REMINDERS_PER_TODO: usize = 10;
+
+/// Furthest before the due date a reminder can be, in minutes (30 days).
+pub const MAX_REMINDER_OFFSET_MINUTES: i32 = 30 * 24 * 60;
+
+/// How many times a delivery is attempted before it is given up.
+pub const MAX_DELIVERY_ATTEMPTS: i32 = 5;
+
+/// Where a notification is delivered.
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize, ToSchema, Type,
+)]
+#[serde(rename_all = "snake_case")]
+#[sqlx(type_name = "delivery_channel", rename_all = "snake_case")]
+pub enum DeliveryChannel {
+    /// The user's notification inbox
+    InApp,
+    /// The user's email address
+    Email,
+    /// A POST to the reminder's webhook URL
+    Webhook,
+}
+
+/// An HTTPS URL on a public host that webhooks are posted to.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Type)]
+#[sqlx(transparent)]
+pub struct WebhookUrl(String);
+
+impl WebhookUrl {
+    pub fn new(url: String) -> Result<Self, String> {
+        let url = url.trim().to_string();
+        if url.len() > 2000 {
+            return Err("Webhook URL cannot be longer than 2000 characters".to_string());
+        }
+        let rest = url
+            .strip_prefix("https://")
+            .ok_or_else(|| "Webhook URL must start with https://".to_string())?;
+        let authority = rest.split(['/', '?', '#']).next().unwrap_or_default();
+        if authority.contains('@') || authority.chars().any(char::is_whitespace) {
+            return Err("Invalid webhook URL".to_string());
+        }
+        let host = match authority.strip_prefix('[') {
+            Some(ipv6) => ipv6.split(']').next().unwrap_or_default(),
+            None => authority.split(':').next().unwrap_or_default(),
+        }
+        .to_ascii_lowercase();
+        if host.is_empty() {
+            return Err("Invalid webhook URL".to_string());
+        }
+        if !is_public_host(&host) {
+            return Err("Webhook URL must point to a public host".to_string());
+        }
+        Ok(Self(url))
+    }
+
+    pub fn value(&self) -> &str {
+        &self.0
+    }
+}
+
+/// Whether `host` may be public, ruling out local names and IP literals of
+/// loopback, private and link-local networks.
+fn is_public_host(host: &str) -> bool {
+    if host == "localhost" || host.ends_with(".localhost") || host.ends_with(".local") {
+        return false;
+    }
+    match host.parse::<std::net::IpAddr>() {
+        Ok(std::net::IpAddr::V4(ip)) => {
+            !(ip.is_loopback()
+                || ip.is_private()
+                || ip.is_link_local()
+                || ip.is_unspecified()
+                || ip.is_broadcast())
+        }
+        Ok(std::net::IpAddr::V6(ip)) => {
+            let segments = ip.segments();
+            !(ip.is_loopback()
+                || ip.is_unspecified()
+                || (segments[0] & 0xfe00) == 0xfc00
+                || (segments[0] & 0xffc0) == 0xfe80
+                || ip.to_ipv4_mapped().is_some())
+        }
+        Err(_) => true,
+    }
+}
+
+/// When a reminder fires.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ReminderTrigger {
+    At(DateTime<Utc>),
+    /// This many minutes before the todo is due
+    BeforeDue(i32),
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, sqlx::FromRow)]
+pub struct Reminder {
+    pub id: Uuid,
+    pub todo_id: TodoId,
+    /// Who is reminded
+    pub user_id: Uuid,
+    pub remind_at: Option<DateTime<Utc>>,
+    pub offset_minutes: Option<i32>,
+    pub channels: Vec<DeliveryChannel>,
+    pub webhook_url: Option<WebhookUrl>,
+    /// The reminder time it last fired for
+    pub fired_for: Option<DateTime<Utc>>,
+    pub created_at: DateTime<Utc>,
+}
+
+impl Reminder {
+    pub fn new(
+        todo: &Todo,
+        user_id: Uuid,
+        trigger: ReminderTrigger,
+        mut channels: Vec<DeliveryChannel>,
+        webhook_url: Option<WebhookUrl>,
+        now: DateTime<Utc>,
+    ) -> Result<Self, String> {
+        let (remind_at, offset_minutes) = match trigger {
+            ReminderTrigger::At(at) if at <= now => {
+                return Err("remind_at must be in the future".to_string())
+            }
+            ReminderTrigger::At(at) => (Some(at), None),
+            ReminderTrigger::BeforeDue(_) if todo.due_at.is_none() => {
+                return Err("Todo has no due date to remind before".to_string())
+            }
+            ReminderTrigger::BeforeDue(minutes)
+                if !(0..=MAX_REMINDER_OFFSET_MINUTES).contains(&minutes) =>
+            {
+                return Err(format!(
+                    "offset_minutes must be between 0 and {}",
+                    MAX_REMINDER_OFFSET_MINUTES
+                ))
+            }
+            ReminderTrigger::BeforeDue(minutes) => (None, Some(minutes)),
+        };
+
+        channels.sort();
+        channels.dedup();
+        if channels.is_empty() {
+            return Err("A reminder needs at least one channel".to_string());
+        }
+        match (channels.contains(&DeliveryChannel::Webhook), &webhook_url) {
+            (true, None) => return Err("The webhook channel needs a webhook_url".to_string()),
+            (false, Some(_)) => {
+                return Err("webhook_url is only used by the webhook channel".to_string())
+            }
+            _ => {}
+        }
+
+        Ok(Self {
+            id: Uuid::new_v4(),
+            todo_id: todo.id,
+            user_id,
+            remind_at,
+            offset_minutes,
+            channels,
+            webhook_url,
+            fired_for: None,
+            created_at: now,
+        })
+    }
+
+    /// When the reminder fires for a todo due at `due_at`; never for an
+    /// offset reminder of a todo without a due date.
+    pub fn fire_at(&self, due_at: Option<DateTime<Utc>>) -> Option<DateTime<Utc>> {
+        match (self.remind_at, self.offset_minutes) {
+            (Some(at), _) => Some(at),
+            (None, Some(minutes)) => due_at.map(|due| due - Duration::minutes(minutes.into())),
+            (None, None) => None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, ToSchema, Type)]
+#[serde(rename_all = "snake_case")]
+#[sqlx(type_name = "delivery_status", rename_all = "snake_case")]
+pub enum DeliveryStatus {
+    /// Waiting for its next attempt
+    Pending,
+    Sent,
+    /// Given up after `MAX_DELIVERY_ATTEMPTS` attempts
+    Failed,
+}
+
+/// One channel's delivery of a reminder firing.
+#[derive(Debug, Clone, Serialize, Deserialize, sqlx::FromRow)]
+pub struct ReminderDelivery {
+    pub id: Uuid,
+    pub reminder_id: Uuid,
+    pub channel: DeliveryChannel,
+    /// The reminder time this delivery is for
+    pub scheduled_for: DateTime<Utc>,
+    pub status: DeliveryStatus,
+    pub attempts: i32,
+    pub next_attempt_at: DateTime<Utc>,
+    pub last_error: Option<String>,
+    pub created_at: DateTime<Utc>,
+    pub delivered_at: Option<DateTime<Utc>>,
+}
+
+/// A delivery claimed for an attempt, with what it takes to send it.
+#[derive(Debug, Clone, sqlx::FromRow)]
+pub struct PendingDelivery {
+    pub id: Uuid,
+    pub reminder_id: Uuid,
+    pub todo_id: TodoId,
+    pub user_id: Uuid,
+    pub channel: DeliveryChannel,
+    pub scheduled_for: DateTime<Utc>,
+    /// Attempts so far, this one included
+    pub attempts: i32,
+    pub email: String,
+    pub title: TodoTitle,
+    pub due_at: Option<DateTime<Utc>>,
+    pub webhook_url: Option<WebhookUrl>,
+}
+
+/// How long to wait after a failed attempt before the next one; `None`
+/// once the attempts are used up. Doubles from one minute.
+pub fn retry_delay(attempts: i32) -> Option<Duration> {
+    if attempts >= MAX_DELIVERY_ATTEMPTS {
+        return None;
+    }
+    Some(Duration::minutes(1 << (attempts.clamp(1, 10) - 1)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    fn todo_due(due_at: Option<DateTime<Utc>>) -> Todo {
+        let mut todo = Todo::new(
+            Uuid::new_v4(),
+            TodoTitle::new("Submit report".to_string()).unwrap(),
+            None,
+        );
+        todo.due_at = due_at;
+        todo
+    }
+
+    #[test]
+    fn test_reminder_validation() {
+        let now = Utc.with_ymd_and_hms(2026, 10, 21, 9, 0, 0).unwrap();
+        let due = now + Duration::days(1);
+        let todo = todo_due(Some(due));
+        let user = Uuid::new_v4();
+        let reminder = |trigger, channels: Vec<DeliveryChannel>, url: Option<&str>| {
+            let url = url.map(|u| WebhookUrl::new(u.to_string()).unwrap());
+            Reminder::new(&todo, user, trigger, channels, url, now)
+        };
+
+        let before = reminder(
+            ReminderTrigger::BeforeDue(90),
+            vec![
+                DeliveryChannel::Email,
+                DeliveryChannel::InApp,
+                DeliveryChannel::Email,
+            ],
+            None,
+        )
+        .unwrap();
+        assert_eq!(
+            before.channels,
+            vec![DeliveryChannel::InApp, DeliveryChannel::Email]
+        );
+        assert_eq!(
+            before.fire_at(todo.due_at),
+            Some(due - Duration::minutes(90))
+        );
+        // Follows the due date, and never fires without one
+        assert_eq!(
+            before.fire_at(Some(due + Duration::hours(1))),
+            Some(due - Duration::minutes(30))
+        );
+        assert_eq!(before.fire_at(None), None);
+
+        let at = reminder(
+            ReminderTrigger::At(now + Duration::hours(2)),
+            vec![DeliveryChannel::Webhook],
+            Some("https://hooks.example.com/todo"),
+        )
+        .unwrap();
+        assert_eq!(at.fire_at(None), Some(now + Duration::hours(2)));
+
+        let in_app = vec![DeliveryChannel::InApp];
+        assert!(reminder(ReminderTrigger::At(now), in_app.clone(), None).is_err());
+        assert!(reminder(ReminderTrigger::BeforeDue(-5), in_app.clone(), None).is_err());
+        assert!(reminder(
+            ReminderTrigger::BeforeDue(MAX_REMINDER_OFFSET_MINUTES + 1),
+            in_app.clone(),
+            None
+        )
+        .is_err());
+        assert!(reminder(ReminderTrigger::BeforeDue(10), Vec::new(), None).is_err());
+        assert!(reminder(
+            ReminderTrigger::BeforeDue(10),
+            vec![DeliveryChannel::Webhook],
+            None
+        )
+        .is_err());
+        assert!(reminder(
+            ReminderTrigger::BeforeDue(10),
+            in_app.clone(),
+            Some("https://hooks.example.com/todo")
+        )
+        .is_err());
+        assert!(Reminder::new(
+            &todo_due(None),
+            user,
+            ReminderTrigger::BeforeDue(10),
+            in_app,
+            None,
+            now
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn test_webhook_url_validation() {
+        assert!(WebhookUrl::new("https://hooks.example.com/a?b=c".to_string()).is_ok());
+        assert!(WebhookUrl::new("https://203.0.113.7:8443/hook".to_string()).is_ok());
+        for invalid in [
+            "http://hooks.example.com/",
+            "https://",
+            "https://localhost/hook",
+            "https://127.0.0.1/hook",
+            "https://10.1.2.3/hook",
+            "https://192.168.0.1:8080/",
+            "https://169.254.169.254/latest/meta-data",
+            "https://[::1]/hook",
+            "https://[::ffff:127.0.0.1]/hook",
+            "https://user@hooks.example.com/",
+        ] {
+            assert!(WebhookUrl::new(invalid.to_string()).is_err(), "{}", invalid);
+        }
+    }
+
+    #[test]
+    fn test_retry_delay() {
+        assert_eq!(retry_delay(1), Some(Duration::minutes(1)));
+        assert_eq!(retry_delay(2), Some(Duration::minutes(2)));
+        assert_eq!(retry_delay(4), Some(Duration::minutes(8)));
+        assert_eq!(retry_delay(MAX_DELIVERY_ATTEMPTS), None);
+    }
+}
@@ -0,0 +1,203 @@
+use chrono::{DateTime, Duration, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::Type;
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+use super::priority::TodoPriority;
+use super::search::SearchQuery;
+use super::tag::parse_tags;
+use super::todo_filter::TodoFilter;
+use super::workflow::TodoStatus;
+
+/// Longest relative due window a filter can have.
+pub const MAX_DUE_WITHIN_DAYS: u32 = 3650;
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, ToSchema, Type)]
+#[sqlx(transparent)]
+pub struct SavedFilterName(String);
+
+impl SavedFilterName {
+    pub fn new(name: String) -> Result<Self, String> {
+        let name = name.trim().to_string();
+        if name.is_empty() {
+            return Err("Filter name cannot be empty".to_string());
+        }
+        if name.chars().count() > 100 {
+            return Err("Filter name cannot be longer than 100 characters".to_string());
+        }
+        Ok(Self(name))
+    }
+
+    pub fn value(&self) -> &str {
+        &self.0
+    }
+}
+
+/// What a saved filter matches, stored as JSON. Every set field must
+/// match, as with the list endpoint's query parameters.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct FilterDefinition {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub completed: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub status: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub priority: Option<TodoPriority>,
+    /// Todos with all of these tags
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub tags: Vec<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub due_after: Option<DateTime<Utc>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub due_before: Option<DateTime<Utc>>,
+    /// Todos due within this many days of when the filter is evaluated,
+    /// overdue ones included; cannot be combined with `due_before`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub due_within_days: Option<u32>,
+    /// Search query, in the syntax of `GET /api/v1/todos/search`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub text: Option<String>,
+    /// Only todos of this workspace
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub workspace_id: Option<Uuid>,
+    /// Only todos of this project
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub project_id: Option<Uuid>,
+}
+
+impl FilterDefinition {
+    /// The list filter this definition stands for when evaluated at `now`.
+    pub fn filter(&self, now: DateTime<Utc>) -> Result<TodoFilter, String> {
+        let due_before = match (self.due_before, self.due_within_days) {
+            (Some(_), Some(_)) => {
+                return Err("due_before cannot be combined with due_within_days".to_string())
+            }
+            (_, Some(days)) if days > MAX_DUE_WITHIN_DAYS => {
+                return Err(format!(
+                    "due_within_days cannot be more than {}",
+                    MAX_DUE_WITHIN_DAYS
+                ))
+            }
+            (_, Some(days)) => Some(now + Duration::days(days.into())),
+            (due_before, None) => due_before,
+        };
+        let filter = TodoFilter {
+            completed: self.completed,
+            status: self.status.clone().map(TodoStatus::new).transpose()?,
+            priority: self.priority,
+            tags: parse_tags(self.tags.clone())?,
+            due_after: self.due_after,
+            due_before,
+            text: self
+                .text
+                .as_deref()
+                .map(|text| SearchQuery::parse(text, None))
+                .transpose()?,
+            workspace_id: self.workspace_id,
+            project_id: self.project_id,
+            ..Default::default()
+        };
+        filter.validate()?;
+        Ok(filter)
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, sqlx::FromRow)]
+pub struct SavedFilter {
+    pub id: Uuid,
+    pub user_id: Uuid,
+    pub name: SavedFilterName,
+    #[sqlx(json)]
+    pub definition: FilterDefinition,
+    /// When the filter's todos were last listed
+    pub last_viewed_at: Option<DateTime<Utc>>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl SavedFilter {
+    pub fn new(user_id: Uuid, name: SavedFilterName, definition: FilterDefinition) -> Self {
+        let now = Utc::now();
+        Self {
+            id: Uuid::new_v4(),
+            user_id,
+            name,
+            definition,
+            last_viewed_at: None,
+            created_at: now,
+            updated_at: now,
+        }
+    }
+
+    pub fn redefine(&mut self, name: SavedFilterName, definition: FilterDefinition) {
+        self.name = name;
+        self.definition = definition;
+        self.updated_at = Utc::now();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::entities::SearchTerm;
+    use chrono::TimeZone;
+
+    #[test]
+    fn test_filter_definition() {
+        let now = Utc.with_ymd_and_hms(2026, 10, 21, 9, 0, 0).unwrap();
+        let definition: FilterDefinition = serde_json::from_value(serde_json::json!({
+            "completed": false,
+            "tags": ["#Work", "q4"],
+            "due_within_days": 7,
+            "text": "\"release notes\" deploy*"
+        }))
+        .unwrap();
+
+        let filter = definition.filter(now).unwrap();
+        assert_eq!(filter.completed, Some(false));
+        assert_eq!(
+            filter.tags.iter().map(|t| t.value()).collect::<Vec<_>>(),
+            vec!["work", "q4"]
+        );
+        assert_eq!(
+            filter.due_before,
+            Some(Utc.with_ymd_and_hms(2026, 10, 28, 9, 0, 0).unwrap())
+        );
+        assert_eq!(
+            filter.text.unwrap().terms,
+            vec![
+                SearchTerm::Phrase("release notes".to_string()),
+                SearchTerm::Prefix("deploy".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_filter_definition_validation() {
+        let now = Utc::now();
+        let invalid = |json: serde_json::Value| {
+            serde_json::from_value::<FilterDefinition>(json)
+                .map_err(|e| e.to_string())
+                .and_then(|d| d.filter(now))
+                .is_err()
+        };
+
+        assert!(invalid(serde_json::json!({ "colour": "red" })));
+        assert!(invalid(serde_json::json!({ "status": "In Review" })));
+        assert!(invalid(serde_json::json!({ "tags": ["two words"] })));
+        assert!(invalid(serde_json::json!({ "text": "   " })));
+        assert!(invalid(serde_json::json!({ "due_within_days": 3651 })));
+        assert!(invalid(serde_json::json!({
+            "due_before": "2026-11-01T00:00:00Z",
+            "due_within_days": 3
+        })));
+        assert!(invalid(serde_json::json!({
+            "due_after": "2026-11-01T00:00:00Z",
+            "due_before": "2026-10-01T00:00:00Z"
+        })));
+        assert!(!invalid(serde_json::json!({})));
+        assert!(SavedFilterName::new("  ".to_string()).is_err());
+    }
+}
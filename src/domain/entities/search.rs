@@ -0,0 +1,296 @@
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+use super::todo::Todo;
+
+const MAX_QUERY_LENGTH: usize = 200;
+const SNIPPET_CONTEXT_CHARS: usize = 40;
+
+pub const HIGHLIGHT_START: &str = "<mark>";
+pub const HIGHLIGHT_END: &str = "</mark>";
+
+/// Text search configuration used to match a query.
+///
+/// `English` and `Simple` use Postgres full-text search (stemmed and
+/// unstemmed respectively). `Japanese` has no word boundaries Postgres can
+/// tokenize, so it falls back to case-insensitive substring matching.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum SearchLanguage {
+    English,
+    Simple,
+    Japanese,
+}
+
+impl SearchLanguage {
+    /// Picks `Japanese` when the query contains kana or kanji, `English` otherwise.
+    pub fn detect(query: &str) -> Self {
+        if query.chars().any(is_japanese) {
+            Self::Japanese
+        } else {
+            Self::English
+        }
+    }
+
+    /// Name of the Postgres text search configuration.
+    pub fn config(&self) -> &'static str {
+        match self {
+            Self::English => "english",
+            Self::Simple | Self::Japanese => "simple",
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum SearchTerm {
+    /// A single word, matched after normalization.
+    Word(String),
+    /// A `"quoted phrase"`, matched as adjacent words.
+    Phrase(String),
+    /// A word ending in `*`, matched as a prefix.
+    Prefix(String),
+}
+
+impl SearchTerm {
+    pub fn text(&self) -> &str {
+        match self {
+            Self::Word(t) | Self::Phrase(t) | Self::Prefix(t) => t,
+        }
+    }
+}
+
+/// A parsed search query. All terms must match.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SearchQuery {
+    pub terms: Vec<SearchTerm>,
+    pub language: SearchLanguage,
+}
+
+impl SearchQuery {
+    pub fn parse(query: &str, language: Option<SearchLanguage>) -> Result<Self, String> {
+        if query.chars().count() > MAX_QUERY_LENGTH {
+            return Err(format!(
+                "Search query cannot be longer than {} characters",
+                MAX_QUERY_LENGTH
+            ));
+        }
+
+        let mut terms = Vec::new();
+        let mut rest = query.trim();
+        while !rest.is_empty() {
+            if let Some(after_quote) = rest.strip_prefix('"') {
+                let (phrase, remainder) = after_quote.split_once('"').unwrap_or((after_quote, ""));
+                let phrase = phrase.split_whitespace().collect::<Vec<_>>().join(" ");
+                if !phrase.is_empty() {
+                    terms.push(SearchTerm::Phrase(phrase));
+                }
+                rest = remainder.trim_start();
+                continue;
+            }
+
+            let end = rest
+                .find(|c: char| c.is_whitespace() || c == '"')
+                .unwrap_or(rest.len());
+            let word = &rest[..end];
+            rest = rest[end..].trim_start();
+
+            match word.strip_suffix('*') {
+                Some(prefix) if !prefix.trim_end_matches('*').is_empty() => {
+                    terms.push(SearchTerm::Prefix(prefix.trim_end_matches('*').to_string()))
+                }
+                Some(_) => {}
+                None => terms.push(SearchTerm::Word(word.to_string())),
+            }
+        }
+
+        if terms.is_empty() {
+            return Err("Search query cannot be empty".to_string());
+        }
+
+        Ok(Self {
+            terms,
+            language: language.unwrap_or_else(|| SearchLanguage::detect(query)),
+        })
+    }
+}
+
+/// A todo matched by a search, with its relevance and highlighted snippets.
+#[derive(Debug, Clone)]
+pub struct TodoSearchHit {
+    pub todo: Todo,
+    pub rank: f32,
+    pub title_snippet: String,
+    pub description_snippet: Option<String>,
+}
+
+/// Wraps every case-insensitive occurrence of `terms` in `text` with
+/// highlight markers, trimming long text to a window around the first match.
+/// Used where Postgres' `ts_headline` cannot tokenize the text. The text is
+/// HTML-escaped, so only the markers are markup.
+pub fn highlight(text: &str, terms: &[SearchTerm]) -> Option<String> {
+    let chars: Vec<char> = text.chars().collect();
+    let lower: Vec<char> = chars.iter().map(|c| fold_case(*c)).collect();
+    let needles: Vec<Vec<char>> = terms
+        .iter()
+        .map(|t| t.text().chars().map(fold_case).collect::<Vec<_>>())
+        .filter(|n| !n.is_empty())
+        .collect();
+
+    // Mark matched character positions
+    let mut marked = vec![false; chars.len()];
+    for needle in &needles {
+        let mut i = 0;
+        while i + needle.len() <= lower.len() {
+            if lower[i..i + needle.len()] == needle[..] {
+                marked[i..i + needle.len()]
+                    .iter_mut()
+                    .for_each(|m| *m = true);
+                i += needle.len();
+            } else {
+                i += 1;
+            }
+        }
+    }
+    let first = marked.iter().position(|m| *m)?;
+
+    let start = first.saturating_sub(SNIPPET_CONTEXT_CHARS);
+    let end = (first + SNIPPET_CONTEXT_CHARS * 2).min(chars.len());
+    let mut snippet = String::new();
+    if start > 0 {
+        snippet.push('…');
+    }
+    for i in start..end {
+        if marked[i] && (i == start || !marked[i - 1]) {
+            snippet.push_str(HIGHLIGHT_START);
+        }
+        push_escaped(&mut snippet, chars[i]);
+        if marked[i] && (i + 1 == end || !marked[i + 1]) {
+            snippet.push_str(HIGHLIGHT_END);
+        }
+    }
+    if end < chars.len() {
+        snippet.push('…');
+    }
+    Some(snippet)
+}
+
+/// `text` with the characters that are special in HTML escaped.
+pub fn escape_html(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    text.chars().for_each(|c| push_escaped(&mut escaped, c));
+    escaped
+}
+
+fn push_escaped(out: &mut String, c: char) {
+    match c {
+        '&' => out.push_str("&amp;"),
+        '<' => out.push_str("&lt;"),
+        '>' => out.push_str("&gt;"),
+        '"' => out.push_str("&quot;"),
+        '\'' => out.push_str("&#39;"),
+        c => out.push(c),
+    }
+}
+
+fn fold_case(c: char) -> char {
+    c.to_lowercase().next().unwrap_or(c)
+}
+
+fn is_japanese(c: char) -> bool {
+    matches!(c,
+        '\u{3040}'..='\u{30ff}' // Hiragana and Katakana
+        | '\u{3400}'..='\u{4dbf}' // CJK Extension A
+        | '\u{4e00}'..='\u{9fff}' // CJK Unified Ideographs
+        | '\u{ff66}'..='\u{ff9f}' // Half-width Katakana
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_search_query_parsing() {
+        let query = SearchQuery::parse(r#"weekly "monthly report" budg*"#, None).unwrap();
+        assert_eq!(
+            query.terms,
+            vec![
+                SearchTerm::Word("weekly".to_string()),
+                SearchTerm::Phrase("monthly report".to_string()),
+                SearchTerm::Prefix("budg".to_string()),
+            ]
+        );
+        assert_eq!(query.language, SearchLanguage::English);
+
+        // Unterminated phrase runs to the end
+        let query = SearchQuery::parse(r#""call  the bank"#, None).unwrap();
+        assert_eq!(
+            query.terms,
+            vec![SearchTerm::Phrase("call the bank".to_string())]
+        );
+
+        // Empty queries
+        assert_eq!(
+            SearchQuery::parse("   ", None).unwrap_err(),
+            "Search query cannot be empty"
+        );
+        assert!(SearchQuery::parse(r#"* "" "#, None).is_err());
+        assert!(SearchQuery::parse(&"a".repeat(201), None).is_err());
+    }
+
+    #[test]
+    fn test_search_language_detection() {
+        assert_eq!(
+            SearchLanguage::detect("週次レポート"),
+            SearchLanguage::Japanese
+        );
+        assert_eq!(
+            SearchLanguage::detect("report ですか"),
+            SearchLanguage::Japanese
+        );
+        assert_eq!(
+            SearchLanguage::detect("weekly report"),
+            SearchLanguage::English
+        );
+
+        // Explicit language wins
+        let query = SearchQuery::parse("週次", Some(SearchLanguage::Simple)).unwrap();
+        assert_eq!(query.language, SearchLanguage::Simple);
+    }
+
+    #[test]
+    fn test_highlight() {
+        let terms = vec![SearchTerm::Word("レポート".to_string())];
+        assert_eq!(
+            highlight("週次レポートを書く", &terms).unwrap(),
+            "週次<mark>レポート</mark>を書く"
+        );
+
+        // Case-insensitive, adjacent matches are merged
+        let terms = vec![
+            SearchTerm::Word("rep".to_string()),
+            SearchTerm::Prefix("ort".to_string()),
+        ];
+        assert_eq!(
+            highlight("Weekly Report", &terms).unwrap(),
+            "Weekly <mark>Report</mark>"
+        );
+
+        // Long text is trimmed around the first match
+        let text = format!("{}needle{}", "x".repeat(100), "y".repeat(100));
+        let terms = vec![SearchTerm::Word("needle".to_string())];
+        let snippet = highlight(&text, &terms).unwrap();
+        assert!(snippet.starts_with('…') && snippet.ends_with('…'));
+        assert!(snippet.contains("<mark>needle</mark>"));
+
+        assert!(highlight("nothing here", &terms).is_none());
+
+        // The text around and inside matches is escaped
+        let terms = vec![SearchTerm::Word("<b>".to_string())];
+        assert_eq!(
+            highlight("a <b> & <script>", &terms).unwrap(),
+            "a <mark>&lt;b&gt;</mark> &amp; &lt;script&gt;"
+        );
+        assert_eq!(escape_html(r#"'"<&>"#), "&#39;&quot;&lt;&amp;&gt;");
+    }
+}
@@ -0,0 +1,73 @@
+use serde::{Deserialize, Serialize};
+use sqlx::Type;
+use utoipa::ToSchema;
+
+/// Most tags a todo can have.
+pub const MAX_TAGS: usize = 20;
+
+/// A label such as `finance`, stored lowercased and without its `#`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize, ToSchema, Type)]
+#[sqlx(transparent)]
+pub struct TodoTag(String);
+
+impl TodoTag {
+    pub fn new(tag: String) -> Result<Self, String> {
+        let tag = tag.trim();
+        let tag = tag.strip_prefix('#').unwrap_or(tag).to_lowercase();
+        if tag.is_empty() || tag.chars().count() > 50 {
+            return Err("Tags must be 1 to 50 characters long".to_string());
+        }
+        if !tag
+            .chars()
+            .all(|c| c.is_alphanumeric() || matches!(c, '_' | '-' | '/'))
+        {
+            return Err(format!(
+                "Tag {:?} may only contain letters, digits, '_', '-' and '/'",
+                tag
+            ));
+        }
+        Ok(Self(tag))
+    }
+
+    pub fn value(&self) -> &str {
+        &self.0
+    }
+}
+
+/// Validates a todo's tags, dropping repeats but keeping their order.
+pub fn parse_tags(tags: Vec<String>) -> Result<Vec<TodoTag>, String> {
+    let mut parsed: Vec<TodoTag> = Vec::new();
+    for tag in tags {
+        let tag = TodoTag::new(tag)?;
+        if !parsed.contains(&tag) {
+            parsed.push(tag);
+        }
+    }
+    if parsed.len() > MAX_TAGS {
+        return Err(format!("A todo can have at most {} tags", MAX_TAGS));
+    }
+    Ok(parsed)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_tag_validation() {
+        let tag = |t: &str| TodoTag::new(t.to_string()).map(|t| t.value().to_string());
+
+        assert_eq!(tag("#Finance"), Ok("finance".to_string()));
+        assert_eq!(tag(" work/q4 "), Ok("work/q4".to_string()));
+        assert_eq!(tag("家計"), Ok("家計".to_string()));
+        assert!(tag("#").is_err());
+        assert!(tag("two words").is_err());
+        assert!(tag("a,b").is_err());
+        assert!(tag(&"x".repeat(51)).is_err());
+
+        let tags = parse_tags(vec!["Work".into(), "home".into(), "#work".into()]).unwrap();
+        let values: Vec<_> = tags.iter().map(TodoTag::value).collect();
+        assert_eq!(values, vec!["work", "home"]);
+        assert!(parse_tags((0..21).map(|i| format!("t{}", i)).collect()).is_err());
+    }
+}
@@ -0,0 +1,451 @@
+use std::collections::BTreeMap;
+
+use chrono::{DateTime, Duration, NaiveDate, NaiveTime, Utc};
+use chrono_tz::Tz;
+use serde::{Deserialize, Serialize};
+use sqlx::Type;
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+use super::recurrence::resolve_local;
+use super::tag::TodoTag;
+use super::todo::TodoId;
+
+/// Maximum length of a time entry's note, in characters.
+pub const MAX_TIME_ENTRY_NOTE_LENGTH: usize = 1000;
+
+/// Longest time entry that can be entered by hand, in hours.
+pub const MAX_MANUAL_ENTRY_HOURS: i64 = 24;
+
+/// Longest range a time report can cover, in days.
+pub const MAX_REPORT_DAYS: i64 = 366;
+
+/// What the time of an entry was spent on.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Type)]
+#[sqlx(transparent)]
+pub struct TimeEntryNote(String);
+
+impl TimeEntryNote {
+    pub fn new(note: String) -> Result<Self, String> {
+        let note = note.trim().replace("\r\n", "\n");
+        if note.is_empty() {
+            return Err("Note cannot be empty".to_string());
+        }
+        if note.chars().count() > MAX_TIME_ENTRY_NOTE_LENGTH {
+            return Err(format!(
+                "Note cannot be longer than {} characters",
+                MAX_TIME_ENTRY_NOTE_LENGTH
+            ));
+        }
+        if note
+            .chars()
+            .any(|c| c.is_control() && c != '\n' && c != '\t')
+        {
+            return Err("Note cannot contain control characters".to_string());
+        }
+        Ok(Self(note))
+    }
+
+    pub fn value(&self) -> &str {
+        &self.0
+    }
+}
+
+/// Time a user spent on a todo. Entries started by a timer have no end
+/// until the timer is stopped.
+#[derive(Debug, Clone, Serialize, Deserialize, sqlx::FromRow)]
+pub struct TimeEntry {
+    pub id: Uuid,
+    pub todo_id: TodoId,
+    pub user_id: Uuid,
+    pub started_at: DateTime<Utc>,
+    pub ended_at: Option<DateTime<Utc>>,
+    pub note: Option<TimeEntryNote>,
+    pub created_at: DateTime<Utc>,
+}
+
+impl TimeEntry {
+    /// A running timer, started now.
+    pub fn start(todo_id: TodoId, user_id: Uuid) -> Self {
+        let now = Utc::now();
+        Self {
+            id: Uuid::new_v4(),
+            todo_id,
+            user_id,
+            started_at: now,
+            ended_at: None,
+            note: None,
+            created_at: now,
+        }
+    }
+
+    /// Time entered by hand. It must lie in the past and last at most
+    /// `MAX_MANUAL_ENTRY_HOURS`.
+    pub fn manual(
+        todo_id: TodoId,
+        user_id: Uuid,
+        started_at: DateTime<Utc>,
+        ended_at: DateTime<Utc>,
+        note: Option<TimeEntryNote>,
+        now: DateTime<Utc>,
+    ) -> Result<Self, String> {
+        if ended_at <= started_at {
+            return Err("ended_at must be after started_at".to_string());
+        }
+        if ended_at > now {
+            return Err("Time entries cannot end in the future".to_string());
+        }
+        if ended_at - started_at > Duration::hours(MAX_MANUAL_ENTRY_HOURS) {
+            return Err(format!(
+                "Time entries cannot be longer than {} hours",
+                MAX_MANUAL_ENTRY_HOURS
+            ));
+        }
+        Ok(Self {
+            id: Uuid::new_v4(),
+            todo_id,
+            user_id,
+            started_at,
+            ended_at: Some(ended_at),
+            note,
+            created_at: now,
+        })
+    }
+
+    pub fn is_running(&self) -> bool {
+        self.ended_at.is_none()
+    }
+
+    /// Whole seconds tracked, up to `now` while running.
+    pub fn duration_seconds(&self, now: DateTime<Utc>) -> i64 {
+        (self.ended_at.unwrap_or(now) - self.started_at)
+            .num_seconds()
+            .max(0)
+    }
+}
+
+/// What a time report's rows are grouped by.
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize, ToSchema,
+)]
+#[serde(rename_all = "snake_case")]
+pub enum ReportDimension {
+    /// Calendar day in the report's time zone
+    Day,
+    /// Workspace of the todo
+    Workspace,
+    /// Project of the todo, if it is in one
+    Project,
+    /// Tag of the todo; time on a todo with several tags counts toward each
+    Tag,
+}
+
+impl ReportDimension {
+    /// Parses a comma-separated list such as `day,tag`, in any order.
+    pub fn parse_list(list: &str) -> Result<Vec<Self>, String> {
+        let mut dimensions = list
+            .split(',')
+            .map(str::trim)
+            .filter(|name| !name.is_empty())
+            .map(|name| match name {
+                "day" => Ok(Self::Day),
+                "workspace" => Ok(Self::Workspace),
+                "project" => Ok(Self::Project),
+                "tag" => Ok(Self::Tag),
+                _ => Err(format!("Unknown report grouping: {}", name)),
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        dimensions.sort();
+        dimensions.dedup();
+        Ok(dimensions)
+    }
+
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::Day => "day",
+            Self::Workspace => "workspace",
+            Self::Project => "project",
+            Self::Tag => "tag",
+        }
+    }
+}
+
+/// The days a time report covers, `from` through `to` in `tz`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ReportRange {
+    pub from: NaiveDate,
+    pub to: NaiveDate,
+    pub tz: Tz,
+}
+
+impl ReportRange {
+    pub fn new(from: NaiveDate, to: NaiveDate, tz: Tz) -> Result<Self, String> {
+        if to < from {
+            return Err("to cannot be before from".to_string());
+        }
+        if (to - from).num_days() >= MAX_REPORT_DAYS {
+            return Err(format!(
+                "Reports cannot cover more than {} days",
+                MAX_REPORT_DAYS
+            ));
+        }
+        Ok(Self { from, to, tz })
+    }
+
+    /// The instant the first day begins.
+    pub fn start(&self) -> DateTime<Utc> {
+        start_of_day(self.from, self.tz)
+    }
+
+    /// The instant the last day ends.
+    pub fn end(&self) -> DateTime<Utc> {
+        start_of_day(self.to + Duration::days(1), self.tz)
+    }
+}
+
+fn start_of_day(date: NaiveDate, tz: Tz) -> DateTime<Utc> {
+    let midnight = date.and_time(NaiveTime::MIN);
+    resolve_local(tz, midnight).unwrap_or_else(|| midnight.and_utc())
+}
+
+/// Tracked time within a report's range, with the todo it was spent on.
+#[derive(Debug, Clone, sqlx::FromRow)]
+pub struct TrackedSpan {
+    pub started_at: DateTime<Utc>,
+    pub ended_at: DateTime<Utc>,
+    pub workspace_id: Uuid,
+    pub workspace_name: String,
+    pub project_id: Option<Uuid>,
+    pub project_name: Option<String>,
+    pub tags: Vec<TodoTag>,
+}
+
+/// Tracked time of one group. Fields of dimensions the report is not
+/// grouped by are `None`, as are `project` for todos outside projects and
+/// `tag` for untagged todos.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TimeReportRow {
+    pub day: Option<NaiveDate>,
+    pub workspace: Option<(Uuid, String)>,
+    pub project: Option<(Uuid, String)>,
+    pub tag: Option<TodoTag>,
+    pub seconds: i64,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TimeReport {
+    /// Every span counted once, whatever the grouping
+    pub total_seconds: i64,
+    /// Ordered by day, workspace name, project name and tag
+    pub rows: Vec<TimeReportRow>,
+}
+
+impl TimeReport {
+    pub fn new(spans: &[TrackedSpan], range: &ReportRange, group_by: &[ReportDimension]) -> Self {
+        type Key = (
+            Option<NaiveDate>,
+            Option<(String, Uuid)>,
+            Option<(String, Uuid)>,
+            Option<String>,
+        );
+        let mut groups: BTreeMap<Key, (Option<TodoTag>, i64)> = BTreeMap::new();
+        let mut total_seconds = 0;
+
+        for span in spans {
+            let pieces = if group_by.contains(&ReportDimension::Day) {
+                split_by_day(span.started_at, span.ended_at, range.tz)
+            } else {
+                vec![(None, (span.ended_at - span.started_at).num_seconds())]
+            };
+            let workspace = group_by
+                .contains(&ReportDimension::Workspace)
+                .then(|| (span.workspace_name.clone(), span.workspace_id));
+            let project = if group_by.contains(&ReportDimension::Project) {
+                span.project_name.clone().zip(span.project_id)
+            } else {
+                None
+            };
+            let tags: Vec<Option<&TodoTag>> =
+                if group_by.contains(&ReportDimension::Tag) && !span.tags.is_empty() {
+                    span.tags.iter().map(Some).collect()
+                } else {
+                    vec![None]
+                };
+
+            for (day, seconds) in pieces {
+                total_seconds += seconds;
+                for tag in &tags {
+                    let key = (
+                        day,
+                        workspace.clone(),
+                        project.clone(),
+                        tag.map(|t| t.value().to_string()),
+                    );
+                    groups.entry(key).or_insert_with(|| (tag.cloned(), 0)).1 += seconds;
+                }
+            }
+        }
+
+        let rows = groups
+            .into_iter()
+            .map(
+                |((day, workspace, project, _), (tag, seconds))| TimeReportRow {
+                    day,
+                    workspace: workspace.map(|(name, id)| (id, name)),
+                    project: project.map(|(name, id)| (id, name)),
+                    tag,
+                    seconds,
+                },
+            )
+            .collect();
+        Self {
+            total_seconds,
+            rows,
+        }
+    }
+}
+
+/// Splits `[start, end)` at the midnights of `tz`, giving the whole seconds
+/// falling on each day.
+fn split_by_day(start: DateTime<Utc>, end: DateTime<Utc>, tz: Tz) -> Vec<(Option<NaiveDate>, i64)> {
+    let mut pieces = Vec::new();
+    let mut at = start;
+    while at < end {
+        let day = at.with_timezone(&tz).date_naive();
+        let next = start_of_day(day + Duration::days(1), tz).min(end);
+        pieces.push((Some(day), (next - at).num_seconds()));
+        at = next;
+    }
+    pieces
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    fn utc(y: i32, m: u32, d: u32, h: u32, min: u32) -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(y, m, d, h, min, 0).unwrap()
+    }
+
+    fn date(y: i32, m: u32, d: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(y, m, d).unwrap()
+    }
+
+    #[test]
+    fn test_manual_time_entry_validation() {
+        let now = utc(2026, 10, 21, 12, 0);
+        let entry =
+            |start, end| TimeEntry::manual(TodoId::new(), Uuid::new_v4(), start, end, None, now);
+
+        let entry_ok = entry(utc(2026, 10, 21, 9, 0), utc(2026, 10, 21, 10, 30)).unwrap();
+        assert_eq!(entry_ok.duration_seconds(now), 5400);
+        assert!(!entry_ok.is_running());
+
+        assert!(entry(utc(2026, 10, 21, 10, 0), utc(2026, 10, 21, 10, 0)).is_err());
+        assert!(entry(utc(2026, 10, 21, 11, 0), utc(2026, 10, 21, 13, 0)).is_err());
+        assert!(entry(utc(2026, 10, 19, 11, 0), utc(2026, 10, 21, 11, 0)).is_err());
+
+        assert_eq!(
+            TimeEntryNote::new("  Call with client\r\n".to_string())
+                .unwrap()
+                .value(),
+            "Call with client"
+        );
+        assert!(TimeEntryNote::new(" ".to_string()).is_err());
+        assert!(TimeEntryNote::new("a".repeat(MAX_TIME_ENTRY_NOTE_LENGTH + 1)).is_err());
+
+        assert_eq!(
+            ReportDimension::parse_list("tag, day,tag").unwrap(),
+            vec![ReportDimension::Day, ReportDimension::Tag]
+        );
+        assert!(ReportDimension::parse_list("client").is_err());
+        assert!(ReportRange::new(date(2026, 10, 2), date(2026, 10, 1), Tz::UTC).is_err());
+        assert!(ReportRange::new(date(2025, 10, 1), date(2026, 10, 1), Tz::UTC).is_ok());
+        assert!(ReportRange::new(date(2025, 9, 30), date(2026, 10, 1), Tz::UTC).is_err());
+    }
+
+    #[test]
+    fn test_time_report() {
+        let tz: Tz = "Asia/Tokyo".parse().unwrap();
+        let range = ReportRange::new(date(2026, 10, 20), date(2026, 10, 21), tz).unwrap();
+        assert_eq!(range.start(), utc(2026, 10, 19, 15, 0));
+        assert_eq!(range.end(), utc(2026, 10, 21, 15, 0));
+
+        let client = Uuid::new_v4();
+        let tag = |t: &str| TodoTag::new(t.to_string()).unwrap();
+        let spans = vec![
+            // 23:00-01:00 in Tokyo, across midnight
+            TrackedSpan {
+                started_at: utc(2026, 10, 20, 14, 0),
+                ended_at: utc(2026, 10, 20, 16, 0),
+                workspace_id: client,
+                workspace_name: "Client".to_string(),
+                project_id: None,
+                project_name: None,
+                tags: vec![tag("design"), tag("review")],
+            },
+            TrackedSpan {
+                started_at: utc(2026, 10, 21, 1, 0),
+                ended_at: utc(2026, 10, 21, 1, 30),
+                workspace_id: client,
+                workspace_name: "Client".to_string(),
+                project_id: None,
+                project_name: None,
+                tags: Vec::new(),
+            },
+        ];
+
+        let by_day = TimeReport::new(&spans, &range, &[ReportDimension::Day]);
+        assert_eq!(by_day.total_seconds, 9000);
+        assert_eq!(
+            by_day
+                .rows
+                .iter()
+                .map(|r| (r.day.unwrap(), r.seconds))
+                .collect::<Vec<_>>(),
+            vec![(date(2026, 10, 20), 3600), (date(2026, 10, 21), 5400)]
+        );
+
+        // Tagged time counts toward each tag, the total only once
+        let by_tag = TimeReport::new(
+            &spans,
+            &range,
+            &[ReportDimension::Workspace, ReportDimension::Tag],
+        );
+        assert_eq!(by_tag.total_seconds, 9000);
+        assert_eq!(
+            by_tag
+                .rows
+                .iter()
+                .map(|r| (r.tag.as_ref().map(|t| t.value()), r.seconds))
+                .collect::<Vec<_>>(),
+            vec![(None, 1800), (Some("design"), 7200), (Some("review"), 7200)]
+        );
+        assert!(by_tag
+            .rows
+            .iter()
+            .all(|r| r.workspace == Some((client, "Client".to_string())) && r.day.is_none()));
+    }
+
+    #[test]
+    fn test_time_report_across_dst() {
+        // New York falls back on 2026-11-01, a 25-hour day
+        let tz: Tz = "America/New_York".parse().unwrap();
+        let range = ReportRange::new(date(2026, 11, 1), date(2026, 11, 1), tz).unwrap();
+        assert_eq!((range.end() - range.start()).num_hours(), 25);
+
+        let spans = vec![TrackedSpan {
+            started_at: range.start(),
+            ended_at: range.end(),
+            workspace_id: Uuid::new_v4(),
+            workspace_name: "Personal".to_string(),
+            project_id: None,
+            project_name: None,
+            tags: Vec::new(),
+        }];
+        let report = TimeReport::new(&spans, &range, &[ReportDimension::Day]);
+        assert_eq!(report.rows.len(), 1);
+        assert_eq!(report.rows[0].seconds, 25 * 3600);
+    }
+}
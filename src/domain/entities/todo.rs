@@ -1,9 +1,19 @@
+use std::collections::HashSet;
+
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use sqlx::Type;
 use utoipa::ToSchema;
 use uuid::Uuid;
 
+use super::board::initial_position;
+use super::checklist::{ChecklistItem, MAX_CHECKLIST_ITEMS};
+use super::priority::TodoPriority;
+use super::recurrence::{RecurrenceRule, TimeZoneName};
+use super::tag::TodoTag;
+use super::workflow::{TodoStatus, WorkflowStatus};
+use crate::shared::patch::Patch;
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, ToSchema, Type)]
 #[sqlx(transparent)]
 pub struct TodoId(pub Uuid);
@@ -49,12 +59,47 @@ impl TodoTitle {
 #[derive(Debug, Clone, Serialize, Deserialize, ToSchema, sqlx::FromRow)]
 pub struct Todo {
     pub id: TodoId,
+    /// Creator and owner
     pub user_id: Uuid,
+    /// User responsible for the todo, if anyone
+    pub assignee_id: Option<Uuid>,
+    /// Project within the workspace, whose workflow the todo follows
+    pub project_id: Option<Uuid>,
     pub title: TodoTitle,
     pub description: Option<String>,
+    pub priority: TodoPriority,
+    pub tags: Vec<TodoTag>,
+    /// Steps of the todo, in order.
+    #[sqlx(json)]
+    pub checklist: Vec<ChecklistItem>,
+    /// Position in the workflow of the project, or of the workspace.
+    pub status: TodoStatus,
+    /// Order within its status column on the board, lowest first.
+    pub position: f64,
+    /// Whether the status is terminal; kept for clients that predate statuses.
     pub completed: bool,
+    /// When the todo last reached a terminal status.
+    pub completed_at: Option<DateTime<Utc>>,
+    pub due_at: Option<DateTime<Utc>>,
+    pub recurrence_rule: Option<RecurrenceRule>,
+    pub time_zone: TimeZoneName,
+    /// Shared by every occurrence of a recurring todo; the first occurrence's id.
+    pub series_id: Option<Uuid>,
+    /// 1-based position of this occurrence in its series, used for `COUNT`.
+    pub occurrence_index: i32,
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
+    /// Set while the todo is in the trash.
+    pub deleted_at: Option<DateTime<Utc>>,
+    /// Incremented by every save; a save based on an older version fails.
+    pub version: i32,
+    /// Maintained by the comment repository, which bumps the version with it.
+    pub comment_count: i32,
+    /// Whether an open todo blocks this one; computed when the todo is loaded.
+    pub blocked: bool,
+    /// Seconds tracked on the todo by finished time entries; computed when
+    /// the todo is loaded.
+    pub tracked_seconds: i64,
 }
 
 impl Todo {
@@ -63,36 +108,251 @@ impl Todo {
         Self {
             id: TodoId::new(),
             user_id,
+            assignee_id: None,
+            project_id: None,
             title,
             description,
+            priority: TodoPriority::default(),
+            tags: Vec::new(),
+            checklist: Vec::new(),
+            status: TodoStatus::default(),
+            position: initial_position(now),
             completed: false,
+            completed_at: None,
+            due_at: None,
+            recurrence_rule: None,
+            time_zone: TimeZoneName::default(),
+            series_id: None,
+            occurrence_index: 1,
             created_at: now,
             updated_at: now,
+            deleted_at: None,
+            version: 1,
+            comment_count: 0,
+            blocked: false,
+            tracked_seconds: 0,
         }
     }
 
+    /// Applies the given fields, moving the todo to `status` if given; the
+    /// caller checks that the workflow allows the move. A blocked todo can
+    /// only reach a terminal status when `force` is set.
     pub fn update(
         &mut self,
         title: Option<TodoTitle>,
-        description: Option<String>,
-        completed: Option<bool>,
-    ) {
+        description: Patch<String>,
+        status: Option<&WorkflowStatus>,
+        force: bool,
+    ) -> Result<(), String> {
+        let completes = status.is_some_and(|status| status.terminal);
+        if self.blocked && !self.completed && completes && !force {
+            return Err(
+                "Todo is blocked by open todos; complete them first or force the completion"
+                    .to_string(),
+            );
+        }
         if let Some(t) = title {
             self.title = t;
         }
-        if let Some(d) = description {
-            self.description = Some(d);
+        description.apply(&mut self.description);
+        let now = Utc::now();
+        if let Some(status) = status {
+            self.status = status.key.clone();
+            if self.completed != status.terminal {
+                self.completed = status.terminal;
+                self.completed_at = status.terminal.then_some(now);
+            }
         }
-        if let Some(c) = completed {
-            self.completed = c;
+        self.updated_at = now;
+        Ok(())
+    }
+
+    /// Applies the given scheduling fields. A recurrence rule needs a due date
+    /// to anchor its occurrences; clearing the time zone resets it to UTC.
+    pub fn schedule(
+        &mut self,
+        due_at: Patch<DateTime<Utc>>,
+        recurrence_rule: Patch<RecurrenceRule>,
+        time_zone: Patch<TimeZoneName>,
+    ) -> Result<(), String> {
+        due_at.apply(&mut self.due_at);
+        recurrence_rule.apply(&mut self.recurrence_rule);
+        match time_zone {
+            Patch::Missing => {}
+            Patch::Null => self.time_zone = TimeZoneName::default(),
+            Patch::Value(tz) => self.time_zone = tz,
+        }
+        if self.recurrence_rule.is_some() {
+            if self.due_at.is_none() {
+                return Err("A recurring todo requires a due date".to_string());
+            }
+            self.series_id.get_or_insert(self.id.0);
         }
         self.updated_at = Utc::now();
+        Ok(())
+    }
+
+    /// Due dates of the upcoming occurrences after this one, honouring the
+    /// rule's `COUNT` relative to this occurrence's position in the series.
+    pub fn upcoming_occurrences(&self, limit: usize) -> Vec<DateTime<Utc>> {
+        let (Some(rule), Some(due_at)) = (&self.recurrence_rule, self.due_at) else {
+            return Vec::new();
+        };
+        let remaining = match rule.count() {
+            Some(count) => (i64::from(count) - i64::from(self.occurrence_index)).max(0) as usize,
+            None => usize::MAX,
+        };
+        rule.occurrences_after(due_at, self.time_zone.tz())
+            .take(limit.min(remaining))
+            .collect()
+    }
+
+    /// Builds the next open occurrence of a recurring todo, if the series has
+    /// one. It stays with the same assignee, in the same project, and starts
+    /// in the default workflow's initial status.
+    pub fn next_occurrence(&self) -> Option<Todo> {
+        let due_at = *self.upcoming_occurrences(1).first()?;
+        let now = Utc::now();
+        Some(Todo {
+            id: TodoId::new(),
+            user_id: self.user_id,
+            assignee_id: self.assignee_id,
+            project_id: self.project_id,
+            title: self.title.clone(),
+            description: self.description.clone(),
+            priority: self.priority,
+            tags: self.tags.clone(),
+            checklist: self
+                .checklist
+                .iter()
+                .map(|item| ChecklistItem {
+                    checked: false,
+                    ..item.clone()
+                })
+                .collect(),
+            status: TodoStatus::default(),
+            position: initial_position(now),
+            completed: false,
+            completed_at: None,
+            due_at: Some(due_at),
+            recurrence_rule: self.recurrence_rule.clone(),
+            time_zone: self.time_zone.clone(),
+            series_id: self.series_id,
+            occurrence_index: self.occurrence_index + 1,
+            created_at: now,
+            updated_at: now,
+            deleted_at: None,
+            version: 1,
+            comment_count: 0,
+            blocked: false,
+            tracked_seconds: 0,
+        })
+    }
+
+    pub fn prioritize(&mut self, priority: TodoPriority) {
+        self.priority = priority;
+        self.updated_at = Utc::now();
+    }
+
+    pub fn retag(&mut self, tags: Vec<TodoTag>) {
+        self.tags = tags;
+        self.updated_at = Utc::now();
+    }
+
+    /// Inserts a checklist item at `index`, or at the end.
+    pub fn add_checklist_item(
+        &mut self,
+        item: ChecklistItem,
+        index: Option<usize>,
+    ) -> Result<(), String> {
+        if self.checklist.len() >= MAX_CHECKLIST_ITEMS {
+            return Err(format!(
+                "A todo can have at most {} checklist items",
+                MAX_CHECKLIST_ITEMS
+            ));
+        }
+        let index = index.unwrap_or(self.checklist.len());
+        if index > self.checklist.len() {
+            return Err(format!("position must be at most {}", self.checklist.len()));
+        }
+        self.checklist.insert(index, item);
+        self.updated_at = Utc::now();
+        Ok(())
+    }
+
+    /// Puts the checklist in the order of `item_ids`, which must list every
+    /// item once.
+    pub fn reorder_checklist(&mut self, item_ids: &[Uuid]) -> Result<(), String> {
+        let mut seen = HashSet::new();
+        let complete = item_ids.len() == self.checklist.len()
+            && item_ids
+                .iter()
+                .all(|id| seen.insert(*id) && self.checklist.iter().any(|item| item.id == *id));
+        if !complete {
+            return Err("item_ids must list every checklist item once".to_string());
+        }
+        self.checklist
+            .sort_by_key(|item| item_ids.iter().position(|id| *id == item.id));
+        self.updated_at = Utc::now();
+        Ok(())
+    }
+
+    /// Checks or unchecks an item; `false` if the todo has no such item.
+    pub fn toggle_checklist_item(&mut self, item_id: Uuid) -> bool {
+        let Some(item) = self.checklist.iter_mut().find(|item| item.id == item_id) else {
+            return false;
+        };
+        item.checked = !item.checked;
+        self.updated_at = Utc::now();
+        true
+    }
+
+    /// Removes an item; `false` if the todo has no such item.
+    pub fn remove_checklist_item(&mut self, item_id: Uuid) -> bool {
+        let count = self.checklist.len();
+        self.checklist.retain(|item| item.id != item_id);
+        if self.checklist.len() == count {
+            return false;
+        }
+        self.updated_at = Utc::now();
+        true
+    }
+
+    /// Hands the todo to `assignee_id`, or to nobody.
+    pub fn assign(&mut self, assignee_id: Option<Uuid>) {
+        self.assignee_id = assignee_id;
+        self.updated_at = Utc::now();
+    }
+
+    /// Moves the todo to `project_id`, or out of any project; the caller
+    /// checks that its status is in the new project's workflow.
+    pub fn move_to_project(&mut self, project_id: Option<Uuid>) {
+        self.project_id = project_id;
+        self.updated_at = Utc::now();
+    }
+
+    pub fn is_trashed(&self) -> bool {
+        self.deleted_at.is_some()
+    }
+
+    /// Moves the todo to the trash.
+    pub fn trash(&mut self) {
+        let now = Utc::now();
+        self.deleted_at = Some(now);
+        self.updated_at = now;
+    }
+
+    /// Takes the todo back out of the trash.
+    pub fn restore(&mut self) {
+        self.deleted_at = None;
+        self.updated_at = Utc::now();
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::domain::entities::Workflow;
 
     #[test]
     fn test_todo_title_validation() {
@@ -119,4 +379,133 @@ mod tests {
             "Title cannot be longer than 255 characters"
         );
     }
+
+    #[test]
+    fn test_recurring_todo_next_occurrence() {
+        let mut todo = Todo::new(
+            Uuid::new_v4(),
+            TodoTitle::new("Weekly report".to_string()).unwrap(),
+            None,
+        );
+
+        // A rule without a due date is rejected
+        let rule = RecurrenceRule::new("FREQ=WEEKLY;COUNT=2".to_string()).unwrap();
+        assert!(todo
+            .schedule(Patch::Missing, Patch::Value(rule.clone()), Patch::Missing)
+            .is_err());
+
+        let due = DateTime::parse_from_rfc3339("2026-10-19T09:00:00Z")
+            .unwrap()
+            .with_timezone(&Utc);
+        todo.schedule(Patch::Value(due), Patch::Value(rule), Patch::Missing)
+            .unwrap();
+        assert_eq!(todo.series_id, Some(todo.id.0));
+
+        let next = todo.next_occurrence().expect("second occurrence");
+        assert_eq!(next.due_at, Some(due + chrono::Duration::weeks(1)));
+        assert_eq!(next.series_id, todo.series_id);
+        assert_eq!(next.occurrence_index, 2);
+        assert!(!next.completed);
+
+        // COUNT=2 ends the series after the second occurrence
+        assert!(next.next_occurrence().is_none());
+    }
+
+    #[test]
+    fn test_todo_update_clears_fields() {
+        let mut todo = Todo::new(
+            Uuid::new_v4(),
+            TodoTitle::new("Pay rent".to_string()).unwrap(),
+            Some("Before the 1st".to_string()),
+        );
+        todo.schedule(
+            Patch::Value(Utc::now()),
+            Patch::Missing,
+            Patch::Value(TimeZoneName::new("Asia/Tokyo".to_string()).unwrap()),
+        )
+        .unwrap();
+
+        // Omitted fields are left alone
+        let workflow = Workflow::default();
+        todo.update(None, Patch::Missing, workflow.get("done"), false)
+            .unwrap();
+        assert_eq!(todo.description.as_deref(), Some("Before the 1st"));
+
+        todo.update(None, Patch::Null, None, false).unwrap();
+        todo.schedule(Patch::Null, Patch::Missing, Patch::Null)
+            .unwrap();
+        assert_eq!(todo.description, None);
+        assert_eq!(todo.due_at, None);
+        assert_eq!(todo.time_zone, TimeZoneName::default());
+        assert!(todo.completed);
+        assert!(todo.completed_at.is_some());
+        assert_eq!(todo.status.value(), "done");
+
+        // Leaving the terminal statuses reopens the todo
+        todo.update(None, Patch::Missing, workflow.get("in_progress"), false)
+            .unwrap();
+        assert!(!todo.completed);
+        assert_eq!(todo.completed_at, None);
+    }
+
+    #[test]
+    fn test_blocked_todo_completion() {
+        let mut todo = Todo::new(
+            Uuid::new_v4(),
+            TodoTitle::new("Deploy".to_string()).unwrap(),
+            None,
+        );
+        todo.blocked = true;
+        let workflow = Workflow::default();
+
+        // Other changes are still allowed
+        todo.update(None, Patch::Value("After review".to_string()), None, false)
+            .unwrap();
+        todo.update(None, Patch::Missing, workflow.get("in_progress"), false)
+            .unwrap();
+        assert!(todo
+            .update(None, Patch::Missing, workflow.get("cancelled"), false)
+            .is_err());
+        assert!(!todo.completed);
+
+        todo.update(None, Patch::Missing, workflow.get("done"), true)
+            .unwrap();
+        assert!(todo.completed);
+    }
+
+    #[test]
+    fn test_checklist() {
+        let mut todo = Todo::new(
+            Uuid::new_v4(),
+            TodoTitle::new("Pack".to_string()).unwrap(),
+            None,
+        );
+        let item = |text: &str| ChecklistItem::new(text.to_string()).unwrap();
+        let texts = |todo: &Todo| {
+            todo.checklist
+                .iter()
+                .map(|i| i.text.clone())
+                .collect::<Vec<_>>()
+        };
+
+        assert!(ChecklistItem::new(" ".to_string()).is_err());
+        todo.add_checklist_item(item("passport"), None).unwrap();
+        todo.add_checklist_item(item("charger"), None).unwrap();
+        todo.add_checklist_item(item("tickets"), Some(0)).unwrap();
+        assert!(todo.add_checklist_item(item("socks"), Some(4)).is_err());
+        assert_eq!(texts(&todo), vec!["tickets", "passport", "charger"]);
+
+        let ids: Vec<_> = todo.checklist.iter().map(|i| i.id).collect();
+        assert!(todo.reorder_checklist(&[ids[2], ids[0]]).is_err());
+        assert!(todo.reorder_checklist(&[ids[2], ids[0], ids[0]]).is_err());
+        todo.reorder_checklist(&[ids[2], ids[0], ids[1]]).unwrap();
+        assert_eq!(texts(&todo), vec!["charger", "tickets", "passport"]);
+
+        assert!(todo.toggle_checklist_item(ids[0]));
+        assert!(todo.checklist[1].checked);
+        assert!(!todo.toggle_checklist_item(Uuid::new_v4()));
+        assert!(todo.remove_checklist_item(ids[1]));
+        assert!(!todo.remove_checklist_item(ids[1]));
+        assert_eq!(texts(&todo), vec!["charger", "tickets"]);
+    }
 }
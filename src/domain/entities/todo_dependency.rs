@@ -0,0 +1,123 @@
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, HashMap};
+
+use super::todo::{Todo, TodoId};
+use serde::{Deserialize, Serialize};
+
+/// A "blocked by" link: `todo_id` can't be completed while `blocker_id` is
+/// open.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, sqlx::FromRow)]
+pub struct TodoDependency {
+    pub todo_id: TodoId,
+    pub blocker_id: TodoId,
+}
+
+/// Orders `todos` so that every todo comes after the todos blocking it.
+/// Among todos that are ready at the same time, the one due soonest comes
+/// first, then the oldest. Links to todos outside `todos` are ignored.
+pub fn work_order(todos: Vec<Todo>, dependencies: &[TodoDependency]) -> Vec<Todo> {
+    let index: HashMap<TodoId, usize> = todos
+        .iter()
+        .enumerate()
+        .map(|(i, todo)| (todo.id, i))
+        .collect();
+    let mut blocker_count = vec![0usize; todos.len()];
+    let mut blocks: Vec<Vec<usize>> = vec![Vec::new(); todos.len()];
+    for dependency in dependencies {
+        if let (Some(&todo), Some(&blocker)) = (
+            index.get(&dependency.todo_id),
+            index.get(&dependency.blocker_id),
+        ) {
+            blocker_count[todo] += 1;
+            blocks[blocker].push(todo);
+        }
+    }
+
+    // Max-heap, so reversed: soonest due first, undated last, then oldest
+    let key = |todo: &Todo| {
+        Reverse((
+            todo.due_at.is_none(),
+            todo.due_at,
+            todo.created_at,
+            todo.id.0,
+        ))
+    };
+    let mut ready: BinaryHeap<_> = blocker_count
+        .iter()
+        .enumerate()
+        .filter(|(_, count)| **count == 0)
+        .map(|(i, _)| (key(&todos[i]), i))
+        .collect();
+
+    let mut order = Vec::with_capacity(todos.len());
+    while let Some((_, i)) = ready.pop() {
+        order.push(i);
+        for &next in &blocks[i] {
+            blocker_count[next] -= 1;
+            if blocker_count[next] == 0 {
+                ready.push((key(&todos[next]), next));
+            }
+        }
+    }
+
+    // Todos caught in a cycle, which adding links prevents, come last
+    let mut slots: Vec<Option<Todo>> = todos.into_iter().map(Some).collect();
+    let mut ordered: Vec<Todo> = order.into_iter().filter_map(|i| slots[i].take()).collect();
+    ordered.extend(slots.into_iter().flatten());
+    ordered
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::entities::TodoTitle;
+    use chrono::Utc;
+    use uuid::Uuid;
+
+    fn todo(title: &str, due_in_days: Option<i64>) -> Todo {
+        let mut todo = Todo::new(
+            Uuid::new_v4(),
+            TodoTitle::new(title.to_string()).unwrap(),
+            None,
+        );
+        todo.due_at = due_in_days.map(|days| Utc::now() + chrono::Duration::days(days));
+        todo
+    }
+
+    fn blocked_by(todo: &Todo, blocker: &Todo) -> TodoDependency {
+        TodoDependency {
+            todo_id: todo.id,
+            blocker_id: blocker.id,
+        }
+    }
+
+    #[test]
+    fn test_work_order() {
+        let design = todo("Design", Some(3));
+        let build = todo("Build", Some(1));
+        let test = todo("Test", None);
+        let docs = todo("Write docs", Some(2));
+        let dependencies = [
+            blocked_by(&build, &design),
+            blocked_by(&test, &build),
+            blocked_by(&docs, &design),
+            // Blockers outside the listing don't hold anything up
+            TodoDependency {
+                todo_id: design.id,
+                blocker_id: TodoId::new(),
+            },
+        ];
+
+        let order = work_order(
+            vec![test.clone(), docs.clone(), build.clone(), design.clone()],
+            &dependencies,
+        );
+        let titles: Vec<_> = order.iter().map(|t| t.title.value()).collect();
+        // Build and the docs are ready together; Build is due first
+        assert_eq!(titles, vec!["Design", "Build", "Write docs", "Test"]);
+
+        let unrelated = work_order(vec![test.clone(), docs.clone(), build.clone()], &[]);
+        let titles: Vec<_> = unrelated.iter().map(|t| t.title.value()).collect();
+        assert_eq!(titles, vec!["Build", "Write docs", "Test"]);
+    }
+}
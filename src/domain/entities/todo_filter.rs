@@ -0,0 +1,268 @@
+use base64::engine::general_purpose::URL_SAFE_NO_PAD;
+use base64::Engine;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+use super::priority::TodoPriority;
+use super::search::SearchQuery;
+use super::tag::TodoTag;
+use super::todo::Todo;
+use super::todo_query::TodoQuery;
+use super::workflow::TodoStatus;
+
+/// Criteria for listing todos. Every set field must match; `_after` bounds
+/// are inclusive and `_before` bounds are exclusive.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TodoFilter {
+    pub completed: Option<bool>,
+    pub status: Option<TodoStatus>,
+    pub priority: Option<TodoPriority>,
+    /// Todos with all of these tags.
+    pub tags: Vec<TodoTag>,
+    pub created_after: Option<DateTime<Utc>>,
+    pub created_before: Option<DateTime<Utc>>,
+    pub updated_after: Option<DateTime<Utc>>,
+    pub updated_before: Option<DateTime<Utc>>,
+    pub due_after: Option<DateTime<Utc>>,
+    pub due_before: Option<DateTime<Utc>>,
+    /// Todos assigned to this user, among those the lister owns or has been
+    /// shared.
+    pub assignee_id: Option<Uuid>,
+    /// Todos matching this search query.
+    pub text: Option<SearchQuery>,
+    pub workspace_id: Option<Uuid>,
+    pub project_id: Option<Uuid>,
+    /// Todos matching this `q` expression.
+    pub query: Option<TodoQuery>,
+}
+
+impl TodoFilter {
+    pub fn validate(&self) -> Result<(), String> {
+        let ranges = [
+            ("created", self.created_after, self.created_before),
+            ("updated", self.updated_after, self.updated_before),
+            ("due", self.due_after, self.due_before),
+        ];
+        for (name, after, before) in ranges {
+            if let (Some(after), Some(before)) = (after, before) {
+                if after >= before {
+                    return Err(format!(
+                        "{}_after must be earlier than {}_before",
+                        name, name
+                    ));
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum TodoSortField {
+    #[default]
+    CreatedAt,
+    UpdatedAt,
+    Title,
+    DueAt,
+    /// Open todos by `smart_score`, most pressing first
+    Smart,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum SortOrder {
+    Asc,
+    Desc,
+}
+
+/// Ordering for todo lists. Ties are broken by id so pages are stable, and
+/// todos without a due date sort last in either direction.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TodoSort {
+    pub field: TodoSortField,
+    pub order: SortOrder,
+}
+
+impl TodoSort {
+    /// Newest first for timestamps, A-Z for titles, soonest first for due dates
+    /// and highest score first for smart ranking.
+    pub fn new(field: Option<TodoSortField>, order: Option<SortOrder>) -> Self {
+        let field = field.unwrap_or_default();
+        let default_order = match field {
+            TodoSortField::CreatedAt | TodoSortField::UpdatedAt | TodoSortField::Smart => {
+                SortOrder::Desc
+            }
+            TodoSortField::Title | TodoSortField::DueAt => SortOrder::Asc,
+        };
+        Self {
+            field,
+            order: order.unwrap_or(default_order),
+        }
+    }
+}
+
+impl Default for TodoSort {
+    fn default() -> Self {
+        Self::new(None, None)
+    }
+}
+
+/// Value of the sort column at a cursor position.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum CursorKey {
+    Timestamp(DateTime<Utc>),
+    Text(String),
+    Null,
+}
+
+/// A keyset pagination position: the sort key and id of a boundary todo.
+///
+/// A forward cursor continues with the todos after that row in `sort`
+/// order; a backward cursor returns the page before it.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TodoCursor {
+    pub sort: TodoSort,
+    pub key: CursorKey,
+    pub id: Uuid,
+    pub backward: bool,
+}
+
+impl TodoCursor {
+    pub fn new(todo: &Todo, sort: TodoSort, backward: bool) -> Self {
+        let key = match sort.field {
+            // Smart ranks are paged by offset; such cursors never decode
+            TodoSortField::CreatedAt | TodoSortField::Smart => {
+                CursorKey::Timestamp(todo.created_at)
+            }
+            TodoSortField::UpdatedAt => CursorKey::Timestamp(todo.updated_at),
+            TodoSortField::Title => CursorKey::Text(todo.title.value().to_string()),
+            TodoSortField::DueAt => todo.due_at.map_or(CursorKey::Null, CursorKey::Timestamp),
+        };
+        Self {
+            sort,
+            key,
+            id: todo.id.0,
+            backward,
+        }
+    }
+
+    /// Opaque URL-safe token for clients.
+    pub fn encode(&self) -> String {
+        let json = serde_json::to_vec(self).unwrap_or_default();
+        URL_SAFE_NO_PAD.encode(json)
+    }
+
+    pub fn decode(token: &str) -> Result<Self, String> {
+        let invalid = || "Invalid cursor".to_string();
+        let json = URL_SAFE_NO_PAD.decode(token).map_err(|_| invalid())?;
+        let cursor: Self = serde_json::from_slice(&json).map_err(|_| invalid())?;
+
+        let key_matches_field = matches!(
+            (cursor.sort.field, &cursor.key),
+            (TodoSortField::Title, CursorKey::Text(_))
+                | (
+                    TodoSortField::CreatedAt | TodoSortField::UpdatedAt | TodoSortField::DueAt,
+                    CursorKey::Timestamp(_)
+                )
+                | (TodoSortField::DueAt, CursorKey::Null)
+        );
+        if !key_matches_field {
+            return Err(invalid());
+        }
+        Ok(cursor)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_filter_range_validation() {
+        let now = Utc::now();
+        let valid = TodoFilter {
+            due_after: Some(now),
+            due_before: Some(now + chrono::Duration::days(1)),
+            ..Default::default()
+        };
+        assert!(valid.validate().is_ok());
+
+        let inverted = TodoFilter {
+            created_after: Some(now),
+            created_before: Some(now),
+            ..Default::default()
+        };
+        assert_eq!(
+            inverted.validate().unwrap_err(),
+            "created_after must be earlier than created_before"
+        );
+    }
+
+    #[test]
+    fn test_sort_defaults() {
+        assert_eq!(
+            TodoSort::default(),
+            TodoSort {
+                field: TodoSortField::CreatedAt,
+                order: SortOrder::Desc
+            }
+        );
+        assert_eq!(
+            TodoSort::new(Some(TodoSortField::DueAt), None).order,
+            SortOrder::Asc
+        );
+        assert_eq!(
+            TodoSort::new(Some(TodoSortField::Title), Some(SortOrder::Desc)).order,
+            SortOrder::Desc
+        );
+    }
+
+    #[test]
+    fn test_cursor_round_trip() {
+        let mut todo = Todo::new(
+            Uuid::new_v4(),
+            crate::domain::entities::TodoTitle::new("2026-10-18T00:00:00Z".to_string()).unwrap(),
+            None,
+        );
+        let sort = TodoSort::new(Some(TodoSortField::Title), None);
+        let cursor = TodoCursor::new(&todo, sort, false);
+        assert_eq!(TodoCursor::decode(&cursor.encode()).unwrap(), cursor);
+        assert_eq!(
+            cursor.key,
+            CursorKey::Text("2026-10-18T00:00:00Z".to_string())
+        );
+
+        // Todos without a due date get a null key
+        let sort = TodoSort::new(Some(TodoSortField::DueAt), None);
+        let cursor = TodoCursor::new(&todo, sort, true);
+        assert_eq!(cursor.key, CursorKey::Null);
+        assert_eq!(TodoCursor::decode(&cursor.encode()).unwrap(), cursor);
+
+        todo.due_at = Some(Utc::now());
+        let cursor = TodoCursor::new(&todo, sort, false);
+        assert!(matches!(cursor.key, CursorKey::Timestamp(_)));
+    }
+
+    #[test]
+    fn test_cursor_rejects_tampering() {
+        assert_eq!(
+            TodoCursor::decode("not a cursor").unwrap_err(),
+            "Invalid cursor"
+        );
+
+        // Key type must match the sort field
+        let forged = URL_SAFE_NO_PAD.encode(
+            serde_json::json!({
+                "sort": { "field": "created_at", "order": "desc" },
+                "key": { "Text": "x" },
+                "id": Uuid::new_v4(),
+                "backward": false
+            })
+            .to_string(),
+        );
+        assert!(TodoCursor::decode(&forged).is_err());
+    }
+}
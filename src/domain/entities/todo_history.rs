@@ -0,0 +1,229 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+use sqlx::Type;
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+use super::tag::TodoTag;
+use super::todo::{Todo, TodoId};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, ToSchema, Type)]
+#[serde(rename_all = "snake_case")]
+#[sqlx(type_name = "todo_action", rename_all = "snake_case")]
+pub enum TodoAction {
+    Created,
+    Updated,
+    Completed,
+    /// Moved to the trash
+    Deleted,
+    /// Taken back out of the trash
+    Restored,
+    /// Assignee set or cleared
+    Assigned,
+}
+
+/// A recorded change to a todo. `before` and `after` hold only the fields
+/// that changed.
+#[derive(Debug, Clone, Serialize, Deserialize, sqlx::FromRow)]
+pub struct TodoHistoryEntry {
+    pub id: Uuid,
+    pub todo_id: TodoId,
+    /// Owner of the todo
+    pub user_id: Uuid,
+    /// User who made the change; `None` once that account is deleted
+    pub actor_id: Option<Uuid>,
+    pub action: TodoAction,
+    /// `None` for creations
+    pub before: Option<Value>,
+    pub after: Value,
+    pub created_at: DateTime<Utc>,
+}
+
+impl TodoHistoryEntry {
+    pub fn created(todo: &Todo, actor_id: Uuid) -> Self {
+        Self::new(
+            todo,
+            actor_id,
+            TodoAction::Created,
+            None,
+            Value::Object(snapshot(todo)),
+        )
+    }
+
+    /// The change from `before` to `after`, or `None` if no recorded field
+    /// changed.
+    pub fn changed(before: &Todo, after: &Todo, actor_id: Uuid) -> Option<Self> {
+        let old = snapshot(before);
+        let new = snapshot(after);
+        let (old, new): (Map<String, Value>, Map<String, Value>) = new
+            .into_iter()
+            .filter(|(field, value)| old.get(field) != Some(value))
+            .map(|(field, value)| ((field.clone(), old[&field].clone()), (field, value)))
+            .unzip();
+        if new.is_empty() {
+            return None;
+        }
+
+        let action = match (before.is_trashed(), after.is_trashed()) {
+            (false, true) => TodoAction::Deleted,
+            (true, false) => TodoAction::Restored,
+            _ if !before.completed && after.completed => TodoAction::Completed,
+            _ if before.assignee_id != after.assignee_id => TodoAction::Assigned,
+            _ => TodoAction::Updated,
+        };
+        Some(Self::new(
+            after,
+            actor_id,
+            action,
+            Some(Value::Object(old)),
+            Value::Object(new),
+        ))
+    }
+
+    fn new(
+        todo: &Todo,
+        actor_id: Uuid,
+        action: TodoAction,
+        before: Option<Value>,
+        after: Value,
+    ) -> Self {
+        Self {
+            id: Uuid::new_v4(),
+            todo_id: todo.id,
+            user_id: todo.user_id,
+            actor_id: Some(actor_id),
+            action,
+            before,
+            after,
+            created_at: Utc::now(),
+        }
+    }
+}
+
+/// The recorded fields of a todo.
+fn snapshot(todo: &Todo) -> Map<String, Value> {
+    Map::from_iter([
+        ("title".to_string(), Value::from(todo.title.value())),
+        (
+            "description".to_string(),
+            Value::from(todo.description.clone()),
+        ),
+        ("priority".to_string(), Value::from(todo.priority.as_str())),
+        (
+            "tags".to_string(),
+            Value::from_iter(todo.tags.iter().map(TodoTag::value)),
+        ),
+        (
+            "checklist".to_string(),
+            serde_json::to_value(&todo.checklist).unwrap_or_default(),
+        ),
+        ("status".to_string(), Value::from(todo.status.value())),
+        ("completed".to_string(), Value::from(todo.completed)),
+        (
+            "assignee_id".to_string(),
+            Value::from(todo.assignee_id.map(|id| id.to_string())),
+        ),
+        (
+            "project_id".to_string(),
+            Value::from(todo.project_id.map(|id| id.to_string())),
+        ),
+        ("due_at".to_string(), timestamp(todo.due_at)),
+        (
+            "recurrence_rule".to_string(),
+            Value::from(todo.recurrence_rule.as_ref().map(|r| r.value())),
+        ),
+        ("time_zone".to_string(), Value::from(todo.time_zone.value())),
+        ("deleted_at".to_string(), timestamp(todo.deleted_at)),
+    ])
+}
+
+fn timestamp(value: Option<DateTime<Utc>>) -> Value {
+    Value::from(value.map(|t| t.to_rfc3339()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::entities::{TodoTitle, Workflow};
+    use crate::shared::patch::Patch;
+    use serde_json::json;
+
+    fn todo() -> Todo {
+        Todo::new(
+            Uuid::new_v4(),
+            TodoTitle::new("Write report".to_string()).unwrap(),
+            Some("Draft".to_string()),
+        )
+    }
+
+    #[test]
+    fn test_history_records_changed_fields() {
+        let actor = Uuid::new_v4();
+        let before = todo();
+
+        let created = TodoHistoryEntry::created(&before, actor);
+        assert_eq!(created.action, TodoAction::Created);
+        assert_eq!(created.before, None);
+        assert_eq!(created.after["title"], "Write report");
+
+        let mut after = before.clone();
+        after.update(None, Patch::Null, None, false).unwrap();
+        let entry = TodoHistoryEntry::changed(&before, &after, actor).unwrap();
+        assert_eq!(entry.action, TodoAction::Updated);
+        assert_eq!(entry.before, Some(json!({ "description": "Draft" })));
+        assert_eq!(entry.after, json!({ "description": null }));
+        assert_eq!(entry.actor_id, Some(actor));
+
+        // Only updated_at changed
+        let mut touched = before.clone();
+        touched.update(None, Patch::Missing, None, false).unwrap();
+        assert!(TodoHistoryEntry::changed(&before, &touched, actor).is_none());
+    }
+
+    #[test]
+    fn test_history_action() {
+        let actor = Uuid::new_v4();
+        let before = todo();
+
+        let mut completed = before.clone();
+        completed
+            .update(
+                Some(TodoTitle::new("Final report".to_string()).unwrap()),
+                Patch::Missing,
+                Workflow::default().get("done"),
+                false,
+            )
+            .unwrap();
+        let entry = TodoHistoryEntry::changed(&before, &completed, actor).unwrap();
+        assert_eq!(entry.action, TodoAction::Completed);
+        assert_eq!(
+            entry.after,
+            json!({ "completed": true, "status": "done", "title": "Final report" })
+        );
+
+        let mut trashed = completed.clone();
+        trashed.trash();
+        let entry = TodoHistoryEntry::changed(&completed, &trashed, actor).unwrap();
+        assert_eq!(entry.action, TodoAction::Deleted);
+
+        let mut restored = trashed.clone();
+        restored.restore();
+        let entry = TodoHistoryEntry::changed(&trashed, &restored, actor).unwrap();
+        assert_eq!(entry.action, TodoAction::Restored);
+        assert_eq!(entry.after, json!({ "deleted_at": null }));
+
+        let assignee = Uuid::new_v4();
+        let mut assigned = restored.clone();
+        assigned.assign(Some(assignee));
+        let entry = TodoHistoryEntry::changed(&restored, &assigned, actor).unwrap();
+        assert_eq!(entry.action, TodoAction::Assigned);
+        assert_eq!(entry.before, Some(json!({ "assignee_id": null })));
+        assert_eq!(entry.after, json!({ "assignee_id": assignee.to_string() }));
+
+        let mut unassigned = assigned.clone();
+        unassigned.assign(None);
+        let entry = TodoHistoryEntry::changed(&assigned, &unassigned, actor).unwrap();
+        assert_eq!(entry.action, TodoAction::Assigned);
+    }
+}
@@ -0,0 +1,530 @@
+use std::fmt;
+
+use chrono::{DateTime, Duration, NaiveDate, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use super::priority::TodoPriority;
+use super::search::SearchQuery;
+use super::tag::TodoTag;
+use super::workflow::TodoStatus;
+
+const MAX_QUERY_LENGTH: usize = 500;
+const MAX_TERMS: usize = 50;
+const MAX_DEPTH: usize = 16;
+
+/// How a field is compared with a value.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Comparison {
+    Eq,
+    Lt,
+    Le,
+    Gt,
+    Ge,
+}
+
+impl Comparison {
+    pub fn sql(self) -> &'static str {
+        match self {
+            Self::Eq => "=",
+            Self::Lt => "<",
+            Self::Le => "<=",
+            Self::Gt => ">",
+            Self::Ge => ">=",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum DateField {
+    Due,
+    Created,
+    Updated,
+}
+
+/// A parsed `q` expression for listing todos, such as
+/// `status:open tag:work due<2026-11-01 (priority:high OR assignee:me) -tag:later`.
+///
+/// Terms next to each other must all match; `OR` binds looser than that,
+/// and `-` or `NOT` negates the term or group after it. `status:open` and
+/// `status:closed` match todos by whether they are completed, any other
+/// status by its key. Dates are `YYYY-MM-DD` days in UTC or RFC 3339 times;
+/// `due:none` matches todos without a due date. Other words and
+/// `"quoted phrases"` are searched for in the title and description.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum TodoQuery {
+    And(Vec<TodoQuery>),
+    Or(Vec<TodoQuery>),
+    Not(Box<TodoQuery>),
+    Completed(bool),
+    Status(TodoStatus),
+    Priority(Comparison, TodoPriority),
+    Tag(TodoTag),
+    /// `None` matches unassigned todos.
+    Assignee(Option<Uuid>),
+    Date(DateField, Comparison, DateTime<Utc>),
+    NoDueDate,
+    Text(SearchQuery),
+}
+
+/// Why a query could not be parsed, and where.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct QueryError {
+    /// Offset of the offending character, counting characters from 0.
+    pub position: usize,
+    pub message: String,
+}
+
+impl QueryError {
+    fn new(position: usize, message: impl Into<String>) -> Self {
+        Self {
+            position,
+            message: message.into(),
+        }
+    }
+}
+
+impl fmt::Display for QueryError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} at position {}", self.message, self.position)
+    }
+}
+
+impl TodoQuery {
+    /// Parses `query` for `user_id`, who `assignee:me` stands for.
+    pub fn parse(query: &str, user_id: Uuid) -> Result<Self, QueryError> {
+        let length = query.chars().count();
+        if length > MAX_QUERY_LENGTH {
+            return Err(QueryError::new(
+                MAX_QUERY_LENGTH,
+                format!(
+                    "Query cannot be longer than {} characters",
+                    MAX_QUERY_LENGTH
+                ),
+            ));
+        }
+
+        let tokens = tokenize(query)?;
+        if tokens.is_empty() {
+            return Err(QueryError::new(0, "Query cannot be empty"));
+        }
+        let mut parser = Parser {
+            tokens,
+            next: 0,
+            end: length,
+            user_id,
+            terms: 0,
+        };
+        let parsed = parser.or(0)?;
+        match parser.peek() {
+            None => Ok(parsed),
+            Some(token) => Err(QueryError::new(token.position, "Unexpected ')'")),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Token {
+    Open,
+    Close,
+    Not,
+    And,
+    Or,
+    Word(String),
+    Phrase(String),
+}
+
+#[derive(Debug, Clone)]
+struct Spanned {
+    token: Token,
+    position: usize,
+}
+
+fn tokenize(query: &str) -> Result<Vec<Spanned>, QueryError> {
+    let chars: Vec<char> = query.chars().collect();
+    let mut tokens = Vec::new();
+    let mut i = 0;
+    while i < chars.len() {
+        let position = i;
+        let token = match chars[i] {
+            c if c.is_whitespace() => {
+                i += 1;
+                continue;
+            }
+            '(' => {
+                i += 1;
+                Token::Open
+            }
+            ')' => {
+                i += 1;
+                Token::Close
+            }
+            '-' if chars.get(i + 1).is_some_and(|c| !c.is_whitespace()) => {
+                i += 1;
+                Token::Not
+            }
+            '"' => {
+                let close = chars[i + 1..]
+                    .iter()
+                    .position(|c| *c == '"')
+                    .ok_or_else(|| QueryError::new(position, "Unterminated quote"))?;
+                let phrase: String = chars[i + 1..i + 1 + close].iter().collect();
+                i += close + 2;
+                if phrase.trim().is_empty() {
+                    return Err(QueryError::new(position, "Empty phrase"));
+                }
+                Token::Phrase(phrase)
+            }
+            _ => {
+                let start = i;
+                while i < chars.len()
+                    && !chars[i].is_whitespace()
+                    && !matches!(chars[i], '(' | ')' | '"')
+                {
+                    i += 1;
+                }
+                let word: String = chars[start..i].iter().collect();
+                match word.as_str() {
+                    "AND" => Token::And,
+                    "OR" => Token::Or,
+                    "NOT" => Token::Not,
+                    _ => Token::Word(word),
+                }
+            }
+        };
+        tokens.push(Spanned { token, position });
+    }
+    Ok(tokens)
+}
+
+struct Parser {
+    tokens: Vec<Spanned>,
+    next: usize,
+    /// Position just past the query, for errors at its end.
+    end: usize,
+    user_id: Uuid,
+    terms: usize,
+}
+
+impl Parser {
+    fn peek(&self) -> Option<&Spanned> {
+        self.tokens.get(self.next)
+    }
+
+    fn advance(&mut self) -> Option<Spanned> {
+        let token = self.tokens.get(self.next).cloned();
+        self.next += 1;
+        token
+    }
+
+    /// `and (OR and)*`
+    fn or(&mut self, depth: usize) -> Result<TodoQuery, QueryError> {
+        let mut alternatives = vec![self.and(depth)?];
+        while self.peek().is_some_and(|t| t.token == Token::Or) {
+            self.advance();
+            alternatives.push(self.and(depth)?);
+        }
+        Ok(flatten(alternatives, TodoQuery::Or))
+    }
+
+    /// `unary ([AND] unary)*`
+    fn and(&mut self, depth: usize) -> Result<TodoQuery, QueryError> {
+        let mut terms = vec![self.unary(depth)?];
+        loop {
+            match self.peek().map(|t| &t.token) {
+                None | Some(Token::Close | Token::Or) => break,
+                Some(Token::And) => {
+                    self.advance();
+                }
+                Some(_) => {}
+            }
+            terms.push(self.unary(depth)?);
+        }
+        Ok(flatten(terms, TodoQuery::And))
+    }
+
+    /// `(-|NOT) unary | '(' or ')' | term`
+    fn unary(&mut self, depth: usize) -> Result<TodoQuery, QueryError> {
+        let Some(Spanned { token, position }) = self.advance() else {
+            return Err(QueryError::new(self.end, "Expected a term"));
+        };
+        if depth >= MAX_DEPTH {
+            return Err(QueryError::new(position, "Query is nested too deeply"));
+        }
+        match token {
+            Token::Not => Ok(TodoQuery::Not(Box::new(self.unary(depth + 1)?))),
+            Token::Open => {
+                let inner = self.or(depth + 1)?;
+                match self.advance() {
+                    Some(Spanned {
+                        token: Token::Close,
+                        ..
+                    }) => Ok(inner),
+                    _ => Err(QueryError::new(position, "Unclosed '('")),
+                }
+            }
+            Token::Close => Err(QueryError::new(position, "Unexpected ')'")),
+            Token::And | Token::Or => Err(QueryError::new(
+                position,
+                "Expected a term before AND or OR",
+            )),
+            Token::Word(word) => {
+                self.count_term(position)?;
+                self.term(&word, position)
+            }
+            Token::Phrase(phrase) => {
+                self.count_term(position)?;
+                let query = SearchQuery::parse(&format!("\"{}\"", phrase), None)
+                    .map_err(|e| QueryError::new(position, e))?;
+                Ok(TodoQuery::Text(query))
+            }
+        }
+    }
+
+    fn count_term(&mut self, position: usize) -> Result<(), QueryError> {
+        self.terms += 1;
+        if self.terms > MAX_TERMS {
+            return Err(QueryError::new(
+                position,
+                format!("Query cannot have more than {} terms", MAX_TERMS),
+            ));
+        }
+        Ok(())
+    }
+
+    /// A `field:value` or `field<value` comparison, or else a search word.
+    fn term(&self, word: &str, position: usize) -> Result<TodoQuery, QueryError> {
+        let chars: Vec<char> = word.chars().collect();
+        let operator = chars
+            .iter()
+            .position(|c| matches!(c, ':' | '<' | '>' | '='))
+            .filter(|i| {
+                *i > 0
+                    && chars[..*i]
+                        .iter()
+                        .all(|c| c.is_ascii_alphabetic() || *c == '_')
+            });
+        let Some(operator) = operator else {
+            let query = SearchQuery::parse(word, None).map_err(|e| QueryError::new(position, e))?;
+            return Ok(TodoQuery::Text(query));
+        };
+
+        let field: String = chars[..operator].iter().collect();
+        let (comparison, width) = match (chars[operator], chars.get(operator + 1)) {
+            ('<', Some('=')) => (Comparison::Le, 2),
+            ('>', Some('=')) => (Comparison::Ge, 2),
+            ('<', _) => (Comparison::Lt, 1),
+            ('>', _) => (Comparison::Gt, 1),
+            _ => (Comparison::Eq, 1),
+        };
+        let value_position = position + operator + width;
+        let value: String = chars[operator + width..].iter().collect();
+        if value.is_empty() {
+            return Err(QueryError::new(
+                value_position,
+                format!("Expected a value after {:?}", word),
+            ));
+        }
+        let invalid = |message: String| QueryError::new(value_position, message);
+
+        let ordered = matches!(field.as_str(), "priority" | "due" | "created" | "updated");
+        let known = ordered || matches!(field.as_str(), "status" | "tag" | "assignee");
+        if !known {
+            return Err(QueryError::new(
+                position,
+                format!("Unknown field {:?}", field),
+            ));
+        }
+        if comparison != Comparison::Eq && !ordered {
+            return Err(QueryError::new(
+                position + operator,
+                format!("{} can only be compared with ':'", field),
+            ));
+        }
+
+        match field.as_str() {
+            "status" => match value.as_str() {
+                "open" => Ok(TodoQuery::Completed(false)),
+                "closed" => Ok(TodoQuery::Completed(true)),
+                _ => TodoStatus::new(value)
+                    .map(TodoQuery::Status)
+                    .map_err(invalid),
+            },
+            "tag" => TodoTag::new(value).map(TodoQuery::Tag).map_err(invalid),
+            "assignee" => match value.as_str() {
+                "me" => Ok(TodoQuery::Assignee(Some(self.user_id))),
+                "none" => Ok(TodoQuery::Assignee(None)),
+                _ => Uuid::parse_str(&value)
+                    .map(|id| TodoQuery::Assignee(Some(id)))
+                    .map_err(|_| invalid("assignee must be me, none or a user ID".to_string())),
+            },
+            "priority" => {
+                let priority = [
+                    TodoPriority::None,
+                    TodoPriority::Low,
+                    TodoPriority::Medium,
+                    TodoPriority::High,
+                    TodoPriority::Urgent,
+                ]
+                .into_iter()
+                .find(|p| p.as_str() == value)
+                .ok_or_else(|| {
+                    invalid(format!(
+                        "Unknown priority {:?}; use none, low, medium, high or urgent",
+                        value
+                    ))
+                })?;
+                Ok(TodoQuery::Priority(comparison, priority))
+            }
+            _ => {
+                let field = match field.as_str() {
+                    "due" => DateField::Due,
+                    "created" => DateField::Created,
+                    _ => DateField::Updated,
+                };
+                if value == "none" && field == DateField::Due && comparison == Comparison::Eq {
+                    return Ok(TodoQuery::NoDueDate);
+                }
+                date_term(field, comparison, &value).ok_or_else(|| {
+                    invalid(format!(
+                        "Invalid date {:?}; use YYYY-MM-DD or an RFC 3339 time",
+                        value
+                    ))
+                })
+            }
+        }
+    }
+}
+
+/// Compares with a UTC day as a whole: `:` matches any time that day, `<`
+/// anything before it and `<=` anything up to its end.
+fn date_term(field: DateField, comparison: Comparison, value: &str) -> Option<TodoQuery> {
+    if let Ok(time) = DateTime::parse_from_rfc3339(value) {
+        return Some(TodoQuery::Date(field, comparison, time.with_timezone(&Utc)));
+    }
+    let day = NaiveDate::parse_from_str(value, "%Y-%m-%d").ok()?;
+    let start = day.and_hms_opt(0, 0, 0)?.and_utc();
+    let end = start + Duration::days(1);
+    Some(match comparison {
+        Comparison::Eq => TodoQuery::And(vec![
+            TodoQuery::Date(field, Comparison::Ge, start),
+            TodoQuery::Date(field, Comparison::Lt, end),
+        ]),
+        Comparison::Lt | Comparison::Ge => TodoQuery::Date(field, comparison, start),
+        Comparison::Le => TodoQuery::Date(field, Comparison::Lt, end),
+        Comparison::Gt => TodoQuery::Date(field, Comparison::Ge, end),
+    })
+}
+
+fn flatten(mut items: Vec<TodoQuery>, combine: fn(Vec<TodoQuery>) -> TodoQuery) -> TodoQuery {
+    if items.len() == 1 {
+        items.remove(0)
+    } else {
+        combine(items)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    fn parse(query: &str) -> Result<TodoQuery, QueryError> {
+        TodoQuery::parse(query, Uuid::nil())
+    }
+
+    fn error(query: &str) -> (usize, String) {
+        let error = parse(query).unwrap_err();
+        (error.position, error.message)
+    }
+
+    fn tag(tag: &str) -> TodoQuery {
+        TodoQuery::Tag(TodoTag::new(tag.to_string()).unwrap())
+    }
+
+    #[test]
+    fn test_query_parsing() {
+        let day = Utc.with_ymd_and_hms(2026, 11, 1, 0, 0, 0).unwrap();
+        assert_eq!(
+            parse("status:open tag:work due<2026-11-01 (priority:high OR assignee:me) -tag:later"),
+            Ok(TodoQuery::And(vec![
+                TodoQuery::Completed(false),
+                tag("work"),
+                TodoQuery::Date(DateField::Due, Comparison::Lt, day),
+                TodoQuery::Or(vec![
+                    TodoQuery::Priority(Comparison::Eq, TodoPriority::High),
+                    TodoQuery::Assignee(Some(Uuid::nil())),
+                ]),
+                TodoQuery::Not(Box::new(tag("later"))),
+            ]))
+        );
+
+        // OR binds looser than juxtaposition and AND
+        assert_eq!(
+            parse("tag:a AND tag:b OR NOT tag:c"),
+            Ok(TodoQuery::Or(vec![
+                TodoQuery::And(vec![tag("a"), tag("b")]),
+                TodoQuery::Not(Box::new(tag("c"))),
+            ]))
+        );
+
+        assert_eq!(
+            parse("due:2026-11-01"),
+            Ok(TodoQuery::And(vec![
+                TodoQuery::Date(DateField::Due, Comparison::Ge, day),
+                TodoQuery::Date(DateField::Due, Comparison::Lt, day + Duration::days(1)),
+            ]))
+        );
+        assert_eq!(
+            parse("updated>=2026-11-01T09:00:00+09:00"),
+            Ok(TodoQuery::Date(
+                DateField::Updated,
+                Comparison::Ge,
+                Utc.with_ymd_and_hms(2026, 11, 1, 0, 0, 0).unwrap()
+            ))
+        );
+        assert_eq!(parse("due:none"), Ok(TodoQuery::NoDueDate));
+        assert_eq!(parse("assignee:none"), Ok(TodoQuery::Assignee(None)));
+        assert_eq!(
+            parse("priority>=medium"),
+            Ok(TodoQuery::Priority(Comparison::Ge, TodoPriority::Medium))
+        );
+
+        // Anything else is searched for
+        assert!(matches!(parse("\"release notes\""), Ok(TodoQuery::Text(_))));
+        assert!(matches!(parse("10:30"), Ok(TodoQuery::Text(_))));
+        assert!(matches!(parse("e-mail"), Ok(TodoQuery::Text(_))));
+    }
+
+    #[test]
+    fn test_query_errors() {
+        assert_eq!(error(""), (0, "Query cannot be empty".to_string()));
+        assert_eq!(error("tag:work )"), (9, "Unexpected ')'".to_string()));
+        assert_eq!(error("tag:a (tag:b"), (6, "Unclosed '('".to_string()));
+        assert_eq!(error("tag:a OR"), (8, "Expected a term".to_string()));
+        assert_eq!(
+            error("OR tag:a"),
+            (0, "Expected a term before AND or OR".to_string())
+        );
+        assert_eq!(error("say \"hi"), (4, "Unterminated quote".to_string()));
+        assert_eq!(
+            error("colour:red"),
+            (0, "Unknown field \"colour\"".to_string())
+        );
+        assert_eq!(
+            error("tag<work"),
+            (3, "tag can only be compared with ':'".to_string())
+        );
+        assert_eq!(
+            error("a due:"),
+            (6, "Expected a value after \"due:\"".to_string())
+        );
+        assert_eq!(error("due<11/01").0, 4);
+        assert_eq!(error("priority:critical").0, 9);
+        assert_eq!(error("タグ tag:#").0, 7);
+        assert_eq!(error("assignee:bob").0, 9);
+        assert!(error(&"(".repeat(20)).1.contains("nested"));
+        assert!(error(&"x ".repeat(51)).1.contains("50 terms"));
+        assert_eq!(error(&"x".repeat(501)).0, 500);
+    }
+}
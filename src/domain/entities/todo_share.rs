@@ -0,0 +1,101 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::Type;
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+use super::todo::TodoId;
+
+/// Access granted to a user a todo or project is shared with.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, ToSchema, Type)]
+#[serde(rename_all = "snake_case")]
+#[sqlx(type_name = "share_permission", rename_all = "snake_case")]
+pub enum SharePermission {
+    /// Can read and comment
+    Viewer,
+    /// Can also change the todo and its attachments
+    Editor,
+}
+
+/// How a user is related to a todo.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TodoRole {
+    Owner,
+    Editor,
+    Viewer,
+}
+
+impl From<SharePermission> for TodoRole {
+    fn from(permission: SharePermission) -> Self {
+        match permission {
+            SharePermission::Viewer => Self::Viewer,
+            SharePermission::Editor => Self::Editor,
+        }
+    }
+}
+
+/// Something a user may want to do with a todo.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TodoPermission {
+    View,
+    Comment,
+    Edit,
+    /// Move to the trash
+    Delete,
+    /// Manage who the todo is shared with
+    Share,
+}
+
+impl TodoRole {
+    pub fn allows(self, permission: TodoPermission) -> bool {
+        match permission {
+            TodoPermission::View | TodoPermission::Comment => true,
+            TodoPermission::Edit => matches!(self, Self::Owner | Self::Editor),
+            TodoPermission::Delete | TodoPermission::Share => self == Self::Owner,
+        }
+    }
+}
+
+/// A todo shared with another user.
+#[derive(Debug, Clone, Serialize, Deserialize, sqlx::FromRow)]
+pub struct TodoShare {
+    pub todo_id: TodoId,
+    pub user_id: Uuid,
+    /// Email of the user the todo is shared with
+    pub email: String,
+    pub permission: SharePermission,
+    pub created_at: DateTime<Utc>,
+}
+
+/// A project shared with another user, covering every todo in it.
+#[derive(Debug, Clone, Serialize, Deserialize, sqlx::FromRow)]
+pub struct ProjectShare {
+    pub project_id: Uuid,
+    pub user_id: Uuid,
+    /// Email of the user the project is shared with
+    pub email: String,
+    pub permission: SharePermission,
+    pub created_at: DateTime<Utc>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_todo_role_permissions() {
+        use TodoPermission::*;
+
+        for permission in [View, Comment, Edit, Delete, Share] {
+            assert!(TodoRole::Owner.allows(permission));
+        }
+
+        let editor = TodoRole::from(SharePermission::Editor);
+        assert!(editor.allows(View) && editor.allows(Comment) && editor.allows(Edit));
+        assert!(!editor.allows(Delete) && !editor.allows(Share));
+
+        let viewer = TodoRole::from(SharePermission::Viewer);
+        assert!(viewer.allows(View) && viewer.allows(Comment));
+        assert!(!viewer.allows(Edit) && !viewer.allows(Delete) && !viewer.allows(Share));
+    }
+}
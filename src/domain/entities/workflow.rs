@@ -0,0 +1,392 @@
+use std::collections::HashSet;
+
+use serde::{Deserialize, Serialize};
+use sqlx::Type;
+use utoipa::ToSchema;
+
+use super::todo::Todo;
+
+/// Most statuses a workflow can have.
+pub const MAX_WORKFLOW_STATUSES: usize = 20;
+
+/// Key of a workflow status, e.g. `in_review`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize, ToSchema, Type)]
+#[sqlx(transparent)]
+pub struct TodoStatus(String);
+
+impl TodoStatus {
+    pub fn new(key: String) -> Result<Self, String> {
+        if key.is_empty() || key.len() > 50 {
+            return Err("Status keys must be 1 to 50 characters long".to_string());
+        }
+        if !key
+            .chars()
+            .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '_')
+        {
+            return Err(format!(
+                "Status key {:?} may only contain lowercase letters, digits and underscores",
+                key
+            ));
+        }
+        Ok(Self(key))
+    }
+
+    pub fn value(&self) -> &str {
+        &self.0
+    }
+}
+
+/// The initial status of the default workflow.
+impl Default for TodoStatus {
+    fn default() -> Self {
+        Self("todo".to_string())
+    }
+}
+
+/// A status todos can be in, and the statuses they can move on to from it.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct WorkflowStatus {
+    pub key: TodoStatus,
+    pub name: String,
+    /// Todos in a terminal status count as completed.
+    pub terminal: bool,
+    /// A terminal status for todos that were dropped rather than done.
+    /// Recurring todos cancelled here don't spawn their next occurrence.
+    pub cancelled: bool,
+    pub transitions: Vec<TodoStatus>,
+}
+
+impl WorkflowStatus {
+    /// Whether todos in this status were done.
+    pub fn is_done(&self) -> bool {
+        self.terminal && !self.cancelled
+    }
+}
+
+/// The statuses of a workspace's todos. New todos start in the first status,
+/// which is never terminal.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Workflow {
+    statuses: Vec<WorkflowStatus>,
+}
+
+impl Workflow {
+    pub fn new(statuses: Vec<WorkflowStatus>) -> Result<Self, String> {
+        if statuses.is_empty() || statuses.len() > MAX_WORKFLOW_STATUSES {
+            return Err(format!(
+                "A workflow must have 1 to {} statuses",
+                MAX_WORKFLOW_STATUSES
+            ));
+        }
+        let mut keys = HashSet::new();
+        for status in &statuses {
+            if !keys.insert(&status.key) {
+                return Err(format!("Duplicate status: {}", status.key.value()));
+            }
+            let name_length = status.name.trim().chars().count();
+            if name_length == 0 || name_length > 100 {
+                return Err("Status names must be 1 to 100 characters long".to_string());
+            }
+            if status.cancelled && !status.terminal {
+                return Err(format!(
+                    "Cancelled status {} must be terminal",
+                    status.key.value()
+                ));
+            }
+        }
+        for status in &statuses {
+            let mut targets = HashSet::new();
+            for target in &status.transitions {
+                if !keys.contains(target) {
+                    return Err(format!("Unknown status: {}", target.value()));
+                }
+                if *target == status.key || !targets.insert(target) {
+                    return Err(format!("Invalid transitions from {}", status.key.value()));
+                }
+            }
+        }
+        if statuses[0].terminal {
+            return Err("The first status cannot be terminal".to_string());
+        }
+        if !statuses.iter().any(WorkflowStatus::is_done) {
+            return Err(
+                "A workflow needs at least one terminal status that isn't cancelled".to_string(),
+            );
+        }
+        Ok(Self { statuses })
+    }
+
+    pub fn statuses(&self) -> &[WorkflowStatus] {
+        &self.statuses
+    }
+
+    /// The status new todos start in.
+    pub fn initial(&self) -> &WorkflowStatus {
+        &self.statuses[0]
+    }
+
+    pub fn get(&self, key: &str) -> Option<&WorkflowStatus> {
+        self.statuses
+            .iter()
+            .find(|status| status.key.value() == key)
+    }
+
+    /// The status a todo moves to from `from`, if the workflow allows it.
+    /// Todos in a status the workflow no longer has can move anywhere.
+    pub fn transition(
+        &self,
+        from: &TodoStatus,
+        to: &TodoStatus,
+    ) -> Result<&WorkflowStatus, String> {
+        let target = self
+            .get(to.value())
+            .ok_or_else(|| format!("Unknown status: {}", to.value()))?;
+        match self.get(from.value()) {
+            Some(current) if !current.transitions.contains(to) => Err(format!(
+                "Cannot move a todo from {} to {}",
+                from.value(),
+                to.value()
+            )),
+            _ => Ok(target),
+        }
+    }
+
+    /// The status `todo` moves to for a requested `status` and `completed`
+    /// flag, or `None` if it stays where it is. Each is only considered
+    /// when it differs from the todo's; a change of `completed` alone moves
+    /// to the first terminal status the todo can reach that isn't
+    /// cancelled, or reopens it in the initial status, or else the first
+    /// open one it can reach.
+    pub fn target(
+        &self,
+        todo: &Todo,
+        status: Option<TodoStatus>,
+        completed: Option<bool>,
+    ) -> Result<Option<&WorkflowStatus>, String> {
+        let status = status.filter(|status| *status != todo.status);
+        let completed = completed.filter(|completed| *completed != todo.completed);
+        match (status, completed) {
+            (Some(status), completed) => {
+                let target = self.transition(&todo.status, &status)?;
+                if completed.is_some_and(|completed| completed != target.terminal) {
+                    return Err(format!(
+                        "completed contradicts status {}",
+                        target.key.value()
+                    ));
+                }
+                Ok(Some(target))
+            }
+            (None, Some(completed)) => {
+                let reachable =
+                    |status: &&WorkflowStatus| self.transition(&todo.status, &status.key).is_ok();
+                let initial = Some(self.initial()).filter(|_| !completed);
+                initial
+                    .filter(reachable)
+                    .or_else(|| {
+                        self.statuses
+                            .iter()
+                            .filter(|status| {
+                                if completed {
+                                    status.is_done()
+                                } else {
+                                    !status.terminal
+                                }
+                            })
+                            .find(reachable)
+                    })
+                    .map(Some)
+                    .ok_or_else(|| {
+                        format!(
+                            "A todo in status {} cannot be {}",
+                            todo.status.value(),
+                            if completed { "completed" } else { "reopened" }
+                        )
+                    })
+            }
+            (None, None) => Ok(None),
+        }
+    }
+}
+
+/// To do, in progress, in review, then done or cancelled; finished todos
+/// can be reopened.
+impl Default for Workflow {
+    fn default() -> Self {
+        let status = |key: &str, name: &str, terminal: bool, transitions: &[&str]| WorkflowStatus {
+            key: TodoStatus(key.to_string()),
+            name: name.to_string(),
+            terminal,
+            cancelled: key == "cancelled",
+            transitions: transitions
+                .iter()
+                .map(|key| TodoStatus(key.to_string()))
+                .collect(),
+        };
+        Self {
+            statuses: vec![
+                status(
+                    "todo",
+                    "To do",
+                    false,
+                    &["in_progress", "done", "cancelled"],
+                ),
+                status(
+                    "in_progress",
+                    "In progress",
+                    false,
+                    &["todo", "in_review", "done", "cancelled"],
+                ),
+                status(
+                    "in_review",
+                    "In review",
+                    false,
+                    &["in_progress", "done", "cancelled"],
+                ),
+                status("done", "Done", true, &["todo", "in_progress"]),
+                status("cancelled", "Cancelled", true, &["todo"]),
+            ],
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::entities::TodoTitle;
+    use uuid::Uuid;
+
+    fn key(key: &str) -> TodoStatus {
+        TodoStatus::new(key.to_string()).unwrap()
+    }
+
+    fn status(name: &str, terminal: bool, transitions: &[&str]) -> WorkflowStatus {
+        WorkflowStatus {
+            key: key(name),
+            name: name.to_string(),
+            terminal,
+            cancelled: false,
+            transitions: transitions.iter().map(|t| key(t)).collect(),
+        }
+    }
+
+    #[test]
+    fn test_workflow_validation() {
+        assert!(TodoStatus::new("In Review".to_string()).is_err());
+        assert!(Workflow::new(Vec::new()).is_err());
+        assert!(Workflow::new(vec![status("done", true, &[])]).is_err());
+        assert!(Workflow::new(vec![status("open", false, &[])]).is_err());
+        assert!(Workflow::new(vec![
+            status("open", false, &["shipped"]),
+            status("done", true, &[]),
+        ])
+        .is_err());
+        assert!(Workflow::new(vec![
+            status("open", false, &["done"]),
+            status("done", true, &[]),
+            status("open", false, &[]),
+        ])
+        .is_err());
+        assert!(Workflow::new(vec![
+            status("open", false, &["done"]),
+            status("done", true, &["open"]),
+        ])
+        .is_ok());
+        assert!(Workflow::new(Workflow::default().statuses).is_ok());
+
+        // Cancelled statuses are terminal, and some other terminal status
+        // is for done todos
+        let dropped = WorkflowStatus {
+            cancelled: true,
+            ..status("dropped", true, &[])
+        };
+        assert!(Workflow::new(vec![status("open", false, &["dropped"]), dropped.clone()]).is_err());
+        assert!(Workflow::new(vec![
+            status("open", false, &["dropped"]),
+            WorkflowStatus {
+                terminal: false,
+                ..dropped.clone()
+            },
+            status("done", true, &[]),
+        ])
+        .is_err());
+        assert!(Workflow::new(vec![
+            status("open", false, &["dropped", "done"]),
+            dropped,
+            status("done", true, &[]),
+        ])
+        .is_ok());
+    }
+
+    #[test]
+    fn test_workflow_target() {
+        let workflow = Workflow::default();
+        let mut todo = Todo::new(
+            Uuid::new_v4(),
+            TodoTitle::new("Release".to_string()).unwrap(),
+            None,
+        );
+
+        let target = |todo: &Todo, status: Option<&str>, completed: Option<bool>| {
+            workflow
+                .target(todo, status.map(key), completed)
+                .map(|target| target.map(|t| t.key.value().to_string()))
+        };
+
+        assert_eq!(target(&todo, None, None), Ok(None));
+        assert_eq!(target(&todo, Some("todo"), Some(false)), Ok(None));
+        assert_eq!(
+            target(&todo, Some("in_review"), None),
+            Err("Cannot move a todo from todo to in_review".to_string())
+        );
+        assert!(target(&todo, Some("shipped"), None).is_err());
+        assert!(target(&todo, Some("in_progress"), Some(true)).is_err());
+
+        // completed alone picks a status
+        assert_eq!(
+            target(&todo, None, Some(true)),
+            Ok(Some("done".to_string()))
+        );
+        todo.status = key("in_review");
+        assert_eq!(
+            target(&todo, Some("cancelled"), Some(true)),
+            Ok(Some("cancelled".to_string()))
+        );
+
+        todo.status = key("cancelled");
+        todo.completed = true;
+        assert_eq!(
+            target(&todo, None, Some(false)),
+            Ok(Some("todo".to_string()))
+        );
+
+        // Completing never cancels
+        let cancel_first = Workflow::new(vec![
+            status("open", false, &["dropped", "done"]),
+            WorkflowStatus {
+                cancelled: true,
+                ..status("dropped", true, &["open"])
+            },
+            status("done", true, &["open"]),
+        ])
+        .unwrap();
+        todo.status = key("open");
+        todo.completed = false;
+        assert_eq!(
+            cancel_first
+                .target(&todo, None, Some(true))
+                .unwrap()
+                .map(|t| t.key.value()),
+            Some("done")
+        );
+
+        // Without a way back to an open status, reopening fails
+        let one_way = Workflow::new(vec![
+            status("open", false, &["done"]),
+            status("done", true, &[]),
+        ])
+        .unwrap();
+        todo.status = key("done");
+        todo.completed = true;
+        assert!(one_way.target(&todo, None, Some(false)).is_err());
+    }
+}
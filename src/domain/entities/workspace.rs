@@ -0,0 +1,145 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::Type;
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+/// Name of the workspace created for every new user.
+pub const PERSONAL_WORKSPACE_NAME: &str = "Personal";
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, ToSchema, Type)]
+#[sqlx(transparent)]
+pub struct WorkspaceName(String);
+
+impl WorkspaceName {
+    pub fn new(name: String) -> Result<Self, String> {
+        let name = name.trim().to_string();
+        if name.is_empty() {
+            return Err("Workspace name cannot be empty".to_string());
+        }
+        if name.chars().count() > 100 {
+            return Err("Workspace name cannot be longer than 100 characters".to_string());
+        }
+        Ok(Self(name))
+    }
+
+    pub fn value(&self) -> &str {
+        &self.0
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, ToSchema, Type)]
+#[serde(rename_all = "snake_case")]
+#[sqlx(type_name = "workspace_role", rename_all = "snake_case")]
+pub enum WorkspaceRole {
+    /// Full control, including over other owners
+    Owner,
+    /// Manages members, invitations and settings such as the workflow
+    Admin,
+    Member,
+}
+
+impl WorkspaceRole {
+    pub fn can_manage_members(self) -> bool {
+        matches!(self, Self::Owner | Self::Admin)
+    }
+
+    /// Whether this role may change workspace-wide settings.
+    pub fn can_configure(self) -> bool {
+        matches!(self, Self::Owner | Self::Admin)
+    }
+
+    /// Whether this role may give `role` to someone, or take it away.
+    pub fn can_assign(self, role: WorkspaceRole) -> bool {
+        match role {
+            Self::Owner => self == Self::Owner,
+            Self::Admin | Self::Member => self.can_manage_members(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, sqlx::FromRow)]
+pub struct Workspace {
+    pub id: Uuid,
+    pub name: WorkspaceName,
+    pub created_at: DateTime<Utc>,
+}
+
+impl Workspace {
+    pub fn new(name: WorkspaceName) -> Self {
+        Self {
+            id: Uuid::new_v4(),
+            name,
+            created_at: Utc::now(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, sqlx::FromRow)]
+pub struct WorkspaceMember {
+    pub workspace_id: Uuid,
+    pub user_id: Uuid,
+    pub email: String,
+    pub role: WorkspaceRole,
+    /// When the user joined
+    pub created_at: DateTime<Utc>,
+}
+
+/// An invitation to join a workspace, addressed to an email that may not be
+/// registered yet.
+#[derive(Debug, Clone, Serialize, Deserialize, sqlx::FromRow)]
+pub struct WorkspaceInvitation {
+    pub id: Uuid,
+    pub workspace_id: Uuid,
+    pub workspace_name: WorkspaceName,
+    pub email: String,
+    pub role: WorkspaceRole,
+    /// `None` once the inviting account is deleted
+    pub invited_by: Option<Uuid>,
+    pub created_at: DateTime<Utc>,
+}
+
+impl WorkspaceInvitation {
+    pub fn new(
+        workspace: &Workspace,
+        email: String,
+        role: WorkspaceRole,
+        invited_by: Uuid,
+    ) -> Self {
+        Self {
+            id: Uuid::new_v4(),
+            workspace_id: workspace.id,
+            workspace_name: workspace.name.clone(),
+            email,
+            role,
+            invited_by: Some(invited_by),
+            created_at: Utc::now(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_workspace_name_validation() {
+        assert_eq!(
+            WorkspaceName::new("  Team  ".to_string()).unwrap().value(),
+            "Team"
+        );
+        assert!(WorkspaceName::new("   ".to_string()).is_err());
+        assert!(WorkspaceName::new("a".repeat(101)).is_err());
+    }
+
+    #[test]
+    fn test_workspace_role_assignment() {
+        use WorkspaceRole::*;
+
+        assert!(Owner.can_assign(Owner));
+        assert!(!Admin.can_assign(Owner));
+        assert!(Admin.can_assign(Admin) && Admin.can_assign(Member));
+        assert!(!Member.can_manage_members());
+        assert!(!Member.can_assign(Member));
+    }
+}
@@ -0,0 +1,23 @@
+use async_trait::async_trait;
+use uuid::Uuid;
+
+use crate::domain::entities::{Attachment, TodoId};
+use crate::shared::error::AppResult;
+
+/// Attachment metadata. Deleting an attachment, directly or through its todo,
+/// queues its object for deletion from the object store.
+#[async_trait]
+pub trait AttachmentRepository: Send + Sync {
+    /// Inserts the attachment unless it would take the uploader's attachments
+    /// past `quota_bytes` in total.
+    async fn create(&self, attachment: &Attachment, quota_bytes: i64) -> AppResult<Attachment>;
+    async fn find_by_id(&self, id: Uuid, todo_id: TodoId) -> AppResult<Option<Attachment>>;
+    /// Attachments of the todo, oldest first.
+    async fn find_by_todo(&self, todo_id: TodoId) -> AppResult<Vec<Attachment>>;
+    /// Total size of the user's attachments, in bytes.
+    async fn total_size_by_user(&self, user_id: Uuid) -> AppResult<i64>;
+    async fn delete(&self, id: Uuid, todo_id: TodoId) -> AppResult<()>;
+    /// Storage keys of deleted attachments whose objects still need deleting.
+    async fn find_pending_object_deletions(&self, limit: i64) -> AppResult<Vec<String>>;
+    async fn complete_object_deletions(&self, storage_keys: &[String]) -> AppResult<()>;
+}
@@ -0,0 +1,22 @@
+use async_trait::async_trait;
+use uuid::Uuid;
+
+use crate::domain::entities::{Comment, CommentCursor, TodoId};
+use crate::shared::error::AppResult;
+
+/// Comments of a todo. Creating and deleting one also keeps the todo's
+/// `comment_count` up to date.
+#[async_trait]
+pub trait CommentRepository: Send + Sync {
+    async fn create(&self, comment: &Comment) -> AppResult<Comment>;
+    async fn find_by_id(&self, id: Uuid, todo_id: TodoId) -> AppResult<Option<Comment>>;
+    /// Comments of the todo oldest first, starting after `cursor` when given.
+    async fn find_by_todo(
+        &self,
+        todo_id: TodoId,
+        cursor: Option<&CommentCursor>,
+        limit: i64,
+    ) -> AppResult<Vec<Comment>>;
+    async fn update(&self, comment: &Comment) -> AppResult<Comment>;
+    async fn delete(&self, id: Uuid, todo_id: TodoId) -> AppResult<()>;
+}
@@ -1,5 +1,29 @@
+pub mod attachment_repository;
+pub mod comment_repository;
+pub mod notification_repository;
+pub mod project_repository;
+pub mod project_share_repository;
+pub mod reminder_repository;
+pub mod saved_filter_repository;
+pub mod time_entry_repository;
+pub mod todo_dependency_repository;
 pub mod todo_repository;
+pub mod todo_share_repository;
 pub mod user_repository;
+pub mod workflow_repository;
+pub mod workspace_repository;
 
-pub use todo_repository::TodoRepository;
+pub use attachment_repository::AttachmentRepository;
+pub use comment_repository::CommentRepository;
+pub use notification_repository::NotificationRepository;
+pub use project_repository::ProjectRepository;
+pub use project_share_repository::ProjectShareRepository;
+pub use reminder_repository::ReminderRepository;
+pub use saved_filter_repository::SavedFilterRepository;
+pub use time_entry_repository::TimeEntryRepository;
+pub use todo_dependency_repository::TodoDependencyRepository;
+pub use todo_repository::{TodoChangeSet, TodoRepository};
+pub use todo_share_repository::TodoShareRepository;
 pub use user_repository::UserRepository;
+pub use workflow_repository::WorkflowRepository;
+pub use workspace_repository::WorkspaceRepository;
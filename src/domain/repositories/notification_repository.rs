@@ -0,0 +1,57 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+use crate::domain::entities::{
+    Notification, NotificationCursor, NotificationEvent, NotificationPreference,
+};
+use crate::shared::error::AppResult;
+
+/// Notifications shown in users' inboxes, and users' preferences on which
+/// events notify them.
+#[async_trait]
+pub trait NotificationRepository: Send + Sync {
+    /// Creating a notification whose id exists already does nothing.
+    async fn create(&self, notification: &Notification) -> AppResult<()>;
+
+    /// A user's notifications, newest first, starting after `cursor`.
+    async fn find_by_user(
+        &self,
+        user_id: Uuid,
+        unread_only: bool,
+        cursor: Option<&NotificationCursor>,
+        limit: i64,
+    ) -> AppResult<Vec<Notification>>;
+
+    async fn count_unread(&self, user_id: Uuid) -> AppResult<i64>;
+
+    /// Marks one of the user's notifications read, keeping the time it was
+    /// first read. `None` if the user has no such notification.
+    async fn mark_read(
+        &self,
+        id: Uuid,
+        user_id: Uuid,
+        at: DateTime<Utc>,
+    ) -> AppResult<Option<Notification>>;
+
+    /// Marks all of the user's unread notifications read, returning how many
+    /// there were.
+    async fn mark_all_read(&self, user_id: Uuid, at: DateTime<Utc>) -> AppResult<u64>;
+
+    /// The preferences the user has set; events without one use their
+    /// defaults.
+    async fn find_preferences(&self, user_id: Uuid) -> AppResult<Vec<NotificationPreference>>;
+
+    async fn find_preference(
+        &self,
+        user_id: Uuid,
+        event: NotificationEvent,
+    ) -> AppResult<Option<NotificationPreference>>;
+
+    /// Sets the given preferences, leaving those of other events as they are.
+    async fn save_preferences(
+        &self,
+        user_id: Uuid,
+        preferences: &[NotificationPreference],
+    ) -> AppResult<()>;
+}
@@ -0,0 +1,23 @@
+use async_trait::async_trait;
+use uuid::Uuid;
+
+use crate::domain::entities::Project;
+use crate::shared::error::AppResult;
+
+/// Projects of the workspace the current request works in; names are unique
+/// per workspace.
+#[async_trait]
+pub trait ProjectRepository: Send + Sync {
+    /// Fails with `Conflict` when the workspace already has a project of that
+    /// name.
+    async fn create(&self, project: &Project) -> AppResult<Project>;
+    async fn find_by_id(&self, id: Uuid) -> AppResult<Option<Project>>;
+    /// The workspace's projects by name.
+    async fn find_all(&self) -> AppResult<Vec<Project>>;
+    /// Fails with `Conflict` when the workspace already has a project of that
+    /// name.
+    async fn update(&self, project: &Project) -> AppResult<Project>;
+    /// Fails with `Conflict` while todos, trashed ones included, are in the
+    /// project.
+    async fn delete(&self, id: Uuid) -> AppResult<bool>;
+}
@@ -0,0 +1,21 @@
+use async_trait::async_trait;
+use uuid::Uuid;
+
+use crate::domain::entities::{ProjectShare, SharePermission};
+use crate::shared::error::AppResult;
+
+#[async_trait]
+pub trait ProjectShareRepository: Send + Sync {
+    /// Shares the project with `user_id`, or changes the permission of an
+    /// existing share.
+    async fn upsert(
+        &self,
+        project_id: Uuid,
+        user_id: Uuid,
+        permission: SharePermission,
+    ) -> AppResult<ProjectShare>;
+    /// Shares of one project, oldest first.
+    async fn find_by_project(&self, project_id: Uuid) -> AppResult<Vec<ProjectShare>>;
+    /// Revokes a share, returning whether there was one.
+    async fn delete(&self, project_id: Uuid, user_id: Uuid) -> AppResult<bool>;
+}
@@ -0,0 +1,49 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Duration, Utc};
+use uuid::Uuid;
+
+use crate::domain::entities::{PendingDelivery, Reminder, ReminderDelivery, TodoId};
+use crate::shared::error::AppResult;
+
+/// Users' reminders of todos and the log of their deliveries.
+#[async_trait]
+pub trait ReminderRepository: Send + Sync {
+    async fn create(&self, reminder: &Reminder) -> AppResult<Reminder>;
+    async fn find_by_id(
+        &self,
+        id: Uuid,
+        todo_id: TodoId,
+        user_id: Uuid,
+    ) -> AppResult<Option<Reminder>>;
+    /// The user's reminders of the todo, oldest first.
+    async fn find_by_todo(&self, todo_id: TodoId, user_id: Uuid) -> AppResult<Vec<Reminder>>;
+    async fn delete(&self, id: Uuid, todo_id: TodoId, user_id: Uuid) -> AppResult<bool>;
+    /// Deliveries of the reminder, most recent first.
+    async fn find_deliveries(&self, reminder_id: Uuid) -> AppResult<Vec<ReminderDelivery>>;
+
+    /// Fires up to `limit` reminders of open todos that are due by `now`
+    /// and have not fired for their current time, queueing a delivery per
+    /// channel the user's notification preferences allow. Reminders another
+    /// instance is firing are skipped. Returns how many deliveries were
+    /// queued.
+    async fn fire_due(&self, now: DateTime<Utc>, limit: i64) -> AppResult<u64>;
+    /// Claims up to `limit` pending deliveries due by `now` for an attempt,
+    /// counting the attempt and holding them from other instances for
+    /// `lease`. A delivery whose attempt never completes is retried once the
+    /// lease runs out.
+    async fn claim_deliveries(
+        &self,
+        now: DateTime<Utc>,
+        limit: i64,
+        lease: Duration,
+    ) -> AppResult<Vec<PendingDelivery>>;
+    async fn mark_sent(&self, id: Uuid, at: DateTime<Utc>) -> AppResult<()>;
+    /// Records a failed attempt, retrying at `retry_at` or, without one,
+    /// giving the delivery up.
+    async fn mark_failed(
+        &self,
+        id: Uuid,
+        error: &str,
+        retry_at: Option<DateTime<Utc>>,
+    ) -> AppResult<()>;
+}
@@ -0,0 +1,20 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+use crate::domain::entities::SavedFilter;
+use crate::shared::error::AppResult;
+
+/// Filters users saved for themselves; names are unique per user.
+#[async_trait]
+pub trait SavedFilterRepository: Send + Sync {
+    /// Fails with `Conflict` when the user already has a filter of that name.
+    async fn create(&self, filter: &SavedFilter) -> AppResult<SavedFilter>;
+    async fn find_by_id(&self, id: Uuid, user_id: Uuid) -> AppResult<Option<SavedFilter>>;
+    /// The user's filters by name.
+    async fn find_by_user(&self, user_id: Uuid) -> AppResult<Vec<SavedFilter>>;
+    /// Fails with `Conflict` when the user already has a filter of that name.
+    async fn update(&self, filter: &SavedFilter) -> AppResult<SavedFilter>;
+    async fn mark_viewed(&self, id: Uuid, user_id: Uuid, at: DateTime<Utc>) -> AppResult<()>;
+    async fn delete(&self, id: Uuid, user_id: Uuid) -> AppResult<bool>;
+}
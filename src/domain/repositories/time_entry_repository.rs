@@ -0,0 +1,33 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+use crate::domain::entities::{TimeEntry, TodoId, TrackedSpan};
+use crate::shared::error::AppResult;
+
+/// Time tracked on todos. A user has at most one running timer.
+#[async_trait]
+pub trait TimeEntryRepository: Send + Sync {
+    /// Fails with `Conflict` when the entry is a running timer and the user
+    /// already has one.
+    async fn create(&self, entry: &TimeEntry) -> AppResult<TimeEntry>;
+    async fn find_by_id(&self, id: Uuid, todo_id: TodoId) -> AppResult<Option<TimeEntry>>;
+    /// Entries of the todo, most recently started first.
+    async fn find_by_todo(&self, todo_id: TodoId) -> AppResult<Vec<TimeEntry>>;
+    /// Ends the user's running timer on the todo at `at`, if there is one.
+    async fn stop(
+        &self,
+        user_id: Uuid,
+        todo_id: TodoId,
+        at: DateTime<Utc>,
+    ) -> AppResult<Option<TimeEntry>>;
+    async fn delete(&self, id: Uuid, todo_id: TodoId) -> AppResult<bool>;
+    /// The user's time between `start` and `end` in every workspace, clipped
+    /// to that range.
+    async fn tracked_spans(
+        &self,
+        user_id: Uuid,
+        start: DateTime<Utc>,
+        end: DateTime<Utc>,
+    ) -> AppResult<Vec<TrackedSpan>>;
+}
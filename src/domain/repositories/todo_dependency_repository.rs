@@ -0,0 +1,19 @@
+use async_trait::async_trait;
+use uuid::Uuid;
+
+use crate::domain::entities::{Todo, TodoDependency, TodoId};
+use crate::shared::error::AppResult;
+
+#[async_trait]
+pub trait TodoDependencyRepository: Send + Sync {
+    /// Records that `todo_id` is blocked by `blocker_id`. Fails if the
+    /// blocker already depends on the todo, which would make a cycle.
+    async fn add(&self, todo_id: TodoId, blocker_id: TodoId) -> AppResult<()>;
+    /// Removes a link, returning whether there was one.
+    async fn remove(&self, todo_id: TodoId, blocker_id: TodoId) -> AppResult<bool>;
+    /// Active todos blocking `todo_id`, oldest first.
+    async fn find_blockers(&self, todo_id: TodoId) -> AppResult<Vec<Todo>>;
+    /// The user's open todos and the links between them.
+    async fn find_open_by_user(&self, user_id: Uuid)
+        -> AppResult<(Vec<Todo>, Vec<TodoDependency>)>;
+}
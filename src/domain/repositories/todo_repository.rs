@@ -1,20 +1,164 @@
 use async_trait::async_trait;
+use chrono::{DateTime, Utc};
 use uuid::Uuid;
 
-use crate::domain::entities::{Todo, TodoId};
+use crate::domain::entities::{
+    SearchQuery, SharePermission, SortOrder, Todo, TodoCursor, TodoFilter, TodoHistoryEntry,
+    TodoId, TodoRole, TodoSearchHit, TodoSort, TodoStatus,
+};
 use crate::shared::error::AppResult;
 
+/// Writes produced by a batch of todo operations.
+#[derive(Debug, Default)]
+pub struct TodoChangeSet {
+    pub created: Vec<Todo>,
+    pub updated: Vec<Todo>,
+    /// Todos to move to the trash, after `updated` has been applied
+    pub trashed: Vec<TodoId>,
+    pub history: Vec<TodoHistoryEntry>,
+}
+
+impl TodoChangeSet {
+    pub fn is_empty(&self) -> bool {
+        self.created.is_empty() && self.updated.is_empty() && self.trashed.is_empty()
+    }
+}
+
+/// Every write takes the history entries describing it, which are saved in
+/// the same transaction.
 #[async_trait]
 pub trait TodoRepository: Send + Sync {
-    async fn create(&self, todo: &Todo) -> AppResult<Todo>;
+    async fn create(&self, todo: &Todo, history: &[TodoHistoryEntry]) -> AppResult<Todo>;
     async fn find_by_id(&self, id: TodoId, user_id: Uuid) -> AppResult<Option<Todo>>;
+    /// An active todo the user owns or that is shared with them, together
+    /// with the user's role on it.
+    async fn find_accessible(
+        &self,
+        id: TodoId,
+        user_id: Uuid,
+    ) -> AppResult<Option<(Todo, TodoRole)>>;
+    /// Active todos among `ids` the user owns or that are shared with them,
+    /// with the user's role on each, in no particular order. Other ids are
+    /// skipped.
+    async fn find_accessible_by_ids(
+        &self,
+        ids: &[TodoId],
+        user_id: Uuid,
+    ) -> AppResult<Vec<(Todo, TodoRole)>>;
+    /// Todos in `sort` order, starting after `cursor` when given (keyset
+    /// pagination) and then skipping `offset` rows. Always in display order.
     async fn find_all_by_user(
         &self,
         user_id: Uuid,
+        filter: &TodoFilter,
+        sort: TodoSort,
+        cursor: Option<&TodoCursor>,
+        limit: i64,
+        offset: i64,
+    ) -> AppResult<Vec<Todo>>;
+    /// Todos ranked by `smart_score` at `now`, highest first when `order`
+    /// is descending, skipping `offset` rows.
+    async fn find_smart(
+        &self,
+        user_id: Uuid,
+        filter: &TodoFilter,
+        now: DateTime<Utc>,
+        order: SortOrder,
         limit: i64,
         offset: i64,
     ) -> AppResult<Vec<Todo>>;
-    async fn count_by_user(&self, user_id: Uuid) -> AppResult<i64>;
-    async fn update(&self, todo: &Todo) -> AppResult<Todo>;
+    async fn count_by_user(&self, user_id: Uuid, filter: &TodoFilter) -> AppResult<i64>;
+    /// Todos matching every term of `query`, most relevant first.
+    async fn search(
+        &self,
+        user_id: Uuid,
+        query: &SearchQuery,
+        limit: i64,
+        offset: i64,
+    ) -> AppResult<Vec<TodoSearchHit>>;
+    async fn count_search(&self, user_id: Uuid, query: &SearchQuery) -> AppResult<i64>;
+    /// Active todos of other users shared with `user_id`, directly or through
+    /// their project, most recently shared first.
+    async fn find_shared_with_user(
+        &self,
+        user_id: Uuid,
+        limit: i64,
+        offset: i64,
+    ) -> AppResult<Vec<(Todo, SharePermission)>>;
+    async fn count_shared_with_user(&self, user_id: Uuid) -> AppResult<i64>;
+    async fn update(&self, todo: &Todo, history: &[TodoHistoryEntry]) -> AppResult<Todo>;
+    /// Saves a completed occurrence of a recurring todo and inserts the next
+    /// one atomically, returning the updated `completed` todo. The next
+    /// occurrence is shared with the same users.
+    async fn complete_occurrence(
+        &self,
+        completed: &Todo,
+        next: &Todo,
+        history: &[TodoHistoryEntry],
+    ) -> AppResult<Todo>;
+    /// Saves a todo placed in its status column right below `after_id`, or
+    /// at the top of the column, together with the next occurrence it
+    /// spawned, if any. Cards are ordered per project, across users.
+    async fn move_to(
+        &self,
+        todo: &Todo,
+        after_id: Option<TodoId>,
+        next: Option<&Todo>,
+        history: &[TodoHistoryEntry],
+    ) -> AppResult<Todo>;
+    /// Number of the project's active todos in each status, among those the
+    /// user owns or that are shared with them.
+    async fn count_by_status(
+        &self,
+        user_id: Uuid,
+        project_id: Uuid,
+    ) -> AppResult<Vec<(TodoStatus, i64)>>;
+    /// The first `limit` cards of every status column of the project's
+    /// board, in board order.
+    async fn find_board(&self, user_id: Uuid, project_id: Uuid, limit: i64)
+        -> AppResult<Vec<Todo>>;
+    /// One page of a status column of the project's board, in board order.
+    async fn find_board_column(
+        &self,
+        user_id: Uuid,
+        project_id: Uuid,
+        status: &TodoStatus,
+        limit: i64,
+        offset: i64,
+    ) -> AppResult<Vec<Todo>>;
+    /// Applies every change of a batch in one transaction. Updated todos are
+    /// saved for their owners; only the user's own todos are trashed.
+    async fn apply_changes(&self, user_id: Uuid, changes: &TodoChangeSet) -> AppResult<()>;
+    /// A todo in the trash. Every other lookup only sees active todos.
+    async fn find_trashed_by_id(&self, id: TodoId, user_id: Uuid) -> AppResult<Option<Todo>>;
+    /// Trashed todos, most recently deleted first.
+    async fn find_trashed_by_user(
+        &self,
+        user_id: Uuid,
+        limit: i64,
+        offset: i64,
+    ) -> AppResult<Vec<Todo>>;
+    async fn count_trashed_by_user(&self, user_id: Uuid) -> AppResult<i64>;
+    /// Permanently deletes a todo, whether active or trashed.
     async fn delete(&self, id: TodoId, user_id: Uuid) -> AppResult<()>;
+    /// History of one todo, active or trashed, newest first.
+    async fn find_history_by_todo(
+        &self,
+        id: TodoId,
+        user_id: Uuid,
+        limit: i64,
+        offset: i64,
+    ) -> AppResult<Vec<TodoHistoryEntry>>;
+    async fn count_history_by_todo(&self, id: TodoId, user_id: Uuid) -> AppResult<i64>;
+    /// History of all of the user's todos, newest first.
+    async fn find_history_by_user(
+        &self,
+        user_id: Uuid,
+        limit: i64,
+        offset: i64,
+    ) -> AppResult<Vec<TodoHistoryEntry>>;
+    async fn count_history_by_user(&self, user_id: Uuid) -> AppResult<i64>;
+    /// Permanently deletes todos of any user trashed before `cutoff`,
+    /// returning how many were removed.
+    async fn purge_trashed_before(&self, cutoff: DateTime<Utc>) -> AppResult<u64>;
 }
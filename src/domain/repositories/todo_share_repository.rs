@@ -0,0 +1,21 @@
+use async_trait::async_trait;
+use uuid::Uuid;
+
+use crate::domain::entities::{SharePermission, TodoId, TodoShare};
+use crate::shared::error::AppResult;
+
+#[async_trait]
+pub trait TodoShareRepository: Send + Sync {
+    /// Shares the todo with `user_id`, or changes the permission of an
+    /// existing share.
+    async fn upsert(
+        &self,
+        todo_id: TodoId,
+        user_id: Uuid,
+        permission: SharePermission,
+    ) -> AppResult<TodoShare>;
+    /// Shares of one todo, oldest first.
+    async fn find_by_todo(&self, todo_id: TodoId) -> AppResult<Vec<TodoShare>>;
+    /// Revokes a share, returning whether there was one.
+    async fn delete(&self, todo_id: TodoId, user_id: Uuid) -> AppResult<bool>;
+}
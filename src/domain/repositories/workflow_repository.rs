@@ -0,0 +1,18 @@
+use async_trait::async_trait;
+use uuid::Uuid;
+
+use crate::domain::entities::Workflow;
+use crate::shared::error::AppResult;
+
+/// Workflows of the workspace the current request works in and of its
+/// projects. `None` stands for the workspace's own workflow.
+#[async_trait]
+pub trait WorkflowRepository: Send + Sync {
+    /// The workflow todos of the project follow: its own if it has one, else
+    /// the workspace's, which is the default one until it is customized.
+    async fn find(&self, project_id: Option<Uuid>) -> AppResult<Workflow>;
+    /// Replaces the project's or the workspace's workflow. Fails if any todo
+    /// following it, trashed ones included, is in a status the new workflow
+    /// drops.
+    async fn replace(&self, project_id: Option<Uuid>, workflow: &Workflow) -> AppResult<()>;
+}
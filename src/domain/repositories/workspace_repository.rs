@@ -0,0 +1,46 @@
+use async_trait::async_trait;
+use uuid::Uuid;
+
+use crate::domain::entities::{Workspace, WorkspaceInvitation, WorkspaceMember, WorkspaceRole};
+use crate::shared::error::AppResult;
+
+#[async_trait]
+pub trait WorkspaceRepository: Send + Sync {
+    /// Creates a workspace with `owner_id` as its only member.
+    async fn create(&self, workspace: &Workspace, owner_id: Uuid) -> AppResult<Workspace>;
+    async fn find_by_id(&self, id: Uuid) -> AppResult<Option<Workspace>>;
+    /// Workspaces the user is a member of, in the order they joined them.
+    async fn find_by_user(&self, user_id: Uuid) -> AppResult<Vec<(Workspace, WorkspaceRole)>>;
+    async fn find_role(
+        &self,
+        workspace_id: Uuid,
+        user_id: Uuid,
+    ) -> AppResult<Option<WorkspaceRole>>;
+    /// Members of a workspace, in the order they joined.
+    async fn find_members(&self, workspace_id: Uuid) -> AppResult<Vec<WorkspaceMember>>;
+    async fn update_member_role(
+        &self,
+        workspace_id: Uuid,
+        user_id: Uuid,
+        role: WorkspaceRole,
+    ) -> AppResult<Option<WorkspaceMember>>;
+    /// Removes a member, returning whether there was one.
+    async fn remove_member(&self, workspace_id: Uuid, user_id: Uuid) -> AppResult<bool>;
+    async fn count_owners(&self, workspace_id: Uuid) -> AppResult<i64>;
+    /// Saves an invitation, replacing a pending one for the same email.
+    async fn create_invitation(
+        &self,
+        invitation: &WorkspaceInvitation,
+    ) -> AppResult<WorkspaceInvitation>;
+    async fn find_invitation(&self, id: Uuid) -> AppResult<Option<WorkspaceInvitation>>;
+    /// Pending invitations addressed to `email`, oldest first.
+    async fn find_invitations_by_email(&self, email: &str) -> AppResult<Vec<WorkspaceInvitation>>;
+    /// Adds the invited user to the workspace and discards the invitation.
+    /// Users who already are members keep their role.
+    async fn accept_invitation(
+        &self,
+        invitation: &WorkspaceInvitation,
+        user_id: Uuid,
+    ) -> AppResult<WorkspaceMember>;
+    async fn delete_invitation(&self, id: Uuid) -> AppResult<()>;
+}
@@ -1,13 +1,24 @@
 use std::sync::Arc;
 
-use sqlx::postgres::PgPoolOptions;
 use sqlx::PgPool;
 
-use crate::domain::repositories::{TodoRepository, UserRepository};
+use crate::domain::repositories::{
+    AttachmentRepository, CommentRepository, NotificationRepository, ProjectRepository,
+    ProjectShareRepository, ReminderRepository, SavedFilterRepository, TimeEntryRepository,
+    TodoDependencyRepository, TodoRepository, TodoShareRepository, UserRepository,
+    WorkflowRepository, WorkspaceRepository,
+};
 use crate::infrastructure::auth::jwt::JwtConfig;
+use crate::infrastructure::notifications::{NotificationChannels, NotificationConfig};
+use crate::infrastructure::persistence::postgres::tenant::{pool_options, Tenant};
 use crate::infrastructure::persistence::postgres::{
-    PostgresTodoRepository, PostgresUserRepository,
+    PostgresAttachmentRepository, PostgresCommentRepository, PostgresNotificationRepository,
+    PostgresProjectRepository, PostgresProjectShareRepository, PostgresReminderRepository,
+    PostgresSavedFilterRepository, PostgresTimeEntryRepository, PostgresTodoDependencyRepository,
+    PostgresTodoRepository, PostgresTodoShareRepository, PostgresUserRepository,
+    PostgresWorkflowRepository, PostgresWorkspaceRepository,
 };
+use crate::infrastructure::storage::{AttachmentLimits, ObjectStore, StorageConfig};
 use crate::shared::error::AppResult;
 
 #[derive(Clone)]
@@ -15,6 +26,21 @@ pub struct AppState {
     pub db_pool: PgPool,
     pub todo_repository: Arc<dyn TodoRepository>,
     pub user_repository: Arc<dyn UserRepository>,
+    pub comment_repository: Arc<dyn CommentRepository>,
+    pub attachment_repository: Arc<dyn AttachmentRepository>,
+    pub todo_share_repository: Arc<dyn TodoShareRepository>,
+    pub todo_dependency_repository: Arc<dyn TodoDependencyRepository>,
+    pub workspace_repository: Arc<dyn WorkspaceRepository>,
+    pub workflow_repository: Arc<dyn WorkflowRepository>,
+    pub project_repository: Arc<dyn ProjectRepository>,
+    pub project_share_repository: Arc<dyn ProjectShareRepository>,
+    pub saved_filter_repository: Arc<dyn SavedFilterRepository>,
+    pub time_entry_repository: Arc<dyn TimeEntryRepository>,
+    pub reminder_repository: Arc<dyn ReminderRepository>,
+    pub notification_repository: Arc<dyn NotificationRepository>,
+    pub notification_channels: NotificationChannels,
+    pub object_store: Arc<dyn ObjectStore>,
+    pub attachment_limits: AttachmentLimits,
     pub jwt_config: JwtConfig,
 }
 
@@ -24,15 +50,15 @@ impl AppState {
 
         let database_url = std::env::var("DATABASE_URL").expect("DATABASE_URL must be set");
 
-        let db_pool = PgPoolOptions::new()
-            .max_connections(5)
-            .connect(&database_url)
+        // The tenant role only exists once the migrations have run
+        let db_pool = Tenant::System
+            .scope(pool_options().max_connections(5).connect(&database_url))
             .await?;
 
         // Run migrations
         tracing::info!("Running database migrations...");
-        sqlx::migrate!("./migrations")
-            .run(&db_pool)
+        Tenant::System
+            .scope(sqlx::migrate!("./migrations").run(&db_pool))
             .await
             .expect("Failed to run migrations");
         tracing::info!("Migrations completed successfully");
@@ -41,6 +67,33 @@ impl AppState {
             Arc::new(PostgresTodoRepository::new(db_pool.clone()));
         let user_repository: Arc<dyn UserRepository> =
             Arc::new(PostgresUserRepository::new(db_pool.clone()));
+        let comment_repository: Arc<dyn CommentRepository> =
+            Arc::new(PostgresCommentRepository::new(db_pool.clone()));
+        let attachment_repository: Arc<dyn AttachmentRepository> =
+            Arc::new(PostgresAttachmentRepository::new(db_pool.clone()));
+        let todo_dependency_repository: Arc<dyn TodoDependencyRepository> =
+            Arc::new(PostgresTodoDependencyRepository::new(db_pool.clone()));
+        let todo_share_repository: Arc<dyn TodoShareRepository> =
+            Arc::new(PostgresTodoShareRepository::new(db_pool.clone()));
+        let workspace_repository: Arc<dyn WorkspaceRepository> =
+            Arc::new(PostgresWorkspaceRepository::new(db_pool.clone()));
+        let workflow_repository: Arc<dyn WorkflowRepository> =
+            Arc::new(PostgresWorkflowRepository::new(db_pool.clone()));
+        let project_repository: Arc<dyn ProjectRepository> =
+            Arc::new(PostgresProjectRepository::new(db_pool.clone()));
+        let project_share_repository: Arc<dyn ProjectShareRepository> =
+            Arc::new(PostgresProjectShareRepository::new(db_pool.clone()));
+        let saved_filter_repository: Arc<dyn SavedFilterRepository> =
+            Arc::new(PostgresSavedFilterRepository::new(db_pool.clone()));
+        let time_entry_repository: Arc<dyn TimeEntryRepository> =
+            Arc::new(PostgresTimeEntryRepository::new(db_pool.clone()));
+        let reminder_repository: Arc<dyn ReminderRepository> =
+            Arc::new(PostgresReminderRepository::new(db_pool.clone()));
+        let notification_repository: Arc<dyn NotificationRepository> =
+            Arc::new(PostgresNotificationRepository::new(db_pool.clone()));
+        let object_store = StorageConfig::from_env().build();
+        let notification_channels =
+            NotificationConfig::from_env().build(notification_repository.clone());
 
         let jwt_config = JwtConfig::from_env();
 
@@ -48,6 +101,21 @@ impl AppState {
             db_pool,
             todo_repository,
             user_repository,
+            comment_repository,
+            attachment_repository,
+            todo_share_repository,
+            todo_dependency_repository,
+            workspace_repository,
+            workflow_repository,
+            project_repository,
+            project_share_repository,
+            saved_filter_repository,
+            time_entry_repository,
+            reminder_repository,
+            notification_repository,
+            notification_channels,
+            object_store,
+            attachment_limits: AttachmentLimits::from_env(),
             jwt_config,
         })
     }
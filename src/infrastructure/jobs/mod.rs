@@ -0,0 +1,9 @@
+pub mod object_cleanup;
+pub mod reminder_scheduler;
+pub mod trash_purge;
+
+pub use object_cleanup::{delete_orphaned_objects, spawn_object_cleanup};
+pub use reminder_scheduler::{
+    deliver_reminders, spawn_reminder_scheduler, ReminderSchedulerConfig,
+};
+pub use trash_purge::{spawn_trash_purge, TrashPurgeConfig};
@@ -0,0 +1,58 @@
+use std::sync::Arc;
+
+use tokio::task::JoinHandle;
+
+use crate::domain::repositories::AttachmentRepository;
+use crate::infrastructure::persistence::postgres::Tenant;
+use crate::infrastructure::storage::ObjectStore;
+use crate::shared::error::AppResult;
+
+/// How many objects one cleanup pass deletes at most.
+const CLEANUP_BATCH_SIZE: i64 = 100;
+
+/// Deletes one batch of objects whose attachments have been deleted,
+/// returning how many were removed.
+pub async fn delete_orphaned_objects(
+    attachment_repository: &dyn AttachmentRepository,
+    object_store: &dyn ObjectStore,
+) -> AppResult<usize> {
+    let keys = attachment_repository
+        .find_pending_object_deletions(CLEANUP_BATCH_SIZE)
+        .await?;
+
+    let mut deleted = Vec::with_capacity(keys.len());
+    for key in keys {
+        match object_store.delete(&key).await {
+            Ok(()) => deleted.push(key),
+            // Left queued and retried on the next pass
+            Err(e) => tracing::warn!("Failed to delete object {}: {:?}", key, e),
+        }
+    }
+    if !deleted.is_empty() {
+        attachment_repository
+            .complete_object_deletions(&deleted)
+            .await?;
+    }
+    Ok(deleted.len())
+}
+
+/// Periodically deletes the stored content of deleted attachments,
+/// including those removed along with their todo.
+pub fn spawn_object_cleanup(
+    attachment_repository: Arc<dyn AttachmentRepository>,
+    object_store: Arc<dyn ObjectStore>,
+) -> JoinHandle<()> {
+    tokio::spawn(Tenant::System.scope(async move {
+        let mut interval = tokio::time::interval(std::time::Duration::from_secs(60));
+        loop {
+            interval.tick().await;
+            match delete_orphaned_objects(attachment_repository.as_ref(), object_store.as_ref())
+                .await
+            {
+                Ok(0) => {}
+                Ok(deleted) => tracing::info!("Deleted {} orphaned objects", deleted),
+                Err(e) => tracing::error!("Failed to clean up objects: {:?}", e),
+            }
+        }
+    }))
+}
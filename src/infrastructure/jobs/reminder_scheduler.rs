@@ -0,0 +1,105 @@
+use std::sync::Arc;
+
+use chrono::{Duration, Utc};
+use tokio::task::JoinHandle;
+
+use crate::domain::entities::{retry_delay, Notification};
+use crate::domain::repositories::ReminderRepository;
+use crate::infrastructure::notifications::{NotificationChannels, Recipient};
+use crate::infrastructure::persistence::postgres::Tenant;
+use crate::shared::error::AppResult;
+
+#[derive(Clone)]
+pub struct ReminderSchedulerConfig {
+    /// How often due reminders are looked for
+    pub interval: std::time::Duration,
+    /// Most reminders fired, and deliveries attempted, in one pass
+    pub batch_size: i64,
+    /// How long a claimed delivery is held from other instances
+    pub lease: Duration,
+}
+
+impl ReminderSchedulerConfig {
+    pub fn from_env() -> Self {
+        let interval_seconds: u64 = std::env::var("REMINDER_POLL_SECONDS")
+            .unwrap_or_else(|_| "30".to_string())
+            .parse()
+            .expect("REMINDER_POLL_SECONDS must be a number");
+        Self {
+            interval: std::time::Duration::from_secs(interval_seconds),
+            batch_size: 100,
+            lease: Duration::minutes(5),
+        }
+    }
+}
+
+/// Fires due reminders and attempts one batch of pending deliveries,
+/// returning how many were sent. Instances running passes at the same time
+/// never fire a reminder or claim a delivery twice.
+pub async fn deliver_reminders(
+    reminder_repository: &dyn ReminderRepository,
+    channels: &NotificationChannels,
+    config: &ReminderSchedulerConfig,
+) -> AppResult<usize> {
+    let now = Utc::now();
+    reminder_repository.fire_due(now, config.batch_size).await?;
+    let deliveries = reminder_repository
+        .claim_deliveries(now, config.batch_size, config.lease)
+        .await?;
+
+    let mut sent = 0;
+    for delivery in deliveries {
+        let recipient = Recipient {
+            user_id: delivery.user_id,
+            email: delivery.email.clone(),
+            webhook_url: delivery.webhook_url.clone(),
+        };
+        let notification = Notification::reminder(&delivery);
+        let result = match channels.get(delivery.channel) {
+            Some(channel) => channel.send(&recipient, &notification).await,
+            None => Err(anyhow::anyhow!("No {:?} channel is configured", delivery.channel).into()),
+        };
+
+        match result {
+            Ok(()) => {
+                reminder_repository
+                    .mark_sent(delivery.id, Utc::now())
+                    .await?;
+                sent += 1;
+            }
+            Err(e) => {
+                let retry_at = retry_delay(delivery.attempts).map(|delay| Utc::now() + delay);
+                tracing::warn!(
+                    "Reminder delivery {} over {:?} failed (attempt {}): {}",
+                    delivery.id,
+                    delivery.channel,
+                    delivery.attempts,
+                    e
+                );
+                reminder_repository
+                    .mark_failed(delivery.id, &e.to_string(), retry_at)
+                    .await?;
+            }
+        }
+    }
+    Ok(sent)
+}
+
+/// Periodically delivers due reminders of todos in all workspaces.
+pub fn spawn_reminder_scheduler(
+    reminder_repository: Arc<dyn ReminderRepository>,
+    channels: NotificationChannels,
+    config: ReminderSchedulerConfig,
+) -> JoinHandle<()> {
+    tokio::spawn(Tenant::System.scope(async move {
+        let mut interval = tokio::time::interval(config.interval);
+        loop {
+            interval.tick().await;
+            match deliver_reminders(reminder_repository.as_ref(), &channels, &config).await {
+                Ok(0) => {}
+                Ok(sent) => tracing::info!("Delivered {} reminders", sent),
+                Err(e) => tracing::error!("Failed to deliver reminders: {:?}", e),
+            }
+        }
+    }))
+}
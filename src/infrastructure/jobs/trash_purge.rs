@@ -0,0 +1,48 @@
+use std::sync::Arc;
+
+use chrono::{Duration, Utc};
+use tokio::task::JoinHandle;
+
+use crate::domain::repositories::TodoRepository;
+use crate::infrastructure::persistence::postgres::Tenant;
+
+#[derive(Clone)]
+pub struct TrashPurgeConfig {
+    /// How long trashed todos are kept before being permanently deleted
+    pub retention: Duration,
+    /// How often the purge runs
+    pub interval: std::time::Duration,
+}
+
+impl TrashPurgeConfig {
+    pub fn from_env() -> Self {
+        let retention_days: i64 = std::env::var("TRASH_RETENTION_DAYS")
+            .unwrap_or_else(|_| "30".to_string())
+            .parse()
+            .expect("TRASH_RETENTION_DAYS must be a number");
+        Self {
+            retention: Duration::days(retention_days),
+            interval: std::time::Duration::from_secs(60 * 60),
+        }
+    }
+}
+
+/// Periodically deletes todos that have been in the trash longer than the
+/// retention period, across all workspaces.
+pub fn spawn_trash_purge(
+    todo_repository: Arc<dyn TodoRepository>,
+    config: TrashPurgeConfig,
+) -> JoinHandle<()> {
+    tokio::spawn(Tenant::System.scope(async move {
+        let mut interval = tokio::time::interval(config.interval);
+        loop {
+            interval.tick().await;
+            let cutoff = Utc::now() - config.retention;
+            match todo_repository.purge_trashed_before(cutoff).await {
+                Ok(0) => {}
+                Ok(purged) => tracing::info!("Purged {} todos from trash", purged),
+                Err(e) => tracing::error!("Failed to purge trash: {:?}", e),
+            }
+        }
+    }))
+}
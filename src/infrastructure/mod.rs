@@ -1,3 +1,6 @@
 pub mod auth;
 pub mod config;
+pub mod jobs;
+pub mod notifications;
 pub mod persistence;
+pub mod storage;
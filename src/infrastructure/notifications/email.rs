@@ -0,0 +1,87 @@
+use anyhow::Context;
+use async_trait::async_trait;
+use reqwest::header::{AUTHORIZATION, CONTENT_TYPE};
+
+use super::{NotificationChannel, Recipient};
+use crate::domain::entities::{DeliveryChannel, Notification};
+use crate::shared::error::AppResult;
+
+#[derive(Clone)]
+pub struct EmailConfig {
+    /// HTTP endpoint of the mail service; without one, emails are only
+    /// logged
+    pub api_url: Option<String>,
+    pub api_key: Option<String>,
+    pub from: String,
+}
+
+impl EmailConfig {
+    pub fn from_env() -> Self {
+        Self {
+            api_url: std::env::var("EMAIL_API_URL").ok(),
+            api_key: std::env::var("EMAIL_API_KEY").ok(),
+            from: std::env::var("EMAIL_FROM")
+                .unwrap_or_else(|_| "Todo <noreply@localhost>".to_string()),
+        }
+    }
+}
+
+/// Sends notifications to the recipient's email address through a mail
+/// service's HTTP API, which takes a JSON body of `from`, `to`, `subject`
+/// and `text`.
+pub struct EmailChannel {
+    client: reqwest::Client,
+    config: EmailConfig,
+}
+
+impl EmailChannel {
+    pub fn new(config: EmailConfig) -> Self {
+        Self {
+            client: reqwest::Client::builder()
+                .timeout(std::time::Duration::from_secs(10))
+                .build()
+                .expect("HTTP client configuration is valid"),
+            config,
+        }
+    }
+}
+
+#[async_trait]
+impl NotificationChannel for EmailChannel {
+    fn kind(&self) -> DeliveryChannel {
+        DeliveryChannel::Email
+    }
+
+    async fn send(&self, recipient: &Recipient, notification: &Notification) -> AppResult<()> {
+        let Some(api_url) = &self.config.api_url else {
+            tracing::info!(
+                "Email to {} (no EMAIL_API_URL set): {}",
+                recipient.email,
+                notification.title
+            );
+            return Ok(());
+        };
+
+        let body = serde_json::json!({
+            "from": self.config.from,
+            "to": recipient.email,
+            "subject": notification.title,
+            "text": notification.body,
+        });
+        let mut request = self
+            .client
+            .post(api_url)
+            .header(CONTENT_TYPE, "application/json")
+            .body(body.to_string());
+        if let Some(api_key) = &self.config.api_key {
+            request = request.header(AUTHORIZATION, format!("Bearer {}", api_key));
+        }
+        request
+            .send()
+            .await
+            .context("Mail service request failed")?
+            .error_for_status()
+            .context("Mail service rejected the email")?;
+        Ok(())
+    }
+}
@@ -0,0 +1,32 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+
+use super::{NotificationChannel, Recipient};
+use crate::domain::entities::{DeliveryChannel, Notification};
+use crate::domain::repositories::NotificationRepository;
+use crate::shared::error::AppResult;
+
+/// Puts notifications in the recipient's inbox.
+pub struct InAppChannel {
+    notification_repository: Arc<dyn NotificationRepository>,
+}
+
+impl InAppChannel {
+    pub fn new(notification_repository: Arc<dyn NotificationRepository>) -> Self {
+        Self {
+            notification_repository,
+        }
+    }
+}
+
+#[async_trait]
+impl NotificationChannel for InAppChannel {
+    fn kind(&self) -> DeliveryChannel {
+        DeliveryChannel::InApp
+    }
+
+    async fn send(&self, _recipient: &Recipient, notification: &Notification) -> AppResult<()> {
+        self.notification_repository.create(notification).await
+    }
+}
@@ -0,0 +1,76 @@
+pub mod email;
+pub mod in_app;
+pub mod webhook;
+
+pub use email::{EmailChannel, EmailConfig};
+pub use in_app::InAppChannel;
+pub use webhook::WebhookChannel;
+
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use uuid::Uuid;
+
+use crate::domain::entities::{DeliveryChannel, Notification, WebhookUrl};
+use crate::domain::repositories::NotificationRepository;
+use crate::shared::error::AppResult;
+
+/// Who a notification goes to, with their address on each channel.
+#[derive(Debug, Clone)]
+pub struct Recipient {
+    pub user_id: Uuid,
+    pub email: String,
+    pub webhook_url: Option<WebhookUrl>,
+}
+
+/// Delivers notifications over one channel. A failed send may be retried
+/// with the same notification, so receivers can deduplicate by its id.
+#[async_trait]
+pub trait NotificationChannel: Send + Sync {
+    fn kind(&self) -> DeliveryChannel;
+    async fn send(&self, recipient: &Recipient, notification: &Notification) -> AppResult<()>;
+}
+
+/// The channels notifications can be delivered on.
+#[derive(Clone, Default)]
+pub struct NotificationChannels(Vec<Arc<dyn NotificationChannel>>);
+
+impl NotificationChannels {
+    pub fn new(channels: Vec<Arc<dyn NotificationChannel>>) -> Self {
+        Self(channels)
+    }
+
+    pub fn get(&self, kind: DeliveryChannel) -> Option<Arc<dyn NotificationChannel>> {
+        self.0
+            .iter()
+            .find(|channel| channel.kind() == kind)
+            .cloned()
+    }
+}
+
+#[derive(Clone)]
+pub struct NotificationConfig {
+    pub email: EmailConfig,
+    /// Key webhook bodies are signed with, if any
+    pub webhook_secret: Option<String>,
+}
+
+impl NotificationConfig {
+    pub fn from_env() -> Self {
+        Self {
+            email: EmailConfig::from_env(),
+            webhook_secret: std::env::var("WEBHOOK_SIGNING_SECRET").ok(),
+        }
+    }
+
+    pub fn build(
+        self,
+        notification_repository: Arc<dyn NotificationRepository>,
+    ) -> NotificationChannels {
+        NotificationChannels::new(vec![
+            Arc::new(InAppChannel::new(notification_repository)),
+            Arc::new(EmailChannel::new(self.email)),
+            Arc::new(WebhookChannel::new(self.webhook_secret)),
+        ])
+    }
+}
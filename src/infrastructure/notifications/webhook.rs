@@ -0,0 +1,70 @@
+use anyhow::{anyhow, Context};
+use async_trait::async_trait;
+use hmac::{Hmac, Mac};
+use reqwest::header::CONTENT_TYPE;
+use sha2::Sha256;
+
+use super::{NotificationChannel, Recipient};
+use crate::domain::entities::{DeliveryChannel, Notification};
+use crate::shared::error::AppResult;
+
+/// Header carrying `sha256=` and the hex HMAC-SHA256 of the body.
+pub const SIGNATURE_HEADER: &str = "X-Todo-Signature";
+
+/// POSTs notifications as JSON to the recipient's webhook URL. Redirects
+/// are not followed, so a webhook cannot be bounced to another host.
+pub struct WebhookChannel {
+    client: reqwest::Client,
+    secret: Option<String>,
+}
+
+impl WebhookChannel {
+    pub fn new(secret: Option<String>) -> Self {
+        Self {
+            client: reqwest::Client::builder()
+                .timeout(std::time::Duration::from_secs(10))
+                .redirect(reqwest::redirect::Policy::none())
+                .build()
+                .expect("HTTP client configuration is valid"),
+            secret,
+        }
+    }
+}
+
+#[async_trait]
+impl NotificationChannel for WebhookChannel {
+    fn kind(&self) -> DeliveryChannel {
+        DeliveryChannel::Webhook
+    }
+
+    async fn send(&self, recipient: &Recipient, notification: &Notification) -> AppResult<()> {
+        let url = recipient
+            .webhook_url
+            .as_ref()
+            .ok_or_else(|| anyhow!("No webhook URL to deliver to"))?;
+        let body = serde_json::to_vec(notification).context("Failed to encode notification")?;
+
+        let mut request = self
+            .client
+            .post(url.value())
+            .header(CONTENT_TYPE, "application/json");
+        if let Some(secret) = &self.secret {
+            let mut mac = Hmac::<Sha256>::new_from_slice(secret.as_bytes())
+                .expect("HMAC accepts any key length");
+            mac.update(&body);
+            request = request.header(
+                SIGNATURE_HEADER,
+                format!("sha256={}", hex::encode(mac.finalize().into_bytes())),
+            );
+        }
+        let response = request
+            .body(body)
+            .send()
+            .await
+            .context("Webhook request failed")?;
+        if !response.status().is_success() {
+            return Err(anyhow!("Webhook responded with {}", response.status()).into());
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,146 @@
+use async_trait::async_trait;
+use sqlx::PgPool;
+use uuid::Uuid;
+
+use crate::domain::entities::{Attachment, TodoId};
+use crate::domain::repositories::AttachmentRepository;
+use crate::shared::error::{AppError, AppResult};
+
+/// Columns selected for every `Attachment` row.
+const ATTACHMENT_COLUMNS: &str =
+    "id, todo_id, user_id, file_name, content_type, size_bytes, storage_key, created_at";
+
+pub struct PostgresAttachmentRepository {
+    pool: PgPool,
+}
+
+impl PostgresAttachmentRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl AttachmentRepository for PostgresAttachmentRepository {
+    async fn create(&self, attachment: &Attachment, quota_bytes: i64) -> AppResult<Attachment> {
+        let mut tx = self.pool.begin().await?;
+
+        // Lock the uploader so concurrent uploads cannot both fit the quota
+        sqlx::query("SELECT id FROM users WHERE id = $1 FOR UPDATE")
+            .bind(attachment.user_id)
+            .execute(&mut *tx)
+            .await?;
+        let used: (i64,) = sqlx::query_as("SELECT attachment_bytes_used($1)")
+            .bind(attachment.user_id)
+            .fetch_one(&mut *tx)
+            .await?;
+        if used.0 + attachment.size_bytes > quota_bytes {
+            return Err(quota_exceeded(quota_bytes));
+        }
+
+        let created = sqlx::query_as::<_, Attachment>(&format!(
+            r#"
+            INSERT INTO attachments (id, todo_id, user_id, file_name, content_type, size_bytes,
+                                     storage_key, created_at)
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8)
+            RETURNING {ATTACHMENT_COLUMNS}
+            "#
+        ))
+        .bind(attachment.id)
+        .bind(attachment.todo_id)
+        .bind(attachment.user_id)
+        .bind(&attachment.file_name)
+        .bind(&attachment.content_type)
+        .bind(attachment.size_bytes)
+        .bind(&attachment.storage_key)
+        .bind(attachment.created_at)
+        .fetch_one(&mut *tx)
+        .await?;
+
+        tx.commit().await?;
+        Ok(created)
+    }
+
+    async fn find_by_id(&self, id: Uuid, todo_id: TodoId) -> AppResult<Option<Attachment>> {
+        let attachment = sqlx::query_as::<_, Attachment>(&format!(
+            r#"
+            SELECT {ATTACHMENT_COLUMNS}
+            FROM attachments
+            WHERE id = $1 AND todo_id = $2
+            "#
+        ))
+        .bind(id)
+        .bind(todo_id)
+        .fetch_optional(&self.pool)
+        .await?;
+
+        Ok(attachment)
+    }
+
+    async fn find_by_todo(&self, todo_id: TodoId) -> AppResult<Vec<Attachment>> {
+        let attachments = sqlx::query_as::<_, Attachment>(&format!(
+            r#"
+            SELECT {ATTACHMENT_COLUMNS}
+            FROM attachments
+            WHERE todo_id = $1
+            ORDER BY created_at, id
+            "#
+        ))
+        .bind(todo_id)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(attachments)
+    }
+
+    async fn total_size_by_user(&self, user_id: Uuid) -> AppResult<i64> {
+        let total: (i64,) = sqlx::query_as("SELECT attachment_bytes_used($1)")
+            .bind(user_id)
+            .fetch_one(&self.pool)
+            .await?;
+
+        Ok(total.0)
+    }
+
+    async fn delete(&self, id: Uuid, todo_id: TodoId) -> AppResult<()> {
+        sqlx::query("DELETE FROM attachments WHERE id = $1 AND todo_id = $2")
+            .bind(id)
+            .bind(todo_id)
+            .execute(&self.pool)
+            .await?;
+
+        Ok(())
+    }
+
+    async fn find_pending_object_deletions(&self, limit: i64) -> AppResult<Vec<String>> {
+        let keys: Vec<(String,)> = sqlx::query_as(
+            r#"
+            SELECT storage_key
+            FROM object_deletions
+            ORDER BY created_at
+            LIMIT $1
+            "#,
+        )
+        .bind(limit)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(keys.into_iter().map(|(key,)| key).collect())
+    }
+
+    async fn complete_object_deletions(&self, storage_keys: &[String]) -> AppResult<()> {
+        sqlx::query("DELETE FROM object_deletions WHERE storage_key = ANY($1)")
+            .bind(storage_keys)
+            .execute(&self.pool)
+            .await?;
+
+        Ok(())
+    }
+}
+
+fn quota_exceeded(quota_bytes: i64) -> AppError {
+    AppError::PayloadTooLarge(format!(
+        "Attachment quota of {} bytes exceeded",
+        quota_bytes
+    ))
+}
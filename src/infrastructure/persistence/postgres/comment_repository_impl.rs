@@ -0,0 +1,138 @@
+use async_trait::async_trait;
+use sqlx::PgPool;
+use uuid::Uuid;
+
+use crate::domain::entities::{Comment, CommentCursor, TodoId};
+use crate::domain::repositories::CommentRepository;
+use crate::shared::error::AppResult;
+
+/// Columns selected for every `Comment` row.
+const COMMENT_COLUMNS: &str = "id, todo_id, author_id, body, created_at, edited_at";
+
+pub struct PostgresCommentRepository {
+    pool: PgPool,
+}
+
+impl PostgresCommentRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl CommentRepository for PostgresCommentRepository {
+    async fn create(&self, comment: &Comment) -> AppResult<Comment> {
+        let mut tx = self.pool.begin().await?;
+
+        let created = sqlx::query_as::<_, Comment>(&format!(
+            r#"
+            INSERT INTO comments (id, todo_id, author_id, body, created_at)
+            VALUES ($1, $2, $3, $4, $5)
+            RETURNING {COMMENT_COLUMNS}
+            "#
+        ))
+        .bind(comment.id)
+        .bind(comment.todo_id)
+        .bind(comment.author_id)
+        .bind(&comment.body)
+        .bind(comment.created_at)
+        .fetch_one(&mut *tx)
+        .await?;
+
+        // The count is part of the todo's representation, so it gets a new version
+        sqlx::query(
+            "UPDATE todos SET comment_count = comment_count + 1, version = version + 1 \
+             WHERE id = $1",
+        )
+        .bind(comment.todo_id)
+        .execute(&mut *tx)
+        .await?;
+
+        tx.commit().await?;
+        Ok(created)
+    }
+
+    async fn find_by_id(&self, id: Uuid, todo_id: TodoId) -> AppResult<Option<Comment>> {
+        let comment = sqlx::query_as::<_, Comment>(&format!(
+            r#"
+            SELECT {COMMENT_COLUMNS}
+            FROM comments
+            WHERE id = $1 AND todo_id = $2
+            "#
+        ))
+        .bind(id)
+        .bind(todo_id)
+        .fetch_optional(&self.pool)
+        .await?;
+
+        Ok(comment)
+    }
+
+    async fn find_by_todo(
+        &self,
+        todo_id: TodoId,
+        cursor: Option<&CommentCursor>,
+        limit: i64,
+    ) -> AppResult<Vec<Comment>> {
+        let comments = sqlx::query_as::<_, Comment>(&format!(
+            r#"
+            SELECT {COMMENT_COLUMNS}
+            FROM comments
+            WHERE todo_id = $1
+                AND ($2::timestamptz IS NULL OR (created_at, id) > ($2, $3))
+            ORDER BY created_at, id
+            LIMIT $4
+            "#
+        ))
+        .bind(todo_id)
+        .bind(cursor.map(|c| c.created_at))
+        .bind(cursor.map(|c| c.id))
+        .bind(limit)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(comments)
+    }
+
+    async fn update(&self, comment: &Comment) -> AppResult<Comment> {
+        let updated = sqlx::query_as::<_, Comment>(&format!(
+            r#"
+            UPDATE comments
+            SET body = $1, edited_at = $2
+            WHERE id = $3 AND todo_id = $4
+            RETURNING {COMMENT_COLUMNS}
+            "#
+        ))
+        .bind(&comment.body)
+        .bind(comment.edited_at)
+        .bind(comment.id)
+        .bind(comment.todo_id)
+        .fetch_one(&self.pool)
+        .await?;
+
+        Ok(updated)
+    }
+
+    async fn delete(&self, id: Uuid, todo_id: TodoId) -> AppResult<()> {
+        let mut tx = self.pool.begin().await?;
+
+        let result = sqlx::query("DELETE FROM comments WHERE id = $1 AND todo_id = $2")
+            .bind(id)
+            .bind(todo_id)
+            .execute(&mut *tx)
+            .await?;
+
+        if result.rows_affected() > 0 {
+            sqlx::query(
+                "UPDATE todos SET comment_count = comment_count - 1, version = version + 1 \
+                 WHERE id = $1",
+            )
+            .bind(todo_id)
+            .execute(&mut *tx)
+            .await?;
+        }
+
+        tx.commit().await?;
+        Ok(())
+    }
+}
@@ -1,5 +1,31 @@
+pub mod attachment_repository_impl;
+pub mod comment_repository_impl;
+pub mod notification_repository_impl;
+pub mod project_repository_impl;
+pub mod project_share_repository_impl;
+pub mod reminder_repository_impl;
+pub mod saved_filter_repository_impl;
+pub mod tenant;
+pub mod time_entry_repository_impl;
+pub mod todo_dependency_repository_impl;
 pub mod todo_repository_impl;
+pub mod todo_share_repository_impl;
 pub mod user_repository_impl;
+pub mod workflow_repository_impl;
+pub mod workspace_repository_impl;
 
+pub use attachment_repository_impl::PostgresAttachmentRepository;
+pub use comment_repository_impl::PostgresCommentRepository;
+pub use notification_repository_impl::PostgresNotificationRepository;
+pub use project_repository_impl::PostgresProjectRepository;
+pub use project_share_repository_impl::PostgresProjectShareRepository;
+pub use reminder_repository_impl::PostgresReminderRepository;
+pub use saved_filter_repository_impl::PostgresSavedFilterRepository;
+pub use tenant::Tenant;
+pub use time_entry_repository_impl::PostgresTimeEntryRepository;
+pub use todo_dependency_repository_impl::PostgresTodoDependencyRepository;
 pub use todo_repository_impl::PostgresTodoRepository;
+pub use todo_share_repository_impl::PostgresTodoShareRepository;
 pub use user_repository_impl::PostgresUserRepository;
+pub use workflow_repository_impl::PostgresWorkflowRepository;
+pub use workspace_repository_impl::PostgresWorkspaceRepository;
@@ -0,0 +1,180 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sqlx::PgPool;
+use uuid::Uuid;
+
+use crate::domain::entities::{
+    Notification, NotificationCursor, NotificationEvent, NotificationPreference,
+};
+use crate::domain::repositories::NotificationRepository;
+use crate::shared::error::AppResult;
+
+/// Columns selected for every `Notification` row.
+const NOTIFICATION_COLUMNS: &str = "id, user_id, event, todo_id, title, body, created_at, read_at";
+
+pub struct PostgresNotificationRepository {
+    pool: PgPool,
+}
+
+impl PostgresNotificationRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl NotificationRepository for PostgresNotificationRepository {
+    async fn create(&self, notification: &Notification) -> AppResult<()> {
+        sqlx::query(
+            r#"
+            INSERT INTO notifications (id, user_id, event, todo_id, title, body, created_at)
+            VALUES ($1, $2, $3, $4, $5, $6, $7)
+            ON CONFLICT (id) DO NOTHING
+            "#,
+        )
+        .bind(notification.id)
+        .bind(notification.user_id)
+        .bind(notification.event)
+        .bind(notification.todo_id)
+        .bind(&notification.title)
+        .bind(&notification.body)
+        .bind(notification.created_at)
+        .execute(&self.pool)
+        .await?;
+
+        Ok(())
+    }
+
+    async fn find_by_user(
+        &self,
+        user_id: Uuid,
+        unread_only: bool,
+        cursor: Option<&NotificationCursor>,
+        limit: i64,
+    ) -> AppResult<Vec<Notification>> {
+        let notifications = sqlx::query_as::<_, Notification>(&format!(
+            r#"
+            SELECT {NOTIFICATION_COLUMNS}
+            FROM notifications
+            WHERE user_id = $1
+                AND (NOT $2 OR read_at IS NULL)
+                AND ($3::timestamptz IS NULL OR (created_at, id) < ($3, $4))
+            ORDER BY created_at DESC, id DESC
+            LIMIT $5
+            "#
+        ))
+        .bind(user_id)
+        .bind(unread_only)
+        .bind(cursor.map(|c| c.created_at))
+        .bind(cursor.map(|c| c.id))
+        .bind(limit)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(notifications)
+    }
+
+    async fn count_unread(&self, user_id: Uuid) -> AppResult<i64> {
+        let count: i64 = sqlx::query_scalar(
+            "SELECT COUNT(*) FROM notifications WHERE user_id = $1 AND read_at IS NULL",
+        )
+        .bind(user_id)
+        .fetch_one(&self.pool)
+        .await?;
+
+        Ok(count)
+    }
+
+    async fn mark_read(
+        &self,
+        id: Uuid,
+        user_id: Uuid,
+        at: DateTime<Utc>,
+    ) -> AppResult<Option<Notification>> {
+        let notification = sqlx::query_as::<_, Notification>(&format!(
+            r#"
+            UPDATE notifications
+            SET read_at = COALESCE(read_at, $3)
+            WHERE id = $1 AND user_id = $2
+            RETURNING {NOTIFICATION_COLUMNS}
+            "#
+        ))
+        .bind(id)
+        .bind(user_id)
+        .bind(at)
+        .fetch_optional(&self.pool)
+        .await?;
+
+        Ok(notification)
+    }
+
+    async fn mark_all_read(&self, user_id: Uuid, at: DateTime<Utc>) -> AppResult<u64> {
+        let result = sqlx::query(
+            "UPDATE notifications SET read_at = $2 WHERE user_id = $1 AND read_at IS NULL",
+        )
+        .bind(user_id)
+        .bind(at)
+        .execute(&self.pool)
+        .await?;
+
+        Ok(result.rows_affected())
+    }
+
+    async fn find_preferences(&self, user_id: Uuid) -> AppResult<Vec<NotificationPreference>> {
+        let preferences = sqlx::query_as::<_, NotificationPreference>(
+            "SELECT event, in_app, email FROM notification_preferences WHERE user_id = $1",
+        )
+        .bind(user_id)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(preferences)
+    }
+
+    async fn find_preference(
+        &self,
+        user_id: Uuid,
+        event: NotificationEvent,
+    ) -> AppResult<Option<NotificationPreference>> {
+        let preference = sqlx::query_as::<_, NotificationPreference>(
+            r#"
+            SELECT event, in_app, email
+            FROM notification_preferences
+            WHERE user_id = $1 AND event = $2
+            "#,
+        )
+        .bind(user_id)
+        .bind(event)
+        .fetch_optional(&self.pool)
+        .await?;
+
+        Ok(preference)
+    }
+
+    async fn save_preferences(
+        &self,
+        user_id: Uuid,
+        preferences: &[NotificationPreference],
+    ) -> AppResult<()> {
+        let events: Vec<NotificationEvent> = preferences.iter().map(|p| p.event).collect();
+        let in_app: Vec<bool> = preferences.iter().map(|p| p.in_app).collect();
+        let email: Vec<bool> = preferences.iter().map(|p| p.email).collect();
+
+        sqlx::query(
+            r#"
+            INSERT INTO notification_preferences (user_id, event, in_app, email)
+            SELECT $1, * FROM unnest($2::notification_event[], $3::bool[], $4::bool[])
+            ON CONFLICT (user_id, event)
+            DO UPDATE SET in_app = EXCLUDED.in_app, email = EXCLUDED.email
+            "#,
+        )
+        .bind(user_id)
+        .bind(&events)
+        .bind(&in_app)
+        .bind(&email)
+        .execute(&self.pool)
+        .await?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,109 @@
+use async_trait::async_trait;
+use sqlx::PgPool;
+use uuid::Uuid;
+
+use crate::domain::entities::Project;
+use crate::domain::repositories::ProjectRepository;
+use crate::shared::error::{AppError, AppResult};
+
+/// Columns selected for every `Project` row.
+const PROJECT_COLUMNS: &str = "id, workspace_id, name, created_at, updated_at";
+
+pub struct PostgresProjectRepository {
+    pool: PgPool,
+}
+
+impl PostgresProjectRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+/// Maps a clash with another of the workspace's project names to `Conflict`.
+fn duplicate_name(error: sqlx::Error) -> AppError {
+    match error.as_database_error() {
+        Some(e) if e.is_unique_violation() => {
+            AppError::Conflict("A project with this name already exists".to_string())
+        }
+        _ => AppError::Database(error),
+    }
+}
+
+#[async_trait]
+impl ProjectRepository for PostgresProjectRepository {
+    async fn create(&self, project: &Project) -> AppResult<Project> {
+        let created = sqlx::query_as::<_, Project>(&format!(
+            r#"
+            INSERT INTO projects (id, workspace_id, name, created_at, updated_at)
+            VALUES ($1, $2, $3, $4, $5)
+            RETURNING {PROJECT_COLUMNS}
+            "#
+        ))
+        .bind(project.id)
+        .bind(project.workspace_id)
+        .bind(&project.name)
+        .bind(project.created_at)
+        .bind(project.updated_at)
+        .fetch_one(&self.pool)
+        .await
+        .map_err(duplicate_name)?;
+
+        Ok(created)
+    }
+
+    async fn find_by_id(&self, id: Uuid) -> AppResult<Option<Project>> {
+        let project = sqlx::query_as::<_, Project>(&format!(
+            "SELECT {PROJECT_COLUMNS} FROM projects WHERE id = $1"
+        ))
+        .bind(id)
+        .fetch_optional(&self.pool)
+        .await?;
+
+        Ok(project)
+    }
+
+    async fn find_all(&self) -> AppResult<Vec<Project>> {
+        let projects = sqlx::query_as::<_, Project>(&format!(
+            "SELECT {PROJECT_COLUMNS} FROM projects ORDER BY name, id"
+        ))
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(projects)
+    }
+
+    async fn update(&self, project: &Project) -> AppResult<Project> {
+        let updated = sqlx::query_as::<_, Project>(&format!(
+            r#"
+            UPDATE projects
+            SET name = $2, updated_at = $3
+            WHERE id = $1
+            RETURNING {PROJECT_COLUMNS}
+            "#
+        ))
+        .bind(project.id)
+        .bind(&project.name)
+        .bind(project.updated_at)
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(duplicate_name)?;
+
+        updated.ok_or_else(|| AppError::NotFound("Project not found".to_string()))
+    }
+
+    async fn delete(&self, id: Uuid) -> AppResult<bool> {
+        let result = sqlx::query("DELETE FROM projects WHERE id = $1")
+            .bind(id)
+            .execute(&self.pool)
+            .await
+            .map_err(|error| match error.as_database_error() {
+                Some(e) if e.is_foreign_key_violation() => AppError::Conflict(
+                    "Project still has todos; move or delete them first, trashed ones included"
+                        .to_string(),
+                ),
+                _ => AppError::Database(error),
+            })?;
+
+        Ok(result.rows_affected() > 0)
+    }
+}
@@ -0,0 +1,80 @@
+use async_trait::async_trait;
+use sqlx::PgPool;
+use uuid::Uuid;
+
+use crate::domain::entities::{ProjectShare, SharePermission};
+use crate::domain::repositories::ProjectShareRepository;
+use crate::shared::error::AppResult;
+
+pub struct PostgresProjectShareRepository {
+    pool: PgPool,
+}
+
+impl PostgresProjectShareRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl ProjectShareRepository for PostgresProjectShareRepository {
+    async fn upsert(
+        &self,
+        project_id: Uuid,
+        user_id: Uuid,
+        permission: SharePermission,
+    ) -> AppResult<ProjectShare> {
+        let share = sqlx::query_as::<_, ProjectShare>(
+            r#"
+            WITH share AS (
+                INSERT INTO project_shares (project_id, user_id, permission)
+                VALUES ($1, $2, $3)
+                ON CONFLICT (project_id, user_id) DO UPDATE SET permission = EXCLUDED.permission
+                RETURNING project_id, user_id, permission, created_at
+            )
+            SELECT share.project_id, share.user_id, users.email, share.permission, share.created_at
+            FROM share
+            JOIN users ON users.id = share.user_id
+            "#,
+        )
+        .bind(project_id)
+        .bind(user_id)
+        .bind(permission)
+        .fetch_one(&self.pool)
+        .await?;
+
+        Ok(share)
+    }
+
+    async fn find_by_project(&self, project_id: Uuid) -> AppResult<Vec<ProjectShare>> {
+        let shares = sqlx::query_as::<_, ProjectShare>(
+            r#"
+            SELECT s.project_id, s.user_id, u.email, s.permission, s.created_at
+            FROM project_shares s
+            JOIN users u ON u.id = s.user_id
+            WHERE s.project_id = $1
+            ORDER BY s.created_at, s.user_id
+            "#,
+        )
+        .bind(project_id)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(shares)
+    }
+
+    async fn delete(&self, project_id: Uuid, user_id: Uuid) -> AppResult<bool> {
+        let result = sqlx::query(
+            r#"
+            DELETE FROM project_shares
+            WHERE project_id = $1 AND user_id = $2
+            "#,
+        )
+        .bind(project_id)
+        .bind(user_id)
+        .execute(&self.pool)
+        .await?;
+
+        Ok(result.rows_affected() > 0)
+    }
+}
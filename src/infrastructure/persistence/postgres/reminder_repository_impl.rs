@@ -0,0 +1,243 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Duration, Utc};
+use sqlx::PgPool;
+use uuid::Uuid;
+
+use crate::domain::entities::{PendingDelivery, Reminder, ReminderDelivery, TodoId};
+use crate::domain::repositories::ReminderRepository;
+use crate::shared::error::AppResult;
+
+/// Columns selected for every `Reminder` row.
+const REMINDER_COLUMNS: &str =
+    "id, todo_id, user_id, remind_at, offset_minutes, channels, webhook_url, fired_for, created_at";
+
+/// Columns selected for every `ReminderDelivery` row.
+const DELIVERY_COLUMNS: &str = "id, reminder_id, channel, scheduled_for, status, attempts, \
+     next_attempt_at, last_error, created_at, delivered_at";
+
+/// When a reminder `r` of the todo `t` fires.
+const FIRE_AT: &str = "COALESCE(r.remind_at, t.due_at - make_interval(mins => r.offset_minutes))";
+
+pub struct PostgresReminderRepository {
+    pool: PgPool,
+}
+
+impl PostgresReminderRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl ReminderRepository for PostgresReminderRepository {
+    async fn create(&self, reminder: &Reminder) -> AppResult<Reminder> {
+        let created = sqlx::query_as::<_, Reminder>(&format!(
+            r#"
+            INSERT INTO reminders
+                (id, todo_id, user_id, remind_at, offset_minutes, channels, webhook_url, created_at)
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8)
+            RETURNING {REMINDER_COLUMNS}
+            "#
+        ))
+        .bind(reminder.id)
+        .bind(reminder.todo_id)
+        .bind(reminder.user_id)
+        .bind(reminder.remind_at)
+        .bind(reminder.offset_minutes)
+        .bind(&reminder.channels)
+        .bind(&reminder.webhook_url)
+        .bind(reminder.created_at)
+        .fetch_one(&self.pool)
+        .await?;
+
+        Ok(created)
+    }
+
+    async fn find_by_id(
+        &self,
+        id: Uuid,
+        todo_id: TodoId,
+        user_id: Uuid,
+    ) -> AppResult<Option<Reminder>> {
+        let reminder = sqlx::query_as::<_, Reminder>(&format!(
+            r#"
+            SELECT {REMINDER_COLUMNS}
+            FROM reminders
+            WHERE id = $1 AND todo_id = $2 AND user_id = $3
+            "#
+        ))
+        .bind(id)
+        .bind(todo_id)
+        .bind(user_id)
+        .fetch_optional(&self.pool)
+        .await?;
+
+        Ok(reminder)
+    }
+
+    async fn find_by_todo(&self, todo_id: TodoId, user_id: Uuid) -> AppResult<Vec<Reminder>> {
+        let reminders = sqlx::query_as::<_, Reminder>(&format!(
+            r#"
+            SELECT {REMINDER_COLUMNS}
+            FROM reminders
+            WHERE todo_id = $1 AND user_id = $2
+            ORDER BY created_at, id
+            "#
+        ))
+        .bind(todo_id)
+        .bind(user_id)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(reminders)
+    }
+
+    async fn delete(&self, id: Uuid, todo_id: TodoId, user_id: Uuid) -> AppResult<bool> {
+        let result =
+            sqlx::query("DELETE FROM reminders WHERE id = $1 AND todo_id = $2 AND user_id = $3")
+                .bind(id)
+                .bind(todo_id)
+                .bind(user_id)
+                .execute(&self.pool)
+                .await?;
+
+        Ok(result.rows_affected() > 0)
+    }
+
+    async fn find_deliveries(&self, reminder_id: Uuid) -> AppResult<Vec<ReminderDelivery>> {
+        let deliveries = sqlx::query_as::<_, ReminderDelivery>(&format!(
+            r#"
+            SELECT {DELIVERY_COLUMNS}
+            FROM reminder_deliveries
+            WHERE reminder_id = $1
+            ORDER BY scheduled_for DESC, channel
+            "#
+        ))
+        .bind(reminder_id)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(deliveries)
+    }
+
+    async fn fire_due(&self, now: DateTime<Utc>, limit: i64) -> AppResult<u64> {
+        // Marking the reminders fired and queueing their deliveries is one
+        // statement, so a reminder is either fully fired or not at all.
+        // Inbox and email deliveries the user turned off for reminders are
+        // left out; without a preference both are on.
+        let result = sqlx::query(&format!(
+            r#"
+            WITH due AS (
+                SELECT r.id, {FIRE_AT} AS fire_at
+                FROM reminders r
+                JOIN todos t ON t.id = r.todo_id
+                WHERE t.deleted_at IS NULL AND NOT t.completed
+                    AND {FIRE_AT} <= $1
+                    AND r.fired_for IS DISTINCT FROM {FIRE_AT}
+                ORDER BY fire_at
+                LIMIT $2
+                FOR UPDATE OF r SKIP LOCKED
+            ), fired AS (
+                UPDATE reminders r
+                SET fired_for = due.fire_at
+                FROM due
+                WHERE r.id = due.id
+                RETURNING r.id, r.todo_id, r.user_id, r.channels, due.fire_at
+            )
+            INSERT INTO reminder_deliveries
+                (reminder_id, todo_id, user_id, channel, scheduled_for, next_attempt_at)
+            SELECT fired.id, fired.todo_id, fired.user_id, channel, fired.fire_at, $1
+            FROM fired
+            CROSS JOIN unnest(fired.channels) AS channel
+            LEFT JOIN notification_preferences p
+                ON p.user_id = fired.user_id AND p.event = 'reminder'
+            WHERE channel = 'webhook'
+                OR (channel = 'in_app' AND COALESCE(p.in_app, TRUE))
+                OR (channel = 'email' AND COALESCE(p.email, TRUE))
+            ON CONFLICT (reminder_id, scheduled_for, channel) DO NOTHING
+            "#
+        ))
+        .bind(now)
+        .bind(limit)
+        .execute(&self.pool)
+        .await?;
+
+        Ok(result.rows_affected())
+    }
+
+    async fn claim_deliveries(
+        &self,
+        now: DateTime<Utc>,
+        limit: i64,
+        lease: Duration,
+    ) -> AppResult<Vec<PendingDelivery>> {
+        let deliveries = sqlx::query_as::<_, PendingDelivery>(
+            r#"
+            WITH claimed AS (
+                UPDATE reminder_deliveries d
+                SET attempts = d.attempts + 1, next_attempt_at = $3
+                WHERE d.id IN (
+                    SELECT id FROM reminder_deliveries
+                    WHERE status = 'pending' AND next_attempt_at <= $1
+                    ORDER BY next_attempt_at
+                    LIMIT $2
+                    FOR UPDATE SKIP LOCKED
+                )
+                RETURNING d.id, d.reminder_id, d.todo_id, d.user_id, d.channel,
+                    d.scheduled_for, d.attempts
+            )
+            SELECT c.id, c.reminder_id, c.todo_id, c.user_id, c.channel, c.scheduled_for,
+                c.attempts, u.email, t.title, t.due_at, r.webhook_url
+            FROM claimed c
+            JOIN users u ON u.id = c.user_id
+            JOIN todos t ON t.id = c.todo_id
+            JOIN reminders r ON r.id = c.reminder_id
+            ORDER BY c.scheduled_for
+            "#,
+        )
+        .bind(now)
+        .bind(limit)
+        .bind(now + lease)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(deliveries)
+    }
+
+    async fn mark_sent(&self, id: Uuid, at: DateTime<Utc>) -> AppResult<()> {
+        sqlx::query(
+            "UPDATE reminder_deliveries SET status = 'sent', delivered_at = $2, last_error = NULL \
+             WHERE id = $1",
+        )
+        .bind(id)
+        .bind(at)
+        .execute(&self.pool)
+        .await?;
+
+        Ok(())
+    }
+
+    async fn mark_failed(
+        &self,
+        id: Uuid,
+        error: &str,
+        retry_at: Option<DateTime<Utc>>,
+    ) -> AppResult<()> {
+        sqlx::query(
+            r#"
+            UPDATE reminder_deliveries
+            SET last_error = $2,
+                status = CASE WHEN $3::timestamptz IS NULL THEN 'failed' ELSE 'pending' END::delivery_status,
+                next_attempt_at = COALESCE($3, next_attempt_at)
+            WHERE id = $1
+            "#,
+        )
+        .bind(id)
+        .bind(error)
+        .bind(retry_at)
+        .execute(&self.pool)
+        .await?;
+
+        Ok(())
+    }
+}
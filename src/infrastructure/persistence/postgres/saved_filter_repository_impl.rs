@@ -0,0 +1,131 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sqlx::types::Json;
+use sqlx::PgPool;
+use uuid::Uuid;
+
+use crate::domain::entities::SavedFilter;
+use crate::domain::repositories::SavedFilterRepository;
+use crate::shared::error::{AppError, AppResult};
+
+/// Columns selected for every `SavedFilter` row.
+const SAVED_FILTER_COLUMNS: &str =
+    "id, user_id, name, definition, last_viewed_at, created_at, updated_at";
+
+pub struct PostgresSavedFilterRepository {
+    pool: PgPool,
+}
+
+impl PostgresSavedFilterRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+/// Maps a clash with another of the user's filter names to `Conflict`.
+fn duplicate_name(error: sqlx::Error) -> AppError {
+    match error.as_database_error() {
+        Some(e) if e.is_unique_violation() => {
+            AppError::Conflict("A filter with this name already exists".to_string())
+        }
+        _ => AppError::Database(error),
+    }
+}
+
+#[async_trait]
+impl SavedFilterRepository for PostgresSavedFilterRepository {
+    async fn create(&self, filter: &SavedFilter) -> AppResult<SavedFilter> {
+        let created = sqlx::query_as::<_, SavedFilter>(&format!(
+            r#"
+            INSERT INTO saved_filters (id, user_id, name, definition, created_at, updated_at)
+            VALUES ($1, $2, $3, $4, $5, $6)
+            RETURNING {SAVED_FILTER_COLUMNS}
+            "#
+        ))
+        .bind(filter.id)
+        .bind(filter.user_id)
+        .bind(&filter.name)
+        .bind(Json(&filter.definition))
+        .bind(filter.created_at)
+        .bind(filter.updated_at)
+        .fetch_one(&self.pool)
+        .await
+        .map_err(duplicate_name)?;
+
+        Ok(created)
+    }
+
+    async fn find_by_id(&self, id: Uuid, user_id: Uuid) -> AppResult<Option<SavedFilter>> {
+        let filter = sqlx::query_as::<_, SavedFilter>(&format!(
+            r#"
+            SELECT {SAVED_FILTER_COLUMNS}
+            FROM saved_filters
+            WHERE id = $1 AND user_id = $2
+            "#
+        ))
+        .bind(id)
+        .bind(user_id)
+        .fetch_optional(&self.pool)
+        .await?;
+
+        Ok(filter)
+    }
+
+    async fn find_by_user(&self, user_id: Uuid) -> AppResult<Vec<SavedFilter>> {
+        let filters = sqlx::query_as::<_, SavedFilter>(&format!(
+            r#"
+            SELECT {SAVED_FILTER_COLUMNS}
+            FROM saved_filters
+            WHERE user_id = $1
+            ORDER BY name, id
+            "#
+        ))
+        .bind(user_id)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(filters)
+    }
+
+    async fn update(&self, filter: &SavedFilter) -> AppResult<SavedFilter> {
+        let updated = sqlx::query_as::<_, SavedFilter>(&format!(
+            r#"
+            UPDATE saved_filters
+            SET name = $3, definition = $4, updated_at = $5
+            WHERE id = $1 AND user_id = $2
+            RETURNING {SAVED_FILTER_COLUMNS}
+            "#
+        ))
+        .bind(filter.id)
+        .bind(filter.user_id)
+        .bind(&filter.name)
+        .bind(Json(&filter.definition))
+        .bind(filter.updated_at)
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(duplicate_name)?;
+
+        updated.ok_or_else(|| AppError::NotFound("Filter not found".to_string()))
+    }
+
+    async fn mark_viewed(&self, id: Uuid, user_id: Uuid, at: DateTime<Utc>) -> AppResult<()> {
+        sqlx::query("UPDATE saved_filters SET last_viewed_at = $3 WHERE id = $1 AND user_id = $2")
+            .bind(id)
+            .bind(user_id)
+            .bind(at)
+            .execute(&self.pool)
+            .await?;
+
+        Ok(())
+    }
+
+    async fn delete(&self, id: Uuid, user_id: Uuid) -> AppResult<bool> {
+        let result = sqlx::query("DELETE FROM saved_filters WHERE id = $1 AND user_id = $2")
+            .bind(id)
+            .bind(user_id)
+            .execute(&self.pool)
+            .await?;
+
+        Ok(result.rows_affected() > 0)
+    }
+}
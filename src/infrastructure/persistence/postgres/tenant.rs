@@ -0,0 +1,61 @@
+use std::future::Future;
+
+use sqlx::postgres::PgPoolOptions;
+use sqlx::PgConnection;
+use uuid::Uuid;
+
+/// Role every connection switches to while serving a workspace. Unlike the
+/// login role, which may own the tables or be a superuser, it never bypasses
+/// row-level security.
+const TENANT_ROLE: &str = "todo_tenant";
+
+/// Whose rows the database connections of the current task may see.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Tenant {
+    /// Only the rows of one workspace
+    Workspace(Uuid),
+    /// Every row; for migrations and background jobs
+    System,
+}
+
+tokio::task_local! {
+    static CURRENT_TENANT: Tenant;
+}
+
+impl Tenant {
+    /// Runs `future` with its database access limited to this tenant.
+    pub async fn scope<F: Future>(self, future: F) -> F::Output {
+        CURRENT_TENANT.scope(self, future).await
+    }
+
+    /// The tenant of the current task. Outside any scope no tenant rows are
+    /// visible.
+    pub fn current() -> Option<Self> {
+        CURRENT_TENANT.try_with(|tenant| *tenant).ok()
+    }
+}
+
+/// Sets the role and `app.workspace_id` the row-level security policies
+/// check to those of the current tenant.
+async fn apply_tenant(conn: &mut PgConnection) -> Result<(), sqlx::Error> {
+    let (role, workspace_id) = match Tenant::current() {
+        Some(Tenant::System) => ("none", String::new()),
+        Some(Tenant::Workspace(id)) => (TENANT_ROLE, id.to_string()),
+        None => (TENANT_ROLE, String::new()),
+    };
+
+    sqlx::query("SELECT set_config('role', $1, false), set_config('app.workspace_id', $2, false)")
+        .bind(role)
+        .bind(workspace_id)
+        .execute(conn)
+        .await?;
+    Ok(())
+}
+
+/// Pool options that apply the tenant of the acquiring task to every
+/// connection handed out.
+pub fn pool_options() -> PgPoolOptions {
+    PgPoolOptions::new()
+        .after_connect(|conn, _| Box::pin(apply_tenant(conn)))
+        .before_acquire(|conn, _| Box::pin(async move { apply_tenant(conn).await.map(|_| true) }))
+}
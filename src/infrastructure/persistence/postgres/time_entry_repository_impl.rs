@@ -0,0 +1,178 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sqlx::{PgConnection, PgPool};
+use uuid::Uuid;
+
+use crate::domain::entities::{TimeEntry, TodoId, TrackedSpan};
+use crate::domain::repositories::TimeEntryRepository;
+use crate::shared::error::{AppError, AppResult};
+
+/// Columns selected for every `TimeEntry` row.
+const TIME_ENTRY_COLUMNS: &str = "id, todo_id, user_id, started_at, ended_at, note, created_at";
+
+pub struct PostgresTimeEntryRepository {
+    pool: PgPool,
+}
+
+impl PostgresTimeEntryRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+/// Maps a second running timer of the same user to `Conflict`.
+fn timer_running(error: sqlx::Error) -> AppError {
+    match error.as_database_error() {
+        Some(e) if e.is_unique_violation() => AppError::Conflict(
+            "You already have a running timer; stop it before starting another".to_string(),
+        ),
+        _ => AppError::Database(error),
+    }
+}
+
+/// The time tracked on a todo is part of its representation, so finishing,
+/// entering or deleting tracked time gives the todo a new version.
+async fn bump_version(conn: &mut PgConnection, todo_id: TodoId) -> AppResult<()> {
+    sqlx::query("UPDATE todos SET version = version + 1 WHERE id = $1")
+        .bind(todo_id)
+        .execute(conn)
+        .await?;
+
+    Ok(())
+}
+
+#[async_trait]
+impl TimeEntryRepository for PostgresTimeEntryRepository {
+    async fn create(&self, entry: &TimeEntry) -> AppResult<TimeEntry> {
+        let mut tx = self.pool.begin().await?;
+
+        let created = sqlx::query_as::<_, TimeEntry>(&format!(
+            r#"
+            INSERT INTO time_entries (id, todo_id, user_id, started_at, ended_at, note, created_at)
+            VALUES ($1, $2, $3, $4, $5, $6, $7)
+            RETURNING {TIME_ENTRY_COLUMNS}
+            "#
+        ))
+        .bind(entry.id)
+        .bind(entry.todo_id)
+        .bind(entry.user_id)
+        .bind(entry.started_at)
+        .bind(entry.ended_at)
+        .bind(&entry.note)
+        .bind(entry.created_at)
+        .fetch_one(&mut *tx)
+        .await
+        .map_err(timer_running)?;
+        // A running timer counts once it stops
+        if created.ended_at.is_some() {
+            bump_version(&mut tx, created.todo_id).await?;
+        }
+
+        tx.commit().await?;
+        Ok(created)
+    }
+
+    async fn find_by_id(&self, id: Uuid, todo_id: TodoId) -> AppResult<Option<TimeEntry>> {
+        let entry = sqlx::query_as::<_, TimeEntry>(&format!(
+            r#"
+            SELECT {TIME_ENTRY_COLUMNS}
+            FROM time_entries
+            WHERE id = $1 AND todo_id = $2
+            "#
+        ))
+        .bind(id)
+        .bind(todo_id)
+        .fetch_optional(&self.pool)
+        .await?;
+
+        Ok(entry)
+    }
+
+    async fn find_by_todo(&self, todo_id: TodoId) -> AppResult<Vec<TimeEntry>> {
+        let entries = sqlx::query_as::<_, TimeEntry>(&format!(
+            r#"
+            SELECT {TIME_ENTRY_COLUMNS}
+            FROM time_entries
+            WHERE todo_id = $1
+            ORDER BY started_at DESC, id
+            "#
+        ))
+        .bind(todo_id)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(entries)
+    }
+
+    async fn stop(
+        &self,
+        user_id: Uuid,
+        todo_id: TodoId,
+        at: DateTime<Utc>,
+    ) -> AppResult<Option<TimeEntry>> {
+        let mut tx = self.pool.begin().await?;
+
+        // A timer stopped within the second it started still ends after it
+        let entry = sqlx::query_as::<_, TimeEntry>(&format!(
+            r#"
+            UPDATE time_entries
+            SET ended_at = GREATEST($3, started_at + INTERVAL '1 microsecond')
+            WHERE user_id = $1 AND todo_id = $2 AND ended_at IS NULL
+            RETURNING {TIME_ENTRY_COLUMNS}
+            "#
+        ))
+        .bind(user_id)
+        .bind(todo_id)
+        .bind(at)
+        .fetch_optional(&mut *tx)
+        .await?;
+        if entry.is_some() {
+            bump_version(&mut tx, todo_id).await?;
+        }
+
+        tx.commit().await?;
+        Ok(entry)
+    }
+
+    async fn delete(&self, id: Uuid, todo_id: TodoId) -> AppResult<bool> {
+        let mut tx = self.pool.begin().await?;
+
+        let finished: Option<(bool,)> = sqlx::query_as(
+            "DELETE FROM time_entries WHERE id = $1 AND todo_id = $2 \
+             RETURNING ended_at IS NOT NULL",
+        )
+        .bind(id)
+        .bind(todo_id)
+        .fetch_optional(&mut *tx)
+        .await?;
+        if let Some((true,)) = finished {
+            bump_version(&mut tx, todo_id).await?;
+        }
+
+        tx.commit().await?;
+        Ok(finished.is_some())
+    }
+
+    async fn tracked_spans(
+        &self,
+        user_id: Uuid,
+        start: DateTime<Utc>,
+        end: DateTime<Utc>,
+    ) -> AppResult<Vec<TrackedSpan>> {
+        let spans = sqlx::query_as::<_, TrackedSpan>(
+            r#"
+            SELECT started_at, ended_at, workspace_id, workspace_name, project_id, project_name,
+                   tags
+            FROM tracked_time($1, $2, $3)
+            ORDER BY started_at
+            "#,
+        )
+        .bind(user_id)
+        .bind(start)
+        .bind(end)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(spans)
+    }
+}
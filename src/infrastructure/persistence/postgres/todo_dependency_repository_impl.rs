@@ -0,0 +1,150 @@
+use async_trait::async_trait;
+use sqlx::{PgConnection, PgPool};
+use uuid::Uuid;
+
+use super::todo_repository_impl::TODO_COLUMNS;
+use crate::domain::entities::{Todo, TodoDependency, TodoId};
+use crate::domain::repositories::TodoDependencyRepository;
+use crate::shared::error::{AppError, AppResult};
+
+pub struct PostgresTodoDependencyRepository {
+    pool: PgPool,
+}
+
+impl PostgresTodoDependencyRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+/// Whether a todo is blocked is part of its representation, so changing its
+/// blockers gives it a new version.
+async fn bump_version(conn: &mut PgConnection, todo_id: TodoId) -> AppResult<()> {
+    sqlx::query("UPDATE todos SET version = version + 1 WHERE id = $1")
+        .bind(todo_id)
+        .execute(conn)
+        .await?;
+
+    Ok(())
+}
+
+#[async_trait]
+impl TodoDependencyRepository for PostgresTodoDependencyRepository {
+    async fn add(&self, todo_id: TodoId, blocker_id: TodoId) -> AppResult<()> {
+        let mut tx = self.pool.begin().await?;
+
+        // Two links added at once could each close half of a cycle, so
+        // additions within a workspace take turns
+        sqlx::query(
+            "SELECT pg_advisory_xact_lock(hashtext('todo_dependencies'), \
+             hashtext(current_setting('app.workspace_id', true)))",
+        )
+        .execute(&mut *tx)
+        .await?;
+
+        let (cycle,): (bool,) = sqlx::query_as(
+            r#"
+            WITH RECURSIVE upstream(id) AS (
+                SELECT blocker_id FROM todo_dependencies WHERE todo_id = $1
+                UNION
+                SELECT d.blocker_id
+                FROM todo_dependencies d
+                JOIN upstream u ON d.todo_id = u.id
+            )
+            SELECT EXISTS (SELECT 1 FROM upstream WHERE id = $2)
+            "#,
+        )
+        .bind(blocker_id)
+        .bind(todo_id)
+        .fetch_one(&mut *tx)
+        .await?;
+        if cycle {
+            return Err(AppError::Validation(
+                "The blocker already depends on this todo".to_string(),
+            ));
+        }
+
+        let result = sqlx::query(
+            r#"
+            INSERT INTO todo_dependencies (todo_id, blocker_id)
+            VALUES ($1, $2)
+            ON CONFLICT (todo_id, blocker_id) DO NOTHING
+            "#,
+        )
+        .bind(todo_id)
+        .bind(blocker_id)
+        .execute(&mut *tx)
+        .await?;
+        if result.rows_affected() > 0 {
+            bump_version(&mut tx, todo_id).await?;
+        }
+        tx.commit().await?;
+
+        Ok(())
+    }
+
+    async fn remove(&self, todo_id: TodoId, blocker_id: TodoId) -> AppResult<bool> {
+        let mut tx = self.pool.begin().await?;
+
+        let result =
+            sqlx::query("DELETE FROM todo_dependencies WHERE todo_id = $1 AND blocker_id = $2")
+                .bind(todo_id)
+                .bind(blocker_id)
+                .execute(&mut *tx)
+                .await?;
+        let removed = result.rows_affected() > 0;
+        if removed {
+            bump_version(&mut tx, todo_id).await?;
+        }
+        tx.commit().await?;
+
+        Ok(removed)
+    }
+
+    async fn find_blockers(&self, todo_id: TodoId) -> AppResult<Vec<Todo>> {
+        let todos = sqlx::query_as::<_, Todo>(&format!(
+            r#"
+            SELECT {TODO_COLUMNS}
+            FROM todos
+            WHERE id IN (SELECT blocker_id FROM todo_dependencies WHERE todo_id = $1)
+                AND deleted_at IS NULL
+            ORDER BY created_at, id
+            "#
+        ))
+        .bind(todo_id)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(todos)
+    }
+
+    async fn find_open_by_user(
+        &self,
+        user_id: Uuid,
+    ) -> AppResult<(Vec<Todo>, Vec<TodoDependency>)> {
+        let todos = sqlx::query_as::<_, Todo>(&format!(
+            r#"
+            SELECT {TODO_COLUMNS}
+            FROM todos
+            WHERE user_id = $1 AND NOT completed AND deleted_at IS NULL
+            "#
+        ))
+        .bind(user_id)
+        .fetch_all(&self.pool)
+        .await?;
+
+        let dependencies = sqlx::query_as::<_, TodoDependency>(
+            r#"
+            SELECT d.todo_id, d.blocker_id
+            FROM todo_dependencies d
+            JOIN todos t ON t.id = d.todo_id
+            WHERE t.user_id = $1 AND NOT t.completed AND t.deleted_at IS NULL
+            "#,
+        )
+        .bind(user_id)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok((todos, dependencies))
+    }
+}
@@ -1,10 +1,55 @@
 use async_trait::async_trait;
-use sqlx::PgPool;
+use chrono::{DateTime, Utc};
+use sqlx::types::Json;
+use sqlx::{PgConnection, PgExecutor, PgPool, Postgres, QueryBuilder};
 use uuid::Uuid;
 
-use crate::domain::entities::{Todo, TodoId};
-use crate::domain::repositories::TodoRepository;
-use crate::shared::error::AppResult;
+use crate::domain::entities::priority::{
+    AGE_POINTS_PER_DAY, DUE_NOW_POINTS, DUE_WINDOW_DAYS, MAX_AGE_POINTS, MAX_OVERDUE_POINTS,
+};
+use crate::domain::entities::search::{escape_html, highlight, HIGHLIGHT_END, HIGHLIGHT_START};
+use crate::domain::entities::{
+    position_between, CursorKey, DateField, SearchLanguage, SearchQuery, SearchTerm,
+    SharePermission, SortOrder, Todo, TodoCursor, TodoFilter, TodoHistoryEntry, TodoId,
+    TodoPriority, TodoQuery, TodoRole, TodoSearchHit, TodoSort, TodoSortField, TodoStatus, TodoTag,
+};
+use crate::domain::repositories::{TodoChangeSet, TodoRepository};
+use crate::shared::error::{AppError, AppResult};
+
+/// Columns selected for every `Todo` row, in `FromRow` order. The table
+/// must be referenced as `todos`, without an alias.
+pub(super) const TODO_COLUMNS: &str = "id, user_id, assignee_id, project_id, title, description, priority, tags, \
+     checklist, status, position, completed, completed_at, due_at, recurrence_rule, time_zone, series_id, occurrence_index, created_at, updated_at, \
+     deleted_at, version, comment_count, \
+     EXISTS (SELECT 1 FROM todo_dependencies d JOIN todos b ON b.id = d.blocker_id \
+             WHERE d.todo_id = todos.id AND NOT b.completed AND b.deleted_at IS NULL) AS blocked, \
+     (SELECT COALESCE(SUM(EXTRACT(EPOCH FROM e.ended_at - e.started_at)), 0)::BIGINT \
+      FROM time_entries e WHERE e.todo_id = todos.id AND e.ended_at IS NOT NULL) AS tracked_seconds";
+
+/// Active todos that `$1` owns or that are shared with them, directly or
+/// through their project.
+const VISIBLE_TO_USER: &str = "(user_id = $1 OR EXISTS (SELECT 1 FROM todo_access s \
+     WHERE s.todo_id = todos.id AND s.user_id = $1)) AND deleted_at IS NULL";
+
+/// Columns selected for every `TodoHistoryEntry` row.
+const HISTORY_COLUMNS: &str = "id, todo_id, user_id, actor_id, action, before, after, created_at";
+
+#[derive(sqlx::FromRow)]
+struct SearchRow {
+    #[sqlx(flatten)]
+    todo: Todo,
+    rank: f32,
+    title_snippet: String,
+    description_snippet: Option<String>,
+}
+
+#[derive(sqlx::FromRow)]
+struct SharedRow {
+    #[sqlx(flatten)]
+    todo: Todo,
+    /// The user's share of the todo, if any
+    permission: Option<SharePermission>,
+}
 
 pub struct PostgresTodoRepository {
     pool: PgPool,
@@ -14,39 +59,729 @@ impl PostgresTodoRepository {
     pub fn new(pool: PgPool) -> Self {
         Self { pool }
     }
-}
 
-#[async_trait]
-impl TodoRepository for PostgresTodoRepository {
-    async fn create(&self, todo: &Todo) -> AppResult<Todo> {
-        let created = sqlx::query_as::<_, Todo>(
+    async fn insert<'e>(executor: impl PgExecutor<'e>, todo: &Todo) -> AppResult<Todo> {
+        let created = sqlx::query_as::<_, Todo>(&format!(
             r#"
-            INSERT INTO todos (id, user_id, title, description, completed, created_at, updated_at)
-            VALUES ($1, $2, $3, $4, $5, $6, $7)
-            RETURNING id, user_id, title, description, completed, created_at, updated_at
-            "#,
-        )
+            INSERT INTO todos (id, user_id, assignee_id, title, description, priority, tags,
+                               status, position, completed, completed_at, due_at,
+                               recurrence_rule, time_zone, series_id, occurrence_index,
+                               created_at, updated_at, checklist, project_id)
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13, $14, $15, $16, $17,
+                    $18, $19, $20)
+            RETURNING {TODO_COLUMNS}
+            "#
+        ))
         .bind(todo.id)
         .bind(todo.user_id)
+        .bind(todo.assignee_id)
         .bind(&todo.title)
         .bind(&todo.description)
+        .bind(todo.priority)
+        .bind(&todo.tags)
+        .bind(&todo.status)
+        .bind(todo.position)
         .bind(todo.completed)
+        .bind(todo.completed_at)
+        .bind(todo.due_at)
+        .bind(&todo.recurrence_rule)
+        .bind(&todo.time_zone)
+        .bind(todo.series_id)
+        .bind(todo.occurrence_index)
         .bind(todo.created_at)
         .bind(todo.updated_at)
-        .fetch_one(&self.pool)
+        .bind(Json(&todo.checklist))
+        .bind(todo.project_id)
+        .fetch_one(executor)
+        .await
+        .map_err(unknown_project)?;
+
+        Ok(created)
+    }
+
+    /// Inserts all `todos` with a single multi-row `INSERT`, returning the ids
+    /// of those inserted. Occurrences the series already has are skipped.
+    async fn insert_many<'e>(
+        executor: impl PgExecutor<'e>,
+        todos: &[Todo],
+    ) -> AppResult<Vec<TodoId>> {
+        let mut builder = QueryBuilder::<Postgres>::new(
+            "INSERT INTO todos (id, user_id, assignee_id, title, description, priority, tags, \
+             status, position, completed, completed_at, due_at, recurrence_rule, time_zone, series_id, occurrence_index, \
+             created_at, updated_at, checklist, project_id) ",
+        );
+        builder.push_values(todos, |mut row, todo| {
+            row.push_bind(todo.id)
+                .push_bind(todo.user_id)
+                .push_bind(todo.assignee_id)
+                .push_bind(todo.title.value())
+                .push_bind(&todo.description)
+                .push_bind(todo.priority)
+                .push_bind(&todo.tags)
+                .push_bind(todo.status.value())
+                .push_bind(todo.position)
+                .push_bind(todo.completed)
+                .push_bind(todo.completed_at)
+                .push_bind(todo.due_at)
+                .push_bind(todo.recurrence_rule.as_ref().map(|r| r.value()))
+                .push_bind(todo.time_zone.value())
+                .push_bind(todo.series_id)
+                .push_bind(todo.occurrence_index)
+                .push_bind(todo.created_at)
+                .push_bind(todo.updated_at)
+                .push_bind(Json(&todo.checklist))
+                .push_bind(todo.project_id);
+        });
+        builder.push(" ON CONFLICT (series_id, occurrence_index) DO NOTHING RETURNING id");
+        let inserted = builder
+            .build_query_scalar::<TodoId>()
+            .fetch_all(executor)
+            .await
+            .map_err(unknown_project)?;
+
+        Ok(inserted)
+    }
+
+    /// Inserts `next`, the occurrence following `previous`, sharing it like
+    /// `previous`. Completing an occurrence, reopening it and completing it
+    /// again spawns the next one only once; returns `history` without the
+    /// entries for `next` if it already existed.
+    async fn spawn_occurrence(
+        conn: &mut PgConnection,
+        previous: &Todo,
+        next: &Todo,
+        history: &[TodoHistoryEntry],
+    ) -> AppResult<Vec<TodoHistoryEntry>> {
+        let inserted = Self::insert_many(&mut *conn, std::slice::from_ref(next)).await?;
+        if inserted.is_empty() {
+            return Ok(without_todos(history, &[next.id]));
+        }
+        Self::copy_shares(&mut *conn, &[(previous.id, next.id)]).await?;
+
+        Ok(history.to_vec())
+    }
+
+    /// Saves all `todos` with a single `UPDATE` joined against the new values.
+    /// Fails if any of them is no longer at the version it was loaded at.
+    async fn save_many<'e>(executor: impl PgExecutor<'e>, todos: &[Todo]) -> AppResult<()> {
+        let result = sqlx::query(
+            r#"
+            UPDATE todos AS t
+            SET title = v.title, description = v.description,
+                priority = v.priority::todo_priority, tags = string_to_array(v.tags, ','),
+                status = v.status, completed = v.completed, completed_at = v.completed_at,
+                due_at = v.due_at,
+                recurrence_rule = v.recurrence_rule, time_zone = v.time_zone,
+                series_id = v.series_id, updated_at = v.updated_at, checklist = v.checklist,
+                project_id = v.project_id, version = t.version + 1
+            FROM UNNEST($1::uuid[], $2::text[], $3::text[], $4::text[], $5::text[], $6::text[],
+                        $7::bool[], $8::timestamptz[], $9::timestamptz[], $10::text[],
+                        $11::text[], $12::uuid[], $13::timestamptz[], $14::int[], $15::uuid[],
+                        $16::jsonb[], $17::uuid[])
+                AS v(id, title, description, priority, tags, status, completed, completed_at,
+                     due_at, recurrence_rule, time_zone, series_id, updated_at, version,
+                     user_id, checklist, project_id)
+            WHERE t.id = v.id AND t.user_id = v.user_id AND t.deleted_at IS NULL
+                AND t.version = v.version
+            "#,
+        )
+        .bind(todos.iter().map(|t| t.id.0).collect::<Vec<_>>())
+        .bind(todos.iter().map(|t| t.title.value()).collect::<Vec<_>>())
+        .bind(
+            todos
+                .iter()
+                .map(|t| t.description.as_deref())
+                .collect::<Vec<_>>(),
+        )
+        .bind(
+            todos
+                .iter()
+                .map(|t| t.priority.as_str())
+                .collect::<Vec<_>>(),
+        )
+        // Arrays cannot be nested, so each todo's tags are joined by commas,
+        // which tags never contain
+        .bind(
+            todos
+                .iter()
+                .map(|t| {
+                    t.tags
+                        .iter()
+                        .map(TodoTag::value)
+                        .collect::<Vec<_>>()
+                        .join(",")
+                })
+                .collect::<Vec<_>>(),
+        )
+        .bind(todos.iter().map(|t| t.status.value()).collect::<Vec<_>>())
+        .bind(todos.iter().map(|t| t.completed).collect::<Vec<_>>())
+        .bind(todos.iter().map(|t| t.completed_at).collect::<Vec<_>>())
+        .bind(todos.iter().map(|t| t.due_at).collect::<Vec<_>>())
+        .bind(
+            todos
+                .iter()
+                .map(|t| t.recurrence_rule.as_ref().map(|r| r.value()))
+                .collect::<Vec<_>>(),
+        )
+        .bind(
+            todos
+                .iter()
+                .map(|t| t.time_zone.value())
+                .collect::<Vec<_>>(),
+        )
+        .bind(todos.iter().map(|t| t.series_id).collect::<Vec<_>>())
+        .bind(todos.iter().map(|t| t.updated_at).collect::<Vec<_>>())
+        .bind(todos.iter().map(|t| t.version).collect::<Vec<_>>())
+        .bind(todos.iter().map(|t| t.user_id).collect::<Vec<_>>())
+        .bind(todos.iter().map(|t| Json(&t.checklist)).collect::<Vec<_>>())
+        .bind(todos.iter().map(|t| t.project_id).collect::<Vec<_>>())
+        .execute(executor)
+        .await
+        .map_err(unknown_project)?;
+
+        if result.rows_affected() != todos.len() as u64 {
+            return Err(concurrent_modification());
+        }
+        Ok(())
+    }
+
+    async fn insert_history<'e>(
+        executor: impl PgExecutor<'e>,
+        entries: &[TodoHistoryEntry],
+    ) -> AppResult<()> {
+        if entries.is_empty() {
+            return Ok(());
+        }
+
+        let mut builder = QueryBuilder::<Postgres>::new(
+            "INSERT INTO todo_history (id, todo_id, user_id, actor_id, action, before, after, \
+             created_at) ",
+        );
+        builder.push_values(entries, |mut row, entry| {
+            row.push_bind(entry.id)
+                .push_bind(entry.todo_id)
+                .push_bind(entry.user_id)
+                .push_bind(entry.actor_id)
+                .push_bind(entry.action)
+                .push_bind(&entry.before)
+                .push_bind(&entry.after)
+                .push_bind(entry.created_at);
+        });
+        builder.build().execute(executor).await?;
+
+        Ok(())
+    }
+
+    /// Shares each next occurrence with the users its preceding occurrence
+    /// is shared with. `pairs` holds `(previous, next)` todo ids.
+    async fn copy_shares<'e>(
+        executor: impl PgExecutor<'e>,
+        pairs: &[(TodoId, TodoId)],
+    ) -> AppResult<()> {
+        if pairs.is_empty() {
+            return Ok(());
+        }
+
+        sqlx::query(
+            r#"
+            INSERT INTO todo_shares (todo_id, user_id, permission)
+            SELECT p.next_id, s.user_id, s.permission
+            FROM UNNEST($1::uuid[], $2::uuid[]) AS p(previous_id, next_id)
+            JOIN todo_shares s ON s.todo_id = p.previous_id
+            ON CONFLICT (todo_id, user_id) DO NOTHING
+            "#,
+        )
+        .bind(
+            pairs
+                .iter()
+                .map(|(previous, _)| previous.0)
+                .collect::<Vec<_>>(),
+        )
+        .bind(pairs.iter().map(|(_, next)| next.0).collect::<Vec<_>>())
+        .execute(executor)
         .await?;
 
+        Ok(())
+    }
+
+    /// Position for `todo` in its status column of its project's board,
+    /// right below `after_id` or at the top. Renumbers the column when the
+    /// neighbours are too close.
+    async fn column_position(
+        conn: &mut PgConnection,
+        todo: &Todo,
+        after_id: Option<TodoId>,
+    ) -> AppResult<f64> {
+        for _ in 0..2 {
+            let (above, below): (Option<f64>, Option<f64>) = sqlx::query_as(
+                r#"
+                WITH anchor AS (SELECT position FROM todos WHERE id = $3)
+                SELECT (SELECT position FROM anchor),
+                       (SELECT MIN(position) FROM todos
+                        WHERE status = $1 AND project_id IS NOT DISTINCT FROM $4
+                            AND deleted_at IS NULL AND id <> $2
+                            AND ($3::uuid IS NULL OR position > (SELECT position FROM anchor)))
+                "#,
+            )
+            .bind(&todo.status)
+            .bind(todo.id)
+            .bind(after_id)
+            .bind(todo.project_id)
+            .fetch_one(&mut *conn)
+            .await?;
+            if let Some(position) = position_between(above, below) {
+                return Ok(position);
+            }
+
+            sqlx::query(
+                r#"
+                UPDATE todos AS t
+                SET position = r.rank
+                FROM (SELECT id, row_number() OVER (ORDER BY position, id)::float8 AS rank
+                      FROM todos
+                      WHERE status = $1 AND project_id IS NOT DISTINCT FROM $3
+                          AND deleted_at IS NULL AND id <> $2) AS r
+                WHERE t.id = r.id
+                "#,
+            )
+            .bind(&todo.status)
+            .bind(todo.id)
+            .bind(todo.project_id)
+            .execute(&mut *conn)
+            .await?;
+        }
+        Err(AppError::Internal(anyhow::anyhow!(
+            "No room for the card after renumbering its column"
+        )))
+    }
+
+    async fn save<'e>(executor: impl PgExecutor<'e>, todo: &Todo) -> AppResult<Todo> {
+        let updated = sqlx::query_as::<_, Todo>(&format!(
+            r#"
+            UPDATE todos
+            SET title = $1, description = $2, completed = $3, due_at = $4, recurrence_rule = $5,
+                time_zone = $6, series_id = $7, updated_at = $8, deleted_at = $9,
+                assignee_id = $13, status = $14, completed_at = $15, position = $16,
+                priority = $17, tags = $18, checklist = $19, project_id = $20,
+                version = version + 1
+            WHERE id = $10 AND user_id = $11 AND version = $12
+            RETURNING {TODO_COLUMNS}
+            "#
+        ))
+        .bind(&todo.title)
+        .bind(&todo.description)
+        .bind(todo.completed)
+        .bind(todo.due_at)
+        .bind(&todo.recurrence_rule)
+        .bind(&todo.time_zone)
+        .bind(todo.series_id)
+        .bind(todo.updated_at)
+        .bind(todo.deleted_at)
+        .bind(todo.id)
+        .bind(todo.user_id)
+        .bind(todo.version)
+        .bind(todo.assignee_id)
+        .bind(&todo.status)
+        .bind(todo.completed_at)
+        .bind(todo.position)
+        .bind(todo.priority)
+        .bind(&todo.tags)
+        .bind(Json(&todo.checklist))
+        .bind(todo.project_id)
+        .fetch_optional(executor)
+        .await
+        .map_err(unknown_project)?
+        .ok_or_else(concurrent_modification)?;
+
+        Ok(updated)
+    }
+}
+
+/// The todo of `row` with the user's role on it, if they have one.
+fn role_of(row: SharedRow, user_id: Uuid) -> Option<(Todo, TodoRole)> {
+    let role = if row.todo.user_id == user_id {
+        Some(TodoRole::Owner)
+    } else {
+        row.permission.map(TodoRole::from)
+    };
+    role.map(|role| (row.todo, role))
+}
+
+/// `history` without the entries of `todos`, which were not written.
+fn without_todos(history: &[TodoHistoryEntry], todos: &[TodoId]) -> Vec<TodoHistoryEntry> {
+    history
+        .iter()
+        .filter(|entry| !todos.contains(&entry.todo_id))
+        .cloned()
+        .collect()
+}
+
+/// Maps a project outside the todo's workspace, or no longer there, to a
+/// validation error.
+fn unknown_project(error: sqlx::Error) -> AppError {
+    match error.as_database_error() {
+        Some(e) if e.constraint() == Some("todos_project_fkey") => {
+            AppError::Validation("Project not found".to_string())
+        }
+        _ => AppError::Database(error),
+    }
+}
+
+fn concurrent_modification() -> AppError {
+    AppError::Conflict("Todo was modified by another request".to_string())
+}
+
+/// Pushes `WHERE` conditions for the user's todos matching `filter`. When
+/// filtering by assignee, todos shared with the user count as theirs.
+fn push_filter(builder: &mut QueryBuilder<'_, Postgres>, user_id: Uuid, filter: &TodoFilter) {
+    match filter.assignee_id {
+        Some(assignee_id) => {
+            builder.push(" WHERE assignee_id = ");
+            builder.push_bind(assignee_id);
+            builder.push(" AND (user_id = ");
+            builder.push_bind(user_id);
+            builder.push(
+                " OR EXISTS (SELECT 1 FROM todo_access s \
+                 WHERE s.todo_id = todos.id AND s.user_id = ",
+            );
+            builder.push_bind(user_id);
+            builder.push("))");
+        }
+        None => {
+            builder.push(" WHERE user_id = ");
+            builder.push_bind(user_id);
+        }
+    }
+    builder.push(" AND deleted_at IS NULL");
+
+    if let Some(completed) = filter.completed {
+        builder.push(" AND completed = ");
+        builder.push_bind(completed);
+    }
+    if let Some(priority) = filter.priority {
+        builder.push(" AND priority = ");
+        builder.push_bind(priority);
+    }
+    if !filter.tags.is_empty() {
+        builder.push(" AND tags @> ");
+        builder.push_bind(
+            filter
+                .tags
+                .iter()
+                .map(|tag| tag.value().to_string())
+                .collect::<Vec<_>>(),
+        );
+    }
+    if let Some(status) = &filter.status {
+        builder.push(" AND status = ");
+        builder.push_bind(status.value().to_string());
+    }
+    if let Some(workspace_id) = filter.workspace_id {
+        builder.push(" AND workspace_id = ");
+        builder.push_bind(workspace_id);
+    }
+    if let Some(project_id) = filter.project_id {
+        builder.push(" AND project_id = ");
+        builder.push_bind(project_id);
+    }
+    if let Some(query) = &filter.text {
+        builder.push(" AND ");
+        push_text_match(builder, query);
+    }
+    if let Some(query) = &filter.query {
+        builder.push(" AND ");
+        push_query(builder, query);
+    }
+
+    let bounds = [
+        ("created_at >= ", filter.created_after),
+        ("created_at < ", filter.created_before),
+        ("updated_at >= ", filter.updated_after),
+        ("updated_at < ", filter.updated_before),
+        ("due_at >= ", filter.due_after),
+        ("due_at < ", filter.due_before),
+    ];
+    for (condition, value) in bounds {
+        if let Some(value) = value {
+            builder.push(" AND ");
+            builder.push(condition);
+            builder.push_bind(value);
+        }
+    }
+}
+
+fn sort_column(field: TodoSortField) -> &'static str {
+    match field {
+        // Smart ranks are listed by `find_smart`; cursors never use them
+        TodoSortField::CreatedAt | TodoSortField::Smart => "created_at",
+        TodoSortField::UpdatedAt => "updated_at",
+        TodoSortField::Title => "title",
+        TodoSortField::DueAt => "due_at",
+    }
+}
+
+/// Pushes the keyset condition for rows past `cursor` in its scan direction.
+/// Only `due_at` can be NULL; those rows sort last in either order.
+fn push_cursor(builder: &mut QueryBuilder<'_, Postgres>, cursor: &TodoCursor) {
+    let column = sort_column(cursor.sort.field);
+    let ascending = (cursor.sort.order == SortOrder::Asc) != cursor.backward;
+    let op = if ascending { ">" } else { "<" };
+
+    let keyset = format!(" AND (({column}, id) {op} (");
+    match &cursor.key {
+        CursorKey::Null if cursor.backward => {
+            builder.push(format!(" AND ({column} IS NOT NULL OR id {op} "));
+            builder.push_bind(cursor.id);
+            builder.push(")");
+            return;
+        }
+        CursorKey::Null => {
+            builder.push(format!(" AND {column} IS NULL AND id {op} "));
+            builder.push_bind(cursor.id);
+            return;
+        }
+        CursorKey::Timestamp(value) => builder.push(keyset).push_bind(*value),
+        CursorKey::Text(value) => builder.push(keyset).push_bind(value.clone()),
+    };
+    builder.push(", ");
+    builder.push_bind(cursor.id);
+    builder.push(")");
+    if !cursor.backward && cursor.sort.field == TodoSortField::DueAt {
+        builder.push(format!(" OR {column} IS NULL"));
+    }
+    builder.push(")");
+}
+
+/// `smart_score` of a row at `smart_now`, built from the same weights.
+/// Whole seconds are counted, as in the domain.
+fn smart_score_sql() -> String {
+    let days = |from: &str, to: &str| {
+        format!("(trunc(EXTRACT(EPOCH FROM {to} - {from}))::float8 / 86400)")
+    };
+    let priority: String = [
+        TodoPriority::Low,
+        TodoPriority::Medium,
+        TodoPriority::High,
+        TodoPriority::Urgent,
+    ]
+    .iter()
+    .map(|p| format!(" WHEN '{}' THEN {:?}", p.as_str(), p.weight()))
+    .collect();
+    let overdue = days("due_at", "smart_now");
+    let due_in = days("smart_now", "due_at");
+    let age = days("created_at", "smart_now");
+    format!(
+        "((CASE priority{priority} ELSE 0.0 END) \
+         + (CASE WHEN due_at IS NULL THEN 0.0 \
+                 WHEN due_at <= smart_now \
+                     THEN {DUE_NOW_POINTS:?} + LEAST({overdue}, {MAX_OVERDUE_POINTS:?}) \
+                 ELSE {DUE_NOW_POINTS:?} * GREATEST(1.0 - {due_in} / {DUE_WINDOW_DAYS:?}, 0.0) END) \
+         + LEAST(GREATEST({age}, 0.0) * {AGE_POINTS_PER_DAY:?}, {MAX_AGE_POINTS:?}))"
+    )
+}
+
+/// Pushes `ORDER BY` for `sort`, reversed when scanning backward from a cursor.
+fn push_order_by(builder: &mut QueryBuilder<'_, Postgres>, sort: TodoSort, backward: bool) {
+    let column = sort_column(sort.field);
+    let ascending = (sort.order == SortOrder::Asc) != backward;
+    let direction = if ascending { "ASC" } else { "DESC" };
+    let nulls = if backward { "FIRST" } else { "LAST" };
+    builder.push(format!(
+        " ORDER BY {column} {direction} NULLS {nulls}, id {direction}"
+    ));
+}
+
+/// Pushes the tsquery all of a full-text query's terms combine into; every
+/// user-supplied term is bound.
+fn push_tsquery(builder: &mut QueryBuilder<'_, Postgres>, query: &SearchQuery) {
+    let config = query.language.config();
+    for (i, term) in query.terms.iter().enumerate() {
+        if i > 0 {
+            builder.push(" && ");
+        }
+        match term {
+            SearchTerm::Word(word) => {
+                builder.push(format!("plainto_tsquery('{config}', "));
+                builder.push_bind(word.clone());
+            }
+            SearchTerm::Phrase(phrase) => {
+                builder.push(format!("phraseto_tsquery('{config}', "));
+                builder.push_bind(phrase.clone());
+            }
+            SearchTerm::Prefix(prefix) => {
+                // Quote the lexeme so tsquery operators in it are literal
+                let lexeme = prefix.replace('\\', "\\\\").replace('\'', "''");
+                builder.push(format!("to_tsquery('{config}', "));
+                builder.push_bind(format!("'{lexeme}':*"));
+            }
+        }
+        builder.push(")");
+    }
+}
+
+/// Pushes a condition matching todos that contain every term of `query`:
+/// full-text for most languages, substrings of the title or description
+/// for Japanese.
+fn push_text_match(builder: &mut QueryBuilder<'_, Postgres>, query: &SearchQuery) {
+    match query.language {
+        SearchLanguage::Japanese => {
+            builder.push("(");
+            for (i, term) in query.terms.iter().enumerate() {
+                if i > 0 {
+                    builder.push(" AND ");
+                }
+                let pattern = like_pattern(term.text());
+                builder.push("(title ILIKE ");
+                builder.push_bind(pattern.clone());
+                builder.push(" OR description ILIKE ");
+                builder.push_bind(pattern);
+                builder.push(")");
+            }
+            builder.push(")");
+        }
+        language => {
+            builder.push(format!("{} @@ (", search_vector_column(language)));
+            push_tsquery(builder, query);
+            builder.push(")");
+        }
+    }
+}
+
+/// Pushes `query` as one condition. Values from the query are always bound;
+/// only column names and operators are written into the SQL.
+fn push_query(builder: &mut QueryBuilder<'_, Postgres>, query: &TodoQuery) {
+    match query {
+        TodoQuery::And(terms) | TodoQuery::Or(terms) => {
+            let separator = match query {
+                TodoQuery::And(_) => " AND ",
+                _ => " OR ",
+            };
+            builder.push("(");
+            for (i, term) in terms.iter().enumerate() {
+                if i > 0 {
+                    builder.push(separator);
+                }
+                push_query(builder, term);
+            }
+            builder.push(")");
+        }
+        TodoQuery::Not(term) => {
+            // Comparisons with NULL are unknown; negated, they should match
+            builder.push("NOT COALESCE(");
+            push_query(builder, term);
+            builder.push(", FALSE)");
+        }
+        TodoQuery::Completed(completed) => {
+            builder.push("completed = ");
+            builder.push_bind(*completed);
+        }
+        TodoQuery::Status(status) => {
+            builder.push("status = ");
+            builder.push_bind(status.value().to_string());
+        }
+        TodoQuery::Priority(comparison, priority) => {
+            builder.push(format!("priority {} ", comparison.sql()));
+            builder.push_bind(*priority);
+        }
+        TodoQuery::Tag(tag) => {
+            builder.push("tags @> ");
+            builder.push_bind(vec![tag.value().to_string()]);
+        }
+        TodoQuery::Assignee(Some(assignee_id)) => {
+            builder.push("assignee_id = ");
+            builder.push_bind(*assignee_id);
+        }
+        TodoQuery::Assignee(None) => {
+            builder.push("assignee_id IS NULL");
+        }
+        TodoQuery::Date(field, comparison, at) => {
+            let column = match field {
+                DateField::Due => "due_at",
+                DateField::Created => "created_at",
+                DateField::Updated => "updated_at",
+            };
+            builder.push(format!("{column} {} ", comparison.sql()));
+            builder.push_bind(*at);
+        }
+        TodoQuery::NoDueDate => {
+            builder.push("due_at IS NULL");
+        }
+        TodoQuery::Text(query) => push_text_match(builder, query),
+    }
+}
+
+/// Pushes the `FROM ... WHERE ...` part of a search. Full-text searches
+/// expose the combined tsquery as `q.query`.
+fn push_search_from(builder: &mut QueryBuilder<'_, Postgres>, user_id: Uuid, query: &SearchQuery) {
+    builder.push(" FROM todos");
+    if query.language != SearchLanguage::Japanese {
+        builder.push(", (SELECT ");
+        push_tsquery(builder, query);
+        builder.push(" AS query) q");
+    }
+
+    builder.push(" WHERE user_id = ");
+    builder.push_bind(user_id);
+    builder.push(" AND deleted_at IS NULL");
+
+    match query.language {
+        SearchLanguage::Japanese => {
+            builder.push(" AND ");
+            push_text_match(builder, query);
+        }
+        language => {
+            builder.push(format!(
+                " AND {} @@ q.query",
+                search_vector_column(language)
+            ));
+        }
+    }
+}
+
+/// SQL for `column` with the characters that are special in HTML escaped,
+/// matching `escape_html`.
+fn html_escaped(column: &str) -> String {
+    format!(
+        "replace(replace(replace(replace(replace({column}, \
+         '&', '&amp;'), '<', '&lt;'), '>', '&gt;'), '\"', '&quot;'), '''', '&#39;')"
+    )
+}
+
+/// `%text%` with LIKE wildcards in `text` escaped.
+fn like_pattern(text: &str) -> String {
+    let escaped = text
+        .replace('\\', "\\\\")
+        .replace('%', "\\%")
+        .replace('_', "\\_");
+    format!("%{escaped}%")
+}
+
+fn search_vector_column(language: SearchLanguage) -> &'static str {
+    match language {
+        SearchLanguage::English => "search_vector",
+        SearchLanguage::Simple | SearchLanguage::Japanese => "search_vector_simple",
+    }
+}
+
+#[async_trait]
+impl TodoRepository for PostgresTodoRepository {
+    async fn create(&self, todo: &Todo, history: &[TodoHistoryEntry]) -> AppResult<Todo> {
+        let mut tx = self.pool.begin().await?;
+        let created = Self::insert(&mut *tx, todo).await?;
+        Self::insert_history(&mut *tx, history).await?;
+        tx.commit().await?;
+
         Ok(created)
     }
 
     async fn find_by_id(&self, id: TodoId, user_id: Uuid) -> AppResult<Option<Todo>> {
-        let todo = sqlx::query_as::<_, Todo>(
+        let todo = sqlx::query_as::<_, Todo>(&format!(
             r#"
-            SELECT id, user_id, title, description, completed, created_at, updated_at
+            SELECT {TODO_COLUMNS}
             FROM todos
-            WHERE id = $1 AND user_id = $2
-            "#,
-        )
+            WHERE id = $1 AND user_id = $2 AND deleted_at IS NULL
+            "#
+        ))
         .bind(id)
         .bind(user_id)
         .fetch_optional(&self.pool)
@@ -55,36 +790,261 @@ impl TodoRepository for PostgresTodoRepository {
         Ok(todo)
     }
 
+    async fn find_accessible(
+        &self,
+        id: TodoId,
+        user_id: Uuid,
+    ) -> AppResult<Option<(Todo, TodoRole)>> {
+        let row = sqlx::query_as::<_, SharedRow>(&format!(
+            r#"
+            SELECT {TODO_COLUMNS},
+                (SELECT s.permission FROM todo_access s
+                 WHERE s.todo_id = todos.id AND s.user_id = $2) AS permission
+            FROM todos
+            WHERE id = $1 AND deleted_at IS NULL
+            "#
+        ))
+        .bind(id)
+        .bind(user_id)
+        .fetch_optional(&self.pool)
+        .await?;
+
+        Ok(row.and_then(|row| role_of(row, user_id)))
+    }
+
+    async fn find_accessible_by_ids(
+        &self,
+        ids: &[TodoId],
+        user_id: Uuid,
+    ) -> AppResult<Vec<(Todo, TodoRole)>> {
+        let rows = sqlx::query_as::<_, SharedRow>(&format!(
+            r#"
+            SELECT {TODO_COLUMNS},
+                (SELECT s.permission FROM todo_access s
+                 WHERE s.todo_id = todos.id AND s.user_id = $1) AS permission
+            FROM todos
+            WHERE id = ANY($2) AND {VISIBLE_TO_USER}
+            "#
+        ))
+        .bind(user_id)
+        .bind(ids.iter().map(|id| id.0).collect::<Vec<_>>())
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(rows
+            .into_iter()
+            .filter_map(|row| role_of(row, user_id))
+            .collect())
+    }
+
     async fn find_all_by_user(
         &self,
         user_id: Uuid,
+        filter: &TodoFilter,
+        sort: TodoSort,
+        cursor: Option<&TodoCursor>,
+        limit: i64,
+        offset: i64,
+    ) -> AppResult<Vec<Todo>> {
+        let mut builder =
+            QueryBuilder::<Postgres>::new(format!("SELECT {TODO_COLUMNS} FROM todos"));
+        push_filter(&mut builder, user_id, filter);
+        if let Some(cursor) = cursor {
+            push_cursor(&mut builder, cursor);
+        }
+        let backward = cursor.is_some_and(|c| c.backward);
+        push_order_by(&mut builder, sort, backward);
+        builder.push(" LIMIT ");
+        builder.push_bind(limit);
+        builder.push(" OFFSET ");
+        builder.push_bind(offset);
+
+        let mut todos = builder
+            .build_query_as::<Todo>()
+            .fetch_all(&self.pool)
+            .await?;
+        if backward {
+            todos.reverse();
+        }
+
+        Ok(todos)
+    }
+
+    async fn find_smart(
+        &self,
+        user_id: Uuid,
+        filter: &TodoFilter,
+        now: DateTime<Utc>,
+        order: SortOrder,
         limit: i64,
         offset: i64,
     ) -> AppResult<Vec<Todo>> {
-        let todos = sqlx::query_as::<_, Todo>(
+        let mut builder = QueryBuilder::<Postgres>::new(format!(
+            "SELECT {TODO_COLUMNS} FROM todos CROSS JOIN (SELECT "
+        ));
+        builder.push_bind(now);
+        builder.push("::timestamptz AS smart_now) AS clock");
+        push_filter(&mut builder, user_id, filter);
+        // Ties go to the todo due soonest, then to the oldest
+        let score = smart_score_sql();
+        builder.push(match order {
+            SortOrder::Desc => {
+                format!(" ORDER BY {score} DESC, due_at ASC NULLS LAST, created_at ASC, id ASC")
+            }
+            SortOrder::Asc => {
+                format!(" ORDER BY {score} ASC, due_at DESC NULLS FIRST, created_at DESC, id DESC")
+            }
+        });
+        builder.push(" LIMIT ");
+        builder.push_bind(limit);
+        builder.push(" OFFSET ");
+        builder.push_bind(offset);
+
+        let todos = builder
+            .build_query_as::<Todo>()
+            .fetch_all(&self.pool)
+            .await?;
+
+        Ok(todos)
+    }
+
+    async fn count_by_user(&self, user_id: Uuid, filter: &TodoFilter) -> AppResult<i64> {
+        let mut builder = QueryBuilder::<Postgres>::new("SELECT COUNT(*) as count FROM todos");
+        push_filter(&mut builder, user_id, filter);
+
+        let count: (i64,) = builder.build_query_as().fetch_one(&self.pool).await?;
+
+        Ok(count.0)
+    }
+
+    async fn search(
+        &self,
+        user_id: Uuid,
+        query: &SearchQuery,
+        limit: i64,
+        offset: i64,
+    ) -> AppResult<Vec<TodoSearchHit>> {
+        let mut builder = QueryBuilder::<Postgres>::new(format!("SELECT {TODO_COLUMNS}, "));
+        match query.language {
+            SearchLanguage::Japanese => {
+                // Every term matches the title or the description; the more
+                // of them in the title, the higher the rank. Snippets are
+                // highlighted below
+                builder.push("(0.5 + 0.5 * (");
+                for (i, term) in query.terms.iter().enumerate() {
+                    if i > 0 {
+                        builder.push(" + ");
+                    }
+                    builder.push("(CASE WHEN title ILIKE ");
+                    builder.push_bind(like_pattern(term.text()));
+                    builder.push(" THEN 1 ELSE 0 END)");
+                }
+                builder.push(format!(
+                    ") / {}.0)::REAL AS rank, \
+                     title AS title_snippet, description AS description_snippet",
+                    query.terms.len()
+                ));
+            }
+            language => {
+                // Text is escaped before the markers are added, so snippets
+                // are safe to render as HTML. Postgres reads the entities as
+                // single tokens that are never highlighted.
+                let config = language.config();
+                let vector = search_vector_column(language);
+                let title = html_escaped("title");
+                let description = html_escaped("description");
+                builder.push(format!(
+                    "ts_rank_cd({vector}, q.query) AS rank, \
+                     ts_headline('{config}', {title}, q.query, \
+                        'StartSel={HIGHLIGHT_START}, StopSel={HIGHLIGHT_END}, HighlightAll=true') \
+                        AS title_snippet, \
+                     CASE WHEN to_tsvector('{config}', COALESCE(description, '')) @@ q.query \
+                        THEN ts_headline('{config}', {description}, q.query, \
+                            'StartSel={HIGHLIGHT_START}, StopSel={HIGHLIGHT_END}, MaxWords=35, MinWords=15') \
+                     END AS description_snippet"
+                ));
+            }
+        }
+        push_search_from(&mut builder, user_id, query);
+        builder.push(" ORDER BY rank DESC, created_at DESC LIMIT ");
+        builder.push_bind(limit);
+        builder.push(" OFFSET ");
+        builder.push_bind(offset);
+
+        let rows = builder
+            .build_query_as::<SearchRow>()
+            .fetch_all(&self.pool)
+            .await?;
+
+        let hits = rows
+            .into_iter()
+            .map(|row| match query.language {
+                SearchLanguage::Japanese => TodoSearchHit {
+                    title_snippet: highlight(&row.title_snippet, &query.terms)
+                        .unwrap_or_else(|| escape_html(&row.title_snippet)),
+                    description_snippet: row
+                        .description_snippet
+                        .and_then(|d| highlight(&d, &query.terms)),
+                    rank: row.rank,
+                    todo: row.todo,
+                },
+                _ => TodoSearchHit {
+                    todo: row.todo,
+                    rank: row.rank,
+                    title_snippet: row.title_snippet,
+                    description_snippet: row.description_snippet,
+                },
+            })
+            .collect();
+
+        Ok(hits)
+    }
+
+    async fn count_search(&self, user_id: Uuid, query: &SearchQuery) -> AppResult<i64> {
+        let mut builder = QueryBuilder::<Postgres>::new("SELECT COUNT(*)");
+        push_search_from(&mut builder, user_id, query);
+
+        let count: (i64,) = builder.build_query_as().fetch_one(&self.pool).await?;
+
+        Ok(count.0)
+    }
+
+    async fn find_shared_with_user(
+        &self,
+        user_id: Uuid,
+        limit: i64,
+        offset: i64,
+    ) -> AppResult<Vec<(Todo, SharePermission)>> {
+        let rows = sqlx::query_as::<_, SharedRow>(&format!(
             r#"
-            SELECT id, user_id, title, description, completed, created_at, updated_at
+            SELECT {TODO_COLUMNS}, s.permission
             FROM todos
-            WHERE user_id = $1
-            ORDER BY created_at DESC
+            JOIN (SELECT todo_id, permission, shared_at
+                  FROM todo_access WHERE user_id = $1) s ON s.todo_id = todos.id
+            WHERE deleted_at IS NULL AND todos.user_id <> $1
+            ORDER BY s.shared_at DESC, todos.id DESC
             LIMIT $2 OFFSET $3
-            "#,
-        )
+            "#
+        ))
         .bind(user_id)
         .bind(limit)
         .bind(offset)
         .fetch_all(&self.pool)
         .await?;
 
-        Ok(todos)
+        Ok(rows
+            .into_iter()
+            .filter_map(|row| row.permission.map(|permission| (row.todo, permission)))
+            .collect())
     }
 
-    async fn count_by_user(&self, user_id: Uuid) -> AppResult<i64> {
+    async fn count_shared_with_user(&self, user_id: Uuid) -> AppResult<i64> {
         let count: (i64,) = sqlx::query_as(
             r#"
             SELECT COUNT(*) as count
-            FROM todos
-            WHERE user_id = $1
+            FROM todo_access s
+            JOIN todos t ON t.id = s.todo_id
+            WHERE s.user_id = $1 AND t.deleted_at IS NULL AND t.user_id <> $1
             "#,
         )
         .bind(user_id)
@@ -94,25 +1054,238 @@ impl TodoRepository for PostgresTodoRepository {
         Ok(count.0)
     }
 
-    async fn update(&self, todo: &Todo) -> AppResult<Todo> {
-        let updated = sqlx::query_as::<_, Todo>(
+    async fn update(&self, todo: &Todo, history: &[TodoHistoryEntry]) -> AppResult<Todo> {
+        let mut tx = self.pool.begin().await?;
+        let updated = Self::save(&mut *tx, todo).await?;
+        Self::insert_history(&mut *tx, history).await?;
+        tx.commit().await?;
+
+        Ok(updated)
+    }
+
+    async fn complete_occurrence(
+        &self,
+        completed: &Todo,
+        next: &Todo,
+        history: &[TodoHistoryEntry],
+    ) -> AppResult<Todo> {
+        let mut tx = self.pool.begin().await?;
+        let updated = Self::save(&mut *tx, completed).await?;
+        let history = Self::spawn_occurrence(&mut tx, completed, next, history).await?;
+        Self::insert_history(&mut *tx, &history).await?;
+        tx.commit().await?;
+
+        Ok(updated)
+    }
+
+    async fn move_to(
+        &self,
+        todo: &Todo,
+        after_id: Option<TodoId>,
+        next: Option<&Todo>,
+        history: &[TodoHistoryEntry],
+    ) -> AppResult<Todo> {
+        let mut tx = self.pool.begin().await?;
+
+        // Moves within a workspace take turns so positions stay ordered
+        sqlx::query(
+            "SELECT pg_advisory_xact_lock(hashtext('todo_board'), \
+             hashtext(current_setting('app.workspace_id', true)))",
+        )
+        .execute(&mut *tx)
+        .await?;
+
+        let mut moved = todo.clone();
+        moved.position = Self::column_position(&mut tx, todo, after_id).await?;
+        let updated = Self::save(&mut *tx, &moved).await?;
+        let history = match next {
+            Some(next) => Self::spawn_occurrence(&mut tx, todo, next, history).await?,
+            None => history.to_vec(),
+        };
+        Self::insert_history(&mut *tx, &history).await?;
+        tx.commit().await?;
+
+        Ok(updated)
+    }
+
+    async fn count_by_status(
+        &self,
+        user_id: Uuid,
+        project_id: Uuid,
+    ) -> AppResult<Vec<(TodoStatus, i64)>> {
+        let counts = sqlx::query_as(&format!(
+            "SELECT status, COUNT(*) FROM todos \
+             WHERE {VISIBLE_TO_USER} AND project_id = $2 GROUP BY status"
+        ))
+        .bind(user_id)
+        .bind(project_id)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(counts)
+    }
+
+    async fn find_board(
+        &self,
+        user_id: Uuid,
+        project_id: Uuid,
+        limit: i64,
+    ) -> AppResult<Vec<Todo>> {
+        let todos = sqlx::query_as::<_, Todo>(&format!(
             r#"
-            UPDATE todos
-            SET title = $1, description = $2, completed = $3, updated_at = $4
-            WHERE id = $5 AND user_id = $6
-            RETURNING id, user_id, title, description, completed, created_at, updated_at
+            SELECT *
+            FROM (SELECT {TODO_COLUMNS},
+                         row_number() OVER (PARTITION BY status ORDER BY position, id) AS rank
+                  FROM todos
+                  WHERE {VISIBLE_TO_USER} AND project_id = $2) AS cards
+            WHERE rank <= $3
+            ORDER BY status, position, id
+            "#
+        ))
+        .bind(user_id)
+        .bind(project_id)
+        .bind(limit)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(todos)
+    }
+
+    async fn find_board_column(
+        &self,
+        user_id: Uuid,
+        project_id: Uuid,
+        status: &TodoStatus,
+        limit: i64,
+        offset: i64,
+    ) -> AppResult<Vec<Todo>> {
+        let todos = sqlx::query_as::<_, Todo>(&format!(
+            r#"
+            SELECT {TODO_COLUMNS}
+            FROM todos
+            WHERE {VISIBLE_TO_USER} AND project_id = $5 AND status = $2
+            ORDER BY position, id
+            LIMIT $3 OFFSET $4
+            "#
+        ))
+        .bind(user_id)
+        .bind(status)
+        .bind(limit)
+        .bind(offset)
+        .bind(project_id)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(todos)
+    }
+
+    async fn apply_changes(&self, user_id: Uuid, changes: &TodoChangeSet) -> AppResult<()> {
+        let mut tx = self.pool.begin().await?;
+
+        let inserted = if changes.created.is_empty() {
+            Vec::new()
+        } else {
+            Self::insert_many(&mut *tx, &changes.created).await?
+        };
+        let skipped: Vec<_> = changes
+            .created
+            .iter()
+            .map(|todo| todo.id)
+            .filter(|id| !inserted.contains(id))
+            .collect();
+        if !changes.updated.is_empty() {
+            Self::save_many(&mut *tx, &changes.updated).await?;
+        }
+        // Next occurrences spawned by completing a recurring todo
+        let occurrences: Vec<_> = changes
+            .created
+            .iter()
+            .filter(|next| inserted.contains(&next.id))
+            .filter_map(|next| {
+                changes
+                    .updated
+                    .iter()
+                    .find(|previous| {
+                        previous.series_id.is_some()
+                            && previous.series_id == next.series_id
+                            && previous.occurrence_index + 1 == next.occurrence_index
+                    })
+                    .map(|previous| (previous.id, next.id))
+            })
+            .collect();
+        Self::copy_shares(&mut *tx, &occurrences).await?;
+        if !changes.trashed.is_empty() {
+            sqlx::query(
+                r#"
+                UPDATE todos
+                SET deleted_at = NOW(), updated_at = NOW(), version = version + 1
+                WHERE id = ANY($1) AND user_id = $2 AND deleted_at IS NULL
+                "#,
+            )
+            .bind(changes.trashed.iter().map(|id| id.0).collect::<Vec<_>>())
+            .bind(user_id)
+            .execute(&mut *tx)
+            .await?;
+        }
+        Self::insert_history(&mut *tx, &without_todos(&changes.history, &skipped)).await?;
+
+        tx.commit().await?;
+        Ok(())
+    }
+
+    async fn find_trashed_by_id(&self, id: TodoId, user_id: Uuid) -> AppResult<Option<Todo>> {
+        let todo = sqlx::query_as::<_, Todo>(&format!(
+            r#"
+            SELECT {TODO_COLUMNS}
+            FROM todos
+            WHERE id = $1 AND user_id = $2 AND deleted_at IS NOT NULL
+            "#
+        ))
+        .bind(id)
+        .bind(user_id)
+        .fetch_optional(&self.pool)
+        .await?;
+
+        Ok(todo)
+    }
+
+    async fn find_trashed_by_user(
+        &self,
+        user_id: Uuid,
+        limit: i64,
+        offset: i64,
+    ) -> AppResult<Vec<Todo>> {
+        let todos = sqlx::query_as::<_, Todo>(&format!(
+            r#"
+            SELECT {TODO_COLUMNS}
+            FROM todos
+            WHERE user_id = $1 AND deleted_at IS NOT NULL
+            ORDER BY deleted_at DESC, id DESC
+            LIMIT $2 OFFSET $3
+            "#
+        ))
+        .bind(user_id)
+        .bind(limit)
+        .bind(offset)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(todos)
+    }
+
+    async fn count_trashed_by_user(&self, user_id: Uuid) -> AppResult<i64> {
+        let count: (i64,) = sqlx::query_as(
+            r#"
+            SELECT COUNT(*) as count
+            FROM todos
+            WHERE user_id = $1 AND deleted_at IS NOT NULL
             "#,
         )
-        .bind(&todo.title)
-        .bind(&todo.description)
-        .bind(todo.completed)
-        .bind(todo.updated_at)
-        .bind(todo.id)
-        .bind(todo.user_id)
+        .bind(user_id)
         .fetch_one(&self.pool)
         .await?;
 
-        Ok(updated)
+        Ok(count.0)
     }
 
     async fn delete(&self, id: TodoId, user_id: Uuid) -> AppResult<()> {
@@ -129,4 +1302,99 @@ impl TodoRepository for PostgresTodoRepository {
 
         Ok(())
     }
+
+    async fn find_history_by_todo(
+        &self,
+        id: TodoId,
+        user_id: Uuid,
+        limit: i64,
+        offset: i64,
+    ) -> AppResult<Vec<TodoHistoryEntry>> {
+        let entries = sqlx::query_as::<_, TodoHistoryEntry>(&format!(
+            r#"
+            SELECT {HISTORY_COLUMNS}
+            FROM todo_history
+            WHERE todo_id = $1 AND user_id = $2
+            ORDER BY created_at DESC, id DESC
+            LIMIT $3 OFFSET $4
+            "#
+        ))
+        .bind(id)
+        .bind(user_id)
+        .bind(limit)
+        .bind(offset)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(entries)
+    }
+
+    async fn count_history_by_todo(&self, id: TodoId, user_id: Uuid) -> AppResult<i64> {
+        let count: (i64,) = sqlx::query_as(
+            r#"
+            SELECT COUNT(*)
+            FROM todo_history
+            WHERE todo_id = $1 AND user_id = $2
+            "#,
+        )
+        .bind(id)
+        .bind(user_id)
+        .fetch_one(&self.pool)
+        .await?;
+
+        Ok(count.0)
+    }
+
+    async fn find_history_by_user(
+        &self,
+        user_id: Uuid,
+        limit: i64,
+        offset: i64,
+    ) -> AppResult<Vec<TodoHistoryEntry>> {
+        let entries = sqlx::query_as::<_, TodoHistoryEntry>(&format!(
+            r#"
+            SELECT {HISTORY_COLUMNS}
+            FROM todo_history
+            WHERE user_id = $1
+            ORDER BY created_at DESC, id DESC
+            LIMIT $2 OFFSET $3
+            "#
+        ))
+        .bind(user_id)
+        .bind(limit)
+        .bind(offset)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(entries)
+    }
+
+    async fn count_history_by_user(&self, user_id: Uuid) -> AppResult<i64> {
+        let count: (i64,) = sqlx::query_as(
+            r#"
+            SELECT COUNT(*)
+            FROM todo_history
+            WHERE user_id = $1
+            "#,
+        )
+        .bind(user_id)
+        .fetch_one(&self.pool)
+        .await?;
+
+        Ok(count.0)
+    }
+
+    async fn purge_trashed_before(&self, cutoff: DateTime<Utc>) -> AppResult<u64> {
+        let result = sqlx::query(
+            r#"
+            DELETE FROM todos
+            WHERE deleted_at < $1
+            "#,
+        )
+        .bind(cutoff)
+        .execute(&self.pool)
+        .await?;
+
+        Ok(result.rows_affected())
+    }
 }
@@ -0,0 +1,80 @@
+use async_trait::async_trait;
+use sqlx::PgPool;
+use uuid::Uuid;
+
+use crate::domain::entities::{SharePermission, TodoId, TodoShare};
+use crate::domain::repositories::TodoShareRepository;
+use crate::shared::error::AppResult;
+
+pub struct PostgresTodoShareRepository {
+    pool: PgPool,
+}
+
+impl PostgresTodoShareRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl TodoShareRepository for PostgresTodoShareRepository {
+    async fn upsert(
+        &self,
+        todo_id: TodoId,
+        user_id: Uuid,
+        permission: SharePermission,
+    ) -> AppResult<TodoShare> {
+        let share = sqlx::query_as::<_, TodoShare>(
+            r#"
+            WITH share AS (
+                INSERT INTO todo_shares (todo_id, user_id, permission)
+                VALUES ($1, $2, $3)
+                ON CONFLICT (todo_id, user_id) DO UPDATE SET permission = EXCLUDED.permission
+                RETURNING todo_id, user_id, permission, created_at
+            )
+            SELECT share.todo_id, share.user_id, users.email, share.permission, share.created_at
+            FROM share
+            JOIN users ON users.id = share.user_id
+            "#,
+        )
+        .bind(todo_id)
+        .bind(user_id)
+        .bind(permission)
+        .fetch_one(&self.pool)
+        .await?;
+
+        Ok(share)
+    }
+
+    async fn find_by_todo(&self, todo_id: TodoId) -> AppResult<Vec<TodoShare>> {
+        let shares = sqlx::query_as::<_, TodoShare>(
+            r#"
+            SELECT s.todo_id, s.user_id, u.email, s.permission, s.created_at
+            FROM todo_shares s
+            JOIN users u ON u.id = s.user_id
+            WHERE s.todo_id = $1
+            ORDER BY s.created_at, s.user_id
+            "#,
+        )
+        .bind(todo_id)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(shares)
+    }
+
+    async fn delete(&self, todo_id: TodoId, user_id: Uuid) -> AppResult<bool> {
+        let result = sqlx::query(
+            r#"
+            DELETE FROM todo_shares
+            WHERE todo_id = $1 AND user_id = $2
+            "#,
+        )
+        .bind(todo_id)
+        .bind(user_id)
+        .execute(&self.pool)
+        .await?;
+
+        Ok(result.rows_affected() > 0)
+    }
+}
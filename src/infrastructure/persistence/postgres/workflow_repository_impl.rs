@@ -0,0 +1,161 @@
+use anyhow::anyhow;
+use async_trait::async_trait;
+use sqlx::PgPool;
+use uuid::Uuid;
+
+use crate::domain::entities::{TodoStatus, Workflow, WorkflowStatus};
+use crate::domain::repositories::WorkflowRepository;
+use crate::shared::error::{AppError, AppResult};
+
+/// The workspace the connection is limited to; see `Tenant`.
+const CURRENT_WORKSPACE: &str = "NULLIF(current_setting('app.workspace_id', true), '')::uuid";
+
+#[derive(sqlx::FromRow)]
+struct StatusRow {
+    key: String,
+    name: String,
+    terminal: bool,
+    cancelled: bool,
+    transitions: Vec<String>,
+}
+
+impl TryFrom<StatusRow> for WorkflowStatus {
+    type Error = String;
+
+    fn try_from(row: StatusRow) -> Result<Self, String> {
+        Ok(Self {
+            key: TodoStatus::new(row.key)?,
+            name: row.name,
+            terminal: row.terminal,
+            cancelled: row.cancelled,
+            transitions: row
+                .transitions
+                .into_iter()
+                .map(TodoStatus::new)
+                .collect::<Result<_, _>>()?,
+        })
+    }
+}
+
+pub struct PostgresWorkflowRepository {
+    pool: PgPool,
+}
+
+impl PostgresWorkflowRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl WorkflowRepository for PostgresWorkflowRepository {
+    async fn find(&self, project_id: Option<Uuid>) -> AppResult<Workflow> {
+        // The project's statuses if it has any, else the workspace's
+        let rows = sqlx::query_as::<_, StatusRow>(&format!(
+            r#"
+            SELECT key, name, terminal, cancelled, transitions
+            FROM workflow_statuses
+            WHERE workspace_id = {CURRENT_WORKSPACE}
+                AND project_id IS NOT DISTINCT FROM (
+                    SELECT s.project_id FROM workflow_statuses s
+                    WHERE s.workspace_id = {CURRENT_WORKSPACE}
+                        AND (s.project_id = $1 OR s.project_id IS NULL)
+                    ORDER BY s.project_id NULLS LAST
+                    LIMIT 1)
+            ORDER BY position
+            "#
+        ))
+        .bind(project_id)
+        .fetch_all(&self.pool)
+        .await?;
+
+        if rows.is_empty() {
+            return Ok(Workflow::default());
+        }
+        rows.into_iter()
+            .map(WorkflowStatus::try_from)
+            .collect::<Result<Vec<_>, _>>()
+            .and_then(Workflow::new)
+            .map_err(|e| AppError::Internal(anyhow!("Stored workflow is invalid: {}", e)))
+    }
+
+    async fn replace(&self, project_id: Option<Uuid>, workflow: &Workflow) -> AppResult<()> {
+        let statuses = workflow.statuses();
+        let keys: Vec<&str> = statuses.iter().map(|s| s.key.value()).collect();
+        let mut tx = self.pool.begin().await?;
+
+        // Replacements of the workspace's workflows take turns, as projects
+        // without their own follow the workspace's
+        sqlx::query(
+            "SELECT pg_advisory_xact_lock(hashtext('workflow_statuses'), \
+             hashtext(current_setting('app.workspace_id', true)))",
+        )
+        .execute(&mut *tx)
+        .await?;
+
+        let dropped: Vec<String> = sqlx::query_scalar(
+            r#"
+            SELECT DISTINCT status FROM todos
+            WHERE status <> ALL($1)
+                AND (project_id = $2
+                     OR ($2::uuid IS NULL AND NOT EXISTS (
+                         SELECT 1 FROM workflow_statuses s WHERE s.project_id = todos.project_id)))
+            ORDER BY status
+            "#,
+        )
+        .bind(&keys)
+        .bind(project_id)
+        .fetch_all(&mut *tx)
+        .await?;
+        if !dropped.is_empty() {
+            return Err(AppError::Conflict(format!(
+                "Todos are still in the dropped statuses: {}",
+                dropped.join(", ")
+            )));
+        }
+
+        sqlx::query(&format!(
+            "DELETE FROM workflow_statuses \
+             WHERE workspace_id = {CURRENT_WORKSPACE} AND project_id IS NOT DISTINCT FROM $1"
+        ))
+        .bind(project_id)
+        .execute(&mut *tx)
+        .await?;
+
+        // Transitions are joined since arrays of arrays must be rectangular
+        sqlx::query(&format!(
+            r#"
+            INSERT INTO workflow_statuses
+                (workspace_id, project_id, key, name, terminal, cancelled, transitions, position)
+            SELECT {CURRENT_WORKSPACE}, $7, v.key, v.name, v.terminal, v.cancelled,
+                   COALESCE(string_to_array(NULLIF(v.transitions, ''), ','), '{{}}'),
+                   v.position
+            FROM UNNEST($1::text[], $2::text[], $3::bool[], $4::text[], $5::int[], $6::bool[])
+                AS v(key, name, terminal, transitions, position, cancelled)
+            "#
+        ))
+        .bind(&keys)
+        .bind(statuses.iter().map(|s| s.name.trim()).collect::<Vec<_>>())
+        .bind(statuses.iter().map(|s| s.terminal).collect::<Vec<_>>())
+        .bind(
+            statuses
+                .iter()
+                .map(|s| {
+                    s.transitions
+                        .iter()
+                        .map(TodoStatus::value)
+                        .collect::<Vec<_>>()
+                        .join(",")
+                })
+                .collect::<Vec<_>>(),
+        )
+        .bind((0..statuses.len() as i32).collect::<Vec<_>>())
+        .bind(statuses.iter().map(|s| s.cancelled).collect::<Vec<_>>())
+        .bind(project_id)
+        .execute(&mut *tx)
+        .await?;
+        tx.commit().await?;
+
+        Ok(())
+    }
+}
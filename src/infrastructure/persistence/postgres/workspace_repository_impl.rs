@@ -0,0 +1,322 @@
+use async_trait::async_trait;
+use sqlx::{PgExecutor, PgPool};
+use uuid::Uuid;
+
+use crate::domain::entities::{Workspace, WorkspaceInvitation, WorkspaceMember, WorkspaceRole};
+use crate::domain::repositories::WorkspaceRepository;
+use crate::shared::error::{AppError, AppResult};
+
+/// Columns selected for every `WorkspaceInvitation` row, from the
+/// invitation `i` joined with its workspace `w`.
+const INVITATION_COLUMNS: &str =
+    "i.id, i.workspace_id, w.name AS workspace_name, i.email, i.role, \
+     i.invited_by, i.created_at";
+
+#[derive(sqlx::FromRow)]
+struct MembershipRow {
+    #[sqlx(flatten)]
+    workspace: Workspace,
+    role: WorkspaceRole,
+}
+
+pub struct PostgresWorkspaceRepository {
+    pool: PgPool,
+}
+
+impl PostgresWorkspaceRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+
+    async fn find_member<'e>(
+        executor: impl PgExecutor<'e>,
+        workspace_id: Uuid,
+        user_id: Uuid,
+    ) -> AppResult<Option<WorkspaceMember>> {
+        let member = sqlx::query_as::<_, WorkspaceMember>(
+            r#"
+            SELECT m.workspace_id, m.user_id, u.email, m.role, m.created_at
+            FROM workspace_members m
+            JOIN users u ON u.id = m.user_id
+            WHERE m.workspace_id = $1 AND m.user_id = $2
+            "#,
+        )
+        .bind(workspace_id)
+        .bind(user_id)
+        .fetch_optional(executor)
+        .await?;
+
+        Ok(member)
+    }
+}
+
+#[async_trait]
+impl WorkspaceRepository for PostgresWorkspaceRepository {
+    async fn create(&self, workspace: &Workspace, owner_id: Uuid) -> AppResult<Workspace> {
+        let mut tx = self.pool.begin().await?;
+
+        let created = sqlx::query_as::<_, Workspace>(
+            r#"
+            INSERT INTO workspaces (id, name, created_at)
+            VALUES ($1, $2, $3)
+            RETURNING id, name, created_at
+            "#,
+        )
+        .bind(workspace.id)
+        .bind(&workspace.name)
+        .bind(workspace.created_at)
+        .fetch_one(&mut *tx)
+        .await?;
+
+        sqlx::query(
+            r#"
+            INSERT INTO workspace_members (workspace_id, user_id, role, created_at)
+            VALUES ($1, $2, 'owner', $3)
+            "#,
+        )
+        .bind(workspace.id)
+        .bind(owner_id)
+        .bind(workspace.created_at)
+        .execute(&mut *tx)
+        .await?;
+
+        tx.commit().await?;
+        Ok(created)
+    }
+
+    async fn find_by_id(&self, id: Uuid) -> AppResult<Option<Workspace>> {
+        let workspace = sqlx::query_as::<_, Workspace>(
+            r#"
+            SELECT id, name, created_at
+            FROM workspaces
+            WHERE id = $1
+            "#,
+        )
+        .bind(id)
+        .fetch_optional(&self.pool)
+        .await?;
+
+        Ok(workspace)
+    }
+
+    async fn find_by_user(&self, user_id: Uuid) -> AppResult<Vec<(Workspace, WorkspaceRole)>> {
+        let rows = sqlx::query_as::<_, MembershipRow>(
+            r#"
+            SELECT w.id, w.name, w.created_at, m.role
+            FROM workspace_members m
+            JOIN workspaces w ON w.id = m.workspace_id
+            WHERE m.user_id = $1
+            ORDER BY m.created_at, w.id
+            "#,
+        )
+        .bind(user_id)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(rows
+            .into_iter()
+            .map(|row| (row.workspace, row.role))
+            .collect())
+    }
+
+    async fn find_role(
+        &self,
+        workspace_id: Uuid,
+        user_id: Uuid,
+    ) -> AppResult<Option<WorkspaceRole>> {
+        let role: Option<(WorkspaceRole,)> = sqlx::query_as(
+            r#"
+            SELECT role
+            FROM workspace_members
+            WHERE workspace_id = $1 AND user_id = $2
+            "#,
+        )
+        .bind(workspace_id)
+        .bind(user_id)
+        .fetch_optional(&self.pool)
+        .await?;
+
+        Ok(role.map(|(role,)| role))
+    }
+
+    async fn find_members(&self, workspace_id: Uuid) -> AppResult<Vec<WorkspaceMember>> {
+        let members = sqlx::query_as::<_, WorkspaceMember>(
+            r#"
+            SELECT m.workspace_id, m.user_id, u.email, m.role, m.created_at
+            FROM workspace_members m
+            JOIN users u ON u.id = m.user_id
+            WHERE m.workspace_id = $1
+            ORDER BY m.created_at, m.user_id
+            "#,
+        )
+        .bind(workspace_id)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(members)
+    }
+
+    async fn update_member_role(
+        &self,
+        workspace_id: Uuid,
+        user_id: Uuid,
+        role: WorkspaceRole,
+    ) -> AppResult<Option<WorkspaceMember>> {
+        let mut tx = self.pool.begin().await?;
+
+        let result = sqlx::query(
+            r#"
+            UPDATE workspace_members
+            SET role = $3
+            WHERE workspace_id = $1 AND user_id = $2
+            "#,
+        )
+        .bind(workspace_id)
+        .bind(user_id)
+        .bind(role)
+        .execute(&mut *tx)
+        .await?;
+        if result.rows_affected() == 0 {
+            return Ok(None);
+        }
+        let member = Self::find_member(&mut *tx, workspace_id, user_id).await?;
+
+        tx.commit().await?;
+        Ok(member)
+    }
+
+    async fn remove_member(&self, workspace_id: Uuid, user_id: Uuid) -> AppResult<bool> {
+        let result = sqlx::query(
+            r#"
+            DELETE FROM workspace_members
+            WHERE workspace_id = $1 AND user_id = $2
+            "#,
+        )
+        .bind(workspace_id)
+        .bind(user_id)
+        .execute(&self.pool)
+        .await?;
+
+        Ok(result.rows_affected() > 0)
+    }
+
+    async fn count_owners(&self, workspace_id: Uuid) -> AppResult<i64> {
+        let count: (i64,) = sqlx::query_as(
+            r#"
+            SELECT COUNT(*) as count
+            FROM workspace_members
+            WHERE workspace_id = $1 AND role = 'owner'
+            "#,
+        )
+        .bind(workspace_id)
+        .fetch_one(&self.pool)
+        .await?;
+
+        Ok(count.0)
+    }
+
+    async fn create_invitation(
+        &self,
+        invitation: &WorkspaceInvitation,
+    ) -> AppResult<WorkspaceInvitation> {
+        let created = sqlx::query_as::<_, WorkspaceInvitation>(&format!(
+            r#"
+            WITH i AS (
+                INSERT INTO workspace_invitations
+                    (id, workspace_id, email, role, invited_by, created_at)
+                VALUES ($1, $2, $3, $4, $5, $6)
+                ON CONFLICT (workspace_id, email) DO UPDATE
+                SET role = EXCLUDED.role, invited_by = EXCLUDED.invited_by,
+                    created_at = EXCLUDED.created_at
+                RETURNING *
+            )
+            SELECT {INVITATION_COLUMNS}
+            FROM i
+            JOIN workspaces w ON w.id = i.workspace_id
+            "#
+        ))
+        .bind(invitation.id)
+        .bind(invitation.workspace_id)
+        .bind(&invitation.email)
+        .bind(invitation.role)
+        .bind(invitation.invited_by)
+        .bind(invitation.created_at)
+        .fetch_one(&self.pool)
+        .await?;
+
+        Ok(created)
+    }
+
+    async fn find_invitation(&self, id: Uuid) -> AppResult<Option<WorkspaceInvitation>> {
+        let invitation = sqlx::query_as::<_, WorkspaceInvitation>(&format!(
+            r#"
+            SELECT {INVITATION_COLUMNS}
+            FROM workspace_invitations i
+            JOIN workspaces w ON w.id = i.workspace_id
+            WHERE i.id = $1
+            "#
+        ))
+        .bind(id)
+        .fetch_optional(&self.pool)
+        .await?;
+
+        Ok(invitation)
+    }
+
+    async fn find_invitations_by_email(&self, email: &str) -> AppResult<Vec<WorkspaceInvitation>> {
+        let invitations = sqlx::query_as::<_, WorkspaceInvitation>(&format!(
+            r#"
+            SELECT {INVITATION_COLUMNS}
+            FROM workspace_invitations i
+            JOIN workspaces w ON w.id = i.workspace_id
+            WHERE i.email = $1
+            ORDER BY i.created_at, i.id
+            "#
+        ))
+        .bind(email)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(invitations)
+    }
+
+    async fn accept_invitation(
+        &self,
+        invitation: &WorkspaceInvitation,
+        user_id: Uuid,
+    ) -> AppResult<WorkspaceMember> {
+        let mut tx = self.pool.begin().await?;
+
+        sqlx::query(
+            r#"
+            INSERT INTO workspace_members (workspace_id, user_id, role)
+            VALUES ($1, $2, $3)
+            ON CONFLICT (workspace_id, user_id) DO NOTHING
+            "#,
+        )
+        .bind(invitation.workspace_id)
+        .bind(user_id)
+        .bind(invitation.role)
+        .execute(&mut *tx)
+        .await?;
+        sqlx::query("DELETE FROM workspace_invitations WHERE id = $1")
+            .bind(invitation.id)
+            .execute(&mut *tx)
+            .await?;
+        let member = Self::find_member(&mut *tx, invitation.workspace_id, user_id)
+            .await?
+            .ok_or_else(|| AppError::NotFound("Workspace not found".to_string()))?;
+
+        tx.commit().await?;
+        Ok(member)
+    }
+
+    async fn delete_invitation(&self, id: Uuid) -> AppResult<()> {
+        sqlx::query("DELETE FROM workspace_invitations WHERE id = $1")
+            .bind(id)
+            .execute(&self.pool)
+            .await?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,97 @@
+use std::io::{ErrorKind, SeekFrom};
+use std::path::{Component, Path, PathBuf};
+
+use anyhow::Context;
+use async_trait::async_trait;
+use bytes::Bytes;
+use tokio::fs::{self, File};
+use tokio::io::{AsyncReadExt, AsyncSeekExt};
+use tokio_util::io::ReaderStream;
+use uuid::Uuid;
+
+use super::{ByteRange, ByteStream, ObjectStore};
+use crate::shared::error::{AppError, AppResult};
+
+/// Stores each object as a file under `root`, at the path given by its key.
+pub struct LocalObjectStore {
+    root: PathBuf,
+}
+
+impl LocalObjectStore {
+    pub fn new(root: impl Into<PathBuf>) -> Self {
+        Self { root: root.into() }
+    }
+
+    /// Path of the object's file. Keys may not leave `root`.
+    fn path(&self, key: &str) -> AppResult<PathBuf> {
+        let relative = Path::new(key);
+        let is_plain = relative
+            .components()
+            .all(|component| matches!(component, Component::Normal(_)));
+        if key.is_empty() || !is_plain {
+            return Err(anyhow::anyhow!("Invalid object key: {}", key).into());
+        }
+        Ok(self.root.join(relative))
+    }
+}
+
+#[async_trait]
+impl ObjectStore for LocalObjectStore {
+    async fn put(&self, key: &str, data: Bytes) -> AppResult<()> {
+        let path = self.path(key)?;
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)
+                .await
+                .with_context(|| format!("Failed to create {}", parent.display()))?;
+        }
+
+        // Write to a temporary file first so readers never see partial content
+        let temp = path.with_extension(format!("{}.tmp", Uuid::new_v4()));
+        fs::write(&temp, &data)
+            .await
+            .with_context(|| format!("Failed to write {}", temp.display()))?;
+        if let Err(e) = fs::rename(&temp, &path).await {
+            let _ = fs::remove_file(&temp).await;
+            return Err(anyhow::Error::new(e)
+                .context(format!("Failed to store object {}", key))
+                .into());
+        }
+        Ok(())
+    }
+
+    async fn get(&self, key: &str, range: Option<ByteRange>) -> AppResult<ByteStream> {
+        let path = self.path(key)?;
+        let mut file = match File::open(&path).await {
+            Ok(file) => file,
+            Err(e) if e.kind() == ErrorKind::NotFound => {
+                return Err(AppError::NotFound("Object not found".to_string()));
+            }
+            Err(e) => {
+                return Err(anyhow::Error::new(e)
+                    .context(format!("Failed to open object {}", key))
+                    .into());
+            }
+        };
+
+        match range {
+            Some(range) => {
+                file.seek(SeekFrom::Start(range.start))
+                    .await
+                    .with_context(|| format!("Failed to seek in object {}", key))?;
+                Ok(Box::pin(ReaderStream::new(file.take(range.length()))))
+            }
+            None => Ok(Box::pin(ReaderStream::new(file))),
+        }
+    }
+
+    async fn delete(&self, key: &str) -> AppResult<()> {
+        let path = self.path(key)?;
+        match fs::remove_file(&path).await {
+            Ok(()) => Ok(()),
+            Err(e) if e.kind() == ErrorKind::NotFound => Ok(()),
+            Err(e) => Err(anyhow::Error::new(e)
+                .context(format!("Failed to delete object {}", key))
+                .into()),
+        }
+    }
+}
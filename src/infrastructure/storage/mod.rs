@@ -0,0 +1,97 @@
+pub mod local;
+pub mod s3;
+
+pub use local::LocalObjectStore;
+pub use s3::{S3Config, S3ObjectStore};
+
+use std::io;
+use std::path::PathBuf;
+use std::pin::Pin;
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use bytes::Bytes;
+use futures_util::Stream;
+
+use crate::shared::error::AppResult;
+
+/// Object content, streamed in chunks.
+pub type ByteStream = Pin<Box<dyn Stream<Item = io::Result<Bytes>> + Send>>;
+
+/// An inclusive range of byte offsets within an object.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ByteRange {
+    pub start: u64,
+    pub end: u64,
+}
+
+impl ByteRange {
+    pub fn length(&self) -> u64 {
+        self.end - self.start + 1
+    }
+}
+
+/// Blob storage for attachment content, addressed by key.
+#[async_trait]
+pub trait ObjectStore: Send + Sync {
+    /// Stores `data` under `key`, replacing any existing object.
+    async fn put(&self, key: &str, data: Bytes) -> AppResult<()>;
+    /// Streams the object, or only `range` of it, which must lie within the
+    /// object. Fails with `NotFound` if there is no such object.
+    async fn get(&self, key: &str, range: Option<ByteRange>) -> AppResult<ByteStream>;
+    /// Deletes the object. Deleting a missing object succeeds.
+    async fn delete(&self, key: &str) -> AppResult<()>;
+}
+
+#[derive(Clone)]
+pub enum StorageConfig {
+    Local { root: PathBuf },
+    S3(S3Config),
+}
+
+impl StorageConfig {
+    pub fn from_env() -> Self {
+        let backend = std::env::var("STORAGE_BACKEND").unwrap_or_else(|_| "local".to_string());
+        match backend.as_str() {
+            "local" => Self::Local {
+                root: std::env::var("STORAGE_LOCAL_PATH")
+                    .unwrap_or_else(|_| "./data/attachments".to_string())
+                    .into(),
+            },
+            "s3" => Self::S3(S3Config::from_env()),
+            other => panic!("STORAGE_BACKEND must be local or s3, got {}", other),
+        }
+    }
+
+    pub fn build(self) -> Arc<dyn ObjectStore> {
+        match self {
+            Self::Local { root } => Arc::new(LocalObjectStore::new(root)),
+            Self::S3(config) => Arc::new(S3ObjectStore::new(config)),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct AttachmentLimits {
+    /// Largest accepted upload
+    pub max_file_bytes: i64,
+    /// Total size of all attachments a user may upload
+    pub quota_bytes: i64,
+}
+
+impl AttachmentLimits {
+    pub fn from_env() -> Self {
+        let max_file_bytes: i64 = std::env::var("ATTACHMENT_MAX_BYTES")
+            .unwrap_or_else(|_| (25 * 1024 * 1024).to_string())
+            .parse()
+            .expect("ATTACHMENT_MAX_BYTES must be a number");
+        let quota_bytes: i64 = std::env::var("ATTACHMENT_QUOTA_BYTES")
+            .unwrap_or_else(|_| (1024 * 1024 * 1024).to_string())
+            .parse()
+            .expect("ATTACHMENT_QUOTA_BYTES must be a number");
+        Self {
+            max_file_bytes,
+            quota_bytes,
+        }
+    }
+}
@@ -1,3 +1,4 @@
+use axum::http::header::ETAG;
 use axum::Router;
 use std::net::SocketAddr;
 use tower_http::cors::{Any, CorsLayer};
@@ -7,8 +8,15 @@ use utoipa::OpenApi;
 use utoipa_swagger_ui::SwaggerUi;
 
 use rust_teraform_backend::infrastructure::config::AppState;
+use rust_teraform_backend::infrastructure::jobs::{
+    spawn_object_cleanup, spawn_reminder_scheduler, spawn_trash_purge, ReminderSchedulerConfig,
+    TrashPurgeConfig,
+};
 use rust_teraform_backend::presentation::openapi::ApiDoc;
-use rust_teraform_backend::presentation::routes::{auth_routes, todo_routes};
+use rust_teraform_backend::presentation::routes::{
+    auth_routes, filter_routes, notification_routes, project_routes, report_routes, todo_routes,
+    workspace_routes,
+};
 
 #[tokio::main]
 async fn main() {
@@ -26,11 +34,24 @@ async fn main() {
         .await
         .expect("Failed to initialize app state");
 
+    // Start background jobs
+    spawn_trash_purge(state.todo_repository.clone(), TrashPurgeConfig::from_env());
+    spawn_object_cleanup(
+        state.attachment_repository.clone(),
+        state.object_store.clone(),
+    );
+    spawn_reminder_scheduler(
+        state.reminder_repository.clone(),
+        state.notification_channels.clone(),
+        ReminderSchedulerConfig::from_env(),
+    );
+
     // Build CORS layer
     let cors = CorsLayer::new()
         .allow_origin(Any)
         .allow_methods(Any)
-        .allow_headers(Any);
+        .allow_headers(Any)
+        .expose_headers([ETAG]);
 
     // Build router
     let app = Router::new()
@@ -39,6 +60,11 @@ async fn main() {
         // API routes
         .nest("/api/v1/auth", auth_routes())
         .nest("/api/v1/todos", todo_routes(state.clone()))
+        .nest("/api/v1/workspaces", workspace_routes(state.clone()))
+        .nest("/api/v1/projects", project_routes(state.clone()))
+        .nest("/api/v1/filters", filter_routes(state.clone()))
+        .nest("/api/v1/reports", report_routes(state.clone()))
+        .nest("/api/v1/notifications", notification_routes(state.clone()))
         // Swagger UI
         .merge(SwaggerUi::new("/swagger-ui").url("/api-docs/openapi.json", ApiDoc::openapi()))
         // Middleware
@@ -0,0 +1,48 @@
+use axum::http::{
+    header::{IF_MATCH, IF_NONE_MATCH},
+    HeaderMap, HeaderName, HeaderValue,
+};
+
+use crate::application::dto::VersionPrecondition;
+
+/// Strong entity tag for a resource at `version`. Every write that changes
+/// a todo's representation, counts and computed fields included, bumps it.
+pub fn etag(version: i32) -> HeaderValue {
+    HeaderValue::from_str(&format!("\"{}\"", version)).expect("entity tag is a valid header")
+}
+
+/// The `If-Match` precondition, or `None` if the header is absent or `*`.
+/// Weak and unrecognised tags never match, since `If-Match` compares strongly.
+pub fn if_match(headers: &HeaderMap) -> Option<VersionPrecondition> {
+    let tags = entity_tags(headers, IF_MATCH)?;
+    if tags.iter().any(|t| t == "*") {
+        return None;
+    }
+    Some(VersionPrecondition(
+        tags.iter().filter_map(|t| parse_version(t)).collect(),
+    ))
+}
+
+/// Whether `If-None-Match` names `version`, using weak comparison.
+pub fn if_none_match(headers: &HeaderMap, version: i32) -> bool {
+    entity_tags(headers, IF_NONE_MATCH).is_some_and(|tags| {
+        tags.iter()
+            .any(|t| t == "*" || parse_version(t.trim_start_matches("W/")) == Some(version))
+    })
+}
+
+fn entity_tags(headers: &HeaderMap, name: HeaderName) -> Option<Vec<String>> {
+    let mut values = headers.get_all(name).iter().peekable();
+    values.peek()?;
+    Some(
+        values
+            .filter_map(|v| v.to_str().ok())
+            .flat_map(|v| v.split(','))
+            .map(|t| t.trim().to_string())
+            .collect(),
+    )
+}
+
+fn parse_version(tag: &str) -> Option<i32> {
+    tag.strip_prefix('"')?.strip_suffix('"')?.parse().ok()
+}
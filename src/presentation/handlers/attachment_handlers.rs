@@ -0,0 +1,245 @@
+use axum::{
+    body::Body,
+    extract::{multipart::MultipartError, Extension, Multipart, Path, State},
+    http::{
+        header::{
+            ACCEPT_RANGES, CONTENT_DISPOSITION, CONTENT_LENGTH, CONTENT_RANGE, CONTENT_TYPE,
+            X_CONTENT_TYPE_OPTIONS,
+        },
+        HeaderMap, HeaderValue, StatusCode,
+    },
+    response::{IntoResponse, Response},
+    Json,
+};
+use bytes::BytesMut;
+use uuid::Uuid;
+
+use crate::application::dto::{
+    AttachmentListResponse, AttachmentResponse, UploadAttachmentRequest,
+};
+use crate::application::services::AttachmentService;
+use crate::infrastructure::auth::jwt::Claims;
+use crate::infrastructure::config::AppState;
+use crate::presentation::range::requested_range;
+use crate::shared::error::{AppError, AppResult};
+
+fn attachment_service(state: &AppState) -> AttachmentService {
+    AttachmentService::new(
+        state.attachment_repository.clone(),
+        state.todo_repository.clone(),
+        state.object_store.clone(),
+        state.attachment_limits,
+    )
+}
+
+fn multipart_error(error: MultipartError) -> AppError {
+    if error.status() == StatusCode::PAYLOAD_TOO_LARGE {
+        AppError::PayloadTooLarge(error.body_text())
+    } else {
+        AppError::Validation(error.body_text())
+    }
+}
+
+/// `Content-Disposition` forcing a download, with an ASCII fallback name and
+/// the exact name in RFC 5987 encoding.
+fn content_disposition(file_name: &str) -> HeaderValue {
+    let fallback: String = file_name
+        .chars()
+        .map(|c| match c {
+            ' '..='~' if c != '"' && c != '\\' => c,
+            _ => '_',
+        })
+        .collect();
+    let encoded: String = file_name
+        .bytes()
+        .map(|b| match b {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'.' | b'-' | b'_' => (b as char).to_string(),
+            _ => format!("%{:02X}", b),
+        })
+        .collect();
+    HeaderValue::from_str(&format!(
+        "attachment; filename=\"{}\"; filename*=UTF-8''{}",
+        fallback, encoded
+    ))
+    .unwrap_or_else(|_| HeaderValue::from_static("attachment"))
+}
+
+/// Upload a file to a todo
+#[utoipa::path(
+    post,
+    path = "/api/v1/todos/{id}/attachments",
+    params(
+        ("id" = Uuid, Path, description = "Todo ID")
+    ),
+    request_body(content = UploadAttachmentRequest, content_type = "multipart/form-data"),
+    responses(
+        (status = 201, description = "Attachment uploaded", body = AttachmentResponse),
+        (status = 400, description = "Missing or empty file"),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Only the owner or an editor can upload attachments"),
+        (status = 404, description = "Todo not found"),
+        (status = 413, description = "File too large or attachment quota exceeded")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "attachments"
+)]
+pub async fn upload_attachment(
+    State(state): State<AppState>,
+    Extension(claims): Extension<Claims>,
+    Path(id): Path<Uuid>,
+    mut multipart: Multipart,
+) -> AppResult<impl IntoResponse> {
+    let max_file_bytes = state.attachment_limits.max_file_bytes;
+    while let Some(mut field) = multipart.next_field().await.map_err(multipart_error)? {
+        if field.name() != Some("file") {
+            continue;
+        }
+        let file_name = field.file_name().unwrap_or_default().to_string();
+
+        let mut data = BytesMut::new();
+        while let Some(chunk) = field.chunk().await.map_err(multipart_error)? {
+            data.extend_from_slice(&chunk);
+            // Enough to know it is too large; the service rejects it
+            if data.len() as i64 > max_file_bytes {
+                break;
+            }
+        }
+
+        let response = attachment_service(&state)
+            .upload(claims.sub, id, &file_name, data.freeze())
+            .await?;
+        return Ok((StatusCode::CREATED, Json(response)));
+    }
+    Err(AppError::Validation(
+        "Missing multipart field \"file\"".to_string(),
+    ))
+}
+
+/// List the attachments of a todo
+#[utoipa::path(
+    get,
+    path = "/api/v1/todos/{id}/attachments",
+    params(
+        ("id" = Uuid, Path, description = "Todo ID")
+    ),
+    responses(
+        (status = 200, description = "Attachments, oldest first", body = AttachmentListResponse),
+        (status = 401, description = "Unauthorized"),
+        (status = 404, description = "Todo not found")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "attachments"
+)]
+pub async fn list_attachments(
+    State(state): State<AppState>,
+    Extension(claims): Extension<Claims>,
+    Path(id): Path<Uuid>,
+) -> AppResult<Json<AttachmentListResponse>> {
+    let response = attachment_service(&state).list(claims.sub, id).await?;
+    Ok(Json(response))
+}
+
+/// Download an attachment
+#[utoipa::path(
+    get,
+    path = "/api/v1/todos/{id}/attachments/{attachment_id}",
+    params(
+        ("id" = Uuid, Path, description = "Todo ID"),
+        ("attachment_id" = Uuid, Path, description = "Attachment ID"),
+        ("Range" = Option<String>, Header, description = "A single byte range, e.g. bytes=0-1023")
+    ),
+    responses(
+        (status = 200, description = "File content", content_type = "application/octet-stream"),
+        (status = 206, description = "Requested range of the file", content_type = "application/octet-stream",
+            headers(("Content-Range" = String, description = "Range returned and total size"))),
+        (status = 401, description = "Unauthorized"),
+        (status = 404, description = "Todo or attachment not found"),
+        (status = 416, description = "Range lies outside the file")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "attachments"
+)]
+pub async fn download_attachment(
+    State(state): State<AppState>,
+    Extension(claims): Extension<Claims>,
+    Path((id, attachment_id)): Path<(Uuid, Uuid)>,
+    headers: HeaderMap,
+) -> AppResult<Response> {
+    let service = attachment_service(&state);
+    let attachment = service.find(claims.sub, id, attachment_id).await?;
+    let size = attachment.size_bytes as u64;
+
+    let Ok(range) = requested_range(&headers, size) else {
+        return Ok((
+            StatusCode::RANGE_NOT_SATISFIABLE,
+            [(CONTENT_RANGE, format!("bytes */{}", size))],
+        )
+            .into_response());
+    };
+    let body = Body::from_stream(service.content(&attachment, range).await?);
+
+    let mut response = Response::new(body);
+    let headers = response.headers_mut();
+    headers.insert(
+        CONTENT_TYPE,
+        HeaderValue::from_str(&attachment.content_type)
+            .unwrap_or_else(|_| HeaderValue::from_static("application/octet-stream")),
+    );
+    headers.insert(
+        CONTENT_DISPOSITION,
+        content_disposition(&attachment.file_name),
+    );
+    headers.insert(X_CONTENT_TYPE_OPTIONS, HeaderValue::from_static("nosniff"));
+    headers.insert(ACCEPT_RANGES, HeaderValue::from_static("bytes"));
+    match range {
+        Some(range) => {
+            headers.insert(CONTENT_LENGTH, range.length().into());
+            headers.insert(
+                CONTENT_RANGE,
+                HeaderValue::from_str(&format!("bytes {}-{}/{}", range.start, range.end, size))
+                    .expect("content range is a valid header"),
+            );
+            *response.status_mut() = StatusCode::PARTIAL_CONTENT;
+        }
+        None => {
+            headers.insert(CONTENT_LENGTH, size.into());
+        }
+    }
+    Ok(response)
+}
+
+/// Delete an attachment
+#[utoipa::path(
+    delete,
+    path = "/api/v1/todos/{id}/attachments/{attachment_id}",
+    params(
+        ("id" = Uuid, Path, description = "Todo ID"),
+        ("attachment_id" = Uuid, Path, description = "Attachment ID")
+    ),
+    responses(
+        (status = 204, description = "Attachment deleted"),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Only the owner or an editor can delete attachments"),
+        (status = 404, description = "Todo or attachment not found")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "attachments"
+)]
+pub async fn delete_attachment(
+    State(state): State<AppState>,
+    Extension(claims): Extension<Claims>,
+    Path((id, attachment_id)): Path<(Uuid, Uuid)>,
+) -> AppResult<StatusCode> {
+    attachment_service(&state)
+        .delete(claims.sub, id, attachment_id)
+        .await?;
+    Ok(StatusCode::NO_CONTENT)
+}
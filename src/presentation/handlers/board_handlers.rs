@@ -0,0 +1,132 @@
+use axum::{
+    extract::{Extension, Path, Query, State},
+    http::{header::ETAG, HeaderMap},
+    response::IntoResponse,
+    Json,
+};
+use uuid::Uuid;
+
+use crate::application::dto::{
+    BoardColumnResponse, BoardQuery, BoardResponse, MoveTodoRequest, PaginationQuery, TodoResponse,
+    TodoWriteQuery,
+};
+use crate::application::services::{BoardService, TodoService};
+use crate::infrastructure::auth::jwt::Claims;
+use crate::infrastructure::config::AppState;
+use crate::presentation::etag::{etag, if_match};
+use crate::presentation::handlers::notification_handlers::notifier;
+use crate::shared::error::AppResult;
+
+fn board_service(state: &AppState) -> BoardService {
+    BoardService::new(
+        state.todo_repository.clone(),
+        state.workflow_repository.clone(),
+        state.project_repository.clone(),
+    )
+}
+
+/// Get a project's board: the project's todos you can see, grouped by status
+#[utoipa::path(
+    get,
+    path = "/api/v1/projects/{id}/board",
+    params(
+        ("id" = Uuid, Path, description = "Project ID"),
+        ("limit" = Option<i64>, Query, description = "Cards per column (default: 20, max: 100)")
+    ),
+    responses(
+        (status = 200, description = "One column per status of the project's workflow, with its first cards", body = BoardResponse),
+        (status = 401, description = "Unauthorized"),
+        (status = 404, description = "Project not found")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "board"
+)]
+pub async fn get_board(
+    State(state): State<AppState>,
+    Extension(claims): Extension<Claims>,
+    Path(id): Path<Uuid>,
+    Query(query): Query<BoardQuery>,
+) -> AppResult<Json<BoardResponse>> {
+    let response = board_service(&state).board(claims.sub, id, query).await?;
+    Ok(Json(response))
+}
+
+/// Get one page of a board column
+#[utoipa::path(
+    get,
+    path = "/api/v1/projects/{id}/board/{status}",
+    params(
+        ("id" = Uuid, Path, description = "Project ID"),
+        ("status" = String, Path, description = "Workflow status of the column"),
+        ("page" = Option<i64>, Query, description = "Page number (default: 1)"),
+        ("per_page" = Option<i64>, Query, description = "Cards per page (default: 20, max: 100)")
+    ),
+    responses(
+        (status = 200, description = "A page of the column's cards", body = BoardColumnResponse),
+        (status = 401, description = "Unauthorized"),
+        (status = 404, description = "Project not found, or its workflow has no such status")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "board"
+)]
+pub async fn get_board_column(
+    State(state): State<AppState>,
+    Extension(claims): Extension<Claims>,
+    Path((id, status)): Path<(Uuid, String)>,
+    Query(pagination): Query<PaginationQuery>,
+) -> AppResult<Json<BoardColumnResponse>> {
+    let response = board_service(&state)
+        .column(claims.sub, id, status, pagination)
+        .await?;
+    Ok(Json(response))
+}
+
+/// Move a todo to another place on the board
+///
+/// Changes the todo's status and its position in the column in one step.
+#[utoipa::path(
+    post,
+    path = "/api/v1/todos/{id}/move",
+    params(
+        ("id" = Uuid, Path, description = "Todo ID"),
+        ("If-Match" = Option<String>, Header, description = "Only apply the change if the todo's ETag still matches"),
+        ("force" = Option<bool>, Query, description = "Complete the todo even while open todos block it (default: false)")
+    ),
+    request_body = MoveTodoRequest,
+    responses(
+        (status = 200, description = "Todo moved", body = TodoResponse,
+            headers(("ETag" = String, description = "New version of the todo"))),
+        (status = 400, description = "A move the workflow does not allow, a card outside the target column, or completing a blocked todo without force"),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Only the owner or an editor can move the todo"),
+        (status = 404, description = "Todo not found"),
+        (status = 409, description = "Todo was modified by a concurrent request"),
+        (status = 412, description = "If-Match does not match the current version")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "board"
+)]
+pub async fn move_todo(
+    State(state): State<AppState>,
+    Extension(claims): Extension<Claims>,
+    Path(id): Path<Uuid>,
+    Query(query): Query<TodoWriteQuery>,
+    headers: HeaderMap,
+    Json(request): Json<MoveTodoRequest>,
+) -> AppResult<impl IntoResponse> {
+    let service = TodoService::new(
+        state.todo_repository.clone(),
+        state.workflow_repository.clone(),
+        notifier(&state),
+    );
+    let response = service
+        .move_card(claims.sub, id, request, if_match(&headers), query.force)
+        .await?;
+    Ok(([(ETAG, etag(response.version))], Json(response)))
+}
@@ -0,0 +1,171 @@
+use axum::{
+    extract::{Extension, Path, State},
+    http::{header::ETAG, HeaderMap, StatusCode},
+    response::IntoResponse,
+    Json,
+};
+use uuid::Uuid;
+
+use crate::application::dto::{AddChecklistItemRequest, ReorderChecklistRequest, TodoResponse};
+use crate::application::services::TodoService;
+use crate::infrastructure::auth::jwt::Claims;
+use crate::infrastructure::config::AppState;
+use crate::presentation::etag::{etag, if_match};
+use crate::presentation::handlers::notification_handlers::notifier;
+use crate::shared::error::AppResult;
+
+fn todo_service(state: &AppState) -> TodoService {
+    TodoService::new(
+        state.todo_repository.clone(),
+        state.workflow_repository.clone(),
+        notifier(state),
+    )
+}
+
+/// Add a checklist item to a todo
+#[utoipa::path(
+    post,
+    path = "/api/v1/todos/{id}/checklist",
+    params(
+        ("id" = Uuid, Path, description = "Todo ID"),
+        ("If-Match" = Option<String>, Header, description = "Only apply the change if the todo's ETag still matches")
+    ),
+    request_body = AddChecklistItemRequest,
+    responses(
+        (status = 201, description = "Item added; returns the todo", body = TodoResponse,
+            headers(("ETag" = String, description = "New version of the todo"))),
+        (status = 400, description = "Validation error"),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Only the owner or an editor can change the checklist"),
+        (status = 404, description = "Todo not found"),
+        (status = 409, description = "Todo was modified by a concurrent request"),
+        (status = 412, description = "If-Match does not match the current version")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "todos"
+)]
+pub async fn add_checklist_item(
+    State(state): State<AppState>,
+    Extension(claims): Extension<Claims>,
+    Path(id): Path<Uuid>,
+    headers: HeaderMap,
+    Json(request): Json<AddChecklistItemRequest>,
+) -> AppResult<impl IntoResponse> {
+    let response = todo_service(&state)
+        .add_checklist_item(claims.sub, id, request, if_match(&headers))
+        .await?;
+    Ok((
+        StatusCode::CREATED,
+        [(ETAG, etag(response.version))],
+        Json(response),
+    ))
+}
+
+/// Reorder a todo's checklist
+#[utoipa::path(
+    put,
+    path = "/api/v1/todos/{id}/checklist/order",
+    params(
+        ("id" = Uuid, Path, description = "Todo ID"),
+        ("If-Match" = Option<String>, Header, description = "Only apply the change if the todo's ETag still matches")
+    ),
+    request_body = ReorderChecklistRequest,
+    responses(
+        (status = 200, description = "Checklist reordered; returns the todo", body = TodoResponse,
+            headers(("ETag" = String, description = "New version of the todo"))),
+        (status = 400, description = "item_ids does not list every item once"),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Only the owner or an editor can change the checklist"),
+        (status = 404, description = "Todo not found"),
+        (status = 409, description = "Todo was modified by a concurrent request"),
+        (status = 412, description = "If-Match does not match the current version")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "todos"
+)]
+pub async fn reorder_checklist(
+    State(state): State<AppState>,
+    Extension(claims): Extension<Claims>,
+    Path(id): Path<Uuid>,
+    headers: HeaderMap,
+    Json(request): Json<ReorderChecklistRequest>,
+) -> AppResult<impl IntoResponse> {
+    let response = todo_service(&state)
+        .reorder_checklist(claims.sub, id, request, if_match(&headers))
+        .await?;
+    Ok(([(ETAG, etag(response.version))], Json(response)))
+}
+
+/// Check or uncheck a checklist item
+#[utoipa::path(
+    post,
+    path = "/api/v1/todos/{id}/checklist/{item_id}/toggle",
+    params(
+        ("id" = Uuid, Path, description = "Todo ID"),
+        ("item_id" = Uuid, Path, description = "Checklist item ID"),
+        ("If-Match" = Option<String>, Header, description = "Only apply the change if the todo's ETag still matches")
+    ),
+    responses(
+        (status = 200, description = "Item toggled; returns the todo", body = TodoResponse,
+            headers(("ETag" = String, description = "New version of the todo"))),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Only the owner or an editor can change the checklist"),
+        (status = 404, description = "Todo or checklist item not found"),
+        (status = 409, description = "Todo was modified by a concurrent request"),
+        (status = 412, description = "If-Match does not match the current version")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "todos"
+)]
+pub async fn toggle_checklist_item(
+    State(state): State<AppState>,
+    Extension(claims): Extension<Claims>,
+    Path((id, item_id)): Path<(Uuid, Uuid)>,
+    headers: HeaderMap,
+) -> AppResult<impl IntoResponse> {
+    let response = todo_service(&state)
+        .toggle_checklist_item(claims.sub, id, item_id, if_match(&headers))
+        .await?;
+    Ok(([(ETAG, etag(response.version))], Json(response)))
+}
+
+/// Remove a checklist item
+#[utoipa::path(
+    delete,
+    path = "/api/v1/todos/{id}/checklist/{item_id}",
+    params(
+        ("id" = Uuid, Path, description = "Todo ID"),
+        ("item_id" = Uuid, Path, description = "Checklist item ID"),
+        ("If-Match" = Option<String>, Header, description = "Only apply the change if the todo's ETag still matches")
+    ),
+    responses(
+        (status = 200, description = "Item removed; returns the todo", body = TodoResponse,
+            headers(("ETag" = String, description = "New version of the todo"))),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Only the owner or an editor can change the checklist"),
+        (status = 404, description = "Todo or checklist item not found"),
+        (status = 409, description = "Todo was modified by a concurrent request"),
+        (status = 412, description = "If-Match does not match the current version")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "todos"
+)]
+pub async fn remove_checklist_item(
+    State(state): State<AppState>,
+    Extension(claims): Extension<Claims>,
+    Path((id, item_id)): Path<(Uuid, Uuid)>,
+    headers: HeaderMap,
+) -> AppResult<impl IntoResponse> {
+    let response = todo_service(&state)
+        .remove_checklist_item(claims.sub, id, item_id, if_match(&headers))
+        .await?;
+    Ok(([(ETAG, etag(response.version))], Json(response)))
+}
@@ -0,0 +1,149 @@
+use axum::{
+    extract::{Extension, Path, Query, State},
+    http::StatusCode,
+    response::IntoResponse,
+    Json,
+};
+use uuid::Uuid;
+
+use crate::application::dto::{
+    CommentListQuery, CommentListResponse, CommentResponse, CreateCommentRequest,
+    UpdateCommentRequest,
+};
+use crate::application::services::CommentService;
+use crate::infrastructure::auth::jwt::Claims;
+use crate::infrastructure::config::AppState;
+use crate::presentation::handlers::notification_handlers::notifier;
+use crate::shared::error::AppResult;
+
+fn comment_service(state: &AppState) -> CommentService {
+    CommentService::new(
+        state.comment_repository.clone(),
+        state.todo_repository.clone(),
+        notifier(state),
+    )
+}
+
+/// List the comments on a todo
+#[utoipa::path(
+    get,
+    path = "/api/v1/todos/{id}/comments",
+    params(
+        ("id" = Uuid, Path, description = "Todo ID"),
+        ("cursor" = Option<String>, Query, description = "next_cursor from a previous response"),
+        ("per_page" = Option<i64>, Query, description = "Items per page (default: 20, max: 100)")
+    ),
+    responses(
+        (status = 200, description = "Comments, oldest first", body = CommentListResponse),
+        (status = 400, description = "Invalid cursor"),
+        (status = 401, description = "Unauthorized"),
+        (status = 404, description = "Todo not found")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "comments"
+)]
+pub async fn list_comments(
+    State(state): State<AppState>,
+    Extension(claims): Extension<Claims>,
+    Path(id): Path<Uuid>,
+    Query(query): Query<CommentListQuery>,
+) -> AppResult<Json<CommentListResponse>> {
+    let response = comment_service(&state).list(claims.sub, id, query).await?;
+    Ok(Json(response))
+}
+
+/// Comment on a todo
+#[utoipa::path(
+    post,
+    path = "/api/v1/todos/{id}/comments",
+    params(
+        ("id" = Uuid, Path, description = "Todo ID")
+    ),
+    request_body = CreateCommentRequest,
+    responses(
+        (status = 201, description = "Comment created", body = CommentResponse),
+        (status = 400, description = "Validation error"),
+        (status = 401, description = "Unauthorized"),
+        (status = 404, description = "Todo not found")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "comments"
+)]
+pub async fn create_comment(
+    State(state): State<AppState>,
+    Extension(claims): Extension<Claims>,
+    Path(id): Path<Uuid>,
+    Json(request): Json<CreateCommentRequest>,
+) -> AppResult<impl IntoResponse> {
+    let response = comment_service(&state)
+        .create(claims.sub, id, request)
+        .await?;
+    Ok((StatusCode::CREATED, Json(response)))
+}
+
+/// Edit a comment
+#[utoipa::path(
+    put,
+    path = "/api/v1/todos/{id}/comments/{comment_id}",
+    params(
+        ("id" = Uuid, Path, description = "Todo ID"),
+        ("comment_id" = Uuid, Path, description = "Comment ID")
+    ),
+    request_body = UpdateCommentRequest,
+    responses(
+        (status = 200, description = "Comment updated", body = CommentResponse),
+        (status = 400, description = "Validation error"),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Only the author or the todo's owner can edit the comment"),
+        (status = 404, description = "Todo or comment not found")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "comments"
+)]
+pub async fn update_comment(
+    State(state): State<AppState>,
+    Extension(claims): Extension<Claims>,
+    Path((id, comment_id)): Path<(Uuid, Uuid)>,
+    Json(request): Json<UpdateCommentRequest>,
+) -> AppResult<Json<CommentResponse>> {
+    let response = comment_service(&state)
+        .update(claims.sub, id, comment_id, request)
+        .await?;
+    Ok(Json(response))
+}
+
+/// Delete a comment
+#[utoipa::path(
+    delete,
+    path = "/api/v1/todos/{id}/comments/{comment_id}",
+    params(
+        ("id" = Uuid, Path, description = "Todo ID"),
+        ("comment_id" = Uuid, Path, description = "Comment ID")
+    ),
+    responses(
+        (status = 204, description = "Comment deleted"),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Only the author or the todo's owner can delete the comment"),
+        (status = 404, description = "Todo or comment not found")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "comments"
+)]
+pub async fn delete_comment(
+    State(state): State<AppState>,
+    Extension(claims): Extension<Claims>,
+    Path((id, comment_id)): Path<(Uuid, Uuid)>,
+) -> AppResult<StatusCode> {
+    comment_service(&state)
+        .delete(claims.sub, id, comment_id)
+        .await?;
+    Ok(StatusCode::NO_CONTENT)
+}
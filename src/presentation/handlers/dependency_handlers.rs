@@ -0,0 +1,133 @@
+use axum::{
+    extract::{Extension, Path, Query, State},
+    Json,
+};
+use uuid::Uuid;
+
+use crate::application::dto::{
+    AddBlockerRequest, BlockerListResponse, NextTodosQuery, NextTodosResponse, TodoResponse,
+};
+use crate::application::services::DependencyService;
+use crate::infrastructure::auth::jwt::Claims;
+use crate::infrastructure::config::AppState;
+use crate::shared::error::AppResult;
+
+fn dependency_service(state: &AppState) -> DependencyService {
+    DependencyService::new(
+        state.todo_dependency_repository.clone(),
+        state.todo_repository.clone(),
+    )
+}
+
+/// List your open todos in the order they can be worked on
+#[utoipa::path(
+    get,
+    path = "/api/v1/todos/next",
+    params(
+        ("limit" = Option<usize>, Query, description = "Number of todos (default: 20, max: 100)")
+    ),
+    responses(
+        (status = 200, description = "Open todos, each after the todos blocking it", body = NextTodosResponse),
+        (status = 401, description = "Unauthorized")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "dependencies"
+)]
+pub async fn list_next_todos(
+    State(state): State<AppState>,
+    Extension(claims): Extension<Claims>,
+    Query(query): Query<NextTodosQuery>,
+) -> AppResult<Json<NextTodosResponse>> {
+    let response = dependency_service(&state).next(claims.sub, query).await?;
+    Ok(Json(response))
+}
+
+/// List the todos blocking a todo
+#[utoipa::path(
+    get,
+    path = "/api/v1/todos/{id}/blockers",
+    params(
+        ("id" = Uuid, Path, description = "Todo ID")
+    ),
+    responses(
+        (status = 200, description = "Todos blocking the todo", body = BlockerListResponse),
+        (status = 401, description = "Unauthorized"),
+        (status = 404, description = "Todo not found")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "dependencies"
+)]
+pub async fn list_blockers(
+    State(state): State<AppState>,
+    Extension(claims): Extension<Claims>,
+    Path(id): Path<Uuid>,
+) -> AppResult<Json<BlockerListResponse>> {
+    let response = dependency_service(&state).blockers(claims.sub, id).await?;
+    Ok(Json(response))
+}
+
+/// Mark a todo as blocked by another todo
+#[utoipa::path(
+    post,
+    path = "/api/v1/todos/{id}/blockers",
+    params(
+        ("id" = Uuid, Path, description = "Todo ID")
+    ),
+    request_body = AddBlockerRequest,
+    responses(
+        (status = 200, description = "Blocker added", body = TodoResponse),
+        (status = 400, description = "The link would make a cycle"),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Only the owner or an editor can change the todo"),
+        (status = 404, description = "Todo or blocker not found")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "dependencies"
+)]
+pub async fn add_blocker(
+    State(state): State<AppState>,
+    Extension(claims): Extension<Claims>,
+    Path(id): Path<Uuid>,
+    Json(request): Json<AddBlockerRequest>,
+) -> AppResult<Json<TodoResponse>> {
+    let response = dependency_service(&state)
+        .add_blocker(claims.sub, id, request)
+        .await?;
+    Ok(Json(response))
+}
+
+/// Remove a blocker from a todo
+#[utoipa::path(
+    delete,
+    path = "/api/v1/todos/{id}/blockers/{blocker_id}",
+    params(
+        ("id" = Uuid, Path, description = "Todo ID"),
+        ("blocker_id" = Uuid, Path, description = "Blocking todo ID")
+    ),
+    responses(
+        (status = 200, description = "Blocker removed", body = TodoResponse),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Only the owner or an editor can change the todo"),
+        (status = 404, description = "Todo or blocker not found")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "dependencies"
+)]
+pub async fn remove_blocker(
+    State(state): State<AppState>,
+    Extension(claims): Extension<Claims>,
+    Path((id, blocker_id)): Path<(Uuid, Uuid)>,
+) -> AppResult<Json<TodoResponse>> {
+    let response = dependency_service(&state)
+        .remove_blocker(claims.sub, id, blocker_id)
+        .await?;
+    Ok(Json(response))
+}
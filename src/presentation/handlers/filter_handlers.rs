@@ -0,0 +1,195 @@
+use axum::{
+    extract::{Extension, Path, Query, State},
+    http::StatusCode,
+    response::IntoResponse,
+    Json,
+};
+use uuid::Uuid;
+
+use crate::application::dto::{
+    CreateSavedFilterRequest, FilterTodosQuery, SavedFilterListResponse, SavedFilterResponse,
+    TodoListResponse, UpdateSavedFilterRequest,
+};
+use crate::application::services::SavedFilterService;
+use crate::domain::entities::{SortOrder, TodoSortField};
+use crate::infrastructure::auth::jwt::Claims;
+use crate::infrastructure::config::AppState;
+use crate::presentation::handlers::notification_handlers::notifier;
+use crate::shared::error::AppResult;
+
+fn saved_filter_service(state: &AppState) -> SavedFilterService {
+    SavedFilterService::new(
+        state.saved_filter_repository.clone(),
+        state.todo_repository.clone(),
+        state.workflow_repository.clone(),
+        notifier(state),
+    )
+}
+
+/// List your saved filters with their badge counts
+#[utoipa::path(
+    get,
+    path = "/api/v1/filters",
+    responses(
+        (status = 200, description = "Saved filters by name", body = SavedFilterListResponse),
+        (status = 401, description = "Unauthorized")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "filters"
+)]
+pub async fn list_filters(
+    State(state): State<AppState>,
+    Extension(claims): Extension<Claims>,
+) -> AppResult<Json<SavedFilterListResponse>> {
+    let response = saved_filter_service(&state).list(claims.sub).await?;
+    Ok(Json(response))
+}
+
+/// Save a filter
+#[utoipa::path(
+    post,
+    path = "/api/v1/filters",
+    request_body = CreateSavedFilterRequest,
+    responses(
+        (status = 201, description = "Filter saved", body = SavedFilterResponse),
+        (status = 400, description = "Validation error"),
+        (status = 401, description = "Unauthorized"),
+        (status = 409, description = "You already have a filter with this name")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "filters"
+)]
+pub async fn create_filter(
+    State(state): State<AppState>,
+    Extension(claims): Extension<Claims>,
+    Json(request): Json<CreateSavedFilterRequest>,
+) -> AppResult<impl IntoResponse> {
+    let response = saved_filter_service(&state)
+        .create(claims.sub, request)
+        .await?;
+    Ok((StatusCode::CREATED, Json(response)))
+}
+
+/// Get a saved filter with its badge counts
+#[utoipa::path(
+    get,
+    path = "/api/v1/filters/{id}",
+    params(
+        ("id" = Uuid, Path, description = "Filter ID")
+    ),
+    responses(
+        (status = 200, description = "Saved filter", body = SavedFilterResponse),
+        (status = 401, description = "Unauthorized"),
+        (status = 404, description = "Filter not found")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "filters"
+)]
+pub async fn get_filter(
+    State(state): State<AppState>,
+    Extension(claims): Extension<Claims>,
+    Path(id): Path<Uuid>,
+) -> AppResult<Json<SavedFilterResponse>> {
+    let response = saved_filter_service(&state).get(claims.sub, id).await?;
+    Ok(Json(response))
+}
+
+/// Rename or redefine a saved filter
+#[utoipa::path(
+    put,
+    path = "/api/v1/filters/{id}",
+    params(
+        ("id" = Uuid, Path, description = "Filter ID")
+    ),
+    request_body = UpdateSavedFilterRequest,
+    responses(
+        (status = 200, description = "Filter updated", body = SavedFilterResponse),
+        (status = 400, description = "Validation error"),
+        (status = 401, description = "Unauthorized"),
+        (status = 404, description = "Filter not found"),
+        (status = 409, description = "You already have a filter with this name")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "filters"
+)]
+pub async fn update_filter(
+    State(state): State<AppState>,
+    Extension(claims): Extension<Claims>,
+    Path(id): Path<Uuid>,
+    Json(request): Json<UpdateSavedFilterRequest>,
+) -> AppResult<Json<SavedFilterResponse>> {
+    let response = saved_filter_service(&state)
+        .update(claims.sub, id, request)
+        .await?;
+    Ok(Json(response))
+}
+
+/// Delete a saved filter
+#[utoipa::path(
+    delete,
+    path = "/api/v1/filters/{id}",
+    params(
+        ("id" = Uuid, Path, description = "Filter ID")
+    ),
+    responses(
+        (status = 204, description = "Filter deleted"),
+        (status = 401, description = "Unauthorized"),
+        (status = 404, description = "Filter not found")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "filters"
+)]
+pub async fn delete_filter(
+    State(state): State<AppState>,
+    Extension(claims): Extension<Claims>,
+    Path(id): Path<Uuid>,
+) -> AppResult<StatusCode> {
+    saved_filter_service(&state).delete(claims.sub, id).await?;
+    Ok(StatusCode::NO_CONTENT)
+}
+
+/// List the todos matching a saved filter, marking them as read
+#[utoipa::path(
+    get,
+    path = "/api/v1/filters/{id}/todos",
+    params(
+        ("id" = Uuid, Path, description = "Filter ID"),
+        ("page" = Option<i64>, Query, description = "Page number (default: 1)"),
+        ("per_page" = Option<i64>, Query, description = "Items per page (default: 20, max: 100)"),
+        ("sort" = Option<TodoSortField>, Query, description = "Sort field (default: created_at), as on GET /api/v1/todos"),
+        ("order" = Option<SortOrder>, Query, description = "Sort order (default: desc for timestamps and smart, asc for title and due_at)"),
+        ("cursor" = Option<String>, Query, description = "next_cursor or prev_cursor from a previous response; replaces page and must be used with the same sort and order"),
+        ("include_total" = Option<bool>, Query, description = "Count all matching todos (default: true)")
+    ),
+    responses(
+        (status = 200, description = "Matching todos", body = TodoListResponse),
+        (status = 400, description = "Invalid sort or cursor"),
+        (status = 401, description = "Unauthorized"),
+        (status = 404, description = "Filter not found")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "filters"
+)]
+pub async fn list_filter_todos(
+    State(state): State<AppState>,
+    Extension(claims): Extension<Claims>,
+    Path(id): Path<Uuid>,
+    Query(query): Query<FilterTodosQuery>,
+) -> AppResult<Json<TodoListResponse>> {
+    let response = saved_filter_service(&state)
+        .todos(claims.sub, id, query)
+        .await?;
+    Ok(Json(response))
+}
@@ -1,2 +1,15 @@
+pub mod attachment_handlers;
 pub mod auth_handlers;
+pub mod board_handlers;
+pub mod checklist_handlers;
+pub mod comment_handlers;
+pub mod dependency_handlers;
+pub mod filter_handlers;
+pub mod notification_handlers;
+pub mod project_handlers;
+pub mod reminder_handlers;
+pub mod share_handlers;
+pub mod time_entry_handlers;
 pub mod todo_handlers;
+pub mod workflow_handlers;
+pub mod workspace_handlers;
@@ -0,0 +1,153 @@
+use axum::{
+    extract::{Extension, Path, Query, State},
+    Json,
+};
+use uuid::Uuid;
+
+use crate::application::dto::{
+    MarkAllReadResponse, NotificationListQuery, NotificationListResponse,
+    NotificationPreferencesResponse, NotificationResponse, UpdateNotificationPreferencesRequest,
+};
+use crate::application::services::{NotificationService, Notifier};
+use crate::infrastructure::auth::jwt::Claims;
+use crate::infrastructure::config::AppState;
+use crate::shared::error::AppResult;
+
+fn notification_service(state: &AppState) -> NotificationService {
+    NotificationService::new(state.notification_repository.clone())
+}
+
+/// Notifies users of the events handled by other services.
+pub fn notifier(state: &AppState) -> Notifier {
+    Notifier::new(
+        state.notification_repository.clone(),
+        state.user_repository.clone(),
+        state.notification_channels.clone(),
+    )
+}
+
+/// List your notifications
+#[utoipa::path(
+    get,
+    path = "/api/v1/notifications",
+    params(
+        ("unread" = Option<bool>, Query, description = "Only unread notifications (default: false)"),
+        ("cursor" = Option<String>, Query, description = "next_cursor from a previous response"),
+        ("per_page" = Option<i64>, Query, description = "Items per page (default: 20, max: 100)")
+    ),
+    responses(
+        (status = 200, description = "Notifications, newest first", body = NotificationListResponse),
+        (status = 400, description = "Invalid cursor"),
+        (status = 401, description = "Unauthorized")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "notifications"
+)]
+pub async fn list_notifications(
+    State(state): State<AppState>,
+    Extension(claims): Extension<Claims>,
+    Query(query): Query<NotificationListQuery>,
+) -> AppResult<Json<NotificationListResponse>> {
+    let response = notification_service(&state).list(claims.sub, query).await?;
+    Ok(Json(response))
+}
+
+/// Mark a notification read
+#[utoipa::path(
+    post,
+    path = "/api/v1/notifications/{id}/read",
+    params(
+        ("id" = Uuid, Path, description = "Notification ID")
+    ),
+    responses(
+        (status = 200, description = "The notification, read", body = NotificationResponse),
+        (status = 401, description = "Unauthorized"),
+        (status = 404, description = "Notification not found")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "notifications"
+)]
+pub async fn mark_notification_read(
+    State(state): State<AppState>,
+    Extension(claims): Extension<Claims>,
+    Path(id): Path<Uuid>,
+) -> AppResult<Json<NotificationResponse>> {
+    let response = notification_service(&state)
+        .mark_read(claims.sub, id)
+        .await?;
+    Ok(Json(response))
+}
+
+/// Mark all your notifications read
+#[utoipa::path(
+    post,
+    path = "/api/v1/notifications/read-all",
+    responses(
+        (status = 200, description = "How many notifications were unread", body = MarkAllReadResponse),
+        (status = 401, description = "Unauthorized")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "notifications"
+)]
+pub async fn mark_all_notifications_read(
+    State(state): State<AppState>,
+    Extension(claims): Extension<Claims>,
+) -> AppResult<Json<MarkAllReadResponse>> {
+    let response = notification_service(&state)
+        .mark_all_read(claims.sub)
+        .await?;
+    Ok(Json(response))
+}
+
+/// Get your notification preferences
+#[utoipa::path(
+    get,
+    path = "/api/v1/notifications/preferences",
+    responses(
+        (status = 200, description = "Preferences of every event", body = NotificationPreferencesResponse),
+        (status = 401, description = "Unauthorized")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "notifications"
+)]
+pub async fn get_notification_preferences(
+    State(state): State<AppState>,
+    Extension(claims): Extension<Claims>,
+) -> AppResult<Json<NotificationPreferencesResponse>> {
+    let response = notification_service(&state).preferences(claims.sub).await?;
+    Ok(Json(response))
+}
+
+/// Choose which events notify you in the app and by email
+#[utoipa::path(
+    put,
+    path = "/api/v1/notifications/preferences",
+    request_body = UpdateNotificationPreferencesRequest,
+    responses(
+        (status = 200, description = "Preferences of every event", body = NotificationPreferencesResponse),
+        (status = 400, description = "An event is listed twice"),
+        (status = 401, description = "Unauthorized")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "notifications"
+)]
+pub async fn update_notification_preferences(
+    State(state): State<AppState>,
+    Extension(claims): Extension<Claims>,
+    Json(request): Json<UpdateNotificationPreferencesRequest>,
+) -> AppResult<Json<NotificationPreferencesResponse>> {
+    let response = notification_service(&state)
+        .update_preferences(claims.sub, request)
+        .await?;
+    Ok(Json(response))
+}
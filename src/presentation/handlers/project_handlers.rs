@@ -0,0 +1,213 @@
+use axum::{
+    extract::{Extension, Path, State},
+    http::StatusCode,
+    response::IntoResponse,
+    Json,
+};
+use uuid::Uuid;
+
+use crate::application::dto::{
+    CreateProjectRequest, ProjectListResponse, ProjectResponse, UpdateProjectRequest,
+    UpdateWorkflowRequest, WorkflowResponse,
+};
+use crate::application::services::ProjectService;
+use crate::infrastructure::config::AppState;
+use crate::presentation::middleware::WorkspaceContext;
+use crate::shared::error::AppResult;
+
+fn project_service(state: &AppState) -> ProjectService {
+    ProjectService::new(
+        state.project_repository.clone(),
+        state.workflow_repository.clone(),
+    )
+}
+
+/// List the workspace's projects
+#[utoipa::path(
+    get,
+    path = "/api/v1/projects",
+    responses(
+        (status = 200, description = "Projects by name", body = ProjectListResponse),
+        (status = 401, description = "Unauthorized")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "projects"
+)]
+pub async fn list_projects(State(state): State<AppState>) -> AppResult<Json<ProjectListResponse>> {
+    let response = project_service(&state).list().await?;
+    Ok(Json(response))
+}
+
+/// Create a project
+#[utoipa::path(
+    post,
+    path = "/api/v1/projects",
+    request_body = CreateProjectRequest,
+    responses(
+        (status = 201, description = "Project created", body = ProjectResponse),
+        (status = 400, description = "Validation error"),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Only owners and admins can manage projects"),
+        (status = 409, description = "The workspace already has a project with this name")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "projects"
+)]
+pub async fn create_project(
+    State(state): State<AppState>,
+    Extension(workspace): Extension<WorkspaceContext>,
+    Json(request): Json<CreateProjectRequest>,
+) -> AppResult<impl IntoResponse> {
+    let response = project_service(&state)
+        .create(workspace.workspace_id, workspace.role, request)
+        .await?;
+    Ok((StatusCode::CREATED, Json(response)))
+}
+
+/// Get a project
+#[utoipa::path(
+    get,
+    path = "/api/v1/projects/{id}",
+    params(
+        ("id" = Uuid, Path, description = "Project ID")
+    ),
+    responses(
+        (status = 200, description = "Project", body = ProjectResponse),
+        (status = 401, description = "Unauthorized"),
+        (status = 404, description = "Project not found")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "projects"
+)]
+pub async fn get_project(
+    State(state): State<AppState>,
+    Path(id): Path<Uuid>,
+) -> AppResult<Json<ProjectResponse>> {
+    let response = project_service(&state).get(id).await?;
+    Ok(Json(response))
+}
+
+/// Rename a project
+#[utoipa::path(
+    put,
+    path = "/api/v1/projects/{id}",
+    params(
+        ("id" = Uuid, Path, description = "Project ID")
+    ),
+    request_body = UpdateProjectRequest,
+    responses(
+        (status = 200, description = "Project renamed", body = ProjectResponse),
+        (status = 400, description = "Validation error"),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Only owners and admins can manage projects"),
+        (status = 404, description = "Project not found"),
+        (status = 409, description = "The workspace already has a project with this name")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "projects"
+)]
+pub async fn update_project(
+    State(state): State<AppState>,
+    Extension(workspace): Extension<WorkspaceContext>,
+    Path(id): Path<Uuid>,
+    Json(request): Json<UpdateProjectRequest>,
+) -> AppResult<Json<ProjectResponse>> {
+    let response = project_service(&state)
+        .update(workspace.role, id, request)
+        .await?;
+    Ok(Json(response))
+}
+
+/// Delete a project without todos
+#[utoipa::path(
+    delete,
+    path = "/api/v1/projects/{id}",
+    params(
+        ("id" = Uuid, Path, description = "Project ID")
+    ),
+    responses(
+        (status = 204, description = "Project deleted"),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Only owners and admins can manage projects"),
+        (status = 404, description = "Project not found"),
+        (status = 409, description = "Todos, trashed ones included, are still in the project")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "projects"
+)]
+pub async fn delete_project(
+    State(state): State<AppState>,
+    Extension(workspace): Extension<WorkspaceContext>,
+    Path(id): Path<Uuid>,
+) -> AppResult<StatusCode> {
+    project_service(&state).delete(workspace.role, id).await?;
+    Ok(StatusCode::NO_CONTENT)
+}
+
+/// Get the workflow statuses the project's todos move through
+#[utoipa::path(
+    get,
+    path = "/api/v1/projects/{id}/workflow",
+    params(
+        ("id" = Uuid, Path, description = "Project ID")
+    ),
+    responses(
+        (status = 200, description = "The project's own workflow, or the workspace's if it has none", body = WorkflowResponse),
+        (status = 401, description = "Unauthorized"),
+        (status = 404, description = "Project not found")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "projects"
+)]
+pub async fn get_project_workflow(
+    State(state): State<AppState>,
+    Path(id): Path<Uuid>,
+) -> AppResult<Json<WorkflowResponse>> {
+    let response = project_service(&state).workflow(id).await?;
+    Ok(Json(response))
+}
+
+/// Give the project workflow statuses of its own
+#[utoipa::path(
+    put,
+    path = "/api/v1/projects/{id}/workflow",
+    params(
+        ("id" = Uuid, Path, description = "Project ID")
+    ),
+    request_body = UpdateWorkflowRequest,
+    responses(
+        (status = 200, description = "Workflow replaced", body = WorkflowResponse),
+        (status = 400, description = "Invalid workflow"),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Only owners and admins can change the workflow"),
+        (status = 404, description = "Project not found"),
+        (status = 409, description = "The project's todos are still in a status the workflow drops")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "projects"
+)]
+pub async fn update_project_workflow(
+    State(state): State<AppState>,
+    Extension(workspace): Extension<WorkspaceContext>,
+    Path(id): Path<Uuid>,
+    Json(request): Json<UpdateWorkflowRequest>,
+) -> AppResult<Json<WorkflowResponse>> {
+    let response = project_service(&state)
+        .replace_workflow(workspace.role, id, request)
+        .await?;
+    Ok(Json(response))
+}
@@ -0,0 +1,137 @@
+use axum::{
+    extract::{Extension, Path, State},
+    http::StatusCode,
+    response::IntoResponse,
+    Json,
+};
+use uuid::Uuid;
+
+use crate::application::dto::{
+    CreateReminderRequest, ReminderDeliveryListResponse, ReminderListResponse, ReminderResponse,
+};
+use crate::application::services::ReminderService;
+use crate::infrastructure::auth::jwt::Claims;
+use crate::infrastructure::config::AppState;
+use crate::shared::error::AppResult;
+
+fn reminder_service(state: &AppState) -> ReminderService {
+    ReminderService::new(
+        state.reminder_repository.clone(),
+        state.todo_repository.clone(),
+    )
+}
+
+/// List your reminders of a todo
+#[utoipa::path(
+    get,
+    path = "/api/v1/todos/{id}/reminders",
+    params(
+        ("id" = Uuid, Path, description = "Todo ID")
+    ),
+    responses(
+        (status = 200, description = "Your reminders, oldest first", body = ReminderListResponse),
+        (status = 401, description = "Unauthorized"),
+        (status = 404, description = "Todo not found")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "reminders"
+)]
+pub async fn list_reminders(
+    State(state): State<AppState>,
+    Extension(claims): Extension<Claims>,
+    Path(id): Path<Uuid>,
+) -> AppResult<Json<ReminderListResponse>> {
+    let response = reminder_service(&state).list(claims.sub, id).await?;
+    Ok(Json(response))
+}
+
+/// Set a reminder of a todo
+#[utoipa::path(
+    post,
+    path = "/api/v1/todos/{id}/reminders",
+    params(
+        ("id" = Uuid, Path, description = "Todo ID")
+    ),
+    request_body = CreateReminderRequest,
+    responses(
+        (status = 201, description = "Reminder created", body = ReminderResponse),
+        (status = 400, description = "Validation error"),
+        (status = 401, description = "Unauthorized"),
+        (status = 404, description = "Todo not found")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "reminders"
+)]
+pub async fn create_reminder(
+    State(state): State<AppState>,
+    Extension(claims): Extension<Claims>,
+    Path(id): Path<Uuid>,
+    Json(request): Json<CreateReminderRequest>,
+) -> AppResult<impl IntoResponse> {
+    let response = reminder_service(&state)
+        .create(claims.sub, id, request)
+        .await?;
+    Ok((StatusCode::CREATED, Json(response)))
+}
+
+/// Delete a reminder
+#[utoipa::path(
+    delete,
+    path = "/api/v1/todos/{id}/reminders/{reminder_id}",
+    params(
+        ("id" = Uuid, Path, description = "Todo ID"),
+        ("reminder_id" = Uuid, Path, description = "Reminder ID")
+    ),
+    responses(
+        (status = 204, description = "Reminder deleted"),
+        (status = 401, description = "Unauthorized"),
+        (status = 404, description = "Todo or reminder not found")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "reminders"
+)]
+pub async fn delete_reminder(
+    State(state): State<AppState>,
+    Extension(claims): Extension<Claims>,
+    Path((id, reminder_id)): Path<(Uuid, Uuid)>,
+) -> AppResult<StatusCode> {
+    reminder_service(&state)
+        .delete(claims.sub, id, reminder_id)
+        .await?;
+    Ok(StatusCode::NO_CONTENT)
+}
+
+/// List the deliveries of a reminder
+#[utoipa::path(
+    get,
+    path = "/api/v1/todos/{id}/reminders/{reminder_id}/deliveries",
+    params(
+        ("id" = Uuid, Path, description = "Todo ID"),
+        ("reminder_id" = Uuid, Path, description = "Reminder ID")
+    ),
+    responses(
+        (status = 200, description = "Delivery log, most recent first", body = ReminderDeliveryListResponse),
+        (status = 401, description = "Unauthorized"),
+        (status = 404, description = "Todo or reminder not found")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "reminders"
+)]
+pub async fn list_reminder_deliveries(
+    State(state): State<AppState>,
+    Extension(claims): Extension<Claims>,
+    Path((id, reminder_id)): Path<(Uuid, Uuid)>,
+) -> AppResult<Json<ReminderDeliveryListResponse>> {
+    let response = reminder_service(&state)
+        .deliveries(claims.sub, id, reminder_id)
+        .await?;
+    Ok(Json(response))
+}
@@ -0,0 +1,238 @@
+use axum::{
+    extract::{Extension, Path, Query, State},
+    http::StatusCode,
+    response::IntoResponse,
+    Json,
+};
+use uuid::Uuid;
+
+use crate::application::dto::{
+    PaginationQuery, ProjectShareListResponse, ProjectShareResponse, ShareTodoRequest,
+    SharedTodoListResponse, TodoShareListResponse, TodoShareResponse,
+};
+use crate::application::services::ShareService;
+use crate::infrastructure::auth::jwt::Claims;
+use crate::infrastructure::config::AppState;
+use crate::presentation::middleware::WorkspaceContext;
+use crate::shared::error::AppResult;
+
+fn share_service(state: &AppState) -> ShareService {
+    ShareService::new(
+        state.todo_share_repository.clone(),
+        state.project_share_repository.clone(),
+        state.todo_repository.clone(),
+        state.project_repository.clone(),
+        state.user_repository.clone(),
+        state.workspace_repository.clone(),
+    )
+}
+
+/// List todos other users have shared with you
+#[utoipa::path(
+    get,
+    path = "/api/v1/todos/shared",
+    params(
+        ("page" = Option<i64>, Query, description = "Page number (default: 1)"),
+        ("per_page" = Option<i64>, Query, description = "Items per page (default: 20, max: 100)")
+    ),
+    responses(
+        (status = 200, description = "Shared todos, most recently shared first", body = SharedTodoListResponse),
+        (status = 401, description = "Unauthorized")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "shares"
+)]
+pub async fn list_shared_todos(
+    State(state): State<AppState>,
+    Extension(claims): Extension<Claims>,
+    Query(pagination): Query<PaginationQuery>,
+) -> AppResult<Json<SharedTodoListResponse>> {
+    let response = share_service(&state)
+        .shared_with_me(claims.sub, pagination)
+        .await?;
+    Ok(Json(response))
+}
+
+/// List the users a todo is shared with
+#[utoipa::path(
+    get,
+    path = "/api/v1/todos/{id}/shares",
+    params(
+        ("id" = Uuid, Path, description = "Todo ID")
+    ),
+    responses(
+        (status = 200, description = "Shares, oldest first", body = TodoShareListResponse),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Only the owner can see who the todo is shared with"),
+        (status = 404, description = "Todo not found")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "shares"
+)]
+pub async fn list_shares(
+    State(state): State<AppState>,
+    Extension(claims): Extension<Claims>,
+    Path(id): Path<Uuid>,
+) -> AppResult<Json<TodoShareListResponse>> {
+    let response = share_service(&state).list(claims.sub, id).await?;
+    Ok(Json(response))
+}
+
+/// Share a todo with another user, or change their permission
+#[utoipa::path(
+    post,
+    path = "/api/v1/todos/{id}/shares",
+    params(
+        ("id" = Uuid, Path, description = "Todo ID")
+    ),
+    request_body = ShareTodoRequest,
+    responses(
+        (status = 201, description = "Todo shared", body = TodoShareResponse),
+        (status = 400, description = "Validation error or user not in the workspace"),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Only the owner can share the todo"),
+        (status = 404, description = "Todo or user not found")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "shares"
+)]
+pub async fn share_todo(
+    State(state): State<AppState>,
+    Extension(claims): Extension<Claims>,
+    Extension(workspace): Extension<WorkspaceContext>,
+    Path(id): Path<Uuid>,
+    Json(request): Json<ShareTodoRequest>,
+) -> AppResult<impl IntoResponse> {
+    let response = share_service(&state)
+        .share(claims.sub, workspace.workspace_id, id, request)
+        .await?;
+    Ok((StatusCode::CREATED, Json(response)))
+}
+
+/// Stop sharing a todo with a user
+#[utoipa::path(
+    delete,
+    path = "/api/v1/todos/{id}/shares/{user_id}",
+    params(
+        ("id" = Uuid, Path, description = "Todo ID"),
+        ("user_id" = Uuid, Path, description = "User the todo is shared with")
+    ),
+    responses(
+        (status = 204, description = "Share revoked"),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Only the owner can revoke other users' shares"),
+        (status = 404, description = "Todo or share not found")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "shares"
+)]
+pub async fn revoke_share(
+    State(state): State<AppState>,
+    Extension(claims): Extension<Claims>,
+    Path((id, user_id)): Path<(Uuid, Uuid)>,
+) -> AppResult<StatusCode> {
+    share_service(&state)
+        .revoke(claims.sub, id, user_id)
+        .await?;
+    Ok(StatusCode::NO_CONTENT)
+}
+
+/// List the users a project is shared with
+#[utoipa::path(
+    get,
+    path = "/api/v1/projects/{id}/shares",
+    params(
+        ("id" = Uuid, Path, description = "Project ID")
+    ),
+    responses(
+        (status = 200, description = "Shares, oldest first", body = ProjectShareListResponse),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Only owners and admins can see who the project is shared with"),
+        (status = 404, description = "Project not found")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "shares"
+)]
+pub async fn list_project_shares(
+    State(state): State<AppState>,
+    Extension(workspace): Extension<WorkspaceContext>,
+    Path(id): Path<Uuid>,
+) -> AppResult<Json<ProjectShareListResponse>> {
+    let response = share_service(&state)
+        .list_project_shares(workspace.role, id)
+        .await?;
+    Ok(Json(response))
+}
+
+/// Share a project, and every todo in it, with a member of the workspace
+#[utoipa::path(
+    post,
+    path = "/api/v1/projects/{id}/shares",
+    params(
+        ("id" = Uuid, Path, description = "Project ID")
+    ),
+    request_body = ShareTodoRequest,
+    responses(
+        (status = 201, description = "Project shared", body = ProjectShareResponse),
+        (status = 400, description = "Validation error or user not in the workspace"),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Only owners and admins can share projects"),
+        (status = 404, description = "Project or user not found")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "shares"
+)]
+pub async fn share_project(
+    State(state): State<AppState>,
+    Extension(workspace): Extension<WorkspaceContext>,
+    Path(id): Path<Uuid>,
+    Json(request): Json<ShareTodoRequest>,
+) -> AppResult<impl IntoResponse> {
+    let response = share_service(&state)
+        .share_project(workspace.workspace_id, workspace.role, id, request)
+        .await?;
+    Ok((StatusCode::CREATED, Json(response)))
+}
+
+/// Stop sharing a project with a user
+#[utoipa::path(
+    delete,
+    path = "/api/v1/projects/{id}/shares/{user_id}",
+    params(
+        ("id" = Uuid, Path, description = "Project ID"),
+        ("user_id" = Uuid, Path, description = "User the project is shared with")
+    ),
+    responses(
+        (status = 204, description = "Share revoked"),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Only owners and admins can revoke other users' shares"),
+        (status = 404, description = "Project or share not found")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "shares"
+)]
+pub async fn revoke_project_share(
+    State(state): State<AppState>,
+    Extension(claims): Extension<Claims>,
+    Extension(workspace): Extension<WorkspaceContext>,
+    Path((id, user_id)): Path<(Uuid, Uuid)>,
+) -> AppResult<StatusCode> {
+    share_service(&state)
+        .revoke_project_share(claims.sub, workspace.role, id, user_id)
+        .await?;
+    Ok(StatusCode::NO_CONTENT)
+}
@@ -0,0 +1,224 @@
+use axum::{
+    extract::{Extension, Path, Query, State},
+    http::{
+        header::{CONTENT_DISPOSITION, CONTENT_TYPE},
+        HeaderValue, StatusCode,
+    },
+    response::{IntoResponse, Response},
+    Json,
+};
+use uuid::Uuid;
+
+use crate::application::dto::{
+    CreateTimeEntryRequest, ReportFormat, TimeEntryListResponse, TimeEntryResponse,
+    TimeReportQuery, TimeReportResponse,
+};
+use crate::application::services::TimeEntryService;
+use crate::infrastructure::auth::jwt::Claims;
+use crate::infrastructure::config::AppState;
+use crate::shared::error::AppResult;
+
+fn time_entry_service(state: &AppState) -> TimeEntryService {
+    TimeEntryService::new(
+        state.time_entry_repository.clone(),
+        state.todo_repository.clone(),
+    )
+}
+
+/// Start a timer on a todo
+#[utoipa::path(
+    post,
+    path = "/api/v1/todos/{id}/timer/start",
+    params(
+        ("id" = Uuid, Path, description = "Todo ID")
+    ),
+    responses(
+        (status = 201, description = "Timer started", body = TimeEntryResponse),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "No permission to edit the todo"),
+        (status = 404, description = "Todo not found"),
+        (status = 409, description = "A timer of yours is already running")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "time"
+)]
+pub async fn start_timer(
+    State(state): State<AppState>,
+    Extension(claims): Extension<Claims>,
+    Path(id): Path<Uuid>,
+) -> AppResult<impl IntoResponse> {
+    let response = time_entry_service(&state)
+        .start_timer(claims.sub, id)
+        .await?;
+    Ok((StatusCode::CREATED, Json(response)))
+}
+
+/// Stop your timer on a todo
+#[utoipa::path(
+    post,
+    path = "/api/v1/todos/{id}/timer/stop",
+    params(
+        ("id" = Uuid, Path, description = "Todo ID")
+    ),
+    responses(
+        (status = 200, description = "Timer stopped", body = TimeEntryResponse),
+        (status = 401, description = "Unauthorized"),
+        (status = 404, description = "Todo not found or no timer running on it")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "time"
+)]
+pub async fn stop_timer(
+    State(state): State<AppState>,
+    Extension(claims): Extension<Claims>,
+    Path(id): Path<Uuid>,
+) -> AppResult<Json<TimeEntryResponse>> {
+    let response = time_entry_service(&state)
+        .stop_timer(claims.sub, id)
+        .await?;
+    Ok(Json(response))
+}
+
+/// List the time tracked on a todo
+#[utoipa::path(
+    get,
+    path = "/api/v1/todos/{id}/time-entries",
+    params(
+        ("id" = Uuid, Path, description = "Todo ID")
+    ),
+    responses(
+        (status = 200, description = "Time entries of every user", body = TimeEntryListResponse),
+        (status = 401, description = "Unauthorized"),
+        (status = 404, description = "Todo not found")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "time"
+)]
+pub async fn list_time_entries(
+    State(state): State<AppState>,
+    Extension(claims): Extension<Claims>,
+    Path(id): Path<Uuid>,
+) -> AppResult<Json<TimeEntryListResponse>> {
+    let response = time_entry_service(&state).list(claims.sub, id).await?;
+    Ok(Json(response))
+}
+
+/// Enter time worked on a todo
+#[utoipa::path(
+    post,
+    path = "/api/v1/todos/{id}/time-entries",
+    params(
+        ("id" = Uuid, Path, description = "Todo ID")
+    ),
+    request_body = CreateTimeEntryRequest,
+    responses(
+        (status = 201, description = "Time entry created", body = TimeEntryResponse),
+        (status = 400, description = "Validation error"),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "No permission to edit the todo"),
+        (status = 404, description = "Todo not found")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "time"
+)]
+pub async fn create_time_entry(
+    State(state): State<AppState>,
+    Extension(claims): Extension<Claims>,
+    Path(id): Path<Uuid>,
+    Json(request): Json<CreateTimeEntryRequest>,
+) -> AppResult<impl IntoResponse> {
+    let response = time_entry_service(&state)
+        .create(claims.sub, id, request)
+        .await?;
+    Ok((StatusCode::CREATED, Json(response)))
+}
+
+/// Delete one of your time entries
+#[utoipa::path(
+    delete,
+    path = "/api/v1/todos/{id}/time-entries/{entry_id}",
+    params(
+        ("id" = Uuid, Path, description = "Todo ID"),
+        ("entry_id" = Uuid, Path, description = "Time entry ID")
+    ),
+    responses(
+        (status = 204, description = "Time entry deleted"),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "The entry is another user's"),
+        (status = 404, description = "Todo or time entry not found")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "time"
+)]
+pub async fn delete_time_entry(
+    State(state): State<AppState>,
+    Extension(claims): Extension<Claims>,
+    Path((id, entry_id)): Path<(Uuid, Uuid)>,
+) -> AppResult<StatusCode> {
+    time_entry_service(&state)
+        .delete(claims.sub, id, entry_id)
+        .await?;
+    Ok(StatusCode::NO_CONTENT)
+}
+
+/// Report your tracked time over a range of days, in every workspace
+#[utoipa::path(
+    get,
+    path = "/api/v1/reports/time",
+    params(
+        ("from" = String, Query, description = "First day, e.g. 2026-10-01"),
+        ("to" = String, Query, description = "Last day, inclusive; at most 366 days after from"),
+        ("time_zone" = Option<String>, Query, description = "IANA time zone the days are in (default: UTC)"),
+        ("group_by" = Option<String>, Query, description = "Comma-separated dimensions: day, workspace, project, tag (default: day)"),
+        ("format" = Option<ReportFormat>, Query, description = "json (default) or csv")
+    ),
+    responses(
+        (status = 200, description = "Tracked time per group", body = TimeReportResponse),
+        (status = 200, description = "The rows as CSV when format=csv", content_type = "text/csv"),
+        (status = 400, description = "Invalid range, time zone or grouping"),
+        (status = 401, description = "Unauthorized")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "time"
+)]
+pub async fn get_time_report(
+    State(state): State<AppState>,
+    Extension(claims): Extension<Claims>,
+    Query(query): Query<TimeReportQuery>,
+) -> AppResult<Response> {
+    let report = time_entry_service(&state)
+        .report(claims.sub, &query)
+        .await?;
+
+    if query.format == ReportFormat::Json {
+        return Ok(Json(report).into_response());
+    }
+    let file_name = format!("time-report-{}-{}.csv", report.from, report.to);
+    Ok((
+        [
+            (
+                CONTENT_TYPE,
+                HeaderValue::from_static("text/csv; charset=utf-8"),
+            ),
+            (
+                CONTENT_DISPOSITION,
+                HeaderValue::from_str(&format!("attachment; filename=\"{}\"", file_name))
+                    .unwrap_or_else(|_| HeaderValue::from_static("attachment")),
+            ),
+        ],
+        report.to_csv(),
+    )
+        .into_response())
+}
@@ -1,17 +1,28 @@
 use axum::{
+    body::Bytes,
     extract::{Extension, Path, Query, State},
-    http::StatusCode,
+    http::{
+        header::{CONTENT_TYPE, ETAG},
+        HeaderMap, StatusCode,
+    },
+    response::{IntoResponse, Response},
     Json,
 };
 use uuid::Uuid;
 
 use crate::application::dto::{
-    CreateTodoRequest, PaginationQuery, TodoListResponse, TodoResponse, UpdateTodoRequest,
+    AssignTodoRequest, CreateTodoRequest, OccurrencePreviewQuery, OccurrencePreviewResponse,
+    PaginationQuery, PatchTodoRequest, QuickAddTodoRequest, TodoBatchRequest, TodoBatchResponse,
+    TodoHistoryListResponse, TodoListQuery, TodoListResponse, TodoResponse, TodoSearchQuery,
+    TodoSearchResponse, TodoWriteQuery, UpdateTodoRequest,
 };
 use crate::application::services::TodoService;
+use crate::domain::entities::{SearchLanguage, SortOrder, TodoPriority, TodoSortField};
 use crate::infrastructure::auth::jwt::Claims;
 use crate::infrastructure::config::AppState;
-use crate::shared::error::AppResult;
+use crate::presentation::etag::{etag, if_match, if_none_match};
+use crate::presentation::handlers::notification_handlers::notifier;
+use crate::shared::error::{AppError, AppResult};
 
 /// List all todos for authenticated user
 #[utoipa::path(
@@ -19,10 +30,27 @@ use crate::shared::error::AppResult;
     path = "/api/v1/todos",
     params(
         ("page" = Option<i64>, Query, description = "Page number (default: 1)"),
-        ("per_page" = Option<i64>, Query, description = "Items per page (default: 20, max: 100)")
+        ("per_page" = Option<i64>, Query, description = "Items per page (default: 20, max: 100)"),
+        ("completed" = Option<bool>, Query, description = "Only completed (true) or open (false) todos"),
+        ("status" = Option<String>, Query, description = "Only todos in this workflow status"),
+        ("priority" = Option<TodoPriority>, Query, description = "Only todos with this priority"),
+        ("tag" = Option<String>, Query, description = "Only todos with this tag"),
+        ("created_after" = Option<DateTime<Utc>>, Query, description = "Created at or after this time"),
+        ("created_before" = Option<DateTime<Utc>>, Query, description = "Created before this time"),
+        ("updated_after" = Option<DateTime<Utc>>, Query, description = "Updated at or after this time"),
+        ("updated_before" = Option<DateTime<Utc>>, Query, description = "Updated before this time"),
+        ("due_after" = Option<DateTime<Utc>>, Query, description = "Due at or after this time"),
+        ("due_before" = Option<DateTime<Utc>>, Query, description = "Due before this time"),
+        ("assignee" = Option<String>, Query, description = "Only todos assigned to `me` or to a user ID, including todos shared with you"),
+        ("q" = Option<String>, Query, description = "Only your todos matching an expression such as `status:open tag:work due<2026-11-01 (priority:high OR assignee:me) -tag:later`. Fields: status (a key, open or closed), priority, tag, assignee (me, none or a user ID), due (or none), created and updated; priority and dates also compare with <, <=, > and >=. Dates are UTC days (YYYY-MM-DD) or RFC 3339 times. Terms must all match unless joined by OR; - or NOT negates, parentheses group, and other words or \"phrases\" are searched for. Errors give the position in `details`"),
+        ("sort" = Option<TodoSortField>, Query, description = "Sort field (default: created_at). `smart` ranks open todos by priority, due date and age, pages by page only and rejects completed=true"),
+        ("order" = Option<SortOrder>, Query, description = "Sort order (default: desc for timestamps and smart, asc for title and due_at)"),
+        ("cursor" = Option<String>, Query, description = "next_cursor or prev_cursor from a previous response; replaces page and must be used with the same sort, order and filters"),
+        ("include_total" = Option<bool>, Query, description = "Count all matching todos (default: true)")
     ),
     responses(
         (status = 200, description = "List of todos", body = TodoListResponse),
+        (status = 400, description = "Invalid filter or q expression"),
         (status = 401, description = "Unauthorized")
     ),
     security(
@@ -33,10 +61,48 @@ use crate::shared::error::AppResult;
 pub async fn list_todos(
     State(state): State<AppState>,
     Extension(claims): Extension<Claims>,
-    Query(pagination): Query<PaginationQuery>,
+    Query(query): Query<TodoListQuery>,
 ) -> AppResult<Json<TodoListResponse>> {
-    let service = TodoService::new(state.todo_repository.clone());
-    let response = service.list(claims.sub, pagination).await?;
+    let service = TodoService::new(
+        state.todo_repository.clone(),
+        state.workflow_repository.clone(),
+        notifier(&state),
+    );
+    let response = service.list(claims.sub, query).await?;
+    Ok(Json(response))
+}
+
+/// Search todos by title and description
+#[utoipa::path(
+    get,
+    path = "/api/v1/todos/search",
+    params(
+        ("q" = String, Query, description = "Search terms; \"quoted phrases\" match adjacent words and a trailing * matches a prefix"),
+        ("lang" = Option<SearchLanguage>, Query, description = "english, simple or japanese (default: japanese if the query contains Japanese text, otherwise english)"),
+        ("page" = Option<i64>, Query, description = "Page number (default: 1)"),
+        ("per_page" = Option<i64>, Query, description = "Items per page (default: 20, max: 100)")
+    ),
+    responses(
+        (status = 200, description = "Matching todos, most relevant first", body = TodoSearchResponse),
+        (status = 400, description = "Invalid search query"),
+        (status = 401, description = "Unauthorized")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "todos"
+)]
+pub async fn search_todos(
+    State(state): State<AppState>,
+    Extension(claims): Extension<Claims>,
+    Query(query): Query<TodoSearchQuery>,
+) -> AppResult<Json<TodoSearchResponse>> {
+    let service = TodoService::new(
+        state.todo_repository.clone(),
+        state.workflow_repository.clone(),
+        notifier(&state),
+    );
+    let response = service.search(claims.sub, query).await?;
     Ok(Json(response))
 }
 
@@ -45,10 +111,13 @@ pub async fn list_todos(
     get,
     path = "/api/v1/todos/{id}",
     params(
-        ("id" = Uuid, Path, description = "Todo ID")
+        ("id" = Uuid, Path, description = "Todo ID"),
+        ("If-None-Match" = Option<String>, Header, description = "ETag of a cached copy; answered with 304 if it is still current")
     ),
     responses(
-        (status = 200, description = "Todo details", body = TodoResponse),
+        (status = 200, description = "Todo details", body = TodoResponse,
+            headers(("ETag" = String, description = "Current version of the todo"))),
+        (status = 304, description = "The cached copy is current"),
         (status = 401, description = "Unauthorized"),
         (status = 404, description = "Todo not found")
     ),
@@ -61,10 +130,19 @@ pub async fn get_todo(
     State(state): State<AppState>,
     Extension(claims): Extension<Claims>,
     Path(id): Path<Uuid>,
-) -> AppResult<Json<TodoResponse>> {
-    let service = TodoService::new(state.todo_repository.clone());
+    headers: HeaderMap,
+) -> AppResult<Response> {
+    let service = TodoService::new(
+        state.todo_repository.clone(),
+        state.workflow_repository.clone(),
+        notifier(&state),
+    );
     let response = service.get(claims.sub, id).await?;
-    Ok(Json(response))
+    let tag = etag(response.version);
+    if if_none_match(&headers, response.version) {
+        return Ok((StatusCode::NOT_MODIFIED, [(ETAG, tag)]).into_response());
+    }
+    Ok(([(ETAG, tag)], Json(response)).into_response())
 }
 
 /// Create a new todo
@@ -73,7 +151,8 @@ pub async fn get_todo(
     path = "/api/v1/todos",
     request_body = CreateTodoRequest,
     responses(
-        (status = 201, description = "Todo created", body = TodoResponse),
+        (status = 201, description = "Todo created", body = TodoResponse,
+            headers(("ETag" = String, description = "Current version of the todo"))),
         (status = 400, description = "Validation error"),
         (status = 401, description = "Unauthorized")
     ),
@@ -86,25 +165,114 @@ pub async fn create_todo(
     State(state): State<AppState>,
     Extension(claims): Extension<Claims>,
     Json(request): Json<CreateTodoRequest>,
-) -> AppResult<(StatusCode, Json<TodoResponse>)> {
-    let service = TodoService::new(state.todo_repository.clone());
+) -> AppResult<impl IntoResponse> {
+    let service = TodoService::new(
+        state.todo_repository.clone(),
+        state.workflow_repository.clone(),
+        notifier(&state),
+    );
     let response = service.create(claims.sub, request).await?;
-    Ok((StatusCode::CREATED, Json(response)))
+    Ok((
+        StatusCode::CREATED,
+        [(ETAG, etag(response.version))],
+        Json(response),
+    ))
+}
+
+/// Create a todo from one line of text
+///
+/// Picks the due date and time, recurrence, `#tags` and `!priority` out of
+/// English or Japanese text such as `Pay rent tomorrow 9am #finance !high
+/// every month` or `明日9時に家賃を払う #家計 !高 毎月`; the rest is the title.
+/// Relative dates are read in the request's `time_zone`, which falls back to
+/// UTC, so clients should send the user's zone.
+#[utoipa::path(
+    post,
+    path = "/api/v1/todos/quick",
+    request_body = QuickAddTodoRequest,
+    responses(
+        (status = 201, description = "Todo created", body = TodoResponse,
+            headers(("ETag" = String, description = "Current version of the todo"))),
+        (status = 400, description = "No title left, or an unknown time zone"),
+        (status = 401, description = "Unauthorized")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "todos"
+)]
+pub async fn quick_add_todo(
+    State(state): State<AppState>,
+    Extension(claims): Extension<Claims>,
+    Json(request): Json<QuickAddTodoRequest>,
+) -> AppResult<impl IntoResponse> {
+    let service = TodoService::new(
+        state.todo_repository.clone(),
+        state.workflow_repository.clone(),
+        notifier(&state),
+    );
+    let response = service.quick_add(claims.sub, request).await?;
+    Ok((
+        StatusCode::CREATED,
+        [(ETAG, etag(response.version))],
+        Json(response),
+    ))
 }
 
-/// Update a todo
+/// Apply a batch of create, update and delete operations
+#[utoipa::path(
+    post,
+    path = "/api/v1/todos/batch",
+    request_body = TodoBatchRequest,
+    responses(
+        (status = 200, description = "Batch applied; see each result for its outcome", body = TodoBatchResponse),
+        (status = 400, description = "Empty or oversized batch"),
+        (status = 401, description = "Unauthorized"),
+        (status = 422, description = "An operation failed in all_or_nothing mode; nothing was written", body = TodoBatchResponse)
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "todos"
+)]
+pub async fn batch_todos(
+    State(state): State<AppState>,
+    Extension(claims): Extension<Claims>,
+    Json(request): Json<TodoBatchRequest>,
+) -> AppResult<(StatusCode, Json<TodoBatchResponse>)> {
+    let service = TodoService::new(
+        state.todo_repository.clone(),
+        state.workflow_repository.clone(),
+        notifier(&state),
+    );
+    let response = service.batch(claims.sub, request).await?;
+    let status = if response.failed > 0 && !response.committed {
+        StatusCode::UNPROCESSABLE_ENTITY
+    } else {
+        StatusCode::OK
+    };
+    Ok((status, Json(response)))
+}
+
+/// Replace a todo
 #[utoipa::path(
     put,
     path = "/api/v1/todos/{id}",
     params(
-        ("id" = Uuid, Path, description = "Todo ID")
+        ("id" = Uuid, Path, description = "Todo ID"),
+        ("If-Match" = Option<String>, Header, description = "Only apply the change if the todo's ETag still matches"),
+        ("force" = Option<bool>, Query, description = "Complete the todo even while open todos block it (default: false)")
     ),
     request_body = UpdateTodoRequest,
     responses(
-        (status = 200, description = "Todo updated", body = TodoResponse),
-        (status = 400, description = "Validation error"),
+        (status = 200, description = "Todo replaced", body = TodoResponse,
+            headers(("ETag" = String, description = "New version of the todo"))),
+        (status = 400, description = "Validation error, a move the workflow does not allow, or completing a blocked todo without force"),
         (status = 401, description = "Unauthorized"),
-        (status = 404, description = "Todo not found")
+        (status = 403, description = "Only the owner or an editor can change the todo"),
+        (status = 404, description = "Todo not found"),
+        (status = 409, description = "Todo was modified by a concurrent request"),
+        (status = 412, description = "If-Match does not match the current version")
     ),
     security(
         ("bearer_auth" = [])
@@ -115,36 +283,390 @@ pub async fn update_todo(
     State(state): State<AppState>,
     Extension(claims): Extension<Claims>,
     Path(id): Path<Uuid>,
+    Query(query): Query<TodoWriteQuery>,
+    headers: HeaderMap,
     Json(request): Json<UpdateTodoRequest>,
+) -> AppResult<impl IntoResponse> {
+    let service = TodoService::new(
+        state.todo_repository.clone(),
+        state.workflow_repository.clone(),
+        notifier(&state),
+    );
+    let response = service
+        .update(claims.sub, id, request, if_match(&headers), query.force)
+        .await?;
+    Ok(([(ETAG, etag(response.version))], Json(response)))
+}
+
+/// Partially update a todo
+///
+/// Accepts a JSON Merge Patch, where `null` clears a field, or a JSON Patch
+/// when sent as `application/json-patch+json`.
+#[utoipa::path(
+    patch,
+    path = "/api/v1/todos/{id}",
+    params(
+        ("id" = Uuid, Path, description = "Todo ID"),
+        ("If-Match" = Option<String>, Header, description = "Only apply the change if the todo's ETag still matches"),
+        ("force" = Option<bool>, Query, description = "Complete the todo even while open todos block it (default: false)")
+    ),
+    request_body(
+        content(
+            (PatchTodoRequest = "application/merge-patch+json"),
+            (json_patch::Patch = "application/json-patch+json")
+        )
+    ),
+    responses(
+        (status = 200, description = "Todo updated", body = TodoResponse,
+            headers(("ETag" = String, description = "New version of the todo"))),
+        (status = 400, description = "Validation error, failed patch operation, a move the workflow does not allow, or completing a blocked todo without force"),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Only the owner or an editor can change the todo"),
+        (status = 404, description = "Todo not found"),
+        (status = 409, description = "Todo was modified by a concurrent request"),
+        (status = 412, description = "If-Match does not match the current version")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "todos"
+)]
+pub async fn patch_todo(
+    State(state): State<AppState>,
+    Extension(claims): Extension<Claims>,
+    Path(id): Path<Uuid>,
+    Query(query): Query<TodoWriteQuery>,
+    headers: HeaderMap,
+    body: Bytes,
+) -> AppResult<impl IntoResponse> {
+    let service = TodoService::new(
+        state.todo_repository.clone(),
+        state.workflow_repository.clone(),
+        notifier(&state),
+    );
+    let content_type = headers
+        .get(CONTENT_TYPE)
+        .and_then(|v| v.to_str().ok())
+        .unwrap_or_default();
+
+    let response = if content_type.starts_with("application/json-patch+json") {
+        let operations = serde_json::from_slice(&body)
+            .map_err(|e| AppError::Validation(format!("Invalid JSON Patch: {}", e)))?;
+        service
+            .json_patch(claims.sub, id, operations, if_match(&headers), query.force)
+            .await?
+    } else {
+        let request: PatchTodoRequest = serde_json::from_slice(&body)
+            .map_err(|e| AppError::Validation(format!("Invalid merge patch: {}", e)))?;
+        service
+            .patch(claims.sub, id, request, if_match(&headers), query.force)
+            .await?
+    };
+    Ok(([(ETAG, etag(response.version))], Json(response)))
+}
+
+/// Assign a todo
+#[utoipa::path(
+    put,
+    path = "/api/v1/todos/{id}/assignee",
+    params(
+        ("id" = Uuid, Path, description = "Todo ID"),
+        ("If-Match" = Option<String>, Header, description = "Only apply the change if the todo's ETag still matches")
+    ),
+    request_body = AssignTodoRequest,
+    responses(
+        (status = 200, description = "Todo assigned", body = TodoResponse,
+            headers(("ETag" = String, description = "New version of the todo"))),
+        (status = 400, description = "The assignee has no access to the todo"),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Only the owner or an editor can assign the todo"),
+        (status = 404, description = "Todo not found"),
+        (status = 409, description = "Todo was modified by a concurrent request"),
+        (status = 412, description = "If-Match does not match the current version")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "todos"
+)]
+pub async fn assign_todo(
+    State(state): State<AppState>,
+    Extension(claims): Extension<Claims>,
+    Path(id): Path<Uuid>,
+    headers: HeaderMap,
+    Json(request): Json<AssignTodoRequest>,
+) -> AppResult<impl IntoResponse> {
+    let service = TodoService::new(
+        state.todo_repository.clone(),
+        state.workflow_repository.clone(),
+        notifier(&state),
+    );
+    let response = service
+        .assign(claims.sub, id, request, if_match(&headers))
+        .await?;
+    Ok(([(ETAG, etag(response.version))], Json(response)))
+}
+
+/// Unassign a todo
+#[utoipa::path(
+    delete,
+    path = "/api/v1/todos/{id}/assignee",
+    params(
+        ("id" = Uuid, Path, description = "Todo ID"),
+        ("If-Match" = Option<String>, Header, description = "Only apply the change if the todo's ETag still matches")
+    ),
+    responses(
+        (status = 200, description = "Todo unassigned", body = TodoResponse,
+            headers(("ETag" = String, description = "New version of the todo"))),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Only the owner, an editor or the assignee can unassign the todo"),
+        (status = 404, description = "Todo not found"),
+        (status = 409, description = "Todo was modified by a concurrent request"),
+        (status = 412, description = "If-Match does not match the current version")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "todos"
+)]
+pub async fn unassign_todo(
+    State(state): State<AppState>,
+    Extension(claims): Extension<Claims>,
+    Path(id): Path<Uuid>,
+    headers: HeaderMap,
+) -> AppResult<impl IntoResponse> {
+    let service = TodoService::new(
+        state.todo_repository.clone(),
+        state.workflow_repository.clone(),
+        notifier(&state),
+    );
+    let response = service.unassign(claims.sub, id, if_match(&headers)).await?;
+    Ok(([(ETAG, etag(response.version))], Json(response)))
+}
+
+/// Move a todo to the trash
+#[utoipa::path(
+    delete,
+    path = "/api/v1/todos/{id}",
+    params(
+        ("id" = Uuid, Path, description = "Todo ID"),
+        ("If-Match" = Option<String>, Header, description = "Only apply the change if the todo's ETag still matches")
+    ),
+    responses(
+        (status = 204, description = "Todo moved to the trash"),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Only the owner can delete the todo"),
+        (status = 404, description = "Todo not found"),
+        (status = 409, description = "Todo was modified by a concurrent request"),
+        (status = 412, description = "If-Match does not match the current version")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "todos"
+)]
+pub async fn delete_todo(
+    State(state): State<AppState>,
+    Extension(claims): Extension<Claims>,
+    Path(id): Path<Uuid>,
+    headers: HeaderMap,
+) -> AppResult<StatusCode> {
+    let service = TodoService::new(
+        state.todo_repository.clone(),
+        state.workflow_repository.clone(),
+        notifier(&state),
+    );
+    service.delete(claims.sub, id, if_match(&headers)).await?;
+    Ok(StatusCode::NO_CONTENT)
+}
+
+/// Preview the next occurrences of a recurring todo
+#[utoipa::path(
+    get,
+    path = "/api/v1/todos/{id}/occurrences",
+    params(
+        ("id" = Uuid, Path, description = "Todo ID"),
+        ("count" = Option<usize>, Query, description = "Number of occurrences (default: 5, max: 100)")
+    ),
+    responses(
+        (status = 200, description = "Upcoming occurrences", body = OccurrencePreviewResponse),
+        (status = 401, description = "Unauthorized"),
+        (status = 404, description = "Todo not found")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "todos"
+)]
+pub async fn preview_occurrences(
+    State(state): State<AppState>,
+    Extension(claims): Extension<Claims>,
+    Path(id): Path<Uuid>,
+    Query(query): Query<OccurrencePreviewQuery>,
+) -> AppResult<Json<OccurrencePreviewResponse>> {
+    let service = TodoService::new(
+        state.todo_repository.clone(),
+        state.workflow_repository.clone(),
+        notifier(&state),
+    );
+    let response = service.preview_occurrences(claims.sub, id, query).await?;
+    Ok(Json(response))
+}
+
+/// List todos in the trash
+#[utoipa::path(
+    get,
+    path = "/api/v1/todos/trash",
+    params(
+        ("page" = Option<i64>, Query, description = "Page number (default: 1)"),
+        ("per_page" = Option<i64>, Query, description = "Items per page (default: 20, max: 100)")
+    ),
+    responses(
+        (status = 200, description = "Trashed todos, most recently deleted first", body = TodoListResponse),
+        (status = 401, description = "Unauthorized")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "todos"
+)]
+pub async fn list_trash(
+    State(state): State<AppState>,
+    Extension(claims): Extension<Claims>,
+    Query(pagination): Query<PaginationQuery>,
+) -> AppResult<Json<TodoListResponse>> {
+    let service = TodoService::new(
+        state.todo_repository.clone(),
+        state.workflow_repository.clone(),
+        notifier(&state),
+    );
+    let response = service.list_trash(claims.sub, pagination).await?;
+    Ok(Json(response))
+}
+
+/// Restore a todo from the trash
+#[utoipa::path(
+    post,
+    path = "/api/v1/todos/{id}/restore",
+    params(
+        ("id" = Uuid, Path, description = "Todo ID")
+    ),
+    responses(
+        (status = 200, description = "Todo restored", body = TodoResponse),
+        (status = 401, description = "Unauthorized"),
+        (status = 404, description = "Todo not found in trash")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "todos"
+)]
+pub async fn restore_todo(
+    State(state): State<AppState>,
+    Extension(claims): Extension<Claims>,
+    Path(id): Path<Uuid>,
 ) -> AppResult<Json<TodoResponse>> {
-    let service = TodoService::new(state.todo_repository.clone());
-    let response = service.update(claims.sub, id, request).await?;
+    let service = TodoService::new(
+        state.todo_repository.clone(),
+        state.workflow_repository.clone(),
+        notifier(&state),
+    );
+    let response = service.restore(claims.sub, id).await?;
     Ok(Json(response))
 }
 
-/// Delete a todo
+/// Permanently delete a todo from the trash
 #[utoipa::path(
     delete,
-    path = "/api/v1/todos/{id}",
+    path = "/api/v1/todos/trash/{id}",
     params(
         ("id" = Uuid, Path, description = "Todo ID")
     ),
     responses(
-        (status = 204, description = "Todo deleted"),
+        (status = 204, description = "Todo permanently deleted"),
         (status = 401, description = "Unauthorized"),
-        (status = 404, description = "Todo not found")
+        (status = 404, description = "Todo not found in trash")
     ),
     security(
         ("bearer_auth" = [])
     ),
     tag = "todos"
 )]
-pub async fn delete_todo(
+pub async fn delete_trashed_todo(
     State(state): State<AppState>,
     Extension(claims): Extension<Claims>,
     Path(id): Path<Uuid>,
 ) -> AppResult<StatusCode> {
-    let service = TodoService::new(state.todo_repository.clone());
-    service.delete(claims.sub, id).await?;
+    let service = TodoService::new(
+        state.todo_repository.clone(),
+        state.workflow_repository.clone(),
+        notifier(&state),
+    );
+    service.delete_permanently(claims.sub, id).await?;
     Ok(StatusCode::NO_CONTENT)
 }
+
+/// List the change history of a todo
+#[utoipa::path(
+    get,
+    path = "/api/v1/todos/{id}/history",
+    params(
+        ("id" = Uuid, Path, description = "Todo ID"),
+        ("page" = Option<i64>, Query, description = "Page number (default: 1)"),
+        ("per_page" = Option<i64>, Query, description = "Items per page (default: 20, max: 100)")
+    ),
+    responses(
+        (status = 200, description = "Changes to the todo, newest first", body = TodoHistoryListResponse),
+        (status = 401, description = "Unauthorized"),
+        (status = 404, description = "Todo not found")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "todos"
+)]
+pub async fn get_todo_history(
+    State(state): State<AppState>,
+    Extension(claims): Extension<Claims>,
+    Path(id): Path<Uuid>,
+    Query(pagination): Query<PaginationQuery>,
+) -> AppResult<Json<TodoHistoryListResponse>> {
+    let service = TodoService::new(
+        state.todo_repository.clone(),
+        state.workflow_repository.clone(),
+        notifier(&state),
+    );
+    let response = service.history(claims.sub, id, pagination).await?;
+    Ok(Json(response))
+}
+
+/// List recent changes across all of the user's todos
+#[utoipa::path(
+    get,
+    path = "/api/v1/todos/activity",
+    params(
+        ("page" = Option<i64>, Query, description = "Page number (default: 1)"),
+        ("per_page" = Option<i64>, Query, description = "Items per page (default: 20, max: 100)")
+    ),
+    responses(
+        (status = 200, description = "Activity feed, newest first", body = TodoHistoryListResponse),
+        (status = 401, description = "Unauthorized")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "todos"
+)]
+pub async fn list_activity(
+    State(state): State<AppState>,
+    Extension(claims): Extension<Claims>,
+    Query(pagination): Query<PaginationQuery>,
+) -> AppResult<Json<TodoHistoryListResponse>> {
+    let service = TodoService::new(
+        state.todo_repository.clone(),
+        state.workflow_repository.clone(),
+        notifier(&state),
+    );
+    let response = service.activity(claims.sub, pagination).await?;
+    Ok(Json(response))
+}
@@ -0,0 +1,60 @@
+use axum::{
+    extract::{Extension, State},
+    Json,
+};
+
+use crate::application::dto::{UpdateWorkflowRequest, WorkflowResponse};
+use crate::application::services::WorkflowService;
+use crate::infrastructure::config::AppState;
+use crate::presentation::middleware::WorkspaceContext;
+use crate::shared::error::AppResult;
+
+fn workflow_service(state: &AppState) -> WorkflowService {
+    WorkflowService::new(state.workflow_repository.clone())
+}
+
+/// Get the workspace's workflow statuses
+#[utoipa::path(
+    get,
+    path = "/api/v1/todos/workflow",
+    responses(
+        (status = 200, description = "Statuses todos can be in, and the moves between them", body = WorkflowResponse),
+        (status = 401, description = "Unauthorized")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "workflow"
+)]
+pub async fn get_workflow(State(state): State<AppState>) -> AppResult<Json<WorkflowResponse>> {
+    let response = workflow_service(&state).get().await?;
+    Ok(Json(response))
+}
+
+/// Replace the workspace's workflow statuses
+#[utoipa::path(
+    put,
+    path = "/api/v1/todos/workflow",
+    request_body = UpdateWorkflowRequest,
+    responses(
+        (status = 200, description = "Workflow replaced", body = WorkflowResponse),
+        (status = 400, description = "Invalid workflow"),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Only owners and admins can change the workflow"),
+        (status = 409, description = "Todos are still in a status the workflow drops")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "workflow"
+)]
+pub async fn update_workflow(
+    State(state): State<AppState>,
+    Extension(workspace): Extension<WorkspaceContext>,
+    Json(request): Json<UpdateWorkflowRequest>,
+) -> AppResult<Json<WorkflowResponse>> {
+    let response = workflow_service(&state)
+        .replace(workspace.role, request)
+        .await?;
+    Ok(Json(response))
+}
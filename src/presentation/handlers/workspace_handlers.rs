@@ -0,0 +1,272 @@
+use axum::{
+    extract::{Extension, Path, State},
+    http::StatusCode,
+    response::IntoResponse,
+    Json,
+};
+use uuid::Uuid;
+
+use crate::application::dto::{
+    CreateWorkspaceRequest, InviteMemberRequest, UpdateMemberRequest,
+    WorkspaceInvitationListResponse, WorkspaceInvitationResponse, WorkspaceListResponse,
+    WorkspaceMemberListResponse, WorkspaceMemberResponse, WorkspaceResponse,
+};
+use crate::application::services::WorkspaceService;
+use crate::infrastructure::auth::jwt::Claims;
+use crate::infrastructure::config::AppState;
+use crate::shared::error::AppResult;
+
+fn workspace_service(state: &AppState) -> WorkspaceService {
+    WorkspaceService::new(
+        state.workspace_repository.clone(),
+        state.user_repository.clone(),
+    )
+}
+
+/// List your workspaces
+#[utoipa::path(
+    get,
+    path = "/api/v1/workspaces",
+    responses(
+        (status = 200, description = "Workspaces, in the order you joined them", body = WorkspaceListResponse),
+        (status = 401, description = "Unauthorized")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "workspaces"
+)]
+pub async fn list_workspaces(
+    State(state): State<AppState>,
+    Extension(claims): Extension<Claims>,
+) -> AppResult<Json<WorkspaceListResponse>> {
+    let response = workspace_service(&state).list(claims.sub).await?;
+    Ok(Json(response))
+}
+
+/// Create a workspace
+#[utoipa::path(
+    post,
+    path = "/api/v1/workspaces",
+    request_body = CreateWorkspaceRequest,
+    responses(
+        (status = 201, description = "Workspace created, owned by you", body = WorkspaceResponse),
+        (status = 400, description = "Validation error"),
+        (status = 401, description = "Unauthorized")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "workspaces"
+)]
+pub async fn create_workspace(
+    State(state): State<AppState>,
+    Extension(claims): Extension<Claims>,
+    Json(request): Json<CreateWorkspaceRequest>,
+) -> AppResult<impl IntoResponse> {
+    let response = workspace_service(&state)
+        .create(claims.sub, request)
+        .await?;
+    Ok((StatusCode::CREATED, Json(response)))
+}
+
+/// List the members of a workspace
+#[utoipa::path(
+    get,
+    path = "/api/v1/workspaces/{id}/members",
+    params(
+        ("id" = Uuid, Path, description = "Workspace ID")
+    ),
+    responses(
+        (status = 200, description = "Members, in the order they joined", body = WorkspaceMemberListResponse),
+        (status = 401, description = "Unauthorized"),
+        (status = 404, description = "Workspace not found")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "workspaces"
+)]
+pub async fn list_members(
+    State(state): State<AppState>,
+    Extension(claims): Extension<Claims>,
+    Path(id): Path<Uuid>,
+) -> AppResult<Json<WorkspaceMemberListResponse>> {
+    let response = workspace_service(&state).members(claims.sub, id).await?;
+    Ok(Json(response))
+}
+
+/// Change a member's role
+#[utoipa::path(
+    patch,
+    path = "/api/v1/workspaces/{id}/members/{user_id}",
+    params(
+        ("id" = Uuid, Path, description = "Workspace ID"),
+        ("user_id" = Uuid, Path, description = "Member's user ID")
+    ),
+    request_body = UpdateMemberRequest,
+    responses(
+        (status = 200, description = "Role changed", body = WorkspaceMemberResponse),
+        (status = 400, description = "The workspace would be left without an owner"),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Only owners manage owners, and owners and admins everyone else"),
+        (status = 404, description = "Workspace or member not found")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "workspaces"
+)]
+pub async fn update_member(
+    State(state): State<AppState>,
+    Extension(claims): Extension<Claims>,
+    Path((id, user_id)): Path<(Uuid, Uuid)>,
+    Json(request): Json<UpdateMemberRequest>,
+) -> AppResult<Json<WorkspaceMemberResponse>> {
+    let response = workspace_service(&state)
+        .update_member(claims.sub, id, user_id, request)
+        .await?;
+    Ok(Json(response))
+}
+
+/// Remove a member from a workspace, or leave it
+#[utoipa::path(
+    delete,
+    path = "/api/v1/workspaces/{id}/members/{user_id}",
+    params(
+        ("id" = Uuid, Path, description = "Workspace ID"),
+        ("user_id" = Uuid, Path, description = "Member's user ID")
+    ),
+    responses(
+        (status = 204, description = "Member removed"),
+        (status = 400, description = "The workspace would be left without an owner"),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Only owners manage owners, and owners and admins everyone else"),
+        (status = 404, description = "Workspace or member not found")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "workspaces"
+)]
+pub async fn remove_member(
+    State(state): State<AppState>,
+    Extension(claims): Extension<Claims>,
+    Path((id, user_id)): Path<(Uuid, Uuid)>,
+) -> AppResult<StatusCode> {
+    workspace_service(&state)
+        .remove_member(claims.sub, id, user_id)
+        .await?;
+    Ok(StatusCode::NO_CONTENT)
+}
+
+/// Invite someone to a workspace by email
+#[utoipa::path(
+    post,
+    path = "/api/v1/workspaces/{id}/invitations",
+    params(
+        ("id" = Uuid, Path, description = "Workspace ID")
+    ),
+    request_body = InviteMemberRequest,
+    responses(
+        (status = 201, description = "Invitation created", body = WorkspaceInvitationResponse),
+        (status = 400, description = "Validation error"),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Only owners invite owners, and owners and admins everyone else"),
+        (status = 404, description = "Workspace not found"),
+        (status = 409, description = "User is already a member")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "workspaces"
+)]
+pub async fn invite_member(
+    State(state): State<AppState>,
+    Extension(claims): Extension<Claims>,
+    Path(id): Path<Uuid>,
+    Json(request): Json<InviteMemberRequest>,
+) -> AppResult<impl IntoResponse> {
+    let response = workspace_service(&state)
+        .invite(claims.sub, id, request)
+        .await?;
+    Ok((StatusCode::CREATED, Json(response)))
+}
+
+/// List invitations addressed to you
+#[utoipa::path(
+    get,
+    path = "/api/v1/workspaces/invitations",
+    responses(
+        (status = 200, description = "Pending invitations, oldest first", body = WorkspaceInvitationListResponse),
+        (status = 401, description = "Unauthorized")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "workspaces"
+)]
+pub async fn list_invitations(
+    State(state): State<AppState>,
+    Extension(claims): Extension<Claims>,
+) -> AppResult<Json<WorkspaceInvitationListResponse>> {
+    let response = workspace_service(&state).invitations(claims.sub).await?;
+    Ok(Json(response))
+}
+
+/// Accept an invitation and join its workspace
+#[utoipa::path(
+    post,
+    path = "/api/v1/workspaces/invitations/{id}/accept",
+    params(
+        ("id" = Uuid, Path, description = "Invitation ID")
+    ),
+    responses(
+        (status = 200, description = "Joined the workspace", body = WorkspaceResponse),
+        (status = 401, description = "Unauthorized"),
+        (status = 404, description = "Invitation not found")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "workspaces"
+)]
+pub async fn accept_invitation(
+    State(state): State<AppState>,
+    Extension(claims): Extension<Claims>,
+    Path(id): Path<Uuid>,
+) -> AppResult<Json<WorkspaceResponse>> {
+    let response = workspace_service(&state)
+        .accept_invitation(claims.sub, id)
+        .await?;
+    Ok(Json(response))
+}
+
+/// Decline an invitation, or withdraw one you manage
+#[utoipa::path(
+    delete,
+    path = "/api/v1/workspaces/invitations/{id}",
+    params(
+        ("id" = Uuid, Path, description = "Invitation ID")
+    ),
+    responses(
+        (status = 204, description = "Invitation deleted"),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Not allowed to withdraw this invitation"),
+        (status = 404, description = "Invitation not found")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "workspaces"
+)]
+pub async fn delete_invitation(
+    State(state): State<AppState>,
+    Extension(claims): Extension<Claims>,
+    Path(id): Path<Uuid>,
+) -> AppResult<StatusCode> {
+    workspace_service(&state)
+        .delete_invitation(claims.sub, id)
+        .await?;
+    Ok(StatusCode::NO_CONTENT)
+}
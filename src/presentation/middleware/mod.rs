@@ -1,3 +1,5 @@
 pub mod auth_middleware;
+pub mod workspace_middleware;
 
 pub use auth_middleware::auth_middleware;
+pub use workspace_middleware::{workspace_middleware, WorkspaceContext, WORKSPACE_HEADER};
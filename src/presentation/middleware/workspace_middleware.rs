@@ -0,0 +1,72 @@
+use axum::{
+    extract::{Request, State},
+    middleware::Next,
+    response::Response,
+};
+use uuid::Uuid;
+
+use crate::domain::entities::WorkspaceRole;
+use crate::infrastructure::config::AppState;
+use crate::infrastructure::persistence::postgres::Tenant;
+use crate::presentation::middleware::auth_middleware::get_claims;
+use crate::shared::error::AppError;
+
+/// Header selecting the workspace a request works in.
+pub const WORKSPACE_HEADER: &str = "x-workspace-id";
+
+/// The workspace a request works in, and the user's role there.
+#[derive(Debug, Clone, Copy)]
+pub struct WorkspaceContext {
+    pub workspace_id: Uuid,
+    pub role: WorkspaceRole,
+}
+
+/// Resolves the workspace selected by the `X-Workspace-Id` header, or the
+/// user's first workspace without one, and runs the request with database
+/// access limited to it. Must run after `auth_middleware`.
+pub async fn workspace_middleware(
+    State(state): State<AppState>,
+    mut request: Request,
+    next: Next,
+) -> Result<Response, AppError> {
+    let user_id = get_claims(&request)?.sub;
+
+    let selected = request
+        .headers()
+        .get(WORKSPACE_HEADER)
+        .map(|value| {
+            value
+                .to_str()
+                .ok()
+                .and_then(|value| Uuid::parse_str(value.trim()).ok())
+                .ok_or_else(|| {
+                    AppError::Validation("X-Workspace-Id must be a workspace ID".to_string())
+                })
+        })
+        .transpose()?;
+
+    let context = match selected {
+        Some(workspace_id) => state
+            .workspace_repository
+            .find_role(workspace_id, user_id)
+            .await?
+            .map(|role| WorkspaceContext { workspace_id, role }),
+        None => state
+            .workspace_repository
+            .find_by_user(user_id)
+            .await?
+            .into_iter()
+            .next()
+            .map(|(workspace, role)| WorkspaceContext {
+                workspace_id: workspace.id,
+                role,
+            }),
+    }
+    .ok_or_else(|| AppError::NotFound("Workspace not found".to_string()))?;
+
+    request.extensions_mut().insert(context);
+
+    Ok(Tenant::Workspace(context.workspace_id)
+        .scope(next.run(request))
+        .await)
+}
@@ -1,4 +1,6 @@
+pub mod etag;
 pub mod handlers;
 pub mod middleware;
 pub mod openapi;
+pub mod range;
 pub mod routes;
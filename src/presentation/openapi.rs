@@ -1,14 +1,48 @@
 use utoipa::{
-    openapi::security::{HttpAuthScheme, HttpBuilder, SecurityScheme},
+    openapi::{
+        path::{ParameterBuilder, ParameterIn},
+        security::{HttpAuthScheme, HttpBuilder, SecurityScheme},
+        KnownFormat, ObjectBuilder, Required, SchemaFormat, Type,
+    },
     Modify, OpenApi,
 };
 
 use crate::application::dto::{
-    AuthResponse, CreateTodoRequest, LoginRequest, RefreshRequest, RegisterRequest,
-    TodoListResponse, TodoResponse, UpdateTodoRequest, UserResponse,
+    AddBlockerRequest, AddChecklistItemRequest, AssignTodoRequest, AttachmentListResponse,
+    AttachmentResponse, AuthResponse, BatchMode, BatchOperation, BatchOperationResult,
+    BatchOperationStatus, BatchUpdateOperation, BlockerListResponse, BoardColumnResponse,
+    BoardResponse, ChecklistProgress, CommentListResponse, CommentResponse, CreateCommentRequest,
+    CreateProjectRequest, CreateReminderRequest, CreateSavedFilterRequest, CreateTimeEntryRequest,
+    CreateTodoRequest, CreateWorkspaceRequest, InviteMemberRequest, LoginRequest,
+    MarkAllReadResponse, MoveTodoRequest, NextTodosResponse, NotificationListResponse,
+    NotificationPreferenceDto, NotificationPreferencesResponse, NotificationResponse,
+    OccurrencePreviewResponse, PatchTodoRequest, ProjectListResponse, ProjectResponse,
+    ProjectShareListResponse, ProjectShareResponse, QuickAddTodoRequest, RefreshRequest,
+    RegisterRequest, ReminderDeliveryListResponse, ReminderDeliveryResponse, ReminderListResponse,
+    ReminderResponse, ReorderChecklistRequest, ReportFormat, SavedFilterListResponse,
+    SavedFilterResponse, ShareTodoRequest, SharedTodoListResponse, SharedTodoResponse,
+    TimeEntryListResponse, TimeEntryResponse, TimeReportResponse, TimeReportRowResponse,
+    TodoBatchRequest, TodoBatchResponse, TodoHistoryListResponse, TodoHistoryResponse,
+    TodoListResponse, TodoResponse, TodoSearchResponse, TodoSearchResult, TodoShareListResponse,
+    TodoShareResponse, UpdateCommentRequest, UpdateMemberRequest,
+    UpdateNotificationPreferencesRequest, UpdateProjectRequest, UpdateSavedFilterRequest,
+    UpdateTodoRequest, UpdateWorkflowRequest, UploadAttachmentRequest, UserResponse,
+    WorkflowResponse, WorkflowStatusBody, WorkspaceInvitationListResponse,
+    WorkspaceInvitationResponse, WorkspaceListResponse, WorkspaceMemberListResponse,
+    WorkspaceMemberResponse, WorkspaceResponse,
 };
-use crate::domain::entities::{Todo, User};
-use crate::presentation::handlers::{auth_handlers, todo_handlers};
+use crate::domain::entities::{
+    ChecklistItem, DeliveryChannel, DeliveryStatus, FilterDefinition, NotificationEvent,
+    ReportDimension, SearchLanguage, SharePermission, SortOrder, Todo, TodoAction, TodoPriority,
+    TodoSortField, User, WorkspaceRole,
+};
+use crate::presentation::handlers::{
+    attachment_handlers, auth_handlers, board_handlers, checklist_handlers, comment_handlers,
+    dependency_handlers, filter_handlers, notification_handlers, project_handlers,
+    reminder_handlers, share_handlers, time_entry_handlers, todo_handlers, workflow_handlers,
+    workspace_handlers,
+};
+use crate::presentation::middleware::WORKSPACE_HEADER;
 
 #[derive(OpenApi)]
 #[openapi(
@@ -17,10 +51,87 @@ use crate::presentation::handlers::{auth_handlers, todo_handlers};
         auth_handlers::login,
         auth_handlers::refresh,
         todo_handlers::list_todos,
+        todo_handlers::search_todos,
         todo_handlers::get_todo,
         todo_handlers::create_todo,
+        todo_handlers::quick_add_todo,
+        todo_handlers::batch_todos,
         todo_handlers::update_todo,
+        todo_handlers::patch_todo,
         todo_handlers::delete_todo,
+        todo_handlers::assign_todo,
+        todo_handlers::unassign_todo,
+        checklist_handlers::add_checklist_item,
+        checklist_handlers::reorder_checklist,
+        checklist_handlers::toggle_checklist_item,
+        checklist_handlers::remove_checklist_item,
+        todo_handlers::preview_occurrences,
+        todo_handlers::list_trash,
+        todo_handlers::restore_todo,
+        todo_handlers::delete_trashed_todo,
+        todo_handlers::get_todo_history,
+        todo_handlers::list_activity,
+        comment_handlers::list_comments,
+        comment_handlers::create_comment,
+        comment_handlers::update_comment,
+        comment_handlers::delete_comment,
+        attachment_handlers::upload_attachment,
+        attachment_handlers::list_attachments,
+        attachment_handlers::download_attachment,
+        attachment_handlers::delete_attachment,
+        share_handlers::list_shared_todos,
+        share_handlers::list_shares,
+        share_handlers::share_todo,
+        share_handlers::revoke_share,
+        share_handlers::list_project_shares,
+        share_handlers::share_project,
+        share_handlers::revoke_project_share,
+        reminder_handlers::list_reminders,
+        reminder_handlers::create_reminder,
+        reminder_handlers::delete_reminder,
+        reminder_handlers::list_reminder_deliveries,
+        notification_handlers::list_notifications,
+        notification_handlers::mark_notification_read,
+        notification_handlers::mark_all_notifications_read,
+        notification_handlers::get_notification_preferences,
+        notification_handlers::update_notification_preferences,
+        time_entry_handlers::start_timer,
+        time_entry_handlers::stop_timer,
+        time_entry_handlers::list_time_entries,
+        time_entry_handlers::create_time_entry,
+        time_entry_handlers::delete_time_entry,
+        time_entry_handlers::get_time_report,
+        dependency_handlers::list_next_todos,
+        dependency_handlers::list_blockers,
+        dependency_handlers::add_blocker,
+        dependency_handlers::remove_blocker,
+        board_handlers::get_board,
+        board_handlers::get_board_column,
+        board_handlers::move_todo,
+        workflow_handlers::get_workflow,
+        workflow_handlers::update_workflow,
+        project_handlers::list_projects,
+        project_handlers::create_project,
+        project_handlers::get_project,
+        project_handlers::update_project,
+        project_handlers::delete_project,
+        project_handlers::get_project_workflow,
+        project_handlers::update_project_workflow,
+        filter_handlers::list_filters,
+        filter_handlers::create_filter,
+        filter_handlers::get_filter,
+        filter_handlers::update_filter,
+        filter_handlers::delete_filter,
+        filter_handlers::list_filter_todos,
+        workspace_handlers::list_workspaces,
+        workspace_handlers::create_workspace,
+        workspace_handlers::list_members,
+        workspace_handlers::update_member,
+        workspace_handlers::remove_member,
+        workspace_handlers::invite_member,
+        workspace_handlers::list_invitations,
+        workspace_handlers::accept_invitation,
+        workspace_handlers::delete_invitation,
     ),
     components(
         schemas(
@@ -32,15 +143,115 @@ use crate::presentation::handlers::{auth_handlers, todo_handlers};
             AuthResponse,
             UserResponse,
             CreateTodoRequest,
+            QuickAddTodoRequest,
             UpdateTodoRequest,
+            PatchTodoRequest,
+            AssignTodoRequest,
+            ChecklistItem,
+            ChecklistProgress,
+            AddChecklistItemRequest,
+            ReorderChecklistRequest,
             TodoResponse,
             TodoListResponse,
+            OccurrencePreviewResponse,
+            SearchLanguage,
+            TodoSortField,
+            TodoPriority,
+            SortOrder,
+            TodoSearchResult,
+            TodoSearchResponse,
+            BatchMode,
+            BatchOperation,
+            BatchUpdateOperation,
+            TodoBatchRequest,
+            BatchOperationStatus,
+            BatchOperationResult,
+            TodoBatchResponse,
+            TodoAction,
+            TodoHistoryResponse,
+            TodoHistoryListResponse,
+            CreateCommentRequest,
+            UpdateCommentRequest,
+            CommentResponse,
+            CommentListResponse,
+            UploadAttachmentRequest,
+            AttachmentResponse,
+            AttachmentListResponse,
+            SharePermission,
+            ShareTodoRequest,
+            TodoShareResponse,
+            TodoShareListResponse,
+            SharedTodoResponse,
+            SharedTodoListResponse,
+            ProjectShareResponse,
+            ProjectShareListResponse,
+            DeliveryChannel,
+            DeliveryStatus,
+            CreateReminderRequest,
+            ReminderResponse,
+            ReminderListResponse,
+            ReminderDeliveryResponse,
+            ReminderDeliveryListResponse,
+            NotificationEvent,
+            NotificationResponse,
+            NotificationListResponse,
+            MarkAllReadResponse,
+            NotificationPreferenceDto,
+            NotificationPreferencesResponse,
+            UpdateNotificationPreferencesRequest,
+            CreateTimeEntryRequest,
+            TimeEntryResponse,
+            TimeEntryListResponse,
+            ReportDimension,
+            ReportFormat,
+            TimeReportRowResponse,
+            TimeReportResponse,
+            AddBlockerRequest,
+            BlockerListResponse,
+            NextTodosResponse,
+            WorkflowStatusBody,
+            UpdateWorkflowRequest,
+            WorkflowResponse,
+            CreateProjectRequest,
+            UpdateProjectRequest,
+            ProjectResponse,
+            ProjectListResponse,
+            BoardColumnResponse,
+            BoardResponse,
+            MoveTodoRequest,
+            FilterDefinition,
+            CreateSavedFilterRequest,
+            UpdateSavedFilterRequest,
+            SavedFilterResponse,
+            SavedFilterListResponse,
+            WorkspaceRole,
+            CreateWorkspaceRequest,
+            WorkspaceResponse,
+            WorkspaceListResponse,
+            WorkspaceMemberResponse,
+            WorkspaceMemberListResponse,
+            UpdateMemberRequest,
+            InviteMemberRequest,
+            WorkspaceInvitationResponse,
+            WorkspaceInvitationListResponse,
         )
     ),
-    modifiers(&SecurityAddon),
+    modifiers(&SecurityAddon, &WorkspaceHeaderAddon),
     tags(
         (name = "auth", description = "Authentication API"),
-        (name = "todos", description = "Todo management API")
+        (name = "todos", description = "Todo management API"),
+        (name = "comments", description = "Comments on todos"),
+        (name = "attachments", description = "Files attached to todos"),
+        (name = "shares", description = "Sharing todos and projects with other users"),
+        (name = "reminders", description = "Reminders of todos and their deliveries"),
+        (name = "notifications", description = "Your notification inbox and preferences"),
+        (name = "time", description = "Time tracked on todos and reports of it"),
+        (name = "dependencies", description = "Todos blocked by other todos"),
+        (name = "workflow", description = "Statuses todos move through"),
+        (name = "projects", description = "Projects grouping a workspace's todos, with their workflows"),
+        (name = "board", description = "A project's todos as cards in one column per status"),
+        (name = "filters", description = "Saved todo filters"),
+        (name = "workspaces", description = "Workspaces, their members and invitations")
     ),
     info(
         title = "Todo API",
@@ -67,3 +278,47 @@ impl Modify for SecurityAddon {
         }
     }
 }
+
+/// Documents the workspace selector on every todo, project and filter
+/// endpoint.
+struct WorkspaceHeaderAddon;
+
+impl Modify for WorkspaceHeaderAddon {
+    fn modify(&self, openapi: &mut utoipa::openapi::OpenApi) {
+        let header = ParameterBuilder::new()
+            .name(WORKSPACE_HEADER)
+            .parameter_in(ParameterIn::Header)
+            .required(Required::False)
+            .description(Some(
+                "Workspace to work in (default: the first workspace you joined)",
+            ))
+            .schema(Some(
+                ObjectBuilder::new()
+                    .schema_type(Type::String)
+                    .format(Some(SchemaFormat::KnownFormat(KnownFormat::Uuid))),
+            ))
+            .build();
+
+        for (path, item) in openapi.paths.paths.iter_mut() {
+            let scoped = ["/api/v1/todos", "/api/v1/projects", "/api/v1/filters"];
+            if !scoped.iter().any(|prefix| path.starts_with(prefix)) {
+                continue;
+            }
+            for operation in [
+                &mut item.get,
+                &mut item.post,
+                &mut item.put,
+                &mut item.patch,
+                &mut item.delete,
+            ]
+            .into_iter()
+            .flatten()
+            {
+                operation
+                    .parameters
+                    .get_or_insert_with(Vec::new)
+                    .push(header.clone());
+            }
+        }
+    }
+}
@@ -0,0 +1,66 @@
+use axum::http::{header::RANGE, HeaderMap};
+
+use crate::infrastructure::storage::ByteRange;
+
+/// A `Range` header that lies entirely outside the object.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RangeNotSatisfiable;
+
+/// The single byte range requested for an object of `size` bytes.
+///
+/// `Ok(None)` asks for the whole object: there is no `Range` header, or it
+/// has another unit, several ranges or bad syntax, which servers may ignore.
+pub fn requested_range(
+    headers: &HeaderMap,
+    size: u64,
+) -> Result<Option<ByteRange>, RangeNotSatisfiable> {
+    let Some(spec) = headers
+        .get(RANGE)
+        .and_then(|v| v.to_str().ok())
+        .and_then(|v| v.trim().strip_prefix("bytes="))
+    else {
+        return Ok(None);
+    };
+    let Some((first, last)) = spec.split_once('-') else {
+        return Ok(None);
+    };
+    if spec.contains(',') {
+        return Ok(None);
+    }
+    let (first, last) = (first.trim(), last.trim());
+
+    let range = match (first.parse::<u64>(), last.parse::<u64>()) {
+        // bytes=-N: the last N bytes
+        (Err(_), Ok(suffix)) if first.is_empty() => {
+            if suffix == 0 || size == 0 {
+                return Err(RangeNotSatisfiable);
+            }
+            ByteRange {
+                start: size.saturating_sub(suffix),
+                end: size - 1,
+            }
+        }
+        // bytes=N-
+        (Ok(start), Err(_)) if last.is_empty() => {
+            if start >= size {
+                return Err(RangeNotSatisfiable);
+            }
+            ByteRange {
+                start,
+                end: size - 1,
+            }
+        }
+        // bytes=N-M
+        (Ok(start), Ok(end)) if start <= end => {
+            if start >= size {
+                return Err(RangeNotSatisfiable);
+            }
+            ByteRange {
+                start,
+                end: end.min(size - 1),
+            }
+        }
+        _ => return Ok(None),
+    };
+    Ok(Some(range))
+}
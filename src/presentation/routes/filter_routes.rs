@@ -0,0 +1,25 @@
+use axum::{
+    middleware,
+    routing::{delete, get, post, put},
+    Router,
+};
+
+use crate::infrastructure::config::AppState;
+use crate::presentation::handlers::filter_handlers;
+use crate::presentation::middleware::{auth_middleware, workspace_middleware};
+
+pub fn filter_routes(state: AppState) -> Router<AppState> {
+    Router::new()
+        .route("/", get(filter_handlers::list_filters))
+        .route("/", post(filter_handlers::create_filter))
+        .route("/{id}", get(filter_handlers::get_filter))
+        .route("/{id}", put(filter_handlers::update_filter))
+        .route("/{id}", delete(filter_handlers::delete_filter))
+        .route("/{id}/todos", get(filter_handlers::list_filter_todos))
+        // Filters are evaluated in the workspace the request works in
+        .layer(middleware::from_fn_with_state(
+            state.clone(),
+            workspace_middleware,
+        ))
+        .layer(middleware::from_fn_with_state(state, auth_middleware))
+}
@@ -1,5 +1,15 @@
 pub mod auth_routes;
+pub mod filter_routes;
+pub mod notification_routes;
+pub mod project_routes;
+pub mod report_routes;
 pub mod todo_routes;
+pub mod workspace_routes;
 
 pub use auth_routes::auth_routes;
+pub use filter_routes::filter_routes;
+pub use notification_routes::notification_routes;
+pub use project_routes::project_routes;
+pub use report_routes::report_routes;
 pub use todo_routes::todo_routes;
+pub use workspace_routes::workspace_routes;
@@ -0,0 +1,29 @@
+use axum::{
+    middleware,
+    routing::{get, post},
+    Router,
+};
+
+use crate::infrastructure::config::AppState;
+use crate::presentation::handlers::notification_handlers;
+use crate::presentation::middleware::auth_middleware;
+
+pub fn notification_routes(state: AppState) -> Router<AppState> {
+    // Notifications belong to the user rather than a workspace
+    Router::new()
+        .route("/", get(notification_handlers::list_notifications))
+        .route(
+            "/preferences",
+            get(notification_handlers::get_notification_preferences)
+                .put(notification_handlers::update_notification_preferences),
+        )
+        .route(
+            "/read-all",
+            post(notification_handlers::mark_all_notifications_read),
+        )
+        .route(
+            "/{id}/read",
+            post(notification_handlers::mark_notification_read),
+        )
+        .layer(middleware::from_fn_with_state(state, auth_middleware))
+}
@@ -0,0 +1,43 @@
+use axum::{
+    middleware,
+    routing::{delete, get, post, put},
+    Router,
+};
+
+use crate::infrastructure::config::AppState;
+use crate::presentation::handlers::{board_handlers, project_handlers, share_handlers};
+use crate::presentation::middleware::{auth_middleware, workspace_middleware};
+
+pub fn project_routes(state: AppState) -> Router<AppState> {
+    Router::new()
+        .route("/", get(project_handlers::list_projects))
+        .route("/", post(project_handlers::create_project))
+        .route("/{id}", get(project_handlers::get_project))
+        .route("/{id}", put(project_handlers::update_project))
+        .route("/{id}", delete(project_handlers::delete_project))
+        .route("/{id}/board", get(board_handlers::get_board))
+        .route(
+            "/{id}/board/{status}",
+            get(board_handlers::get_board_column),
+        )
+        .route(
+            "/{id}/workflow",
+            get(project_handlers::get_project_workflow),
+        )
+        .route(
+            "/{id}/workflow",
+            put(project_handlers::update_project_workflow),
+        )
+        .route("/{id}/shares", get(share_handlers::list_project_shares))
+        .route("/{id}/shares", post(share_handlers::share_project))
+        .route(
+            "/{id}/shares/{user_id}",
+            delete(share_handlers::revoke_project_share),
+        )
+        // Projects belong to the workspace the request works in
+        .layer(middleware::from_fn_with_state(
+            state.clone(),
+            workspace_middleware,
+        ))
+        .layer(middleware::from_fn_with_state(state, auth_middleware))
+}
@@ -0,0 +1,12 @@
+use axum::{middleware, routing::get, Router};
+
+use crate::infrastructure::config::AppState;
+use crate::presentation::handlers::time_entry_handlers;
+use crate::presentation::middleware::auth_middleware;
+
+pub fn report_routes(state: AppState) -> Router<AppState> {
+    // Reports span all of the user's workspaces, so none is resolved
+    Router::new()
+        .route("/time", get(time_entry_handlers::get_time_report))
+        .layer(middleware::from_fn_with_state(state, auth_middleware))
+}
@@ -1,19 +1,126 @@
 use axum::{
+    extract::DefaultBodyLimit,
     middleware,
-    routing::{delete, get, post, put},
+    routing::{delete, get, patch, post, put},
     Router,
 };
 
 use crate::infrastructure::config::AppState;
-use crate::presentation::handlers::todo_handlers;
-use crate::presentation::middleware::auth_middleware;
+use crate::presentation::handlers::{
+    attachment_handlers, board_handlers, checklist_handlers, comment_handlers, dependency_handlers,
+    reminder_handlers, share_handlers, time_entry_handlers, todo_handlers, workflow_handlers,
+};
+use crate::presentation::middleware::{auth_middleware, workspace_middleware};
 
 pub fn todo_routes(state: AppState) -> Router<AppState> {
+    // Room for the multipart framing around the largest accepted file
+    let upload_limit = state.attachment_limits.max_file_bytes as usize + 64 * 1024;
+
     Router::new()
         .route("/", get(todo_handlers::list_todos))
         .route("/", post(todo_handlers::create_todo))
+        .route("/activity", get(todo_handlers::list_activity))
+        .route("/batch", post(todo_handlers::batch_todos))
+        .route("/next", get(dependency_handlers::list_next_todos))
+        .route("/quick", post(todo_handlers::quick_add_todo))
+        .route("/search", get(todo_handlers::search_todos))
+        .route("/shared", get(share_handlers::list_shared_todos))
+        .route("/trash", get(todo_handlers::list_trash))
+        .route("/trash/{id}", delete(todo_handlers::delete_trashed_todo))
+        .route("/workflow", get(workflow_handlers::get_workflow))
+        .route("/workflow", put(workflow_handlers::update_workflow))
         .route("/{id}", get(todo_handlers::get_todo))
         .route("/{id}", put(todo_handlers::update_todo))
+        .route("/{id}", patch(todo_handlers::patch_todo))
         .route("/{id}", delete(todo_handlers::delete_todo))
+        .route("/{id}/assignee", put(todo_handlers::assign_todo))
+        .route("/{id}/assignee", delete(todo_handlers::unassign_todo))
+        .route("/{id}/move", post(board_handlers::move_todo))
+        .route(
+            "/{id}/checklist",
+            post(checklist_handlers::add_checklist_item),
+        )
+        .route(
+            "/{id}/checklist/order",
+            put(checklist_handlers::reorder_checklist),
+        )
+        .route(
+            "/{id}/checklist/{item_id}",
+            delete(checklist_handlers::remove_checklist_item),
+        )
+        .route(
+            "/{id}/checklist/{item_id}/toggle",
+            post(checklist_handlers::toggle_checklist_item),
+        )
+        .route("/{id}/occurrences", get(todo_handlers::preview_occurrences))
+        .route("/{id}/restore", post(todo_handlers::restore_todo))
+        .route("/{id}/history", get(todo_handlers::get_todo_history))
+        .route("/{id}/blockers", get(dependency_handlers::list_blockers))
+        .route("/{id}/blockers", post(dependency_handlers::add_blocker))
+        .route(
+            "/{id}/blockers/{blocker_id}",
+            delete(dependency_handlers::remove_blocker),
+        )
+        .route("/{id}/comments", get(comment_handlers::list_comments))
+        .route("/{id}/comments", post(comment_handlers::create_comment))
+        .route(
+            "/{id}/comments/{comment_id}",
+            put(comment_handlers::update_comment),
+        )
+        .route(
+            "/{id}/comments/{comment_id}",
+            delete(comment_handlers::delete_comment),
+        )
+        .route(
+            "/{id}/attachments",
+            get(attachment_handlers::list_attachments),
+        )
+        .route(
+            "/{id}/attachments",
+            post(attachment_handlers::upload_attachment).layer(DefaultBodyLimit::max(upload_limit)),
+        )
+        .route(
+            "/{id}/attachments/{attachment_id}",
+            get(attachment_handlers::download_attachment),
+        )
+        .route(
+            "/{id}/attachments/{attachment_id}",
+            delete(attachment_handlers::delete_attachment),
+        )
+        .route("/{id}/reminders", get(reminder_handlers::list_reminders))
+        .route("/{id}/reminders", post(reminder_handlers::create_reminder))
+        .route(
+            "/{id}/reminders/{reminder_id}",
+            delete(reminder_handlers::delete_reminder),
+        )
+        .route(
+            "/{id}/reminders/{reminder_id}/deliveries",
+            get(reminder_handlers::list_reminder_deliveries),
+        )
+        .route("/{id}/timer/start", post(time_entry_handlers::start_timer))
+        .route("/{id}/timer/stop", post(time_entry_handlers::stop_timer))
+        .route(
+            "/{id}/time-entries",
+            get(time_entry_handlers::list_time_entries),
+        )
+        .route(
+            "/{id}/time-entries",
+            post(time_entry_handlers::create_time_entry),
+        )
+        .route(
+            "/{id}/time-entries/{entry_id}",
+            delete(time_entry_handlers::delete_time_entry),
+        )
+        .route("/{id}/shares", get(share_handlers::list_shares))
+        .route("/{id}/shares", post(share_handlers::share_todo))
+        .route(
+            "/{id}/shares/{user_id}",
+            delete(share_handlers::revoke_share),
+        )
+        // Layers run bottom-up: authenticate, then resolve the workspace
+        .layer(middleware::from_fn_with_state(
+            state.clone(),
+            workspace_middleware,
+        ))
         .layer(middleware::from_fn_with_state(state, auth_middleware))
 }
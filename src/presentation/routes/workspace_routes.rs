@@ -0,0 +1,35 @@
+use axum::{
+    middleware,
+    routing::{delete, get, patch, post},
+    Router,
+};
+
+use crate::infrastructure::config::AppState;
+use crate::presentation::handlers::workspace_handlers;
+use crate::presentation::middleware::auth_middleware;
+
+pub fn workspace_routes(state: AppState) -> Router<AppState> {
+    Router::new()
+        .route("/", get(workspace_handlers::list_workspaces))
+        .route("/", post(workspace_handlers::create_workspace))
+        .route("/invitations", get(workspace_handlers::list_invitations))
+        .route(
+            "/invitations/{id}/accept",
+            post(workspace_handlers::accept_invitation),
+        )
+        .route(
+            "/invitations/{id}",
+            delete(workspace_handlers::delete_invitation),
+        )
+        .route("/{id}/members", get(workspace_handlers::list_members))
+        .route(
+            "/{id}/members/{user_id}",
+            patch(workspace_handlers::update_member),
+        )
+        .route(
+            "/{id}/members/{user_id}",
+            delete(workspace_handlers::remove_member),
+        )
+        .route("/{id}/invitations", post(workspace_handlers::invite_member))
+        .layer(middleware::from_fn_with_state(state, auth_middleware))
+}
@@ -3,7 +3,8 @@ use axum::{
     response::{IntoResponse, Response},
     Json,
 };
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
+use std::fmt;
 use thiserror::Error;
 
 #[derive(Error, Debug)]
@@ -15,9 +16,18 @@ pub enum AppError {
     #[error("Validation error: {0}")]
     Validation(String),
 
+    #[error("Validation error: {0}")]
+    InvalidInput(InputError),
+
     #[error("Conflict: {0}")]
     Conflict(String),
 
+    #[error("Precondition failed: {0}")]
+    PreconditionFailed(String),
+
+    #[error("Payload too large: {0}")]
+    PayloadTooLarge(String),
+
     // Authentication errors
     #[error("Unauthorized")]
     Unauthorized,
@@ -45,10 +55,33 @@ pub enum AppError {
     Internal(#[from] anyhow::Error),
 }
 
+/// A validation error at a position within one request parameter.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct InputError {
+    /// Name of the parameter
+    pub field: String,
+    /// Offset of the offending character, counting characters from 0
+    pub position: usize,
+    pub message: String,
+}
+
+impl fmt::Display for InputError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}: {} at position {}",
+            self.field, self.message, self.position
+        )
+    }
+}
+
 #[derive(Serialize)]
 struct ErrorResponse {
     error: String,
     message: String,
+    /// Where in the request the error is, when it is known
+    #[serde(skip_serializing_if = "Option::is_none")]
+    details: Option<InputError>,
 }
 
 impl IntoResponse for AppError {
@@ -56,7 +89,10 @@ impl IntoResponse for AppError {
         let (status, message) = match &self {
             AppError::NotFound(msg) => (StatusCode::NOT_FOUND, msg.clone()),
             AppError::Validation(msg) => (StatusCode::BAD_REQUEST, msg.clone()),
+            AppError::InvalidInput(e) => (StatusCode::BAD_REQUEST, e.to_string()),
             AppError::Conflict(msg) => (StatusCode::CONFLICT, msg.clone()),
+            AppError::PreconditionFailed(msg) => (StatusCode::PRECONDITION_FAILED, msg.clone()),
+            AppError::PayloadTooLarge(msg) => (StatusCode::PAYLOAD_TOO_LARGE, msg.clone()),
             AppError::Unauthorized => (StatusCode::UNAUTHORIZED, "Unauthorized".to_string()),
             AppError::InvalidCredentials => {
                 (StatusCode::UNAUTHORIZED, "Invalid credentials".to_string())
@@ -87,9 +123,14 @@ impl IntoResponse for AppError {
             }
         };
 
+        let details = match self {
+            AppError::InvalidInput(e) => Some(e),
+            _ => None,
+        };
         let body = Json(ErrorResponse {
             error: status.to_string(),
             message,
+            details,
         });
 
         (status, body).into_response()
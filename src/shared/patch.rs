@@ -0,0 +1,106 @@
+use serde::{Deserialize, Deserializer};
+
+/// A field of a JSON Merge Patch (RFC 7396) document: left out, explicitly
+/// `null`, or set to a value.
+///
+/// Struct fields of this type must be marked `#[serde(default)]` so that a
+/// missing field deserializes to `Missing`.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub enum Patch<T> {
+    #[default]
+    Missing,
+    Null,
+    Value(T),
+}
+
+impl<T> Patch<T> {
+    pub fn is_missing(&self) -> bool {
+        matches!(self, Self::Missing)
+    }
+
+    pub fn map<U>(self, f: impl FnOnce(T) -> U) -> Patch<U> {
+        match self {
+            Self::Missing => Patch::Missing,
+            Self::Null => Patch::Null,
+            Self::Value(v) => Patch::Value(f(v)),
+        }
+    }
+
+    pub fn try_map<U, E>(self, f: impl FnOnce(T) -> Result<U, E>) -> Result<Patch<U>, E> {
+        Ok(match self {
+            Self::Missing => Patch::Missing,
+            Self::Null => Patch::Null,
+            Self::Value(v) => Patch::Value(f(v)?),
+        })
+    }
+
+    /// The new value of a field that cannot be cleared, or `None` if unchanged.
+    pub fn required(self, field: &str) -> Result<Option<T>, String> {
+        match self {
+            Self::Missing => Ok(None),
+            Self::Null => Err(format!("{} cannot be null", field)),
+            Self::Value(v) => Ok(Some(v)),
+        }
+    }
+
+    /// Applies the change to an optional field.
+    pub fn apply(self, field: &mut Option<T>) {
+        match self {
+            Self::Missing => {}
+            Self::Null => *field = None,
+            Self::Value(v) => *field = Some(v),
+        }
+    }
+}
+
+/// `None` clears the field, as in a full replacement.
+impl<T> From<Option<T>> for Patch<T> {
+    fn from(value: Option<T>) -> Self {
+        value.map_or(Self::Null, Self::Value)
+    }
+}
+
+impl<'de, T: Deserialize<'de>> Deserialize<'de> for Patch<T> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        Ok(Option::<T>::deserialize(deserializer)?.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Debug, Deserialize)]
+    struct Document {
+        #[serde(default)]
+        description: Patch<String>,
+    }
+
+    #[test]
+    fn test_patch_deserialization() {
+        let parse = |json: &str| serde_json::from_str::<Document>(json).unwrap().description;
+        assert_eq!(parse("{}"), Patch::Missing);
+        assert_eq!(parse(r#"{"description": null}"#), Patch::Null);
+        assert_eq!(
+            parse(r#"{"description": "text"}"#),
+            Patch::Value("text".to_string())
+        );
+    }
+
+    #[test]
+    fn test_patch_apply() {
+        let mut field = Some(1);
+        Patch::Missing.apply(&mut field);
+        assert_eq!(field, Some(1));
+        Patch::Value(2).apply(&mut field);
+        assert_eq!(field, Some(2));
+        Patch::Null.apply(&mut field);
+        assert_eq!(field, None);
+
+        assert_eq!(
+            Patch::<i32>::Null.required("title").unwrap_err(),
+            "title cannot be null"
+        );
+        assert_eq!(Patch::Value(1).required("title").unwrap(), Some(1));
+    }
+}
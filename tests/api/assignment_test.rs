@@ -0,0 +1,256 @@
+use axum::http::StatusCode;
+use axum_test::TestServer;
+use rust_teraform_backend::application::dto::{
+    TodoHistoryListResponse, TodoListResponse, TodoResponse, WorkspaceMemberListResponse,
+};
+use rust_teraform_backend::domain::entities::TodoAction;
+use uuid::Uuid;
+
+use crate::common;
+
+/// A todo of the owner's, shared with an editor and a viewer who joined the
+/// owner's workspace. Every email starts with `prefix`.
+struct Team {
+    owner: String,
+    editor: String,
+    viewer: String,
+    viewer_id: Uuid,
+    workspace: String,
+    todo: TodoResponse,
+}
+
+impl Team {
+    fn assignee_url(&self) -> String {
+        format!("/api/v1/todos/{}/assignee", self.todo.id)
+    }
+}
+
+async fn team(server: &TestServer, prefix: &str) -> Team {
+    let email = |role: &str| format!("{}_{}@example.com", prefix, role);
+    let owner = common::register_test_user(server, &email("owner"), "password123")
+        .await
+        .access_token;
+    let editor = common::register_test_user(server, &email("editor"), "password123")
+        .await
+        .access_token;
+    let viewer = common::register_test_user(server, &email("viewer"), "password123")
+        .await
+        .access_token;
+    let workspace = common::join_workspace(server, &owner, &editor, &email("editor")).await;
+    common::join_workspace(server, &owner, &viewer, &email("viewer")).await;
+
+    let todo = common::create_todo(server, &owner, "Review the budget").await;
+    assert_eq!(todo.assignee_id, None);
+    share(server, &owner, &todo, &email("editor"), "editor").await;
+    share(server, &owner, &todo, &email("viewer"), "viewer").await;
+
+    let members: WorkspaceMemberListResponse = server
+        .get(&format!("/api/v1/workspaces/{}/members", workspace))
+        .add_header("Authorization", format!("Bearer {}", owner))
+        .await
+        .json();
+    let viewer_id = members
+        .members
+        .iter()
+        .find(|member| member.email == email("viewer"))
+        .unwrap()
+        .user_id;
+
+    Team {
+        owner,
+        editor,
+        viewer,
+        viewer_id,
+        workspace: workspace.to_string(),
+        todo,
+    }
+}
+
+async fn share(
+    server: &TestServer,
+    owner: &str,
+    todo: &TodoResponse,
+    email: &str,
+    permission: &str,
+) {
+    server
+        .post(&format!("/api/v1/todos/{}/shares", todo.id))
+        .add_header("Authorization", format!("Bearer {}", owner))
+        .json(&serde_json::json!({ "email": email, "permission": permission }))
+        .await
+        .assert_status(StatusCode::CREATED);
+}
+
+/// Assigns the team's todo to the viewer, on behalf of the owner.
+async fn assign_to_viewer(server: &TestServer, team: &Team) {
+    server
+        .put(&team.assignee_url())
+        .add_header("Authorization", format!("Bearer {}", team.owner))
+        .json(&serde_json::json!({ "assignee_id": team.viewer_id }))
+        .await
+        .assert_status_ok();
+}
+
+async fn latest_history_entry(server: &TestServer, team: &Team) -> (TodoAction, serde_json::Value) {
+    let history: TodoHistoryListResponse = server
+        .get(&format!("/api/v1/todos/{}/history", team.todo.id))
+        .add_header("Authorization", format!("Bearer {}", team.owner))
+        .await
+        .json();
+    let entry = &history.entries[0];
+    (entry.action, entry.after.clone())
+}
+
+#[tokio::test]
+async fn test_assign_todo() {
+    let (server, pool) = common::create_test_server().await;
+    let team = team(&server, "assign").await;
+
+    // Viewers can't assign; editors can
+    server
+        .put(&team.assignee_url())
+        .add_header("Authorization", format!("Bearer {}", team.viewer))
+        .add_header("X-Workspace-Id", &team.workspace)
+        .json(&serde_json::json!({ "assignee_id": team.viewer_id }))
+        .await
+        .assert_status(StatusCode::FORBIDDEN);
+    let response = server
+        .put(&team.assignee_url())
+        .add_header("Authorization", format!("Bearer {}", team.editor))
+        .add_header("X-Workspace-Id", &team.workspace)
+        .json(&serde_json::json!({ "assignee_id": team.viewer_id }))
+        .await;
+    response.assert_status_ok();
+    response.assert_header("ETag", "\"2\"");
+    let assigned: TodoResponse = response.json();
+    assert_eq!(assigned.assignee_id, Some(team.viewer_id));
+    assert_eq!(assigned.user_id, team.todo.user_id);
+
+    // Every change of assignee is recorded
+    assert_eq!(
+        latest_history_entry(&server, &team).await,
+        (
+            TodoAction::Assigned,
+            serde_json::json!({ "assignee_id": team.viewer_id.to_string() })
+        )
+    );
+
+    common::cleanup_test_data(&pool).await;
+}
+
+#[tokio::test]
+async fn test_unassign_todo() {
+    let (server, pool) = common::create_test_server().await;
+    let team = team(&server, "unassign").await;
+    assign_to_viewer(&server, &team).await;
+
+    // The assignee can hand the todo back, but not once it's no longer theirs
+    let response = server
+        .delete(&team.assignee_url())
+        .add_header("Authorization", format!("Bearer {}", team.viewer))
+        .add_header("X-Workspace-Id", &team.workspace)
+        .await;
+    response.assert_status_ok();
+    let unassigned: TodoResponse = response.json();
+    assert_eq!(unassigned.assignee_id, None);
+    server
+        .delete(&team.assignee_url())
+        .add_header("Authorization", format!("Bearer {}", team.viewer))
+        .add_header("X-Workspace-Id", &team.workspace)
+        .await
+        .assert_status(StatusCode::FORBIDDEN);
+
+    assert_eq!(
+        latest_history_entry(&server, &team).await,
+        (
+            TodoAction::Assigned,
+            serde_json::json!({ "assignee_id": null })
+        )
+    );
+
+    common::cleanup_test_data(&pool).await;
+}
+
+#[tokio::test]
+async fn test_filter_by_assignee() {
+    let (server, pool) = common::create_test_server().await;
+    let team = team(&server, "assignee_filter").await;
+    assign_to_viewer(&server, &team).await;
+
+    // The assignee sees the todo among theirs, and others can look it up by them
+    let mine: TodoListResponse = server
+        .get("/api/v1/todos?assignee=me")
+        .add_header("Authorization", format!("Bearer {}", team.viewer))
+        .add_header("X-Workspace-Id", &team.workspace)
+        .await
+        .json();
+    assert_eq!(mine.total, Some(1));
+    assert_eq!(mine.todos[0].id, team.todo.id);
+
+    let mine: TodoListResponse = server
+        .get("/api/v1/todos?assignee=me")
+        .add_header("Authorization", format!("Bearer {}", team.owner))
+        .await
+        .json();
+    assert_eq!(mine.total, Some(0));
+    let theirs: TodoListResponse = server
+        .get(&format!("/api/v1/todos?assignee={}", team.viewer_id))
+        .add_header("Authorization", format!("Bearer {}", team.owner))
+        .await
+        .json();
+    assert_eq!(theirs.total, Some(1));
+    server
+        .get("/api/v1/todos?assignee=someone")
+        .add_header("Authorization", format!("Bearer {}", team.owner))
+        .await
+        .assert_status(StatusCode::BAD_REQUEST);
+
+    common::cleanup_test_data(&pool).await;
+}
+
+#[tokio::test]
+async fn test_assigning_without_access_is_rejected() {
+    let (server, pool) = common::create_test_server().await;
+    let team = team(&server, "assign_access").await;
+
+    // Only users who can see the todo can be assigned to it: neither a
+    // member it isn't shared with nor someone outside the workspace
+    let member =
+        common::register_test_user(&server, "assign_access_member@example.com", "password123")
+            .await
+            .access_token;
+    common::join_workspace(
+        &server,
+        &team.owner,
+        &member,
+        "assign_access_member@example.com",
+    )
+    .await;
+    common::register_test_user(&server, "assign_access_other@example.com", "password123").await;
+
+    for email in [
+        "assign_access_member@example.com",
+        "assign_access_other@example.com",
+    ] {
+        let (user_id,): (Uuid,) = sqlx::query_as("SELECT id FROM users WHERE email = $1")
+            .bind(email)
+            .fetch_one(&pool)
+            .await
+            .unwrap();
+        server
+            .put(&team.assignee_url())
+            .add_header("Authorization", format!("Bearer {}", team.owner))
+            .json(&serde_json::json!({ "assignee_id": user_id }))
+            .await
+            .assert_status(StatusCode::BAD_REQUEST);
+    }
+
+    let todo: TodoResponse = server
+        .get(&format!("/api/v1/todos/{}", team.todo.id))
+        .add_header("Authorization", format!("Bearer {}", team.owner))
+        .await
+        .json();
+    assert_eq!(todo.assignee_id, None);
+
+    common::cleanup_test_data(&pool).await;
+}
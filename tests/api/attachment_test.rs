@@ -0,0 +1,381 @@
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+
+use axum::body::Bytes;
+use axum::extract::{Path, State};
+use axum::http::{HeaderMap, StatusCode};
+use axum::response::IntoResponse;
+use axum::routing::put;
+use axum::Router;
+use axum_test::multipart::{MultipartForm, Part};
+use axum_test::TestServer;
+use futures_util::TryStreamExt;
+use rust_teraform_backend::application::dto::{AttachmentListResponse, AttachmentResponse};
+use rust_teraform_backend::infrastructure::jobs::delete_orphaned_objects;
+use rust_teraform_backend::infrastructure::persistence::postgres::{
+    PostgresAttachmentRepository, Tenant,
+};
+use rust_teraform_backend::infrastructure::storage::{
+    ByteRange, LocalObjectStore, ObjectStore, S3Config, S3ObjectStore,
+};
+use rust_teraform_backend::shared::error::AppError;
+use sha2::{Digest, Sha256};
+
+use crate::common;
+
+const PNG: &[u8] = b"\x89PNG\r\n\x1a\n\0\0\0\rIHDR\0\0\0\x01\0\0\0\x01";
+
+fn file_form(name: &str, data: Vec<u8>) -> MultipartForm {
+    MultipartForm::new().add_part(
+        "file",
+        Part::bytes(data).file_name(name).mime_type("text/html"),
+    )
+}
+
+async fn upload_png(server: &TestServer, token: &str, attachments_url: &str) -> AttachmentResponse {
+    let response = server
+        .post(attachments_url)
+        .add_header("Authorization", format!("Bearer {}", token))
+        .multipart(file_form("../screen shot.png", PNG.to_vec()))
+        .await;
+    response.assert_status(StatusCode::CREATED);
+    response.json()
+}
+
+#[tokio::test]
+async fn test_upload_attachment() {
+    let (server, pool) = common::create_test_server().await;
+
+    let auth = common::register_test_user(&server, "files@example.com", "password123").await;
+    let token = auth.access_token;
+    let todo = common::create_todo(&server, &token, "With files").await;
+    let attachments_url = format!("/api/v1/todos/{}/attachments", todo.id);
+
+    // The declared type and path are ignored
+    let attachment = upload_png(&server, &token, &attachments_url).await;
+    assert_eq!(attachment.file_name, "screen shot.png");
+    assert_eq!(attachment.content_type, "image/png");
+    assert_eq!(attachment.size_bytes, PNG.len() as i64);
+
+    let list: AttachmentListResponse = server
+        .get(&attachments_url)
+        .add_header("Authorization", format!("Bearer {}", token))
+        .await
+        .json();
+    assert_eq!(list.attachments.len(), 1);
+    assert_eq!(list.used_bytes, PNG.len() as i64);
+    assert_eq!(list.quota_bytes, common::TEST_QUOTA_BYTES);
+
+    // Other users cannot see the attachment
+    let other = common::register_test_user(&server, "files_other@example.com", "password123").await;
+    server
+        .get(&format!("{}/{}", attachments_url, attachment.id))
+        .add_header("Authorization", format!("Bearer {}", other.access_token))
+        .await
+        .assert_status(StatusCode::NOT_FOUND);
+
+    common::cleanup_test_data(&pool).await;
+}
+
+#[tokio::test]
+async fn test_download_attachment() {
+    let (server, pool) = common::create_test_server().await;
+
+    let auth = common::register_test_user(&server, "download@example.com", "password123").await;
+    let token = auth.access_token;
+    let todo = common::create_todo(&server, &token, "With files").await;
+    let attachments_url = format!("/api/v1/todos/{}/attachments", todo.id);
+    let attachment = upload_png(&server, &token, &attachments_url).await;
+
+    let response = server
+        .get(&format!("{}/{}", attachments_url, attachment.id))
+        .add_header("Authorization", format!("Bearer {}", token))
+        .await;
+    response.assert_status_ok();
+    assert_eq!(response.as_bytes().as_ref(), PNG);
+    assert_eq!(response.header("content-type"), "image/png");
+    assert_eq!(response.header("x-content-type-options"), "nosniff");
+    assert_eq!(response.header("accept-ranges"), "bytes");
+    assert_eq!(
+        response.header("content-disposition"),
+        "attachment; filename=\"screen shot.png\"; filename*=UTF-8''screen%20shot.png"
+    );
+
+    common::cleanup_test_data(&pool).await;
+}
+
+#[tokio::test]
+async fn test_download_attachment_ranges() {
+    let (server, pool) = common::create_test_server().await;
+
+    let auth = common::register_test_user(&server, "ranges@example.com", "password123").await;
+    let token = auth.access_token;
+    let todo = common::create_todo(&server, &token, "With files").await;
+    let attachments_url = format!("/api/v1/todos/{}/attachments", todo.id);
+    let attachment = upload_png(&server, &token, &attachments_url).await;
+    let download = |range: &'static str| {
+        server
+            .get(&format!("{}/{}", attachments_url, attachment.id))
+            .add_header("Authorization", format!("Bearer {}", token))
+            .add_header("Range", range)
+    };
+
+    let response = download("bytes=1-3").await;
+    response.assert_status(StatusCode::PARTIAL_CONTENT);
+    assert_eq!(response.as_bytes().as_ref(), b"PNG");
+    assert_eq!(
+        response.header("content-range"),
+        format!("bytes 1-3/{}", PNG.len()).as_str()
+    );
+
+    let response = download("bytes=-4").await;
+    response.assert_status(StatusCode::PARTIAL_CONTENT);
+    assert_eq!(response.as_bytes().as_ref(), &PNG[PNG.len() - 4..]);
+
+    let response = download("bytes=1000-").await;
+    response.assert_status(StatusCode::RANGE_NOT_SATISFIABLE);
+    assert_eq!(
+        response.header("content-range"),
+        format!("bytes */{}", PNG.len()).as_str()
+    );
+
+    common::cleanup_test_data(&pool).await;
+}
+
+#[tokio::test]
+async fn test_attachment_limits() {
+    let (server, pool) = common::create_test_server().await;
+
+    let auth = common::register_test_user(&server, "quota@example.com", "password123").await;
+    let token = auth.access_token;
+    let todo = common::create_todo(&server, &token, "With files").await;
+    let attachments_url = format!("/api/v1/todos/{}/attachments", todo.id);
+
+    let too_large = vec![b'a'; common::TEST_MAX_FILE_BYTES as usize + 1];
+    server
+        .post(&attachments_url)
+        .add_header("Authorization", format!("Bearer {}", token))
+        .multipart(file_form("big.txt", too_large))
+        .await
+        .assert_status(StatusCode::PAYLOAD_TOO_LARGE);
+
+    server
+        .post(&attachments_url)
+        .add_header("Authorization", format!("Bearer {}", token))
+        .multipart(file_form("empty.txt", Vec::new()))
+        .await
+        .assert_status(StatusCode::BAD_REQUEST);
+
+    server
+        .post(&attachments_url)
+        .add_header("Authorization", format!("Bearer {}", token))
+        .multipart(MultipartForm::new().add_text("note", "no file"))
+        .await
+        .assert_status(StatusCode::BAD_REQUEST);
+
+    // Two files under the size limit exceed the quota together
+    let file = vec![b'a'; common::TEST_MAX_FILE_BYTES as usize];
+    server
+        .post(&attachments_url)
+        .add_header("Authorization", format!("Bearer {}", token))
+        .multipart(file_form("one.txt", file.clone()))
+        .await
+        .assert_status(StatusCode::CREATED);
+    let response = server
+        .post(&attachments_url)
+        .add_header("Authorization", format!("Bearer {}", token))
+        .multipart(file_form("two.txt", file))
+        .await;
+    response.assert_status(StatusCode::PAYLOAD_TOO_LARGE);
+    assert!(response.text().contains("quota"));
+
+    common::cleanup_test_data(&pool).await;
+}
+
+/// Runs the object cleanup job until nothing is left to delete.
+async fn drain_object_deletions(pool: &sqlx::PgPool) {
+    let repository = PostgresAttachmentRepository::new(pool.clone());
+    let store = LocalObjectStore::new(common::test_storage_root());
+    Tenant::System
+        .scope(async { while delete_orphaned_objects(&repository, &store).await.unwrap() > 0 {} })
+        .await;
+}
+
+#[tokio::test]
+async fn test_attachment_content_cleanup() {
+    let (server, pool) = common::create_test_server().await;
+
+    let auth = common::register_test_user(&server, "cleanup@example.com", "password123").await;
+    let token = auth.access_token;
+    let todo = common::create_todo(&server, &token, "With files").await;
+    let attachments_url = format!("/api/v1/todos/{}/attachments", todo.id);
+
+    let mut paths = Vec::new();
+    for name in ["a.txt", "b.txt"] {
+        let attachment: AttachmentResponse = server
+            .post(&attachments_url)
+            .add_header("Authorization", format!("Bearer {}", token))
+            .multipart(file_form(name, b"content".to_vec()))
+            .await
+            .json();
+        let path = common::test_storage_root()
+            .join("attachments")
+            .join(attachment.uploaded_by.to_string())
+            .join(attachment.id.to_string());
+        assert!(path.exists());
+        paths.push((attachment.id, path));
+    }
+
+    // Deleting an attachment removes its content
+    server
+        .delete(&format!("{}/{}", attachments_url, paths[0].0))
+        .add_header("Authorization", format!("Bearer {}", token))
+        .await
+        .assert_status(StatusCode::NO_CONTENT);
+    drain_object_deletions(&pool).await;
+    assert!(!paths[0].1.exists());
+    assert!(paths[1].1.exists());
+
+    // Trashed todos keep their attachments until permanently deleted
+    server
+        .delete(&format!("/api/v1/todos/{}", todo.id))
+        .add_header("Authorization", format!("Bearer {}", token))
+        .await
+        .assert_status(StatusCode::NO_CONTENT);
+    drain_object_deletions(&pool).await;
+    assert!(paths[1].1.exists());
+
+    server
+        .delete(&format!("/api/v1/todos/trash/{}", todo.id))
+        .add_header("Authorization", format!("Bearer {}", token))
+        .await
+        .assert_status(StatusCode::NO_CONTENT);
+    drain_object_deletions(&pool).await;
+    assert!(!paths[1].1.exists());
+
+    common::cleanup_test_data(&pool).await;
+}
+
+type Objects = Arc<Mutex<HashMap<String, Bytes>>>;
+
+/// Minimal S3 stand-in: checks that requests are signed and keeps objects
+/// in memory.
+fn s3_stand_in(objects: Objects) -> Router {
+    async fn put_object(
+        State(objects): State<Objects>,
+        Path((bucket, key)): Path<(String, String)>,
+        headers: HeaderMap,
+        body: Bytes,
+    ) -> StatusCode {
+        let hash = hex::encode(Sha256::digest(&body));
+        if !signed(&headers) || headers["x-amz-content-sha256"] != hash.as_str() {
+            return StatusCode::FORBIDDEN;
+        }
+        objects
+            .lock()
+            .unwrap()
+            .insert(format!("{}/{}", bucket, key), body);
+        StatusCode::OK
+    }
+
+    async fn get_object(
+        State(objects): State<Objects>,
+        Path((bucket, key)): Path<(String, String)>,
+        headers: HeaderMap,
+    ) -> axum::response::Response {
+        if !signed(&headers) {
+            return StatusCode::FORBIDDEN.into_response();
+        }
+        let Some(body) = objects
+            .lock()
+            .unwrap()
+            .get(&format!("{}/{}", bucket, key))
+            .cloned()
+        else {
+            return StatusCode::NOT_FOUND.into_response();
+        };
+        match headers
+            .get("range")
+            .and_then(|r| r.to_str().ok()?.strip_prefix("bytes=")?.split_once('-'))
+        {
+            Some((start, end)) => {
+                let (start, end): (usize, usize) = (start.parse().unwrap(), end.parse().unwrap());
+                (StatusCode::PARTIAL_CONTENT, body.slice(start..=end)).into_response()
+            }
+            None => body.into_response(),
+        }
+    }
+
+    async fn delete_object(
+        State(objects): State<Objects>,
+        Path((bucket, key)): Path<(String, String)>,
+        headers: HeaderMap,
+    ) -> StatusCode {
+        if !signed(&headers) {
+            return StatusCode::FORBIDDEN;
+        }
+        objects
+            .lock()
+            .unwrap()
+            .remove(&format!("{}/{}", bucket, key));
+        StatusCode::NO_CONTENT
+    }
+
+    fn signed(headers: &HeaderMap) -> bool {
+        headers.get("authorization").is_some_and(|v| {
+            v.to_str()
+                .unwrap_or_default()
+                .starts_with("AWS4-HMAC-SHA256 Credential=test-key/")
+        }) && headers.contains_key("x-amz-date")
+    }
+
+    Router::new()
+        .route(
+            "/{bucket}/{*key}",
+            put(put_object).get(get_object).delete(delete_object),
+        )
+        .with_state(objects)
+}
+
+#[tokio::test]
+async fn test_s3_object_store_against_stand_in() {
+    let objects = Objects::default();
+    let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let address = listener.local_addr().unwrap();
+    let app = s3_stand_in(objects.clone());
+    tokio::spawn(async move { axum::serve(listener, app).await.unwrap() });
+
+    let store = S3ObjectStore::new(S3Config {
+        endpoint: format!("http://{}", address),
+        bucket: "attachments".to_string(),
+        region: "us-east-1".to_string(),
+        access_key_id: "test-key".to_string(),
+        secret_access_key: "test-secret".to_string(),
+    });
+    let key = "attachments/user/file";
+
+    store.put(key, Bytes::from_static(PNG)).await.unwrap();
+    assert!(objects
+        .lock()
+        .unwrap()
+        .contains_key("attachments/attachments/user/file"));
+
+    let read = |range| {
+        let store = &store;
+        async move {
+            let stream = store.get(key, range).await?;
+            let chunks: Vec<Bytes> = stream.try_collect().await.unwrap();
+            Ok::<_, AppError>(chunks.concat())
+        }
+    };
+    assert_eq!(read(None).await.unwrap(), PNG);
+    assert_eq!(
+        read(Some(ByteRange { start: 1, end: 3 })).await.unwrap(),
+        b"PNG"
+    );
+
+    store.delete(key).await.unwrap();
+    assert!(matches!(read(None).await, Err(AppError::NotFound(_))));
+    // Deleting again is not an error
+    store.delete(key).await.unwrap();
+}
@@ -0,0 +1,269 @@
+use axum::http::StatusCode;
+use rust_teraform_backend::application::dto::{
+    BatchOperationStatus, TodoBatchResponse, TodoListResponse,
+};
+
+use crate::common;
+
+async fn list_todos(server: &axum_test::TestServer, token: &str) -> TodoListResponse {
+    server
+        .get("/api/v1/todos?sort=title")
+        .add_header("Authorization", format!("Bearer {}", token))
+        .await
+        .json()
+}
+
+#[tokio::test]
+async fn test_batch_operations() {
+    let (server, pool) = common::create_test_server().await;
+
+    let auth = common::register_test_user(&server, "batch@example.com", "password123").await;
+    let token = auth.access_token;
+    let first = common::create_todo(&server, &token, "First").await;
+    let second = common::create_todo(&server, &token, "Second").await;
+
+    let response = server
+        .post("/api/v1/todos/batch")
+        .add_header("Authorization", format!("Bearer {}", token))
+        .json(&serde_json::json!({
+            "operations": [
+                { "op": "create", "title": "Third" },
+                { "op": "create", "title": "Fourth", "description": "Bulk" },
+                { "op": "update", "id": first.id, "completed": true },
+                { "op": "update", "id": first.id, "title": "First (done)" },
+                { "op": "delete", "id": second.id }
+            ]
+        }))
+        .await;
+
+    response.assert_status_ok();
+    let batch: TodoBatchResponse = response.json();
+    assert!(batch.committed);
+    assert_eq!(batch.succeeded, 5);
+    assert_eq!(batch.failed, 0);
+    assert_eq!(batch.results[0].todo.as_ref().unwrap().title, "Third");
+    // Later operations see earlier ones
+    let updated = batch.results[3].todo.as_ref().unwrap();
+    assert_eq!(updated.title, "First (done)");
+    assert!(updated.completed);
+    assert!(batch.results[4].todo.is_none());
+
+    let list = list_todos(&server, &token).await;
+    let titles: Vec<_> = list.todos.iter().map(|t| t.title.as_str()).collect();
+    assert_eq!(titles, vec!["First (done)", "Fourth", "Third"]);
+    assert!(list.todos[0].completed);
+
+    // Deleted todos go to the trash
+    let trash: TodoListResponse = server
+        .get("/api/v1/todos/trash")
+        .add_header("Authorization", format!("Bearer {}", token))
+        .await
+        .json();
+    assert_eq!(trash.todos[0].id, second.id);
+
+    common::cleanup_test_data(&pool).await;
+}
+
+#[tokio::test]
+async fn test_batch_all_or_nothing_rolls_back() {
+    let (server, pool) = common::create_test_server().await;
+
+    let auth = common::register_test_user(&server, "batch_atomic@example.com", "password123").await;
+    let token = auth.access_token;
+    let todo = common::create_todo(&server, &token, "Untouched").await;
+
+    // Other users' todos are not found
+    let other = common::register_test_user(&server, "batch_other@example.com", "password123").await;
+    let foreign = common::create_todo(&server, &other.access_token, "Foreign").await;
+
+    let response = server
+        .post("/api/v1/todos/batch")
+        .add_header("Authorization", format!("Bearer {}", token))
+        .json(&serde_json::json!({
+            "mode": "all_or_nothing",
+            "operations": [
+                { "op": "create", "title": "Never saved" },
+                { "op": "update", "id": todo.id, "completed": true },
+                { "op": "delete", "id": foreign.id },
+                { "op": "create", "title": "" }
+            ]
+        }))
+        .await;
+
+    response.assert_status(StatusCode::UNPROCESSABLE_ENTITY);
+    let batch: TodoBatchResponse = response.json();
+    assert!(!batch.committed);
+    assert_eq!(batch.succeeded, 0);
+    assert_eq!(batch.failed, 2);
+    let statuses: Vec<_> = batch.results.iter().map(|r| r.status).collect();
+    assert_eq!(
+        statuses,
+        vec![
+            BatchOperationStatus::RolledBack,
+            BatchOperationStatus::RolledBack,
+            BatchOperationStatus::Failed,
+            BatchOperationStatus::Failed,
+        ]
+    );
+    assert_eq!(batch.results[2].error.as_deref(), Some("Todo not found"));
+    assert_eq!(
+        batch.results[3].error.as_deref(),
+        Some("Title cannot be empty")
+    );
+
+    let list = list_todos(&server, &token).await;
+    assert_eq!(list.total, Some(1));
+    assert!(!list.todos[0].completed);
+
+    common::cleanup_test_data(&pool).await;
+}
+
+#[tokio::test]
+async fn test_batch_best_effort() {
+    let (server, pool) = common::create_test_server().await;
+
+    let auth = common::register_test_user(&server, "batch_best@example.com", "password123").await;
+    let token = auth.access_token;
+    let todo = common::create_todo(&server, &token, "Delete me").await;
+
+    let response = server
+        .post("/api/v1/todos/batch")
+        .add_header("Authorization", format!("Bearer {}", token))
+        .json(&serde_json::json!({
+            "mode": "best_effort",
+            "operations": [
+                { "op": "delete", "id": todo.id },
+                { "op": "delete", "id": todo.id },
+                { "op": "create", "title": "Saved" }
+            ]
+        }))
+        .await;
+
+    response.assert_status_ok();
+    let batch: TodoBatchResponse = response.json();
+    assert!(batch.committed);
+    assert_eq!(batch.succeeded, 2);
+    assert_eq!(batch.failed, 1);
+    assert_eq!(batch.results[1].status, BatchOperationStatus::Failed);
+
+    let list = list_todos(&server, &token).await;
+    assert_eq!(list.total, Some(1));
+    assert_eq!(list.todos[0].title, "Saved");
+
+    common::cleanup_test_data(&pool).await;
+}
+
+#[tokio::test]
+async fn test_batch_limits() {
+    let (server, pool) = common::create_test_server().await;
+
+    let auth = common::register_test_user(&server, "batch_limits@example.com", "password123").await;
+    let token = auth.access_token;
+
+    let response = server
+        .post("/api/v1/todos/batch")
+        .add_header("Authorization", format!("Bearer {}", token))
+        .json(&serde_json::json!({ "operations": [] }))
+        .await;
+    response.assert_status(StatusCode::BAD_REQUEST);
+
+    let operations: Vec<_> = (0..501)
+        .map(|i| serde_json::json!({ "op": "create", "title": format!("Todo {}", i) }))
+        .collect();
+    let response = server
+        .post("/api/v1/todos/batch")
+        .add_header("Authorization", format!("Bearer {}", token))
+        .json(&serde_json::json!({ "operations": operations }))
+        .await;
+    response.assert_status(StatusCode::BAD_REQUEST);
+
+    // A full batch is written in one go
+    let response = server
+        .post("/api/v1/todos/batch")
+        .add_header("Authorization", format!("Bearer {}", token))
+        .json(&serde_json::json!({ "operations": &operations[..500] }))
+        .await;
+    response.assert_status_ok();
+    let list: TodoListResponse = server
+        .get("/api/v1/todos")
+        .add_header("Authorization", format!("Bearer {}", token))
+        .await
+        .json();
+    assert_eq!(list.total, Some(500));
+
+    common::cleanup_test_data(&pool).await;
+}
+
+#[tokio::test]
+async fn test_batch_on_shared_todos() {
+    let (server, pool) = common::create_test_server().await;
+
+    let owner = common::register_test_user(&server, "batch_owner@example.com", "password123")
+        .await
+        .access_token;
+    let editor = common::register_test_user(&server, "batch_editor@example.com", "password123")
+        .await
+        .access_token;
+    let viewer = common::register_test_user(&server, "batch_viewer@example.com", "password123")
+        .await
+        .access_token;
+    let workspace = common::join_workspace(&server, &owner, &editor, "batch_editor@example.com")
+        .await
+        .to_string();
+    common::join_workspace(&server, &owner, &viewer, "batch_viewer@example.com").await;
+
+    let todo = common::create_todo(&server, &owner, "Shared").await;
+    for (email, permission) in [
+        ("batch_editor@example.com", "editor"),
+        ("batch_viewer@example.com", "viewer"),
+    ] {
+        server
+            .post(&format!("/api/v1/todos/{}/shares", todo.id))
+            .add_header("Authorization", format!("Bearer {}", owner))
+            .json(&serde_json::json!({ "email": email, "permission": permission }))
+            .await
+            .assert_status(StatusCode::CREATED);
+    }
+    let batch = |token: &str, operations: serde_json::Value| {
+        server
+            .post("/api/v1/todos/batch")
+            .add_header("Authorization", format!("Bearer {}", token))
+            .add_header("X-Workspace-Id", &workspace)
+            .json(&serde_json::json!({ "mode": "best_effort", "operations": operations }))
+    };
+
+    // Editors may update but not trash, as with single requests
+    let response: TodoBatchResponse = batch(
+        &editor,
+        serde_json::json!([
+            { "op": "update", "id": todo.id, "title": "Shared (edited)" },
+            { "op": "delete", "id": todo.id }
+        ]),
+    )
+    .await
+    .json();
+    let statuses: Vec<_> = response.results.iter().map(|r| r.status).collect();
+    assert_eq!(
+        statuses,
+        vec![
+            BatchOperationStatus::Succeeded,
+            BatchOperationStatus::Failed
+        ]
+    );
+    assert_eq!(response.results[1].error.as_deref(), Some("Forbidden"));
+
+    let response: TodoBatchResponse = batch(
+        &viewer,
+        serde_json::json!([{ "op": "update", "id": todo.id, "completed": true }]),
+    )
+    .await
+    .json();
+    assert_eq!(response.results[0].status, BatchOperationStatus::Failed);
+    assert_eq!(response.results[0].error.as_deref(), Some("Forbidden"));
+
+    let list = list_todos(&server, &owner).await;
+    assert_eq!(list.todos[0].title, "Shared (edited)");
+    assert!(!list.todos[0].completed);
+
+    common::cleanup_test_data(&pool).await;
+}
@@ -0,0 +1,298 @@
+use axum::http::StatusCode;
+use axum_test::TestServer;
+use rust_teraform_backend::application::dto::{
+    BoardColumnResponse, BoardResponse, ProjectResponse, TodoResponse,
+};
+use uuid::Uuid;
+
+use crate::common;
+
+async fn create_card(
+    server: &TestServer,
+    token: &str,
+    project: &ProjectResponse,
+    title: &str,
+) -> TodoResponse {
+    common::create_todo_with(
+        server,
+        token,
+        serde_json::json!({ "title": title, "project_id": project.id }),
+    )
+    .await
+}
+
+async fn board(server: &TestServer, token: &str, project: &ProjectResponse) -> BoardResponse {
+    server
+        .get(&format!("/api/v1/projects/{}/board", project.id))
+        .add_header("Authorization", format!("Bearer {}", token))
+        .await
+        .json()
+}
+
+async fn move_card(
+    server: &TestServer,
+    token: &str,
+    todo: &TodoResponse,
+    status: &str,
+    after: Option<&TodoResponse>,
+) -> axum_test::TestResponse {
+    server
+        .post(&format!("/api/v1/todos/{}/move", todo.id))
+        .add_header("Authorization", format!("Bearer {}", token))
+        .json(&serde_json::json!({
+            "status": status,
+            "after_id": after.map(|t| t.id),
+        }))
+        .await
+}
+
+fn titles(board: &BoardResponse, status: &str) -> Vec<String> {
+    board
+        .columns
+        .iter()
+        .find(|column| column.status == status)
+        .unwrap()
+        .cards
+        .iter()
+        .map(|card| card.title.clone())
+        .collect()
+}
+
+#[tokio::test]
+async fn test_board_columns() {
+    let (server, pool) = common::create_test_server().await;
+
+    let token = common::register_test_user(&server, "board@example.com", "password123")
+        .await
+        .access_token;
+    let project = common::create_project(&server, &token, "Launch").await;
+    create_card(&server, &token, &project, "Design").await;
+    create_card(&server, &token, &project, "Build").await;
+    create_card(&server, &token, &project, "Ship").await;
+
+    // Every status gets a column; cards start in creation order
+    let response = board(&server, &token, &project).await;
+    let statuses: Vec<_> = response.columns.iter().map(|c| c.status.as_str()).collect();
+    assert_eq!(
+        statuses,
+        vec!["todo", "in_progress", "in_review", "done", "cancelled"]
+    );
+    assert_eq!(titles(&response, "todo"), vec!["Design", "Build", "Ship"]);
+    assert_eq!(response.columns[0].total, 3);
+
+    common::cleanup_test_data(&pool).await;
+}
+
+#[tokio::test]
+async fn test_board_shows_only_the_project() {
+    let (server, pool) = common::create_test_server().await;
+
+    let token = common::register_test_user(&server, "board_scope@example.com", "password123")
+        .await
+        .access_token;
+    let launch = common::create_project(&server, &token, "Launch").await;
+    let website = common::create_project(&server, &token, "Website").await;
+    create_card(&server, &token, &launch, "Ship").await;
+    create_card(&server, &token, &website, "Fix the footer").await;
+    common::create_todo(&server, &token, "Renew passport").await;
+
+    let response = board(&server, &token, &launch).await;
+    assert_eq!(titles(&response, "todo"), vec!["Ship"]);
+    assert_eq!(response.columns[0].total, 1);
+
+    // Columns follow the project's workflow
+    server
+        .put(&format!("/api/v1/projects/{}/workflow", website.id))
+        .add_header("Authorization", format!("Bearer {}", token))
+        .json(&serde_json::json!({
+            "statuses": [
+                { "key": "todo", "name": "Backlog", "transitions": ["shipped"] },
+                { "key": "shipped", "name": "Shipped", "terminal": true }
+            ]
+        }))
+        .await
+        .assert_status_ok();
+    let response = board(&server, &token, &website).await;
+    let statuses: Vec<_> = response.columns.iter().map(|c| c.status.as_str()).collect();
+    assert_eq!(statuses, vec!["todo", "shipped"]);
+    assert_eq!(titles(&response, "todo"), vec!["Fix the footer"]);
+
+    server
+        .get(&format!("/api/v1/projects/{}/board", Uuid::new_v4()))
+        .add_header("Authorization", format!("Bearer {}", token))
+        .await
+        .assert_status(StatusCode::NOT_FOUND);
+
+    common::cleanup_test_data(&pool).await;
+}
+
+#[tokio::test]
+async fn test_board_column_pages() {
+    let (server, pool) = common::create_test_server().await;
+
+    let token = common::register_test_user(&server, "board_pages@example.com", "password123")
+        .await
+        .access_token;
+    let project = common::create_project(&server, &token, "Launch").await;
+    for title in ["Design", "Build", "Ship"] {
+        create_card(&server, &token, &project, title).await;
+    }
+
+    // Columns are paged separately
+    let response: BoardResponse = server
+        .get(&format!("/api/v1/projects/{}/board?limit=2", project.id))
+        .add_header("Authorization", format!("Bearer {}", token))
+        .await
+        .json();
+    assert_eq!(response.columns[0].cards.len(), 2);
+    assert!(response.columns[0].has_more);
+    assert!(!response.columns[1].has_more);
+
+    let column: BoardColumnResponse = server
+        .get(&format!(
+            "/api/v1/projects/{}/board/todo?page=2&per_page=2",
+            project.id
+        ))
+        .add_header("Authorization", format!("Bearer {}", token))
+        .await
+        .json();
+    assert_eq!(column.total, 3);
+    assert_eq!(column.cards[0].title, "Ship");
+    assert!(!column.has_more);
+    server
+        .get(&format!("/api/v1/projects/{}/board/shipped", project.id))
+        .add_header("Authorization", format!("Bearer {}", token))
+        .await
+        .assert_status(StatusCode::NOT_FOUND);
+
+    common::cleanup_test_data(&pool).await;
+}
+
+#[tokio::test]
+async fn test_reordering_cards() {
+    let (server, pool) = common::create_test_server().await;
+
+    let token = common::register_test_user(&server, "board_order@example.com", "password123")
+        .await
+        .access_token;
+    let project = common::create_project(&server, &token, "Launch").await;
+    let design = create_card(&server, &token, &project, "Design").await;
+    create_card(&server, &token, &project, "Build").await;
+    let ship = create_card(&server, &token, &project, "Ship").await;
+
+    move_card(&server, &token, &ship, "todo", None)
+        .await
+        .assert_status_ok();
+    assert_eq!(
+        titles(&board(&server, &token, &project).await, "todo"),
+        vec!["Ship", "Design", "Build"]
+    );
+    move_card(&server, &token, &ship, "todo", Some(&design))
+        .await
+        .assert_status_ok();
+    assert_eq!(
+        titles(&board(&server, &token, &project).await, "todo"),
+        vec!["Design", "Ship", "Build"]
+    );
+
+    // Anchors must be cards of the same project
+    let other = common::create_project(&server, &token, "Website").await;
+    let footer = create_card(&server, &token, &other, "Fix the footer").await;
+    move_card(&server, &token, &ship, "todo", Some(&footer))
+        .await
+        .assert_status(StatusCode::BAD_REQUEST);
+
+    common::cleanup_test_data(&pool).await;
+}
+
+#[tokio::test]
+async fn test_moving_cards_between_columns() {
+    let (server, pool) = common::create_test_server().await;
+
+    let token = common::register_test_user(&server, "board_move@example.com", "password123")
+        .await
+        .access_token;
+    let project = common::create_project(&server, &token, "Launch").await;
+    let design = create_card(&server, &token, &project, "Design").await;
+    let build = create_card(&server, &token, &project, "Build").await;
+    let ship = create_card(&server, &token, &project, "Ship").await;
+
+    // Moving changes the status and the position at once
+    let response = move_card(&server, &token, &design, "in_progress", None).await;
+    response.assert_status_ok();
+    response.assert_header("ETag", "\"2\"");
+    assert_eq!(response.json::<TodoResponse>().status, "in_progress");
+    move_card(&server, &token, &build, "in_progress", Some(&design))
+        .await
+        .assert_status_ok();
+    move_card(&server, &token, &ship, "in_progress", Some(&design))
+        .await
+        .assert_status_ok();
+    let response = board(&server, &token, &project).await;
+    assert_eq!(
+        titles(&response, "in_progress"),
+        vec!["Design", "Ship", "Build"]
+    );
+    assert!(titles(&response, "todo").is_empty());
+
+    // Anchors must be in the target column, and moves follow the workflow
+    move_card(&server, &token, &design, "in_review", Some(&build))
+        .await
+        .assert_status(StatusCode::BAD_REQUEST);
+    move_card(&server, &token, &design, "in_progress", Some(&design))
+        .await
+        .assert_status(StatusCode::BAD_REQUEST);
+    let docs = create_card(&server, &token, &project, "Docs").await;
+    move_card(&server, &token, &docs, "in_review", None)
+        .await
+        .assert_status(StatusCode::BAD_REQUEST);
+
+    let response = move_card(&server, &token, &design, "done", None).await;
+    response.assert_status_ok();
+    assert!(response.json::<TodoResponse>().completed);
+
+    common::cleanup_test_data(&pool).await;
+}
+
+#[tokio::test]
+async fn test_board_is_private() {
+    let (server, pool) = common::create_test_server().await;
+
+    let owner = common::register_test_user(&server, "board_owner@example.com", "password123")
+        .await
+        .access_token;
+    let member = common::register_test_user(&server, "board_member@example.com", "password123")
+        .await
+        .access_token;
+    let outsider = common::register_test_user(&server, "board_other@example.com", "password123")
+        .await
+        .access_token;
+    let workspace =
+        common::join_workspace(&server, &owner, &member, "board_member@example.com").await;
+    let project = common::create_project(&server, &owner, "Launch").await;
+    let build = create_card(&server, &owner, &project, "Build").await;
+
+    // Members see the project's board, without the todos not shared with them
+    let response: BoardResponse = server
+        .get(&format!("/api/v1/projects/{}/board", project.id))
+        .add_header("Authorization", format!("Bearer {}", member))
+        .add_header("X-Workspace-Id", workspace.to_string())
+        .await
+        .json();
+    assert!(response.columns.iter().all(|column| column.total == 0));
+
+    // Outsiders see neither the project nor its cards
+    server
+        .get(&format!("/api/v1/projects/{}/board", project.id))
+        .add_header("Authorization", format!("Bearer {}", outsider))
+        .await
+        .assert_status(StatusCode::NOT_FOUND);
+    server
+        .post(&format!("/api/v1/todos/{}/move", build.id))
+        .add_header("Authorization", format!("Bearer {}", outsider))
+        .json(&serde_json::json!({ "status": "done", "after_id": Uuid::nil() }))
+        .await
+        .assert_status(StatusCode::NOT_FOUND);
+
+    common::cleanup_test_data(&pool).await;
+}
@@ -0,0 +1,241 @@
+use axum::http::StatusCode;
+use axum_test::TestServer;
+use rust_teraform_backend::application::dto::{ChecklistProgress, TodoResponse};
+use uuid::Uuid;
+
+use crate::common;
+
+async fn add_item(
+    server: &TestServer,
+    token: &str,
+    todo: &TodoResponse,
+    text: &str,
+) -> TodoResponse {
+    let response = server
+        .post(&format!("/api/v1/todos/{}/checklist", todo.id))
+        .add_header("Authorization", format!("Bearer {}", token))
+        .json(&serde_json::json!({ "text": text }))
+        .await;
+    response.assert_status(StatusCode::CREATED);
+    response.json()
+}
+
+fn toggle(
+    server: &TestServer,
+    token: &str,
+    todo: &TodoResponse,
+    item_id: Uuid,
+) -> axum_test::TestRequest {
+    server
+        .post(&format!(
+            "/api/v1/todos/{}/checklist/{}/toggle",
+            todo.id, item_id
+        ))
+        .add_header("Authorization", format!("Bearer {}", token))
+}
+
+/// A todo with Passport, Charger and Tickets on its checklist, in that order.
+async fn packing_list(server: &TestServer, token: &str) -> TodoResponse {
+    let todo = common::create_todo(server, token, "Pack for the trip").await;
+    add_item(server, token, &todo, "Passport").await;
+    add_item(server, token, &todo, "Charger").await;
+    add_item(server, token, &todo, "Tickets").await
+}
+
+fn texts(todo: &TodoResponse) -> Vec<&str> {
+    todo.checklist.iter().map(|i| i.text.as_str()).collect()
+}
+
+#[tokio::test]
+async fn test_add_checklist_items() {
+    let (server, pool) = common::create_test_server().await;
+
+    let token = common::register_test_user(&server, "checklist@example.com", "password123")
+        .await
+        .access_token;
+    let todo = common::create_todo(&server, &token, "Pack for the trip").await;
+    assert!(todo.checklist.is_empty());
+
+    add_item(&server, &token, &todo, "Passport").await;
+    add_item(&server, &token, &todo, "Charger").await;
+
+    // Items go to the end unless a position is given
+    let response = server
+        .post(&format!("/api/v1/todos/{}/checklist", todo.id))
+        .add_header("Authorization", format!("Bearer {}", token))
+        .json(&serde_json::json!({ "text": "Tickets", "position": 0 }))
+        .await;
+    response.assert_status(StatusCode::CREATED);
+    assert_eq!(response.header("ETag"), "\"4\"");
+    let packed: TodoResponse = response.json();
+    assert_eq!(texts(&packed), vec!["Tickets", "Passport", "Charger"]);
+
+    server
+        .post(&format!("/api/v1/todos/{}/checklist", todo.id))
+        .add_header("Authorization", format!("Bearer {}", token))
+        .json(&serde_json::json!({ "text": "  " }))
+        .await
+        .assert_status(StatusCode::BAD_REQUEST);
+
+    let fetched: TodoResponse = server
+        .get(&format!("/api/v1/todos/{}", todo.id))
+        .add_header("Authorization", format!("Bearer {}", token))
+        .await
+        .json();
+    assert_eq!(fetched.checklist, packed.checklist);
+
+    common::cleanup_test_data(&pool).await;
+}
+
+#[tokio::test]
+async fn test_toggle_checklist_item() {
+    let (server, pool) = common::create_test_server().await;
+
+    let token = common::register_test_user(&server, "checklist_toggle@example.com", "password123")
+        .await
+        .access_token;
+    let todo = packing_list(&server, &token).await;
+
+    // Toggling bumps the todo's version, so stale writes are caught
+    let response = toggle(&server, &token, &todo, todo.checklist[1].id)
+        .add_header("If-Match", format!("\"{}\"", todo.version))
+        .await;
+    response.assert_status_ok();
+    let toggled: TodoResponse = response.json();
+    assert_eq!(toggled.version, todo.version + 1);
+    assert!(toggled.updated_at > todo.updated_at);
+    assert!(toggled.checklist[1].checked);
+
+    server
+        .patch(&format!("/api/v1/todos/{}", todo.id))
+        .add_header("Authorization", format!("Bearer {}", token))
+        .add_header("If-Match", format!("\"{}\"", todo.version))
+        .json(&serde_json::json!({ "title": "Pack" }))
+        .await
+        .assert_status(StatusCode::PRECONDITION_FAILED);
+
+    // Toggling again unchecks it
+    let response = toggle(&server, &token, &todo, todo.checklist[1].id).await;
+    assert!(!response.json::<TodoResponse>().checklist[1].checked);
+
+    toggle(&server, &token, &todo, Uuid::new_v4())
+        .await
+        .assert_status(StatusCode::NOT_FOUND);
+
+    common::cleanup_test_data(&pool).await;
+}
+
+#[tokio::test]
+async fn test_reorder_checklist() {
+    let (server, pool) = common::create_test_server().await;
+
+    let token = common::register_test_user(&server, "checklist_order@example.com", "password123")
+        .await
+        .access_token;
+    let todo = packing_list(&server, &token).await;
+    let ids: Vec<_> = todo.checklist.iter().map(|i| i.id).collect();
+    let order_url = format!("/api/v1/todos/{}/checklist/order", todo.id);
+
+    // Reordering must list every item once
+    for item_ids in [vec![ids[1], ids[0]], vec![ids[1], ids[1], ids[0]]] {
+        server
+            .put(&order_url)
+            .add_header("Authorization", format!("Bearer {}", token))
+            .json(&serde_json::json!({ "item_ids": item_ids }))
+            .await
+            .assert_status(StatusCode::BAD_REQUEST);
+    }
+
+    let response = server
+        .put(&order_url)
+        .add_header("Authorization", format!("Bearer {}", token))
+        .json(&serde_json::json!({ "item_ids": [ids[2], ids[0], ids[1]] }))
+        .await;
+    response.assert_status_ok();
+    let reordered: TodoResponse = response.json();
+    assert_eq!(texts(&reordered), vec!["Tickets", "Passport", "Charger"]);
+
+    common::cleanup_test_data(&pool).await;
+}
+
+#[tokio::test]
+async fn test_remove_checklist_item() {
+    let (server, pool) = common::create_test_server().await;
+
+    let token = common::register_test_user(&server, "checklist_remove@example.com", "password123")
+        .await
+        .access_token;
+    let todo = packing_list(&server, &token).await;
+    let item_url = format!(
+        "/api/v1/todos/{}/checklist/{}",
+        todo.id, todo.checklist[1].id
+    );
+
+    let response = server
+        .delete(&item_url)
+        .add_header("Authorization", format!("Bearer {}", token))
+        .await;
+    response.assert_status_ok();
+    let removed: TodoResponse = response.json();
+    assert_eq!(texts(&removed), vec!["Passport", "Tickets"]);
+
+    server
+        .delete(&item_url)
+        .add_header("Authorization", format!("Bearer {}", token))
+        .await
+        .assert_status(StatusCode::NOT_FOUND);
+
+    common::cleanup_test_data(&pool).await;
+}
+
+#[tokio::test]
+async fn test_checklist_progress() {
+    let (server, pool) = common::create_test_server().await;
+
+    let token =
+        common::register_test_user(&server, "checklist_progress@example.com", "password123")
+            .await
+            .access_token;
+    let empty = common::create_todo(&server, &token, "Nothing to pack").await;
+    assert_eq!(
+        empty.checklist_progress,
+        ChecklistProgress {
+            checked: 0,
+            total: 0
+        }
+    );
+
+    // Progress follows checked and removed items
+    let todo = packing_list(&server, &token).await;
+    toggle(&server, &token, &todo, todo.checklist[0].id)
+        .await
+        .assert_status_ok();
+    let toggled: TodoResponse = toggle(&server, &token, &todo, todo.checklist[1].id)
+        .await
+        .json();
+    assert_eq!(
+        toggled.checklist_progress,
+        ChecklistProgress {
+            checked: 2,
+            total: 3
+        }
+    );
+
+    let removed: TodoResponse = server
+        .delete(&format!(
+            "/api/v1/todos/{}/checklist/{}",
+            todo.id, todo.checklist[0].id
+        ))
+        .add_header("Authorization", format!("Bearer {}", token))
+        .await
+        .json();
+    assert_eq!(
+        removed.checklist_progress,
+        ChecklistProgress {
+            checked: 1,
+            total: 2
+        }
+    );
+
+    common::cleanup_test_data(&pool).await;
+}
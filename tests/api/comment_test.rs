@@ -0,0 +1,204 @@
+use axum::http::StatusCode;
+use rust_teraform_backend::application::dto::{
+    CommentListResponse, CommentResponse, TodoListResponse, TodoResponse,
+};
+
+use crate::common;
+
+#[tokio::test]
+async fn test_create_comment() {
+    let (server, pool) = common::create_test_server().await;
+
+    let auth = common::register_test_user(&server, "comments@example.com", "password123").await;
+    let token = auth.access_token;
+    let todo = common::create_todo(&server, &token, "Discuss me").await;
+    let comments_url = format!("/api/v1/todos/{}/comments", todo.id);
+
+    let response = server
+        .post(&comments_url)
+        .add_header("Authorization", format!("Bearer {}", token))
+        .json(&serde_json::json!({ "body": "**First**\r\n<img src=x onerror=alert(1)>" }))
+        .await;
+    response.assert_status(StatusCode::CREATED);
+    let comment: CommentResponse = response.json();
+    // Markdown is stored as written, with normalized line endings
+    assert_eq!(comment.body, "**First**\n<img src=x onerror=alert(1)>");
+    assert_eq!(comment.edited_at, None);
+
+    let response = server
+        .post(&comments_url)
+        .add_header("Authorization", format!("Bearer {}", token))
+        .json(&serde_json::json!({ "body": "   " }))
+        .await;
+    response.assert_status(StatusCode::BAD_REQUEST);
+
+    common::cleanup_test_data(&pool).await;
+}
+
+#[tokio::test]
+async fn test_edit_and_delete_comment() {
+    let (server, pool) = common::create_test_server().await;
+
+    let auth = common::register_test_user(&server, "comment_edit@example.com", "password123").await;
+    let token = auth.access_token;
+    let todo = common::create_todo(&server, &token, "Discuss me").await;
+    let comments_url = format!("/api/v1/todos/{}/comments", todo.id);
+    let comment: CommentResponse = server
+        .post(&comments_url)
+        .add_header("Authorization", format!("Bearer {}", token))
+        .json(&serde_json::json!({ "body": "First" }))
+        .await
+        .json();
+
+    let response = server
+        .put(&format!("{}/{}", comments_url, comment.id))
+        .add_header("Authorization", format!("Bearer {}", token))
+        .json(&serde_json::json!({ "body": "Edited" }))
+        .await;
+    response.assert_status_ok();
+    let edited: CommentResponse = response.json();
+    assert_eq!(edited.body, "Edited");
+    assert!(edited.edited_at.is_some());
+
+    server
+        .delete(&format!("{}/{}", comments_url, comment.id))
+        .add_header("Authorization", format!("Bearer {}", token))
+        .await
+        .assert_status(StatusCode::NO_CONTENT);
+    server
+        .delete(&format!("{}/{}", comments_url, comment.id))
+        .add_header("Authorization", format!("Bearer {}", token))
+        .await
+        .assert_status(StatusCode::NOT_FOUND);
+
+    common::cleanup_test_data(&pool).await;
+}
+
+#[tokio::test]
+async fn test_comments_bump_todo_version() {
+    let (server, pool) = common::create_test_server().await;
+
+    let auth =
+        common::register_test_user(&server, "comment_version@example.com", "password123").await;
+    let token = auth.access_token;
+    let todo = common::create_todo(&server, &token, "Discuss me").await;
+    let comments_url = format!("/api/v1/todos/{}/comments", todo.id);
+    let fetch = || {
+        server
+            .get(&format!("/api/v1/todos/{}", todo.id))
+            .add_header("Authorization", format!("Bearer {}", token))
+    };
+
+    // The comment count is part of the todo, so commenting bumps its version
+    let comment: CommentResponse = server
+        .post(&comments_url)
+        .add_header("Authorization", format!("Bearer {}", token))
+        .json(&serde_json::json!({ "body": "First" }))
+        .await
+        .json();
+    let fetched: TodoResponse = fetch().await.json();
+    assert_eq!(fetched.comment_count, 1);
+    assert_eq!(fetched.version, todo.version + 1);
+
+    server
+        .delete(&format!("{}/{}", comments_url, comment.id))
+        .add_header("Authorization", format!("Bearer {}", token))
+        .await
+        .assert_status(StatusCode::NO_CONTENT);
+    let fetched: TodoResponse = fetch().await.json();
+    assert_eq!(fetched.comment_count, 0);
+    assert_eq!(fetched.version, todo.version + 2);
+
+    common::cleanup_test_data(&pool).await;
+}
+
+#[tokio::test]
+async fn test_list_comments_with_cursor() {
+    let (server, pool) = common::create_test_server().await;
+
+    let auth =
+        common::register_test_user(&server, "comment_pages@example.com", "password123").await;
+    let token = auth.access_token;
+    let todo = common::create_todo(&server, &token, "Discuss me").await;
+    let comments_url = format!("/api/v1/todos/{}/comments", todo.id);
+
+    for i in 1..=3 {
+        server
+            .post(&comments_url)
+            .add_header("Authorization", format!("Bearer {}", token))
+            .json(&serde_json::json!({ "body": format!("Comment {}", i) }))
+            .await
+            .assert_status(StatusCode::CREATED);
+    }
+
+    let first: CommentListResponse = server
+        .get(&format!("{}?per_page=2", comments_url))
+        .add_header("Authorization", format!("Bearer {}", token))
+        .await
+        .json();
+    let bodies: Vec<_> = first.comments.iter().map(|c| c.body.as_str()).collect();
+    assert_eq!(bodies, vec!["Comment 1", "Comment 2"]);
+    let cursor = first.next_cursor.expect("a second page");
+
+    let second: CommentListResponse = server
+        .get(&format!("{}?per_page=2&cursor={}", comments_url, cursor))
+        .add_header("Authorization", format!("Bearer {}", token))
+        .await
+        .json();
+    let bodies: Vec<_> = second.comments.iter().map(|c| c.body.as_str()).collect();
+    assert_eq!(bodies, vec!["Comment 3"]);
+    assert_eq!(second.next_cursor, None);
+
+    server
+        .get(&format!("{}?cursor=garbage", comments_url))
+        .add_header("Authorization", format!("Bearer {}", token))
+        .await
+        .assert_status(StatusCode::BAD_REQUEST);
+
+    let todos: TodoListResponse = server
+        .get("/api/v1/todos")
+        .add_header("Authorization", format!("Bearer {}", token))
+        .await
+        .json();
+    assert_eq!(todos.todos[0].comment_count, 3);
+
+    common::cleanup_test_data(&pool).await;
+}
+
+#[tokio::test]
+async fn test_comments_hidden_from_other_users() {
+    let (server, pool) = common::create_test_server().await;
+
+    let owner =
+        common::register_test_user(&server, "comment_owner@example.com", "password123").await;
+    let other =
+        common::register_test_user(&server, "comment_other@example.com", "password123").await;
+    let todo = common::create_todo(&server, &owner.access_token, "Discuss me").await;
+    let comments_url = format!("/api/v1/todos/{}/comments", todo.id);
+
+    let comment: CommentResponse = server
+        .post(&comments_url)
+        .add_header("Authorization", format!("Bearer {}", owner.access_token))
+        .json(&serde_json::json!({ "body": "Private" }))
+        .await
+        .json();
+
+    server
+        .get(&comments_url)
+        .add_header("Authorization", format!("Bearer {}", other.access_token))
+        .await
+        .assert_status(StatusCode::NOT_FOUND);
+    server
+        .post(&comments_url)
+        .add_header("Authorization", format!("Bearer {}", other.access_token))
+        .json(&serde_json::json!({ "body": "Intruder" }))
+        .await
+        .assert_status(StatusCode::NOT_FOUND);
+    server
+        .delete(&format!("{}/{}", comments_url, comment.id))
+        .add_header("Authorization", format!("Bearer {}", other.access_token))
+        .await
+        .assert_status(StatusCode::NOT_FOUND);
+
+    common::cleanup_test_data(&pool).await;
+}
@@ -0,0 +1,192 @@
+use axum::http::StatusCode;
+use rust_teraform_backend::application::dto::TodoResponse;
+use rust_teraform_backend::domain::entities::TodoId;
+use rust_teraform_backend::domain::repositories::TodoRepository;
+use rust_teraform_backend::infrastructure::persistence::postgres::{
+    PostgresTodoRepository, Tenant,
+};
+use rust_teraform_backend::shared::error::AppError;
+
+use crate::common;
+
+#[tokio::test]
+async fn test_etag_and_if_none_match() {
+    let (server, pool) = common::create_test_server().await;
+
+    let auth = common::register_test_user(&server, "etag@example.com", "password123").await;
+    let token = auth.access_token;
+
+    let response = server
+        .post("/api/v1/todos")
+        .add_header("Authorization", format!("Bearer {}", token))
+        .json(&serde_json::json!({ "title": "Cache me" }))
+        .await;
+    response.assert_status(StatusCode::CREATED);
+    assert_eq!(response.header("ETag"), "\"1\"");
+    let todo: TodoResponse = response.json();
+    assert_eq!(todo.version, 1);
+
+    let response = server
+        .get(&format!("/api/v1/todos/{}", todo.id))
+        .add_header("Authorization", format!("Bearer {}", token))
+        .await;
+    response.assert_status_ok();
+    assert_eq!(response.header("ETag"), "\"1\"");
+
+    // Current tags, strong or weak, give 304 without a body
+    for tag in ["\"1\"", "W/\"1\"", "\"7\", \"1\"", "*"] {
+        let response = server
+            .get(&format!("/api/v1/todos/{}", todo.id))
+            .add_header("Authorization", format!("Bearer {}", token))
+            .add_header("If-None-Match", tag)
+            .await;
+        response.assert_status(StatusCode::NOT_MODIFIED);
+        assert!(response.as_bytes().is_empty());
+    }
+
+    // Stale tags get the full todo
+    let response = server
+        .get(&format!("/api/v1/todos/{}", todo.id))
+        .add_header("Authorization", format!("Bearer {}", token))
+        .add_header("If-None-Match", "\"0\"")
+        .await;
+    response.assert_status_ok();
+
+    common::cleanup_test_data(&pool).await;
+}
+
+#[tokio::test]
+async fn test_etag_changes_with_comments() {
+    let (server, pool) = common::create_test_server().await;
+
+    let token = common::register_test_user(&server, "etag_comments@example.com", "password123")
+        .await
+        .access_token;
+    let todo = common::create_todo(&server, &token, "Discuss me").await;
+    let todo_url = format!("/api/v1/todos/{}", todo.id);
+
+    let response = server
+        .get(&todo_url)
+        .add_header("Authorization", format!("Bearer {}", token))
+        .await;
+    let tag = response.header("ETag");
+
+    server
+        .post(&format!("{}/comments", todo_url))
+        .add_header("Authorization", format!("Bearer {}", token))
+        .json(&serde_json::json!({ "body": "First!" }))
+        .await
+        .assert_status(StatusCode::CREATED);
+
+    // The cached copy has the old comment count, so it is no longer current
+    let response = server
+        .get(&todo_url)
+        .add_header("Authorization", format!("Bearer {}", token))
+        .add_header("If-None-Match", tag.clone())
+        .await;
+    response.assert_status_ok();
+    assert_ne!(response.header("ETag"), tag);
+    assert_eq!(response.json::<TodoResponse>().comment_count, 1);
+
+    common::cleanup_test_data(&pool).await;
+}
+
+#[tokio::test]
+async fn test_if_match_on_writes() {
+    let (server, pool) = common::create_test_server().await;
+
+    let auth = common::register_test_user(&server, "if_match@example.com", "password123").await;
+    let token = auth.access_token;
+
+    let todo = common::create_todo(&server, &token, "Shared").await;
+
+    // First tab saves against the current version
+    let response = server
+        .patch(&format!("/api/v1/todos/{}", todo.id))
+        .add_header("Authorization", format!("Bearer {}", token))
+        .add_header("If-Match", "\"1\"")
+        .json(&serde_json::json!({ "title": "Tab one" }))
+        .await;
+    response.assert_status_ok();
+    assert_eq!(response.header("ETag"), "\"2\"");
+
+    // Second tab still holds version 1
+    let response = server
+        .put(&format!("/api/v1/todos/{}", todo.id))
+        .add_header("Authorization", format!("Bearer {}", token))
+        .add_header("If-Match", "\"1\"")
+        .json(&serde_json::json!({ "title": "Tab two" }))
+        .await;
+    response.assert_status(StatusCode::PRECONDITION_FAILED);
+
+    // Weak tags never match If-Match
+    let response = server
+        .patch(&format!("/api/v1/todos/{}", todo.id))
+        .add_header("Authorization", format!("Bearer {}", token))
+        .add_header("If-Match", "W/\"2\"")
+        .json(&serde_json::json!({ "completed": true }))
+        .await;
+    response.assert_status(StatusCode::PRECONDITION_FAILED);
+
+    let response = server
+        .delete(&format!("/api/v1/todos/{}", todo.id))
+        .add_header("Authorization", format!("Bearer {}", token))
+        .add_header("If-Match", "\"1\"")
+        .await;
+    response.assert_status(StatusCode::PRECONDITION_FAILED);
+
+    let current: TodoResponse = server
+        .get(&format!("/api/v1/todos/{}", todo.id))
+        .add_header("Authorization", format!("Bearer {}", token))
+        .await
+        .json();
+    assert_eq!(current.title, "Tab one");
+    assert_eq!(current.version, 2);
+
+    let response = server
+        .delete(&format!("/api/v1/todos/{}", todo.id))
+        .add_header("Authorization", format!("Bearer {}", token))
+        .add_header("If-Match", "*")
+        .await;
+    response.assert_status(StatusCode::NO_CONTENT);
+
+    common::cleanup_test_data(&pool).await;
+}
+
+#[tokio::test]
+async fn test_concurrent_saves_conflict() {
+    let (server, pool) = common::create_test_server().await;
+
+    let auth = common::register_test_user(&server, "conflict@example.com", "password123").await;
+
+    let todo = common::create_todo(&server, &auth.access_token, "Raced").await;
+
+    // Two requests load the same version before either saves
+    let (user_id,): (uuid::Uuid,) = sqlx::query_as("SELECT id FROM users WHERE email = $1")
+        .bind("conflict@example.com")
+        .fetch_one(&pool)
+        .await
+        .unwrap();
+    Tenant::System
+        .scope(async {
+            let repository = PostgresTodoRepository::new(pool.clone());
+            let id = TodoId(todo.id);
+            let mut first = repository.find_by_id(id, user_id).await.unwrap().unwrap();
+            let mut second = first.clone();
+
+            first.completed = true;
+            let saved = repository.update(&first, &[]).await.unwrap();
+            assert_eq!(saved.version, 2);
+
+            second.description = Some("Lost update".to_string());
+            let result = repository.update(&second, &[]).await;
+            assert!(matches!(result, Err(AppError::Conflict(_))));
+
+            let current = repository.find_by_id(id, user_id).await.unwrap().unwrap();
+            assert!(current.completed);
+            assert_eq!(current.description, None);
+        })
+        .await;
+
+    common::cleanup_test_data(&pool).await;
+}
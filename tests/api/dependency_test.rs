@@ -0,0 +1,220 @@
+use axum::http::StatusCode;
+use axum_test::TestServer;
+use rust_teraform_backend::application::dto::{
+    BlockerListResponse, NextTodosResponse, TodoResponse,
+};
+
+use crate::common;
+
+async fn add_blocker(
+    server: &TestServer,
+    token: &str,
+    todo: &TodoResponse,
+    blocker: &TodoResponse,
+) -> axum_test::TestResponse {
+    server
+        .post(&format!("/api/v1/todos/{}/blockers", todo.id))
+        .add_header("Authorization", format!("Bearer {}", token))
+        .json(&serde_json::json!({ "blocker_id": blocker.id }))
+        .await
+}
+
+async fn next_titles(server: &TestServer, token: &str) -> Vec<String> {
+    let next: NextTodosResponse = server
+        .get("/api/v1/todos/next")
+        .add_header("Authorization", format!("Bearer {}", token))
+        .await
+        .json();
+    next.todos.into_iter().map(|t| t.title).collect()
+}
+
+#[tokio::test]
+async fn test_add_and_remove_blockers() {
+    let (server, pool) = common::create_test_server().await;
+
+    let token = common::register_test_user(&server, "deps@example.com", "password123")
+        .await
+        .access_token;
+    let design = common::create_todo(&server, &token, "Design").await;
+    let build = common::create_todo(&server, &token, "Build").await;
+    assert!(!build.blocked);
+
+    let response = add_blocker(&server, &token, &build, &design).await;
+    response.assert_status_ok();
+    assert!(response.json::<TodoResponse>().blocked);
+
+    let blockers: BlockerListResponse = server
+        .get(&format!("/api/v1/todos/{}/blockers", build.id))
+        .add_header("Authorization", format!("Bearer {}", token))
+        .await
+        .json();
+    let titles: Vec<_> = blockers.blockers.iter().map(|t| t.title.as_str()).collect();
+    assert_eq!(titles, vec!["Design"]);
+
+    let remove_url = format!("/api/v1/todos/{}/blockers/{}", build.id, design.id);
+    server
+        .delete(&remove_url)
+        .add_header("Authorization", format!("Bearer {}", token))
+        .await
+        .assert_status_ok();
+    server
+        .delete(&remove_url)
+        .add_header("Authorization", format!("Bearer {}", token))
+        .await
+        .assert_status(StatusCode::NOT_FOUND);
+
+    common::cleanup_test_data(&pool).await;
+}
+
+#[tokio::test]
+async fn test_blocker_cycles_are_refused() {
+    let (server, pool) = common::create_test_server().await;
+
+    let token = common::register_test_user(&server, "deps_cycle@example.com", "password123")
+        .await
+        .access_token;
+    let design = common::create_todo(&server, &token, "Design").await;
+    let build = common::create_todo(&server, &token, "Build").await;
+    let ship = common::create_todo(&server, &token, "Ship").await;
+    add_blocker(&server, &token, &build, &design)
+        .await
+        .assert_status_ok();
+    add_blocker(&server, &token, &ship, &build)
+        .await
+        .assert_status_ok();
+
+    // Directly, through other todos, or on itself
+    for blocker in [&build, &ship, &design] {
+        add_blocker(&server, &token, &design, blocker)
+            .await
+            .assert_status(StatusCode::BAD_REQUEST);
+    }
+
+    common::cleanup_test_data(&pool).await;
+}
+
+#[tokio::test]
+async fn test_blockers_come_first_in_work_order() {
+    let (server, pool) = common::create_test_server().await;
+
+    let token = common::register_test_user(&server, "deps_next@example.com", "password123")
+        .await
+        .access_token;
+    let ship = common::create_todo(&server, &token, "Ship").await;
+    let build = common::create_todo(&server, &token, "Build").await;
+    let design = common::create_todo(&server, &token, "Design").await;
+    add_blocker(&server, &token, &ship, &build)
+        .await
+        .assert_status_ok();
+    add_blocker(&server, &token, &build, &design)
+        .await
+        .assert_status_ok();
+
+    assert_eq!(
+        next_titles(&server, &token).await,
+        vec!["Design", "Build", "Ship"]
+    );
+
+    common::cleanup_test_data(&pool).await;
+}
+
+#[tokio::test]
+async fn test_completing_blocked_todos() {
+    let (server, pool) = common::create_test_server().await;
+
+    let token = common::register_test_user(&server, "deps_done@example.com", "password123")
+        .await
+        .access_token;
+    let design = common::create_todo(&server, &token, "Design").await;
+    let build = common::create_todo(&server, &token, "Build").await;
+    let ship = common::create_todo(&server, &token, "Ship").await;
+    add_blocker(&server, &token, &build, &design)
+        .await
+        .assert_status_ok();
+    add_blocker(&server, &token, &ship, &design)
+        .await
+        .assert_status_ok();
+    let complete = |todo: &TodoResponse, query: &str| {
+        server
+            .patch(&format!("/api/v1/todos/{}{}", todo.id, query))
+            .add_header("Authorization", format!("Bearer {}", token))
+            .json(&serde_json::json!({ "completed": true }))
+    };
+
+    // A blocked todo can't be completed unless forced
+    complete(&build, "")
+        .await
+        .assert_status(StatusCode::BAD_REQUEST);
+    let response = complete(&ship, "?force=true").await;
+    response.assert_status_ok();
+    assert!(response.json::<TodoResponse>().completed);
+
+    // Completing the blocker unblocks the todo
+    complete(&design, "").await.assert_status_ok();
+    let build_now: TodoResponse = server
+        .get(&format!("/api/v1/todos/{}", build.id))
+        .add_header("Authorization", format!("Bearer {}", token))
+        .await
+        .json();
+    assert!(!build_now.blocked);
+    assert_eq!(next_titles(&server, &token).await, vec!["Build"]);
+
+    common::cleanup_test_data(&pool).await;
+}
+
+#[tokio::test]
+async fn test_blocker_changes_bump_version() {
+    let (server, pool) = common::create_test_server().await;
+
+    let token = common::register_test_user(&server, "deps_version@example.com", "password123")
+        .await
+        .access_token;
+    let design = common::create_todo(&server, &token, "Design").await;
+    let build = common::create_todo(&server, &token, "Build").await;
+    let version = || async {
+        server
+            .get(&format!("/api/v1/todos/{}", build.id))
+            .add_header("Authorization", format!("Bearer {}", token))
+            .await
+            .json::<TodoResponse>()
+            .version
+    };
+
+    // Adding, completing and removing the blocker each change `blocked`
+    let added: TodoResponse = server
+        .post(&format!("/api/v1/todos/{}/blockers", build.id))
+        .add_header("Authorization", format!("Bearer {}", token))
+        .json(&serde_json::json!({ "blocker_id": design.id }))
+        .await
+        .json();
+    assert_eq!(added.version, build.version + 1);
+
+    server
+        .patch(&format!("/api/v1/todos/{}", design.id))
+        .add_header("Authorization", format!("Bearer {}", token))
+        .json(&serde_json::json!({ "completed": true }))
+        .await
+        .assert_status_ok();
+    assert_eq!(version().await, build.version + 2);
+
+    // Edits that leave the blocker open or done don't touch the todo
+    server
+        .patch(&format!("/api/v1/todos/{}", design.id))
+        .add_header("Authorization", format!("Bearer {}", token))
+        .json(&serde_json::json!({ "title": "Design v2" }))
+        .await
+        .assert_status_ok();
+    assert_eq!(version().await, build.version + 2);
+
+    server
+        .delete(&format!(
+            "/api/v1/todos/{}/blockers/{}",
+            build.id, design.id
+        ))
+        .add_header("Authorization", format!("Bearer {}", token))
+        .await
+        .assert_status_ok();
+    assert_eq!(version().await, build.version + 3);
+
+    common::cleanup_test_data(&pool).await;
+}
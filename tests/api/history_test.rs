@@ -0,0 +1,156 @@
+use axum::http::StatusCode;
+use rust_teraform_backend::application::dto::TodoHistoryListResponse;
+use rust_teraform_backend::domain::entities::TodoAction;
+
+use crate::common;
+
+#[tokio::test]
+async fn test_todo_history() {
+    let (server, pool) = common::create_test_server().await;
+
+    let auth = common::register_test_user(&server, "history@example.com", "password123").await;
+    let token = auth.access_token;
+
+    let todo = common::create_todo_with(
+        &server,
+        &token,
+        serde_json::json!({ "title": "Draft", "description": "Notes" }),
+    )
+    .await;
+
+    for changes in [
+        serde_json::json!({ "title": "Final", "description": null }),
+        serde_json::json!({ "completed": true }),
+        // No change is recorded for an empty patch
+        serde_json::json!({}),
+    ] {
+        server
+            .patch(&format!("/api/v1/todos/{}", todo.id))
+            .add_header("Authorization", format!("Bearer {}", token))
+            .json(&changes)
+            .await
+            .assert_status_ok();
+    }
+    server
+        .delete(&format!("/api/v1/todos/{}", todo.id))
+        .add_header("Authorization", format!("Bearer {}", token))
+        .await
+        .assert_status(StatusCode::NO_CONTENT);
+
+    // Trashed todos keep their history
+    let response = server
+        .get(&format!("/api/v1/todos/{}/history", todo.id))
+        .add_header("Authorization", format!("Bearer {}", token))
+        .await;
+    response.assert_status_ok();
+    let history: TodoHistoryListResponse = response.json();
+    assert_eq!(history.total, 4);
+    let actions: Vec<_> = history.entries.iter().map(|e| e.action).collect();
+    assert_eq!(
+        actions,
+        vec![
+            TodoAction::Deleted,
+            TodoAction::Completed,
+            TodoAction::Updated,
+            TodoAction::Created,
+        ]
+    );
+
+    let update = &history.entries[2];
+    assert_eq!(
+        update.before,
+        Some(serde_json::json!({ "title": "Draft", "description": "Notes" }))
+    );
+    assert_eq!(
+        update.after,
+        serde_json::json!({ "title": "Final", "description": null })
+    );
+    assert!(update.actor_id.is_some());
+    assert_eq!(history.entries[3].before, None);
+    assert_eq!(history.entries[3].after["title"], "Draft");
+
+    // Other users cannot read it
+    let other =
+        common::register_test_user(&server, "history_other@example.com", "password123").await;
+    let response = server
+        .get(&format!("/api/v1/todos/{}/history", todo.id))
+        .add_header("Authorization", format!("Bearer {}", other.access_token))
+        .await;
+    response.assert_status(StatusCode::NOT_FOUND);
+
+    common::cleanup_test_data(&pool).await;
+}
+
+#[tokio::test]
+async fn test_activity_feed() {
+    let (server, pool) = common::create_test_server().await;
+
+    let auth = common::register_test_user(&server, "activity@example.com", "password123").await;
+    let token = auth.access_token;
+
+    let recurring = common::create_todo_with(
+        &server,
+        &token,
+        serde_json::json!({
+            "title": "Water plants",
+            "due_at": "2026-10-19T00:00:00Z",
+            "recurrence_rule": "FREQ=WEEKLY"
+        }),
+    )
+    .await;
+
+    // Completing a recurring todo records the next occurrence too
+    server
+        .patch(&format!("/api/v1/todos/{}", recurring.id))
+        .add_header("Authorization", format!("Bearer {}", token))
+        .json(&serde_json::json!({ "completed": true }))
+        .await
+        .assert_status_ok();
+
+    // Batches are recorded per todo
+    server
+        .post("/api/v1/todos/batch")
+        .add_header("Authorization", format!("Bearer {}", token))
+        .json(&serde_json::json!({
+            "operations": [
+                { "op": "create", "title": "Batch one" },
+                { "op": "update", "id": recurring.id, "title": "Water all plants" },
+                { "op": "delete", "id": recurring.id }
+            ]
+        }))
+        .await
+        .assert_status_ok();
+
+    // Other users' activity is not included
+    let other =
+        common::register_test_user(&server, "activity_other@example.com", "password123").await;
+    common::create_todo(&server, &other.access_token, "Not mine").await;
+
+    let feed: TodoHistoryListResponse = server
+        .get("/api/v1/todos/activity?per_page=10")
+        .add_header("Authorization", format!("Bearer {}", token))
+        .await
+        .json();
+    assert_eq!(feed.total, 5);
+    let mut actions: Vec<_> = feed.entries.iter().map(|e| e.action).collect();
+    actions.sort_by_key(|a| format!("{:?}", a));
+    assert_eq!(
+        actions,
+        vec![
+            TodoAction::Completed,
+            TodoAction::Created,
+            TodoAction::Created,
+            TodoAction::Created,
+            TodoAction::Deleted,
+        ]
+    );
+    let deleted = feed
+        .entries
+        .iter()
+        .find(|e| e.action == TodoAction::Deleted)
+        .unwrap();
+    assert_eq!(deleted.todo_id, recurring.id);
+    assert_eq!(deleted.after["title"], "Water all plants");
+
+    common::cleanup_test_data(&pool).await;
+}
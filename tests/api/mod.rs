@@ -1,2 +1,25 @@
+pub mod assignment_test;
+pub mod attachment_test;
 pub mod auth_test;
+pub mod batch_test;
+pub mod board_test;
+pub mod checklist_test;
+pub mod comment_test;
+pub mod concurrency_test;
+pub mod dependency_test;
+pub mod history_test;
+pub mod notification_test;
+pub mod priority_test;
+pub mod project_test;
+pub mod quick_add_test;
+pub mod recurrence_test;
+pub mod reminder_test;
+pub mod saved_filter_test;
+pub mod search_test;
+pub mod share_test;
+pub mod time_tracking_test;
+pub mod todo_query_test;
 pub mod todo_test;
+pub mod trash_test;
+pub mod workflow_test;
+pub mod workspace_test;
@@ -0,0 +1,392 @@
+use std::sync::Arc;
+
+use axum::http::StatusCode;
+use axum_test::TestServer;
+use chrono::{Duration, Utc};
+use rust_teraform_backend::application::dto::{
+    MarkAllReadResponse, NotificationListResponse, NotificationPreferencesResponse,
+    NotificationResponse, ReminderDeliveryListResponse, ReminderResponse, TodoResponse,
+    WorkspaceMemberListResponse,
+};
+use rust_teraform_backend::domain::entities::{DeliveryChannel, NotificationEvent};
+use rust_teraform_backend::domain::repositories::NotificationRepository;
+use rust_teraform_backend::infrastructure::jobs::{deliver_reminders, ReminderSchedulerConfig};
+use rust_teraform_backend::infrastructure::notifications::{EmailConfig, NotificationConfig};
+use rust_teraform_backend::infrastructure::persistence::postgres::{
+    PostgresNotificationRepository, PostgresReminderRepository, Tenant,
+};
+
+use crate::common;
+
+/// The owner's todo, shared with a member of the owner's workspace as an
+/// editor and assigned to them. Every email starts with `prefix`.
+struct Offsite {
+    owner: String,
+    member: String,
+    workspace: String,
+    todo: TodoResponse,
+}
+
+async fn offsite(server: &TestServer, prefix: &str) -> Offsite {
+    let email = |role: &str| format!("{}_{}@example.com", prefix, role);
+    let owner = common::register_test_user(server, &email("owner"), "password123")
+        .await
+        .access_token;
+    let member = common::register_test_user(server, &email("member"), "password123")
+        .await
+        .access_token;
+    let workspace = common::join_workspace(server, &owner, &member, &email("member"))
+        .await
+        .to_string();
+    let members: WorkspaceMemberListResponse = server
+        .get(&format!("/api/v1/workspaces/{}/members", workspace))
+        .add_header("Authorization", format!("Bearer {}", owner))
+        .await
+        .json();
+    let member_id = members
+        .members
+        .iter()
+        .find(|m| m.email == email("member"))
+        .unwrap()
+        .user_id;
+
+    let todo = common::create_todo(server, &owner, "Plan the offsite").await;
+    server
+        .post(&format!("/api/v1/todos/{}/shares", todo.id))
+        .add_header("Authorization", format!("Bearer {}", owner))
+        .json(&serde_json::json!({ "email": email("member"), "permission": "editor" }))
+        .await
+        .assert_status(StatusCode::CREATED);
+    server
+        .put(&format!("/api/v1/todos/{}/assignee", todo.id))
+        .add_header("Authorization", format!("Bearer {}", owner))
+        .json(&serde_json::json!({ "assignee_id": member_id }))
+        .await
+        .assert_status_ok();
+
+    Offsite {
+        owner,
+        member,
+        workspace,
+        todo,
+    }
+}
+
+async fn comment(server: &TestServer, offsite: &Offsite, token: &str, body: &str) {
+    server
+        .post(&format!("/api/v1/todos/{}/comments", offsite.todo.id))
+        .add_header("Authorization", format!("Bearer {}", token))
+        .add_header("X-Workspace-Id", &offsite.workspace)
+        .json(&serde_json::json!({ "body": body }))
+        .await
+        .assert_status(StatusCode::CREATED);
+}
+
+async fn inbox(server: &TestServer, token: &str, query: &str) -> NotificationListResponse {
+    server
+        .get(&format!("/api/v1/notifications{}", query))
+        .add_header("Authorization", format!("Bearer {}", token))
+        .await
+        .json()
+}
+
+async fn set_preference(
+    server: &TestServer,
+    token: &str,
+    event: &str,
+    in_app: bool,
+    email: bool,
+) -> NotificationPreferencesResponse {
+    let response = server
+        .put("/api/v1/notifications/preferences")
+        .add_header("Authorization", format!("Bearer {}", token))
+        .json(&serde_json::json!({ "preferences": [
+            { "event": event, "in_app": in_app, "email": email },
+        ] }))
+        .await;
+    response.assert_status_ok();
+    response.json()
+}
+
+/// Schedules a reminder on both channels for a todo due in five minutes and
+/// runs one scheduler pass. Returns the channels it was delivered on.
+async fn deliver_due_reminder(
+    server: &TestServer,
+    pool: &sqlx::PgPool,
+    token: &str,
+) -> Vec<DeliveryChannel> {
+    let todo = common::create_todo_with(
+        server,
+        token,
+        serde_json::json!({
+            "title": "Renew passport",
+            "due_at": Utc::now() + Duration::minutes(5)
+        }),
+    )
+    .await;
+    let reminders_url = format!("/api/v1/todos/{}/reminders", todo.id);
+    let reminder: ReminderResponse = server
+        .post(&reminders_url)
+        .add_header("Authorization", format!("Bearer {}", token))
+        .json(&serde_json::json!({ "offset_minutes": 60, "channels": ["in_app", "email"] }))
+        .await
+        .json();
+
+    let notification_repository: Arc<dyn NotificationRepository> =
+        Arc::new(PostgresNotificationRepository::new(pool.clone()));
+    let channels = NotificationConfig {
+        email: EmailConfig {
+            api_url: None,
+            api_key: None,
+            from: "Todo <noreply@localhost>".to_string(),
+        },
+        webhook_secret: None,
+    }
+    .build(notification_repository);
+    let repository = PostgresReminderRepository::new(pool.clone());
+    let config = ReminderSchedulerConfig {
+        interval: std::time::Duration::from_secs(30),
+        batch_size: 100,
+        lease: Duration::minutes(5),
+    };
+    Tenant::System
+        .scope(async {
+            deliver_reminders(&repository, &channels, &config)
+                .await
+                .unwrap()
+        })
+        .await;
+
+    let deliveries: ReminderDeliveryListResponse = server
+        .get(&format!("{}/{}/deliveries", reminders_url, reminder.id))
+        .add_header("Authorization", format!("Bearer {}", token))
+        .await
+        .json();
+    deliveries.deliveries.iter().map(|d| d.channel).collect()
+}
+
+#[tokio::test]
+async fn test_assignment_notifies_the_assignee() {
+    let (server, pool) = common::create_test_server().await;
+    let offsite = offsite(&server, "inbox_assign").await;
+
+    let member_inbox = inbox(&server, &offsite.member, "").await;
+    assert_eq!(member_inbox.notifications.len(), 1);
+    assert_eq!(
+        member_inbox.notifications[0].event,
+        NotificationEvent::Assigned
+    );
+    assert_eq!(member_inbox.notifications[0].todo_id, Some(offsite.todo.id));
+    assert_eq!(member_inbox.unread_count, 1);
+    assert!(inbox(&server, &offsite.owner, "")
+        .await
+        .notifications
+        .is_empty());
+
+    common::cleanup_test_data(&pool).await;
+}
+
+#[tokio::test]
+async fn test_comments_notify_everyone_else() {
+    let (server, pool) = common::create_test_server().await;
+    let offsite = offsite(&server, "inbox_comment").await;
+
+    // The assignee's own comment notifies only the creator
+    comment(&server, &offsite, &offsite.member, "Booked the venue").await;
+    let owner_inbox = inbox(&server, &offsite.owner, "").await;
+    assert_eq!(owner_inbox.notifications.len(), 1);
+    assert_eq!(
+        owner_inbox.notifications[0].event,
+        NotificationEvent::Comment
+    );
+    assert_eq!(
+        owner_inbox.notifications[0].title,
+        "New comment on Plan the offsite"
+    );
+    assert_eq!(owner_inbox.notifications[0].body, "Booked the venue");
+    assert_eq!(inbox(&server, &offsite.member, "").await.unread_count, 1);
+
+    comment(&server, &offsite, &offsite.owner, "Thanks!").await;
+    let member_inbox = inbox(&server, &offsite.member, "").await;
+    assert_eq!(member_inbox.unread_count, 2);
+    assert_eq!(member_inbox.notifications[0].body, "Thanks!");
+    assert_eq!(inbox(&server, &offsite.owner, "").await.unread_count, 1);
+
+    common::cleanup_test_data(&pool).await;
+}
+
+#[tokio::test]
+async fn test_inbox_pages() {
+    let (server, pool) = common::create_test_server().await;
+    let offsite = offsite(&server, "inbox_pages").await;
+    comment(&server, &offsite, &offsite.owner, "Thanks!").await;
+
+    // Newest first, a page at a time
+    let first = inbox(&server, &offsite.member, "?per_page=1").await;
+    assert_eq!(first.unread_count, 2);
+    assert_eq!(first.notifications.len(), 1);
+    assert_eq!(first.notifications[0].event, NotificationEvent::Comment);
+    let second = inbox(
+        &server,
+        &offsite.member,
+        &format!("?per_page=1&cursor={}", first.next_cursor.unwrap()),
+    )
+    .await;
+    assert_eq!(second.notifications.len(), 1);
+    assert_eq!(second.notifications[0].event, NotificationEvent::Assigned);
+    assert_eq!(second.next_cursor, None);
+
+    server
+        .get("/api/v1/notifications?cursor=garbage")
+        .add_header("Authorization", format!("Bearer {}", offsite.member))
+        .await
+        .assert_status(StatusCode::BAD_REQUEST);
+
+    common::cleanup_test_data(&pool).await;
+}
+
+#[tokio::test]
+async fn test_mark_notifications_read() {
+    let (server, pool) = common::create_test_server().await;
+    let offsite = offsite(&server, "inbox_read").await;
+    comment(&server, &offsite, &offsite.owner, "Thanks!").await;
+    let notifications = inbox(&server, &offsite.member, "").await.notifications;
+
+    // Reading a notification keeps the time it was first read
+    let read_url = format!("/api/v1/notifications/{}/read", notifications[1].id);
+    let read: NotificationResponse = server
+        .post(&read_url)
+        .add_header("Authorization", format!("Bearer {}", offsite.member))
+        .await
+        .json();
+    let read_at = read.read_at.unwrap();
+    let again: NotificationResponse = server
+        .post(&read_url)
+        .add_header("Authorization", format!("Bearer {}", offsite.member))
+        .await
+        .json();
+    assert_eq!(again.read_at, Some(read_at));
+    server
+        .post(&read_url)
+        .add_header("Authorization", format!("Bearer {}", offsite.owner))
+        .await
+        .assert_status(StatusCode::NOT_FOUND);
+
+    let unread = inbox(&server, &offsite.member, "?unread=true").await;
+    assert_eq!(unread.unread_count, 1);
+    let ids: Vec<_> = unread.notifications.iter().map(|n| n.id).collect();
+    assert_eq!(ids, vec![notifications[0].id]);
+
+    let marked: MarkAllReadResponse = server
+        .post("/api/v1/notifications/read-all")
+        .add_header("Authorization", format!("Bearer {}", offsite.member))
+        .await
+        .json();
+    assert_eq!(marked.marked, 1);
+    let unread = inbox(&server, &offsite.member, "?unread=true").await;
+    assert!(unread.notifications.is_empty());
+    assert_eq!(unread.unread_count, 0);
+
+    common::cleanup_test_data(&pool).await;
+}
+
+#[tokio::test]
+async fn test_default_preferences() {
+    let (server, pool) = common::create_test_server().await;
+
+    let token = common::register_test_user(&server, "preferences@example.com", "password123")
+        .await
+        .access_token;
+
+    // Preferences list every event, defaults included
+    let preferences: NotificationPreferencesResponse = server
+        .get("/api/v1/notifications/preferences")
+        .add_header("Authorization", format!("Bearer {}", token))
+        .await
+        .json();
+    let defaults: Vec<_> = preferences
+        .preferences
+        .iter()
+        .map(|p| (p.event, p.in_app, p.email))
+        .collect();
+    assert_eq!(
+        defaults,
+        vec![
+            (NotificationEvent::Reminder, true, true),
+            (NotificationEvent::Assigned, true, false),
+            (NotificationEvent::Comment, true, false),
+        ]
+    );
+
+    common::cleanup_test_data(&pool).await;
+}
+
+#[tokio::test]
+async fn test_update_preferences() {
+    let (server, pool) = common::create_test_server().await;
+    let offsite = offsite(&server, "inbox_quiet").await;
+
+    // Each event may be listed once
+    server
+        .put("/api/v1/notifications/preferences")
+        .add_header("Authorization", format!("Bearer {}", offsite.member))
+        .json(&serde_json::json!({ "preferences": [
+            { "event": "comment", "in_app": false, "email": false },
+            { "event": "comment", "in_app": true, "email": false },
+        ] }))
+        .await
+        .assert_status(StatusCode::BAD_REQUEST);
+
+    let preferences = set_preference(&server, &offsite.member, "comment", false, true).await;
+    let comment_preference = preferences
+        .preferences
+        .iter()
+        .find(|p| p.event == NotificationEvent::Comment)
+        .unwrap();
+    assert!(!comment_preference.in_app && comment_preference.email);
+
+    // Comments now only go to the member's email
+    comment(&server, &offsite, &offsite.owner, "One more thing").await;
+    let member_inbox = inbox(&server, &offsite.member, "").await;
+    assert_eq!(member_inbox.notifications.len(), 1);
+    assert_eq!(
+        member_inbox.notifications[0].event,
+        NotificationEvent::Assigned
+    );
+
+    common::cleanup_test_data(&pool).await;
+}
+
+#[tokio::test]
+async fn test_reminders_by_email_only() {
+    let (server, pool) = common::create_test_server().await;
+
+    let token = common::register_test_user(&server, "quiet@example.com", "password123")
+        .await
+        .access_token;
+    set_preference(&server, &token, "reminder", false, true).await;
+
+    let channels = deliver_due_reminder(&server, &pool, &token).await;
+    assert_eq!(channels, vec![DeliveryChannel::Email]);
+    assert!(inbox(&server, &token, "").await.notifications.is_empty());
+
+    common::cleanup_test_data(&pool).await;
+}
+
+#[tokio::test]
+async fn test_reminders_in_app_only() {
+    let (server, pool) = common::create_test_server().await;
+
+    let token = common::register_test_user(&server, "no_email@example.com", "password123")
+        .await
+        .access_token;
+    set_preference(&server, &token, "reminder", true, false).await;
+
+    let channels = deliver_due_reminder(&server, &pool, &token).await;
+    assert_eq!(channels, vec![DeliveryChannel::InApp]);
+    let notifications = inbox(&server, &token, "").await.notifications;
+    assert_eq!(notifications.len(), 1);
+    assert_eq!(notifications[0].event, NotificationEvent::Reminder);
+
+    common::cleanup_test_data(&pool).await;
+}
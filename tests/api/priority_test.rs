@@ -0,0 +1,193 @@
+use axum::http::StatusCode;
+use axum_test::TestServer;
+use chrono::{Duration, Utc};
+use rust_teraform_backend::application::dto::{TodoListResponse, TodoResponse};
+use rust_teraform_backend::domain::entities::TodoPriority;
+
+use crate::common;
+
+async fn list_titles(server: &TestServer, token: &str, query: &str) -> Vec<String> {
+    let listed: TodoListResponse = server
+        .get(&format!("/api/v1/todos?{}", query))
+        .add_header("Authorization", format!("Bearer {}", token))
+        .await
+        .json();
+    listed.todos.into_iter().map(|t| t.title).collect()
+}
+
+/// Open todos of every kind the smart sort ranks, plus a completed one.
+async fn create_ranked_todos(server: &TestServer, token: &str) {
+    let now = Utc::now();
+    let todos = [
+        serde_json::json!({ "title": "Someday" }),
+        serde_json::json!({
+            "title": "Urgent, in two weeks",
+            "priority": "urgent",
+            "due_at": now + Duration::days(15),
+        }),
+        serde_json::json!({
+            "title": "High, tomorrow",
+            "priority": "high",
+            "due_at": now + Duration::days(1),
+        }),
+        serde_json::json!({ "title": "Overdue", "due_at": now - Duration::days(2) }),
+    ];
+    for todo in todos {
+        common::create_todo_with(server, token, todo).await;
+    }
+    let done = common::create_todo_with(
+        server,
+        token,
+        serde_json::json!({ "title": "Done already", "priority": "urgent" }),
+    )
+    .await;
+    server
+        .patch(&format!("/api/v1/todos/{}", done.id))
+        .add_header("Authorization", format!("Bearer {}", token))
+        .json(&serde_json::json!({ "completed": true }))
+        .await
+        .assert_status_ok();
+}
+
+#[tokio::test]
+async fn test_create_todo_with_priority() {
+    let (server, pool) = common::create_test_server().await;
+
+    let token = common::register_test_user(&server, "priority@example.com", "password123")
+        .await
+        .access_token;
+
+    let plain = common::create_todo(&server, &token, "Water the plants").await;
+    assert_eq!(plain.priority, TodoPriority::None);
+
+    let urgent = common::create_todo_with(
+        &server,
+        &token,
+        serde_json::json!({ "title": "Fix the build", "priority": "urgent" }),
+    )
+    .await;
+    assert_eq!(urgent.priority, TodoPriority::Urgent);
+
+    let response = server
+        .post("/api/v1/todos")
+        .add_header("Authorization", format!("Bearer {}", token))
+        .json(&serde_json::json!({ "title": "Fix the build", "priority": "critical" }))
+        .await;
+    assert!(response.status_code().is_client_error());
+
+    common::cleanup_test_data(&pool).await;
+}
+
+#[tokio::test]
+async fn test_filter_by_priority() {
+    let (server, pool) = common::create_test_server().await;
+
+    let token = common::register_test_user(&server, "priority_filter@example.com", "password123")
+        .await
+        .access_token;
+    common::create_todo(&server, &token, "Water the plants").await;
+    common::create_todo_with(
+        &server,
+        &token,
+        serde_json::json!({ "title": "Fix the build", "priority": "urgent" }),
+    )
+    .await;
+
+    assert_eq!(
+        list_titles(&server, &token, "priority=urgent").await,
+        vec!["Fix the build"]
+    );
+    server
+        .get("/api/v1/todos?priority=critical")
+        .add_header("Authorization", format!("Bearer {}", token))
+        .await
+        .assert_status(StatusCode::BAD_REQUEST);
+
+    common::cleanup_test_data(&pool).await;
+}
+
+#[tokio::test]
+async fn test_reset_priority() {
+    let (server, pool) = common::create_test_server().await;
+
+    let token = common::register_test_user(&server, "priority_reset@example.com", "password123")
+        .await
+        .access_token;
+    let urgent = common::create_todo_with(
+        &server,
+        &token,
+        serde_json::json!({ "title": "Fix the build", "priority": "urgent" }),
+    )
+    .await;
+
+    // null resets the priority
+    let response = server
+        .patch(&format!("/api/v1/todos/{}", urgent.id))
+        .add_header("Authorization", format!("Bearer {}", token))
+        .json(&serde_json::json!({ "priority": null }))
+        .await;
+    response.assert_status_ok();
+    let reset: TodoResponse = response.json();
+    assert_eq!(reset.priority, TodoPriority::None);
+    assert!(list_titles(&server, &token, "priority=urgent")
+        .await
+        .is_empty());
+
+    common::cleanup_test_data(&pool).await;
+}
+
+#[tokio::test]
+async fn test_smart_sort() {
+    let (server, pool) = common::create_test_server().await;
+
+    let token = common::register_test_user(&server, "smart@example.com", "password123")
+        .await
+        .access_token;
+    create_ranked_todos(&server, &token).await;
+
+    // Completed todos are left out
+    assert_eq!(
+        list_titles(&server, &token, "sort=smart").await,
+        vec![
+            "High, tomorrow",
+            "Urgent, in two weeks",
+            "Overdue",
+            "Someday"
+        ]
+    );
+    assert_eq!(
+        list_titles(&server, &token, "sort=smart&order=asc&per_page=2").await,
+        vec!["Someday", "Overdue"]
+    );
+
+    common::cleanup_test_data(&pool).await;
+}
+
+#[tokio::test]
+async fn test_smart_sort_pages() {
+    let (server, pool) = common::create_test_server().await;
+
+    let token = common::register_test_user(&server, "smart_pages@example.com", "password123")
+        .await
+        .access_token;
+    create_ranked_todos(&server, &token).await;
+
+    let listed: TodoListResponse = server
+        .get("/api/v1/todos?sort=smart&per_page=3&page=2")
+        .add_header("Authorization", format!("Bearer {}", token))
+        .await
+        .json();
+    assert_eq!(listed.total, Some(4));
+    assert_eq!(listed.todos.len(), 1);
+    assert_eq!(listed.todos[0].title, "Someday");
+    assert!(listed.next_cursor.is_none());
+
+    // Only open todos are ranked
+    server
+        .get("/api/v1/todos?sort=smart&completed=true")
+        .add_header("Authorization", format!("Bearer {}", token))
+        .await
+        .assert_status(StatusCode::BAD_REQUEST);
+
+    common::cleanup_test_data(&pool).await;
+}
@@ -0,0 +1,337 @@
+use axum::http::StatusCode;
+use rust_teraform_backend::application::dto::{
+    ProjectListResponse, ProjectResponse, TodoListResponse, TodoResponse, WorkflowResponse,
+    WorkspaceResponse,
+};
+
+use crate::common;
+
+/// Backlog, doing and shipped, without the default's review step.
+fn shipping_workflow() -> serde_json::Value {
+    serde_json::json!({
+        "statuses": [
+            { "key": "todo", "name": "Backlog", "transitions": ["doing"] },
+            { "key": "doing", "name": "Doing", "transitions": ["todo", "shipped"] },
+            { "key": "shipped", "name": "Shipped", "terminal": true, "transitions": ["doing"] }
+        ]
+    })
+}
+
+#[tokio::test]
+async fn test_manage_projects() {
+    let (server, pool) = common::create_test_server().await;
+
+    let token = common::register_test_user(&server, "projects@example.com", "password123")
+        .await
+        .access_token;
+
+    let website = common::create_project(&server, &token, "  Website ").await;
+    assert_eq!(website.name, "Website");
+    common::create_project(&server, &token, "App").await;
+    server
+        .post("/api/v1/projects")
+        .add_header("Authorization", format!("Bearer {}", token))
+        .json(&serde_json::json!({ "name": "Website" }))
+        .await
+        .assert_status(StatusCode::CONFLICT);
+    server
+        .post("/api/v1/projects")
+        .add_header("Authorization", format!("Bearer {}", token))
+        .json(&serde_json::json!({ "name": " " }))
+        .await
+        .assert_status(StatusCode::BAD_REQUEST);
+
+    let response = server
+        .put(&format!("/api/v1/projects/{}", website.id))
+        .add_header("Authorization", format!("Bearer {}", token))
+        .json(&serde_json::json!({ "name": "Site" }))
+        .await;
+    response.assert_status_ok();
+    assert_eq!(response.json::<ProjectResponse>().name, "Site");
+
+    let projects: ProjectListResponse = server
+        .get("/api/v1/projects")
+        .add_header("Authorization", format!("Bearer {}", token))
+        .await
+        .json();
+    let names: Vec<_> = projects.projects.iter().map(|p| p.name.as_str()).collect();
+    assert_eq!(names, vec!["App", "Site"]);
+
+    server
+        .delete(&format!("/api/v1/projects/{}", website.id))
+        .add_header("Authorization", format!("Bearer {}", token))
+        .await
+        .assert_status(StatusCode::NO_CONTENT);
+    server
+        .get(&format!("/api/v1/projects/{}", website.id))
+        .add_header("Authorization", format!("Bearer {}", token))
+        .await
+        .assert_status(StatusCode::NOT_FOUND);
+
+    common::cleanup_test_data(&pool).await;
+}
+
+#[tokio::test]
+async fn test_members_cannot_manage_projects() {
+    let (server, pool) = common::create_test_server().await;
+
+    let owner = common::register_test_user(&server, "project_owner@example.com", "password123")
+        .await
+        .access_token;
+    let member = common::register_test_user(&server, "project_member@example.com", "password123")
+        .await
+        .access_token;
+    let workspace =
+        common::join_workspace(&server, &owner, &member, "project_member@example.com").await;
+    let project = common::create_project(&server, &owner, "Website").await;
+
+    // Members see the projects and file todos in them
+    let projects: ProjectListResponse = server
+        .get("/api/v1/projects")
+        .add_header("Authorization", format!("Bearer {}", member))
+        .add_header("X-Workspace-Id", workspace.to_string())
+        .await
+        .json();
+    assert_eq!(projects.projects.len(), 1);
+    server
+        .post("/api/v1/todos")
+        .add_header("Authorization", format!("Bearer {}", member))
+        .add_header("X-Workspace-Id", workspace.to_string())
+        .json(&serde_json::json!({ "title": "Fix the footer", "project_id": project.id }))
+        .await
+        .assert_status(StatusCode::CREATED);
+
+    server
+        .post("/api/v1/projects")
+        .add_header("Authorization", format!("Bearer {}", member))
+        .add_header("X-Workspace-Id", workspace.to_string())
+        .json(&serde_json::json!({ "name": "App" }))
+        .await
+        .assert_status(StatusCode::FORBIDDEN);
+    server
+        .put(&format!("/api/v1/projects/{}", project.id))
+        .add_header("Authorization", format!("Bearer {}", member))
+        .add_header("X-Workspace-Id", workspace.to_string())
+        .json(&serde_json::json!({ "name": "Site" }))
+        .await
+        .assert_status(StatusCode::FORBIDDEN);
+    server
+        .put(&format!("/api/v1/projects/{}/workflow", project.id))
+        .add_header("Authorization", format!("Bearer {}", member))
+        .add_header("X-Workspace-Id", workspace.to_string())
+        .json(&shipping_workflow())
+        .await
+        .assert_status(StatusCode::FORBIDDEN);
+    server
+        .delete(&format!("/api/v1/projects/{}", project.id))
+        .add_header("Authorization", format!("Bearer {}", member))
+        .add_header("X-Workspace-Id", workspace.to_string())
+        .await
+        .assert_status(StatusCode::FORBIDDEN);
+
+    common::cleanup_test_data(&pool).await;
+}
+
+#[tokio::test]
+async fn test_todos_in_projects() {
+    let (server, pool) = common::create_test_server().await;
+
+    let token = common::register_test_user(&server, "project_todos@example.com", "password123")
+        .await
+        .access_token;
+    let project = common::create_project(&server, &token, "Website").await;
+
+    let todo = common::create_todo_with(
+        &server,
+        &token,
+        serde_json::json!({ "title": "Fix the footer", "project_id": project.id }),
+    )
+    .await;
+    assert_eq!(todo.project_id, Some(project.id));
+    common::create_todo(&server, &token, "Renew passport").await;
+
+    let listed: TodoListResponse = server
+        .get(&format!("/api/v1/todos?project_id={}", project.id))
+        .add_header("Authorization", format!("Bearer {}", token))
+        .await
+        .json();
+    let titles: Vec<_> = listed.todos.iter().map(|t| t.title.as_str()).collect();
+    assert_eq!(titles, vec!["Fix the footer"]);
+
+    // A project with todos, even trashed ones, can't be deleted
+    server
+        .delete(&format!("/api/v1/todos/{}", todo.id))
+        .add_header("Authorization", format!("Bearer {}", token))
+        .await
+        .assert_status(StatusCode::NO_CONTENT);
+    server
+        .delete(&format!("/api/v1/projects/{}", project.id))
+        .add_header("Authorization", format!("Bearer {}", token))
+        .await
+        .assert_status(StatusCode::CONFLICT);
+    server
+        .delete(&format!("/api/v1/todos/trash/{}", todo.id))
+        .add_header("Authorization", format!("Bearer {}", token))
+        .await
+        .assert_status(StatusCode::NO_CONTENT);
+    server
+        .delete(&format!("/api/v1/projects/{}", project.id))
+        .add_header("Authorization", format!("Bearer {}", token))
+        .await
+        .assert_status(StatusCode::NO_CONTENT);
+
+    common::cleanup_test_data(&pool).await;
+}
+
+#[tokio::test]
+async fn test_projects_of_other_workspaces_are_rejected() {
+    let (server, pool) = common::create_test_server().await;
+
+    let token = common::register_test_user(&server, "project_iso@example.com", "password123")
+        .await
+        .access_token;
+    let project = common::create_project(&server, &token, "Website").await;
+    let team: WorkspaceResponse = server
+        .post("/api/v1/workspaces")
+        .add_header("Authorization", format!("Bearer {}", token))
+        .json(&serde_json::json!({ "name": "Team" }))
+        .await
+        .json();
+
+    server
+        .get(&format!("/api/v1/projects/{}", project.id))
+        .add_header("Authorization", format!("Bearer {}", token))
+        .add_header("X-Workspace-Id", team.id.to_string())
+        .await
+        .assert_status(StatusCode::NOT_FOUND);
+    let response = server
+        .post("/api/v1/todos")
+        .add_header("Authorization", format!("Bearer {}", token))
+        .add_header("X-Workspace-Id", team.id.to_string())
+        .json(&serde_json::json!({ "title": "Fix the footer", "project_id": project.id }))
+        .await;
+    response.assert_status(StatusCode::BAD_REQUEST);
+    assert!(response.text().contains("Project not found"));
+
+    common::cleanup_test_data(&pool).await;
+}
+
+#[tokio::test]
+async fn test_project_workflow() {
+    let (server, pool) = common::create_test_server().await;
+
+    let token = common::register_test_user(&server, "project_flow@example.com", "password123")
+        .await
+        .access_token;
+    let project = common::create_project(&server, &token, "Website").await;
+    let workflow_path = format!("/api/v1/projects/{}/workflow", project.id);
+
+    // Until it has its own, a project follows the workspace's workflow
+    let workflow: WorkflowResponse = server
+        .get(&workflow_path)
+        .add_header("Authorization", format!("Bearer {}", token))
+        .await
+        .json();
+    assert_eq!(workflow.statuses.len(), 5);
+
+    let response = server
+        .put(&workflow_path)
+        .add_header("Authorization", format!("Bearer {}", token))
+        .json(&shipping_workflow())
+        .await;
+    response.assert_status_ok();
+    let workflow: WorkflowResponse = server
+        .get(&workflow_path)
+        .add_header("Authorization", format!("Bearer {}", token))
+        .await
+        .json();
+    assert_eq!(workflow.statuses[0].name, "Backlog");
+
+    // The workspace keeps its workflow
+    let workflow: WorkflowResponse = server
+        .get("/api/v1/todos/workflow")
+        .add_header("Authorization", format!("Bearer {}", token))
+        .await
+        .json();
+    assert_eq!(workflow.statuses.len(), 5);
+
+    // The project's todos move through its statuses
+    let todo = common::create_todo_with(
+        &server,
+        &token,
+        serde_json::json!({ "title": "Fix the footer", "project_id": project.id }),
+    )
+    .await;
+    server
+        .patch(&format!("/api/v1/todos/{}", todo.id))
+        .add_header("Authorization", format!("Bearer {}", token))
+        .json(&serde_json::json!({ "status": "doing" }))
+        .await
+        .assert_status_ok();
+    let response = server
+        .patch(&format!("/api/v1/todos/{}", todo.id))
+        .add_header("Authorization", format!("Bearer {}", token))
+        .json(&serde_json::json!({ "completed": true }))
+        .await;
+    response.assert_status_ok();
+    assert_eq!(response.json::<TodoResponse>().status, "shipped");
+
+    // Statuses the project's todos are in can't be dropped
+    server
+        .put(&workflow_path)
+        .add_header("Authorization", format!("Bearer {}", token))
+        .json(&serde_json::json!({
+            "statuses": [
+                { "key": "todo", "name": "Backlog", "transitions": ["done"] },
+                { "key": "done", "name": "Done", "terminal": true }
+            ]
+        }))
+        .await
+        .assert_status(StatusCode::CONFLICT);
+
+    common::cleanup_test_data(&pool).await;
+}
+
+#[tokio::test]
+async fn test_moving_todos_between_projects() {
+    let (server, pool) = common::create_test_server().await;
+
+    let token = common::register_test_user(&server, "project_move@example.com", "password123")
+        .await
+        .access_token;
+    let project = common::create_project(&server, &token, "Website").await;
+    server
+        .put(&format!("/api/v1/projects/{}/workflow", project.id))
+        .add_header("Authorization", format!("Bearer {}", token))
+        .json(&shipping_workflow())
+        .await
+        .assert_status_ok();
+
+    let todo = common::create_todo(&server, &token, "Fix the footer").await;
+    let patch = |body: serde_json::Value| {
+        server
+            .patch(&format!("/api/v1/todos/{}", todo.id))
+            .add_header("Authorization", format!("Bearer {}", token))
+            .json(&body)
+    };
+
+    // The todo's status has to be in the project's workflow
+    patch(serde_json::json!({ "status": "in_progress" }))
+        .await
+        .assert_status_ok();
+    patch(serde_json::json!({ "project_id": project.id }))
+        .await
+        .assert_status(StatusCode::BAD_REQUEST);
+    patch(serde_json::json!({ "status": "todo" }))
+        .await
+        .assert_status_ok();
+    let response = patch(serde_json::json!({ "project_id": project.id })).await;
+    response.assert_status_ok();
+    assert_eq!(response.json::<TodoResponse>().project_id, Some(project.id));
+
+    let response = patch(serde_json::json!({ "project_id": null })).await;
+    response.assert_status_ok();
+    assert_eq!(response.json::<TodoResponse>().project_id, None);
+
+    common::cleanup_test_data(&pool).await;
+}
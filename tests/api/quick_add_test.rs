@@ -0,0 +1,124 @@
+use axum::http::StatusCode;
+use axum_test::TestServer;
+use chrono::{Duration, Utc};
+use rust_teraform_backend::application::dto::{TodoListResponse, TodoResponse};
+use rust_teraform_backend::domain::entities::TodoPriority;
+
+use crate::common;
+
+async fn quick_add(
+    server: &TestServer,
+    token: &str,
+    body: serde_json::Value,
+) -> axum_test::TestResponse {
+    server
+        .post("/api/v1/todos/quick")
+        .add_header("Authorization", format!("Bearer {}", token))
+        .json(&body)
+        .await
+}
+
+#[tokio::test]
+async fn test_quick_add() {
+    let (server, _pool) = common::create_test_server().await;
+
+    let token = common::register_test_user(&server, "quick@example.com", "password123")
+        .await
+        .access_token;
+
+    let response = quick_add(
+        &server,
+        &token,
+        serde_json::json!({
+            "text": "Pay rent tomorrow 9am #finance !high every month",
+            "time_zone": "Asia/Tokyo",
+        }),
+    )
+    .await;
+    response.assert_status(StatusCode::CREATED);
+    let todo: TodoResponse = response.json();
+    assert_eq!(todo.title, "Pay rent");
+    assert_eq!(todo.tags, vec!["finance"]);
+    assert_eq!(todo.priority, TodoPriority::High);
+    assert_eq!(todo.recurrence_rule.as_deref(), Some("FREQ=MONTHLY"));
+    assert_eq!(todo.time_zone, "Asia/Tokyo");
+    let due_at = todo.due_at.unwrap();
+    assert!(due_at > Utc::now() && due_at < Utc::now() + Duration::days(2));
+
+    let todo: TodoResponse = quick_add(
+        &server,
+        &token,
+        serde_json::json!({ "text": "明日 報告書を提出 #仕事" }),
+    )
+    .await
+    .json();
+    assert_eq!(todo.title, "報告書を提出");
+    assert_eq!(todo.tags, vec!["仕事"]);
+    assert_eq!(todo.time_zone, "UTC");
+    assert!(todo.due_at.is_some());
+
+    // Nothing left for the title
+    quick_add(
+        &server,
+        &token,
+        serde_json::json!({ "text": "tomorrow #finance" }),
+    )
+    .await
+    .assert_status(StatusCode::BAD_REQUEST);
+    quick_add(
+        &server,
+        &token,
+        serde_json::json!({ "text": "Pay rent", "time_zone": "Mars/Olympus" }),
+    )
+    .await
+    .assert_status(StatusCode::BAD_REQUEST);
+
+    let listed: TodoListResponse = server
+        .get("/api/v1/todos?tag=%23Finance")
+        .add_header("Authorization", format!("Bearer {}", token))
+        .await
+        .json();
+    assert_eq!(listed.total, Some(1));
+    assert_eq!(listed.todos[0].title, "Pay rent");
+}
+
+#[tokio::test]
+async fn test_tags() {
+    let (server, _pool) = common::create_test_server().await;
+
+    let token = common::register_test_user(&server, "tags@example.com", "password123")
+        .await
+        .access_token;
+
+    server
+        .post("/api/v1/todos")
+        .add_header("Authorization", format!("Bearer {}", token))
+        .json(&serde_json::json!({ "title": "Taxes", "tags": ["two words"] }))
+        .await
+        .assert_status(StatusCode::BAD_REQUEST);
+
+    let todo = common::create_todo_with(
+        &server,
+        &token,
+        serde_json::json!({ "title": "Taxes", "tags": ["#Finance", "home", "finance"] }),
+    )
+    .await;
+    assert_eq!(todo.tags, vec!["finance", "home"]);
+
+    // Tags are replaced as a whole, and cleared by null
+    let response = server
+        .patch(&format!("/api/v1/todos/{}", todo.id))
+        .add_header("Authorization", format!("Bearer {}", token))
+        .json(&serde_json::json!({ "tags": ["work"] }))
+        .await;
+    response.assert_status_ok();
+    assert_eq!(response.json::<TodoResponse>().tags, vec!["work"]);
+
+    let response = server
+        .patch(&format!("/api/v1/todos/{}", todo.id))
+        .add_header("Authorization", format!("Bearer {}", token))
+        .json(&serde_json::json!({ "tags": null }))
+        .await;
+    response.assert_status_ok();
+    assert!(response.json::<TodoResponse>().tags.is_empty());
+}
@@ -0,0 +1,285 @@
+use axum::http::StatusCode;
+use rust_teraform_backend::application::dto::{
+    OccurrencePreviewResponse, TodoListResponse, TodoResponse,
+};
+
+use crate::common;
+
+#[tokio::test]
+async fn test_create_recurring_todo() {
+    let (server, pool) = common::create_test_server().await;
+
+    let auth = common::register_test_user(&server, "recurring@example.com", "password123").await;
+
+    let todo = common::create_todo_with(
+        &server,
+        &auth.access_token,
+        serde_json::json!({
+            "title": "Weekly report",
+            "due_at": "2026-10-19T00:00:00Z",
+            "recurrence_rule": "RRULE:freq=weekly;byday=mo",
+            "time_zone": "Asia/Tokyo"
+        }),
+    )
+    .await;
+
+    assert_eq!(
+        todo.recurrence_rule,
+        Some("FREQ=WEEKLY;BYDAY=MO".to_string())
+    );
+    assert_eq!(todo.time_zone, "Asia/Tokyo");
+    assert_eq!(todo.series_id, Some(todo.id));
+
+    common::cleanup_test_data(&pool).await;
+}
+
+#[tokio::test]
+async fn test_create_recurring_todo_validation() {
+    let (server, pool) = common::create_test_server().await;
+
+    let auth =
+        common::register_test_user(&server, "recurring_invalid@example.com", "password123").await;
+
+    // Invalid rule
+    let response = server
+        .post("/api/v1/todos")
+        .add_header("Authorization", format!("Bearer {}", auth.access_token))
+        .json(&serde_json::json!({
+            "title": "Bad rule",
+            "due_at": "2026-10-19T00:00:00Z",
+            "recurrence_rule": "FREQ=SOMETIMES"
+        }))
+        .await;
+    response.assert_status(StatusCode::BAD_REQUEST);
+
+    // Rule without a due date
+    let response = server
+        .post("/api/v1/todos")
+        .add_header("Authorization", format!("Bearer {}", auth.access_token))
+        .json(&serde_json::json!({
+            "title": "No anchor",
+            "recurrence_rule": "FREQ=DAILY"
+        }))
+        .await;
+    response.assert_status(StatusCode::BAD_REQUEST);
+
+    // Unknown time zone
+    let response = server
+        .post("/api/v1/todos")
+        .add_header("Authorization", format!("Bearer {}", auth.access_token))
+        .json(&serde_json::json!({
+            "title": "Bad zone",
+            "due_at": "2026-10-19T00:00:00Z",
+            "recurrence_rule": "FREQ=DAILY",
+            "time_zone": "Nowhere/Special"
+        }))
+        .await;
+    response.assert_status(StatusCode::BAD_REQUEST);
+
+    common::cleanup_test_data(&pool).await;
+}
+
+#[tokio::test]
+async fn test_completing_occurrence_creates_next() {
+    let (server, pool) = common::create_test_server().await;
+
+    let auth =
+        common::register_test_user(&server, "recurring_complete@example.com", "password123").await;
+
+    let created = common::create_todo_with(
+        &server,
+        &auth.access_token,
+        serde_json::json!({
+            "title": "Pay bills",
+            "due_at": "2026-10-25T10:00:00Z",
+            "recurrence_rule": "FREQ=MONTHLY;COUNT=2"
+        }),
+    )
+    .await;
+
+    // Complete the first occurrence
+    let response = server
+        .patch(&format!("/api/v1/todos/{}", created.id))
+        .add_header("Authorization", format!("Bearer {}", auth.access_token))
+        .json(&serde_json::json!({ "completed": true }))
+        .await;
+    response.assert_status_ok();
+
+    let response = server
+        .get("/api/v1/todos")
+        .add_header("Authorization", format!("Bearer {}", auth.access_token))
+        .await;
+    let list: TodoListResponse = response.json();
+    assert_eq!(list.total, Some(2));
+
+    // The completed occurrence is kept as history
+    let next = list
+        .todos
+        .iter()
+        .find(|t| !t.completed)
+        .expect("next occurrence");
+    assert_eq!(next.series_id, Some(created.id));
+    assert_eq!(
+        next.due_at.unwrap().to_rfc3339(),
+        "2026-11-25T10:00:00+00:00"
+    );
+
+    // COUNT=2: completing the last occurrence does not create another
+    server
+        .patch(&format!("/api/v1/todos/{}", next.id))
+        .add_header("Authorization", format!("Bearer {}", auth.access_token))
+        .json(&serde_json::json!({ "completed": true }))
+        .await
+        .assert_status_ok();
+
+    let response = server
+        .get("/api/v1/todos")
+        .add_header("Authorization", format!("Bearer {}", auth.access_token))
+        .await;
+    let list: TodoListResponse = response.json();
+    assert_eq!(list.total, Some(2));
+    assert!(list.todos.iter().all(|t| t.completed));
+
+    common::cleanup_test_data(&pool).await;
+}
+
+#[tokio::test]
+async fn test_completing_again_does_not_duplicate_next() {
+    let (server, pool) = common::create_test_server().await;
+
+    let token = common::register_test_user(&server, "recurring_again@example.com", "password123")
+        .await
+        .access_token;
+    let created = common::create_todo_with(
+        &server,
+        &token,
+        serde_json::json!({
+            "title": "Water plants",
+            "due_at": "2026-10-25T10:00:00Z",
+            "recurrence_rule": "FREQ=WEEKLY"
+        }),
+    )
+    .await;
+    let todo_url = format!("/api/v1/todos/{}", created.id);
+    let set_completed = |completed: bool| {
+        server
+            .patch(&todo_url)
+            .add_header("Authorization", format!("Bearer {}", token))
+            .json(&serde_json::json!({ "completed": completed }))
+    };
+
+    // Complete, reopen and complete again through each way of completing
+    set_completed(true).await.assert_status_ok();
+    set_completed(false).await.assert_status_ok();
+    set_completed(true).await.assert_status_ok();
+    set_completed(false).await.assert_status_ok();
+    server
+        .post(&format!("{}/move", todo_url))
+        .add_header("Authorization", format!("Bearer {}", token))
+        .json(&serde_json::json!({ "status": "done" }))
+        .await
+        .assert_status_ok();
+    set_completed(false).await.assert_status_ok();
+    server
+        .post("/api/v1/todos/batch")
+        .add_header("Authorization", format!("Bearer {}", token))
+        .json(&serde_json::json!({
+            "operations": [{ "op": "update", "id": created.id, "completed": true }]
+        }))
+        .await
+        .assert_status_ok();
+
+    let list: TodoListResponse = server
+        .get("/api/v1/todos")
+        .add_header("Authorization", format!("Bearer {}", token))
+        .await
+        .json();
+    assert_eq!(list.total, Some(2));
+    let next = list.todos.iter().find(|t| t.id != created.id).unwrap();
+    assert_eq!(next.series_id, Some(created.id));
+    assert!(!next.completed);
+
+    common::cleanup_test_data(&pool).await;
+}
+
+#[tokio::test]
+async fn test_cancelling_occurrence_creates_no_next() {
+    let (server, pool) = common::create_test_server().await;
+
+    let token = common::register_test_user(&server, "recurring_cancel@example.com", "password123")
+        .await
+        .access_token;
+    let created = common::create_todo_with(
+        &server,
+        &token,
+        serde_json::json!({
+            "title": "Team lunch",
+            "due_at": "2026-10-25T12:00:00Z",
+            "recurrence_rule": "FREQ=WEEKLY"
+        }),
+    )
+    .await;
+
+    let cancelled: TodoResponse = server
+        .patch(&format!("/api/v1/todos/{}", created.id))
+        .add_header("Authorization", format!("Bearer {}", token))
+        .json(&serde_json::json!({ "status": "cancelled" }))
+        .await
+        .json();
+    assert!(cancelled.completed);
+
+    let list: TodoListResponse = server
+        .get("/api/v1/todos")
+        .add_header("Authorization", format!("Bearer {}", token))
+        .await
+        .json();
+    assert_eq!(list.total, Some(1));
+
+    common::cleanup_test_data(&pool).await;
+}
+
+#[tokio::test]
+async fn test_preview_occurrences() {
+    let (server, pool) = common::create_test_server().await;
+
+    let auth =
+        common::register_test_user(&server, "recurring_preview@example.com", "password123").await;
+
+    let created = common::create_todo_with(
+        &server,
+        &auth.access_token,
+        serde_json::json!({
+            "title": "Standup",
+            "due_at": "2026-10-19T00:30:00Z",
+            "recurrence_rule": "FREQ=DAILY;BYDAY=MO,TU,WE,TH,FR",
+            "time_zone": "Asia/Tokyo"
+        }),
+    )
+    .await;
+
+    let response = server
+        .get(&format!("/api/v1/todos/{}/occurrences?count=5", created.id))
+        .add_header("Authorization", format!("Bearer {}", auth.access_token))
+        .await;
+    response.assert_status_ok();
+
+    let preview: OccurrencePreviewResponse = response.json();
+    let dates: Vec<String> = preview
+        .occurrences
+        .iter()
+        .map(|d| d.format("%Y-%m-%d").to_string())
+        .collect();
+    // Skips the weekend
+    assert_eq!(
+        dates,
+        vec![
+            "2026-10-20",
+            "2026-10-21",
+            "2026-10-22",
+            "2026-10-23",
+            "2026-10-26"
+        ]
+    );
+
+    common::cleanup_test_data(&pool).await;
+}
@@ -0,0 +1,304 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use axum::http::StatusCode;
+use axum_test::TestServer;
+use chrono::{Duration, SubsecRound, Utc};
+use rust_teraform_backend::application::dto::{
+    ReminderDeliveryListResponse, ReminderListResponse, ReminderResponse,
+};
+use rust_teraform_backend::domain::entities::{
+    DeliveryChannel, DeliveryStatus, Notification, NotificationEvent,
+};
+use rust_teraform_backend::domain::repositories::NotificationRepository;
+use rust_teraform_backend::infrastructure::jobs::{deliver_reminders, ReminderSchedulerConfig};
+use rust_teraform_backend::infrastructure::notifications::{
+    EmailChannel, EmailConfig, InAppChannel, NotificationChannel, NotificationChannels, Recipient,
+};
+use rust_teraform_backend::infrastructure::persistence::postgres::{
+    PostgresNotificationRepository, PostgresReminderRepository, Tenant,
+};
+use rust_teraform_backend::shared::error::AppResult;
+
+use crate::common;
+
+/// A webhook channel whose receiver is always down.
+struct UnreachableWebhook;
+
+#[async_trait]
+impl NotificationChannel for UnreachableWebhook {
+    fn kind(&self) -> DeliveryChannel {
+        DeliveryChannel::Webhook
+    }
+
+    async fn send(&self, _recipient: &Recipient, _notification: &Notification) -> AppResult<()> {
+        Err(anyhow::anyhow!("connection refused").into())
+    }
+}
+
+fn test_channels(pool: &sqlx::PgPool) -> NotificationChannels {
+    let notification_repository: Arc<dyn NotificationRepository> =
+        Arc::new(PostgresNotificationRepository::new(pool.clone()));
+    NotificationChannels::new(vec![
+        Arc::new(InAppChannel::new(notification_repository)),
+        Arc::new(EmailChannel::new(EmailConfig {
+            api_url: None,
+            api_key: None,
+            from: "Todo <noreply@localhost>".to_string(),
+        })),
+        Arc::new(UnreachableWebhook),
+    ])
+}
+
+/// Runs one scheduler pass, returning how many deliveries were sent.
+async fn run_pass(pool: &sqlx::PgPool, channels: &NotificationChannels) -> usize {
+    let repository = PostgresReminderRepository::new(pool.clone());
+    let config = ReminderSchedulerConfig {
+        interval: std::time::Duration::from_secs(30),
+        batch_size: 100,
+        lease: Duration::minutes(5),
+    };
+    Tenant::System
+        .scope(async {
+            deliver_reminders(&repository, channels, &config)
+                .await
+                .unwrap()
+        })
+        .await
+}
+
+/// Schedules, on a todo due in ten minutes, a reminder that is already due
+/// on every channel and one due in an hour. Returns the reminders URL and
+/// both reminders.
+async fn schedule_reminders(
+    server: &TestServer,
+    token: &str,
+) -> (String, ReminderResponse, ReminderResponse) {
+    let todo = common::create_todo_with(
+        server,
+        token,
+        serde_json::json!({
+            "title": "Submit report",
+            "due_at": Utc::now() + Duration::minutes(10)
+        }),
+    )
+    .await;
+    let reminders_url = format!("/api/v1/todos/{}/reminders", todo.id);
+
+    // Half an hour before a todo due in ten minutes is already past
+    let response = server
+        .post(&reminders_url)
+        .add_header("Authorization", format!("Bearer {}", token))
+        .json(&serde_json::json!({
+            "offset_minutes": 30,
+            "channels": ["in_app", "email", "webhook"],
+            "webhook_url": "https://hooks.example.com/todo"
+        }))
+        .await;
+    response.assert_status(StatusCode::CREATED);
+    let due: ReminderResponse = response.json();
+    let later: ReminderResponse = server
+        .post(&reminders_url)
+        .add_header("Authorization", format!("Bearer {}", token))
+        .json(&serde_json::json!({ "remind_at": Utc::now() + Duration::hours(1) }))
+        .await
+        .json();
+    (reminders_url, due, later)
+}
+
+#[tokio::test]
+async fn test_reminder_validation() {
+    let (server, pool) = common::create_test_server().await;
+
+    let token = common::register_test_user(&server, "remind@example.com", "password123")
+        .await
+        .access_token;
+    let todo = common::create_todo(&server, &token, "Undated").await;
+    let reminders_url = format!("/api/v1/todos/{}/reminders", todo.id);
+
+    let in_an_hour = Utc::now() + Duration::hours(1);
+    for body in [
+        // Neither or both triggers
+        serde_json::json!({}),
+        serde_json::json!({ "remind_at": in_an_hour, "offset_minutes": 10 }),
+        // In the past
+        serde_json::json!({ "remind_at": Utc::now() - Duration::minutes(1) }),
+        // No due date to remind before
+        serde_json::json!({ "offset_minutes": 10 }),
+        serde_json::json!({ "remind_at": in_an_hour, "channels": [] }),
+        serde_json::json!({ "remind_at": in_an_hour, "channels": ["webhook"] }),
+        serde_json::json!({
+            "remind_at": in_an_hour,
+            "webhook_url": "https://hooks.example.com/todo"
+        }),
+        serde_json::json!({
+            "remind_at": in_an_hour,
+            "channels": ["webhook"],
+            "webhook_url": "http://hooks.example.com/todo"
+        }),
+        serde_json::json!({
+            "remind_at": in_an_hour,
+            "channels": ["webhook"],
+            "webhook_url": "https://127.0.0.1/todo"
+        }),
+    ] {
+        server
+            .post(&reminders_url)
+            .add_header("Authorization", format!("Bearer {}", token))
+            .json(&body)
+            .await
+            .assert_status(StatusCode::BAD_REQUEST);
+    }
+
+    common::cleanup_test_data(&pool).await;
+}
+
+#[tokio::test]
+async fn test_create_reminder() {
+    let (server, pool) = common::create_test_server().await;
+
+    let token = common::register_test_user(&server, "remind_create@example.com", "password123")
+        .await
+        .access_token;
+    let todo = common::create_todo(&server, &token, "Undated").await;
+
+    // Whole seconds survive the round trip through Postgres
+    let in_an_hour = (Utc::now() + Duration::hours(1)).trunc_subsecs(0);
+    let response = server
+        .post(&format!("/api/v1/todos/{}/reminders", todo.id))
+        .add_header("Authorization", format!("Bearer {}", token))
+        .json(&serde_json::json!({
+            "remind_at": in_an_hour,
+            "channels": ["email", "in_app", "email"]
+        }))
+        .await;
+    response.assert_status(StatusCode::CREATED);
+    let reminder: ReminderResponse = response.json();
+    assert_eq!(
+        reminder.channels,
+        vec![DeliveryChannel::InApp, DeliveryChannel::Email]
+    );
+    assert_eq!(reminder.fire_at, Some(in_an_hour));
+    assert!(!reminder.fired);
+
+    common::cleanup_test_data(&pool).await;
+}
+
+#[tokio::test]
+async fn test_delete_reminder() {
+    let (server, pool) = common::create_test_server().await;
+
+    let token = common::register_test_user(&server, "remind_delete@example.com", "password123")
+        .await
+        .access_token;
+    let auth = format!("Bearer {}", token);
+    let todo = common::create_todo(&server, &token, "Undated").await;
+    let reminders_url = format!("/api/v1/todos/{}/reminders", todo.id);
+    let reminder: ReminderResponse = server
+        .post(&reminders_url)
+        .add_header("Authorization", &auth)
+        .json(&serde_json::json!({ "remind_at": Utc::now() + Duration::hours(1) }))
+        .await
+        .json();
+
+    // Reminders are personal
+    let other = common::register_test_user(&server, "other@example.com", "password123")
+        .await
+        .access_token;
+    server
+        .get(&reminders_url)
+        .add_header("Authorization", format!("Bearer {}", other))
+        .await
+        .assert_status(StatusCode::NOT_FOUND);
+
+    server
+        .delete(&format!("{}/{}", reminders_url, reminder.id))
+        .add_header("Authorization", &auth)
+        .await
+        .assert_status(StatusCode::NO_CONTENT);
+    let list: ReminderListResponse = server
+        .get(&reminders_url)
+        .add_header("Authorization", &auth)
+        .await
+        .json();
+    assert!(list.reminders.is_empty());
+
+    common::cleanup_test_data(&pool).await;
+}
+
+#[tokio::test]
+async fn test_reminder_delivery() {
+    let (server, pool) = common::create_test_server().await;
+
+    let token = common::register_test_user(&server, "deliver@example.com", "password123")
+        .await
+        .access_token;
+    let (reminders_url, due, later) = schedule_reminders(&server, &token).await;
+
+    // Passes running at the same time send each delivery once
+    let channels = test_channels(&pool);
+    let (first, second) = tokio::join!(run_pass(&pool, &channels), run_pass(&pool, &channels));
+    assert_eq!(first + second, 2);
+
+    let list: ReminderListResponse = server
+        .get(&reminders_url)
+        .add_header("Authorization", format!("Bearer {}", token))
+        .await
+        .json();
+    let fired: Vec<_> = list.reminders.iter().map(|r| (r.id, r.fired)).collect();
+    assert_eq!(fired, vec![(due.id, true), (later.id, false)]);
+
+    let notifications: Vec<Notification> = sqlx::query_as(
+        "SELECT n.* FROM notifications n JOIN users u ON u.id = n.user_id WHERE u.email = $1",
+    )
+    .bind("deliver@example.com")
+    .fetch_all(&pool)
+    .await
+    .unwrap();
+    assert_eq!(notifications.len(), 1);
+    assert_eq!(notifications[0].event, NotificationEvent::Reminder);
+    assert_eq!(notifications[0].title, "Reminder: Submit report");
+
+    common::cleanup_test_data(&pool).await;
+}
+
+#[tokio::test]
+async fn test_failed_deliveries_are_retried() {
+    let (server, pool) = common::create_test_server().await;
+
+    let token = common::register_test_user(&server, "deliver_retry@example.com", "password123")
+        .await
+        .access_token;
+    let (reminders_url, due, _) = schedule_reminders(&server, &token).await;
+    let channels = test_channels(&pool);
+    run_pass(&pool, &channels).await;
+
+    let deliveries: ReminderDeliveryListResponse = server
+        .get(&format!("{}/{}/deliveries", reminders_url, due.id))
+        .add_header("Authorization", format!("Bearer {}", token))
+        .await
+        .json();
+    assert_eq!(deliveries.deliveries.len(), 3);
+    for delivery in &deliveries.deliveries {
+        assert_eq!(delivery.scheduled_for, due.fire_at.unwrap());
+        assert_eq!(delivery.attempts, 1);
+        if delivery.channel == DeliveryChannel::Webhook {
+            // Retried later
+            assert_eq!(delivery.status, DeliveryStatus::Pending);
+            assert_eq!(
+                delivery.last_error.as_deref(),
+                Some("Internal error: connection refused")
+            );
+            assert!(delivery.next_attempt_at.unwrap() > Utc::now());
+        } else {
+            assert_eq!(delivery.status, DeliveryStatus::Sent);
+            assert!(delivery.delivered_at.is_some());
+            assert_eq!(delivery.next_attempt_at, None);
+        }
+    }
+
+    // Nothing more is due until the webhook's retry
+    assert_eq!(run_pass(&pool, &channels).await, 0);
+
+    common::cleanup_test_data(&pool).await;
+}
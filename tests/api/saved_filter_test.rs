@@ -0,0 +1,300 @@
+use axum::http::StatusCode;
+use axum_test::TestServer;
+use chrono::{Duration, Utc};
+use rust_teraform_backend::application::dto::{
+    SavedFilterListResponse, SavedFilterResponse, TodoListResponse, TodoResponse,
+};
+
+use crate::common;
+
+/// Four todos mentioning a release, two of them tagged work and due within
+/// the week. Returns the one due in two days.
+async fn create_release_todos(server: &TestServer, token: &str) -> TodoResponse {
+    let now = Utc::now();
+    let notes = common::create_todo_with(
+        server,
+        token,
+        serde_json::json!({
+            "title": "Write release notes",
+            "tags": ["work"],
+            "due_at": now + Duration::days(2)
+        }),
+    )
+    .await;
+    for todo in [
+        serde_json::json!({
+            "title": "Review release checklist",
+            "tags": ["work", "q4"],
+            "due_at": now - Duration::days(1)
+        }),
+        serde_json::json!({
+            "title": "Plan release party",
+            "tags": ["work"],
+            "due_at": now + Duration::days(30)
+        }),
+        serde_json::json!({
+            "title": "Buy release candles",
+            "tags": ["home"],
+            "due_at": now + Duration::days(1)
+        }),
+    ] {
+        common::create_todo_with(server, token, todo).await;
+    }
+    notes
+}
+
+/// Work todos about the release due within the week.
+async fn create_this_week_filter(server: &TestServer, token: &str) -> SavedFilterResponse {
+    let response = server
+        .post("/api/v1/filters")
+        .add_header("Authorization", format!("Bearer {}", token))
+        .json(&serde_json::json!({
+            "name": "This week",
+            "definition": {
+                "tags": ["#Work"],
+                "due_within_days": 7,
+                "text": "release"
+            }
+        }))
+        .await;
+    response.assert_status(StatusCode::CREATED);
+    response.json()
+}
+
+async fn get_filter(server: &TestServer, token: &str, id: uuid::Uuid) -> SavedFilterResponse {
+    let response = server
+        .get(&format!("/api/v1/filters/{}", id))
+        .add_header("Authorization", format!("Bearer {}", token))
+        .await;
+    response.assert_status_ok();
+    response.json()
+}
+
+async fn filter_titles(server: &TestServer, token: &str, id: uuid::Uuid) -> Vec<String> {
+    let response = server
+        .get(&format!("/api/v1/filters/{}/todos?sort=title", id))
+        .add_header("Authorization", format!("Bearer {}", token))
+        .await;
+    response.assert_status_ok();
+    let listed: TodoListResponse = response.json();
+    listed.todos.into_iter().map(|t| t.title).collect()
+}
+
+#[tokio::test]
+async fn test_create_saved_filter() {
+    let (server, pool) = common::create_test_server().await;
+
+    let token = common::register_test_user(&server, "filters@example.com", "password123")
+        .await
+        .access_token;
+    create_release_todos(&server, &token).await;
+
+    let filter = create_this_week_filter(&server, &token).await;
+    assert_eq!(filter.name, "This week");
+    assert_eq!(filter.definition.tags, vec!["#Work"]);
+    assert_eq!(filter.open_count, 2);
+    assert_eq!(filter.unread_count, 2);
+    assert!(filter.last_viewed_at.is_none());
+
+    common::cleanup_test_data(&pool).await;
+}
+
+#[tokio::test]
+async fn test_saved_filter_validation() {
+    let (server, pool) = common::create_test_server().await;
+
+    let token = common::register_test_user(&server, "filters_valid@example.com", "password123")
+        .await
+        .access_token;
+    let other = common::register_test_user(&server, "filters2@example.com", "password123")
+        .await
+        .access_token;
+    create_this_week_filter(&server, &token).await;
+
+    // Names are unique per user
+    server
+        .post("/api/v1/filters")
+        .add_header("Authorization", format!("Bearer {}", token))
+        .json(&serde_json::json!({ "name": "This week", "definition": {} }))
+        .await
+        .assert_status(StatusCode::CONFLICT);
+    server
+        .post("/api/v1/filters")
+        .add_header("Authorization", format!("Bearer {}", other))
+        .json(&serde_json::json!({ "name": "This week", "definition": {} }))
+        .await
+        .assert_status(StatusCode::CREATED);
+
+    // Definitions are validated
+    server
+        .post("/api/v1/filters")
+        .add_header("Authorization", format!("Bearer {}", token))
+        .json(&serde_json::json!({ "name": "Bad", "definition": { "status": "Not A Key" } }))
+        .await
+        .assert_status(StatusCode::BAD_REQUEST);
+    let response = server
+        .post("/api/v1/filters")
+        .add_header("Authorization", format!("Bearer {}", token))
+        .json(&serde_json::json!({ "name": "Bad", "definition": { "colour": "red" } }))
+        .await;
+    assert!(response.status_code().is_client_error());
+
+    common::cleanup_test_data(&pool).await;
+}
+
+#[tokio::test]
+async fn test_saved_filter_todos() {
+    let (server, pool) = common::create_test_server().await;
+
+    let token = common::register_test_user(&server, "filters_todos@example.com", "password123")
+        .await
+        .access_token;
+    let notes = create_release_todos(&server, &token).await;
+    let filter = create_this_week_filter(&server, &token).await;
+
+    // Evaluated like the list endpoint, which marks the matches as read
+    assert_eq!(
+        filter_titles(&server, &token, filter.id).await,
+        vec!["Review release checklist", "Write release notes"]
+    );
+    let viewed = get_filter(&server, &token, filter.id).await;
+    assert_eq!(viewed.open_count, 2);
+    assert_eq!(viewed.unread_count, 0);
+    assert!(viewed.last_viewed_at.is_some());
+
+    server
+        .patch(&format!("/api/v1/todos/{}", notes.id))
+        .add_header("Authorization", format!("Bearer {}", token))
+        .json(&serde_json::json!({ "completed": true }))
+        .await
+        .assert_status_ok();
+    let updated = get_filter(&server, &token, filter.id).await;
+    assert_eq!(updated.open_count, 1);
+    assert_eq!(updated.unread_count, 1);
+
+    common::cleanup_test_data(&pool).await;
+}
+
+#[tokio::test]
+async fn test_redefine_saved_filter() {
+    let (server, pool) = common::create_test_server().await;
+
+    let token = common::register_test_user(&server, "filters_edit@example.com", "password123")
+        .await
+        .access_token;
+    create_release_todos(&server, &token).await;
+    let filter = create_this_week_filter(&server, &token).await;
+    let redefine = |definition: serde_json::Value| {
+        server
+            .put(&format!("/api/v1/filters/{}", filter.id))
+            .add_header("Authorization", format!("Bearer {}", token))
+            .json(&serde_json::json!({ "name": "Q4", "definition": definition }))
+    };
+
+    // Redefining narrows the matches
+    let response =
+        redefine(serde_json::json!({ "tags": ["work", "q4"], "completed": false })).await;
+    response.assert_status_ok();
+    let renamed: SavedFilterResponse = response.json();
+    assert_eq!(renamed.name, "Q4");
+    assert_eq!(
+        filter_titles(&server, &token, filter.id).await,
+        vec!["Review release checklist"]
+    );
+
+    // Filters of another workspace list nothing here
+    redefine(serde_json::json!({ "workspace_id": uuid::Uuid::new_v4() }))
+        .await
+        .assert_status_ok();
+    assert!(filter_titles(&server, &token, filter.id).await.is_empty());
+
+    common::cleanup_test_data(&pool).await;
+}
+
+#[tokio::test]
+async fn test_saved_filters_are_private() {
+    let (server, pool) = common::create_test_server().await;
+
+    let token = common::register_test_user(&server, "filters_own@example.com", "password123")
+        .await
+        .access_token;
+    let other = common::register_test_user(&server, "filters_other@example.com", "password123")
+        .await
+        .access_token;
+    let filter = create_this_week_filter(&server, &token).await;
+
+    let listed: SavedFilterListResponse = server
+        .get("/api/v1/filters")
+        .add_header("Authorization", format!("Bearer {}", token))
+        .await
+        .json();
+    assert_eq!(listed.filters.len(), 1);
+
+    // Other users cannot see the filter
+    server
+        .get(&format!("/api/v1/filters/{}/todos", filter.id))
+        .add_header("Authorization", format!("Bearer {}", other))
+        .await
+        .assert_status(StatusCode::NOT_FOUND);
+    server
+        .delete(&format!("/api/v1/filters/{}", filter.id))
+        .add_header("Authorization", format!("Bearer {}", other))
+        .await
+        .assert_status(StatusCode::NOT_FOUND);
+
+    server
+        .delete(&format!("/api/v1/filters/{}", filter.id))
+        .add_header("Authorization", format!("Bearer {}", token))
+        .await
+        .assert_status(StatusCode::NO_CONTENT);
+    server
+        .get(&format!("/api/v1/filters/{}", filter.id))
+        .add_header("Authorization", format!("Bearer {}", token))
+        .await
+        .assert_status(StatusCode::NOT_FOUND);
+
+    common::cleanup_test_data(&pool).await;
+}
+
+#[tokio::test]
+async fn test_saved_filter_by_project() {
+    let (server, pool) = common::create_test_server().await;
+
+    let token = common::register_test_user(&server, "filters_project@example.com", "password123")
+        .await
+        .access_token;
+    let website = common::create_project(&server, &token, "Website").await;
+    let app = common::create_project(&server, &token, "App").await;
+    common::create_todo_with(
+        &server,
+        &token,
+        serde_json::json!({ "title": "Fix the footer", "project_id": website.id }),
+    )
+    .await;
+    common::create_todo_with(
+        &server,
+        &token,
+        serde_json::json!({ "title": "Ship the beta", "project_id": app.id }),
+    )
+    .await;
+    common::create_todo(&server, &token, "Renew passport").await;
+
+    let response = server
+        .post("/api/v1/filters")
+        .add_header("Authorization", format!("Bearer {}", token))
+        .json(&serde_json::json!({
+            "name": "Website",
+            "definition": { "project_id": website.id }
+        }))
+        .await;
+    response.assert_status(StatusCode::CREATED);
+    let filter: SavedFilterResponse = response.json();
+    assert_eq!(filter.definition.project_id, Some(website.id));
+    assert_eq!(filter.open_count, 1);
+    assert_eq!(
+        filter_titles(&server, &token, filter.id).await,
+        vec!["Fix the footer"]
+    );
+
+    common::cleanup_test_data(&pool).await;
+}
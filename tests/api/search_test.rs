@@ -0,0 +1,222 @@
+use axum::http::StatusCode;
+use rust_teraform_backend::application::dto::TodoSearchResponse;
+
+use crate::common;
+
+#[tokio::test]
+async fn test_search_ranks_title_matches_first() {
+    let (server, pool) = common::create_test_server().await;
+
+    let auth = common::register_test_user(&server, "search@example.com", "password123").await;
+    let token = &auth.access_token;
+
+    common::create_todo_with(
+        &server,
+        token,
+        serde_json::json!({ "title": "Buy groceries", "description": "Milk and reports" }),
+    )
+    .await;
+    common::create_todo(&server, token, "Write weekly reports").await;
+    common::create_todo(&server, token, "Call the bank").await;
+
+    let response = server
+        .get("/api/v1/todos/search?q=report")
+        .add_header("Authorization", format!("Bearer {}", token))
+        .await;
+
+    response.assert_status_ok();
+
+    let search: TodoSearchResponse = response.json();
+    assert_eq!(search.total, 2);
+    assert_eq!(search.results[0].todo.title, "Write weekly reports");
+    assert_eq!(
+        search.results[0].title_snippet,
+        "Write weekly <mark>reports</mark>"
+    );
+    assert_eq!(search.results[0].description_snippet, None);
+    assert_eq!(
+        search.results[1].description_snippet.as_deref(),
+        Some("Milk and <mark>reports</mark>")
+    );
+
+    common::cleanup_test_data(&pool).await;
+}
+
+#[tokio::test]
+async fn test_search_phrase_and_prefix() {
+    let (server, pool) = common::create_test_server().await;
+
+    let auth =
+        common::register_test_user(&server, "search_phrase@example.com", "password123").await;
+    let token = &auth.access_token;
+
+    common::create_todo(&server, token, "Monthly budget review").await;
+    common::create_todo(&server, token, "Review the monthly plan").await;
+
+    // Phrase requires adjacent words in order
+    let response = server
+        .get("/api/v1/todos/search")
+        .add_query_param("q", "\"monthly budget\"")
+        .add_header("Authorization", format!("Bearer {}", token))
+        .await;
+    let search: TodoSearchResponse = response.json();
+    assert_eq!(search.total, 1);
+    assert_eq!(search.results[0].todo.title, "Monthly budget review");
+
+    // Prefix matching
+    let response = server
+        .get("/api/v1/todos/search")
+        .add_query_param("q", "budg*")
+        .add_header("Authorization", format!("Bearer {}", token))
+        .await;
+    let search: TodoSearchResponse = response.json();
+    assert_eq!(search.total, 1);
+
+    // tsquery operators in user input are treated literally
+    let response = server
+        .get("/api/v1/todos/search")
+        .add_query_param("q", "mon&|!:*")
+        .add_header("Authorization", format!("Bearer {}", token))
+        .await;
+    response.assert_status_ok();
+
+    common::cleanup_test_data(&pool).await;
+}
+
+#[tokio::test]
+async fn test_search_japanese() {
+    let (server, pool) = common::create_test_server().await;
+
+    let auth = common::register_test_user(&server, "search_ja@example.com", "password123").await;
+    let token = &auth.access_token;
+
+    common::create_todo(&server, token, "週次レポートを書く").await;
+    common::create_todo_with(
+        &server,
+        token,
+        serde_json::json!({ "title": "請求書を送る", "description": "月次レポートを添付" }),
+    )
+    .await;
+    common::create_todo(&server, token, "100%_done").await;
+
+    let response = server
+        .get("/api/v1/todos/search")
+        .add_query_param("q", "レポート")
+        .add_header("Authorization", format!("Bearer {}", token))
+        .await;
+    let search: TodoSearchResponse = response.json();
+    assert_eq!(search.total, 2);
+    assert_eq!(
+        search.results[0].title_snippet,
+        "週次<mark>レポート</mark>を書く"
+    );
+    assert_eq!(
+        search.results[1].description_snippet.as_deref(),
+        Some("月次<mark>レポート</mark>を添付")
+    );
+
+    // LIKE wildcards are matched literally
+    let response = server
+        .get("/api/v1/todos/search")
+        .add_query_param("q", "%_")
+        .add_query_param("lang", "japanese")
+        .add_header("Authorization", format!("Bearer {}", token))
+        .await;
+    let search: TodoSearchResponse = response.json();
+    assert_eq!(search.total, 1);
+
+    common::cleanup_test_data(&pool).await;
+}
+
+#[tokio::test]
+async fn test_search_requires_query() {
+    let (server, pool) = common::create_test_server().await;
+
+    let auth = common::register_test_user(&server, "search_empty@example.com", "password123").await;
+
+    let response = server
+        .get("/api/v1/todos/search?q=")
+        .add_header("Authorization", format!("Bearer {}", auth.access_token))
+        .await;
+
+    response.assert_status(StatusCode::BAD_REQUEST);
+
+    common::cleanup_test_data(&pool).await;
+}
+
+#[tokio::test]
+async fn test_search_snippets_are_escaped() {
+    let (server, pool) = common::create_test_server().await;
+
+    let auth =
+        common::register_test_user(&server, "search_escape@example.com", "password123").await;
+    let token = &auth.access_token;
+
+    common::create_todo_with(
+        &server,
+        token,
+        serde_json::json!({
+            "title": "<img src=x onerror=alert(1)> report",
+            "description": "Tom & Jerry's \"report\""
+        }),
+    )
+    .await;
+    common::create_todo(&server, token, "<b>週次</b>レポート").await;
+
+    let response = server
+        .get("/api/v1/todos/search?q=report")
+        .add_header("Authorization", format!("Bearer {}", token))
+        .await;
+    let search: TodoSearchResponse = response.json();
+    assert_eq!(
+        search.results[0].title_snippet,
+        "&lt;img src=x onerror=alert(1)&gt; <mark>report</mark>"
+    );
+    assert_eq!(
+        search.results[0].description_snippet.as_deref(),
+        Some("Tom &amp; Jerry&#39;s &quot;<mark>report</mark>&quot;")
+    );
+
+    let response = server
+        .get("/api/v1/todos/search")
+        .add_query_param("q", "レポート")
+        .add_header("Authorization", format!("Bearer {}", token))
+        .await;
+    let search: TodoSearchResponse = response.json();
+    assert_eq!(
+        search.results[0].title_snippet,
+        "&lt;b&gt;週次&lt;/b&gt;<mark>レポート</mark>"
+    );
+
+    common::cleanup_test_data(&pool).await;
+}
+
+#[tokio::test]
+async fn test_search_japanese_ranks_on_every_term() {
+    let (server, pool) = common::create_test_server().await;
+
+    let auth =
+        common::register_test_user(&server, "search_ja_rank@example.com", "password123").await;
+    let token = &auth.access_token;
+
+    common::create_todo(&server, token, "会議のレポート").await;
+    common::create_todo_with(
+        &server,
+        token,
+        serde_json::json!({ "title": "週次レポート", "description": "会議で共有" }),
+    )
+    .await;
+
+    // Both titles have the first term; only one has both
+    let response = server
+        .get("/api/v1/todos/search")
+        .add_query_param("q", "レポート 会議")
+        .add_header("Authorization", format!("Bearer {}", token))
+        .await;
+    let search: TodoSearchResponse = response.json();
+    assert_eq!(search.total, 2);
+    assert_eq!(search.results[0].todo.title, "会議のレポート");
+    assert!(search.results[0].rank > search.results[1].rank);
+
+    common::cleanup_test_data(&pool).await;
+}
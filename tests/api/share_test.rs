@@ -0,0 +1,536 @@
+use axum::http::StatusCode;
+use axum_test::{TestResponse, TestServer};
+use rust_teraform_backend::application::dto::{
+    ProjectShareListResponse, ProjectShareResponse, SharedTodoListResponse,
+    TodoHistoryListResponse, TodoResponse, TodoShareListResponse, TodoShareResponse,
+};
+use rust_teraform_backend::domain::entities::SharePermission;
+
+use crate::common;
+
+/// The owner's todo, not shared yet, and two other members of the owner's
+/// workspace. Every email starts with `prefix`.
+struct Offsite {
+    prefix: String,
+    owner: String,
+    viewer: String,
+    editor: String,
+    workspace: String,
+    todo: TodoResponse,
+}
+
+impl Offsite {
+    fn email(&self, role: &str) -> String {
+        format!("{}_{}@example.com", self.prefix, role)
+    }
+
+    fn todo_url(&self) -> String {
+        format!("/api/v1/todos/{}", self.todo.id)
+    }
+
+    fn shares_url(&self) -> String {
+        format!("/api/v1/todos/{}/shares", self.todo.id)
+    }
+}
+
+async fn offsite(server: &TestServer, prefix: &str) -> Offsite {
+    let email = |role: &str| format!("{}_{}@example.com", prefix, role);
+    let owner = common::register_test_user(server, &email("owner"), "password123")
+        .await
+        .access_token;
+    let viewer = common::register_test_user(server, &email("viewer"), "password123")
+        .await
+        .access_token;
+    let editor = common::register_test_user(server, &email("editor"), "password123")
+        .await
+        .access_token;
+
+    // Sharing works within a workspace, so members see it through its ID
+    let workspace = common::join_workspace(server, &owner, &viewer, &email("viewer")).await;
+    common::join_workspace(server, &owner, &editor, &email("editor")).await;
+
+    let todo = common::create_todo(server, &owner, "Plan the offsite").await;
+    Offsite {
+        prefix: prefix.to_string(),
+        owner,
+        viewer,
+        editor,
+        workspace: workspace.to_string(),
+        todo,
+    }
+}
+
+async fn share(
+    server: &TestServer,
+    offsite: &Offsite,
+    email: &str,
+    permission: &str,
+) -> TestResponse {
+    server
+        .post(&offsite.shares_url())
+        .add_header("Authorization", format!("Bearer {}", offsite.owner))
+        .json(&serde_json::json!({ "email": email, "permission": permission }))
+        .await
+}
+
+/// Shares the todo with the viewer and the editor, in that order.
+async fn share_with_both(server: &TestServer, offsite: &Offsite) -> TodoShareListResponse {
+    share(server, offsite, &offsite.email("viewer"), "viewer")
+        .await
+        .assert_status(StatusCode::CREATED);
+    share(server, offsite, &offsite.email("editor"), "editor")
+        .await
+        .assert_status(StatusCode::CREATED);
+    server
+        .get(&offsite.shares_url())
+        .add_header("Authorization", format!("Bearer {}", offsite.owner))
+        .await
+        .json()
+}
+
+/// Creates a project with one todo in it, both owned by the workspace owner,
+/// and returns the project's shares URL and the todo.
+async fn venue_project(server: &TestServer, offsite: &Offsite) -> (String, TodoResponse) {
+    let project = common::create_project(server, &offsite.owner, "Venue").await;
+    let todo = common::create_todo_with(
+        server,
+        &offsite.owner,
+        serde_json::json!({ "title": "Book the venue", "project_id": project.id }),
+    )
+    .await;
+    (format!("/api/v1/projects/{}/shares", project.id), todo)
+}
+
+async fn share_project(
+    server: &TestServer,
+    offsite: &Offsite,
+    token: &str,
+    shares_url: &str,
+    email: &str,
+    permission: &str,
+) -> TestResponse {
+    server
+        .post(shares_url)
+        .add_header("Authorization", format!("Bearer {}", token))
+        .add_header("X-Workspace-Id", &offsite.workspace)
+        .json(&serde_json::json!({ "email": email, "permission": permission }))
+        .await
+}
+
+async fn shared_with(
+    server: &TestServer,
+    offsite: &Offsite,
+    token: &str,
+) -> SharedTodoListResponse {
+    server
+        .get("/api/v1/todos/shared")
+        .add_header("Authorization", format!("Bearer {}", token))
+        .add_header("X-Workspace-Id", &offsite.workspace)
+        .await
+        .json()
+}
+
+#[tokio::test]
+async fn test_share_todo() {
+    let (server, pool) = common::create_test_server().await;
+    let offsite = offsite(&server, "share").await;
+    common::register_test_user(&server, "share_outsider@example.com", "password123").await;
+
+    // Not shared yet
+    server
+        .get(&offsite.todo_url())
+        .add_header("Authorization", format!("Bearer {}", offsite.viewer))
+        .add_header("X-Workspace-Id", &offsite.workspace)
+        .await
+        .assert_status(StatusCode::NOT_FOUND);
+
+    let response = share(&server, &offsite, "share_viewer@example.com", "viewer").await;
+    response.assert_status(StatusCode::CREATED);
+    let viewer_share: TodoShareResponse = response.json();
+    assert_eq!(viewer_share.permission, SharePermission::Viewer);
+    share(&server, &offsite, "share_editor@example.com", "editor")
+        .await
+        .assert_status(StatusCode::CREATED);
+
+    // Owners cannot share with themselves, and only with registered members
+    // of the workspace
+    share(&server, &offsite, "share_owner@example.com", "editor")
+        .await
+        .assert_status(StatusCode::BAD_REQUEST);
+    share(&server, &offsite, "nobody@example.com", "viewer")
+        .await
+        .assert_status(StatusCode::NOT_FOUND);
+    share(&server, &offsite, "share_outsider@example.com", "viewer")
+        .await
+        .assert_status(StatusCode::BAD_REQUEST);
+
+    let shares: TodoShareListResponse = server
+        .get(&offsite.shares_url())
+        .add_header("Authorization", format!("Bearer {}", offsite.owner))
+        .await
+        .json();
+    let emails: Vec<_> = shares.shares.iter().map(|s| s.email.as_str()).collect();
+    assert_eq!(
+        emails,
+        vec!["share_viewer@example.com", "share_editor@example.com"]
+    );
+
+    common::cleanup_test_data(&pool).await;
+}
+
+#[tokio::test]
+async fn test_viewer_permissions() {
+    let (server, pool) = common::create_test_server().await;
+    let offsite = offsite(&server, "share_view").await;
+    share_with_both(&server, &offsite).await;
+
+    // Viewers can read and comment, but not change the todo
+    server
+        .get(&offsite.todo_url())
+        .add_header("Authorization", format!("Bearer {}", offsite.viewer))
+        .add_header("X-Workspace-Id", &offsite.workspace)
+        .await
+        .assert_status_ok();
+    server
+        .patch(&offsite.todo_url())
+        .add_header("Authorization", format!("Bearer {}", offsite.viewer))
+        .add_header("X-Workspace-Id", &offsite.workspace)
+        .json(&serde_json::json!({ "completed": true }))
+        .await
+        .assert_status(StatusCode::FORBIDDEN);
+    server
+        .post(&format!("{}/comments", offsite.todo_url()))
+        .add_header("Authorization", format!("Bearer {}", offsite.viewer))
+        .add_header("X-Workspace-Id", &offsite.workspace)
+        .json(&serde_json::json!({ "body": "Count me in" }))
+        .await
+        .assert_status(StatusCode::CREATED);
+
+    common::cleanup_test_data(&pool).await;
+}
+
+#[tokio::test]
+async fn test_editor_permissions() {
+    let (server, pool) = common::create_test_server().await;
+    let offsite = offsite(&server, "share_edit").await;
+    share_with_both(&server, &offsite).await;
+
+    // Editors can change the todo, but not delete or reshare it
+    server
+        .patch(&offsite.todo_url())
+        .add_header("Authorization", format!("Bearer {}", offsite.editor))
+        .add_header("X-Workspace-Id", &offsite.workspace)
+        .json(&serde_json::json!({ "description": "Booked the venue" }))
+        .await
+        .assert_status_ok();
+    server
+        .delete(&offsite.todo_url())
+        .add_header("Authorization", format!("Bearer {}", offsite.editor))
+        .add_header("X-Workspace-Id", &offsite.workspace)
+        .await
+        .assert_status(StatusCode::FORBIDDEN);
+    server
+        .get(&offsite.shares_url())
+        .add_header("Authorization", format!("Bearer {}", offsite.editor))
+        .add_header("X-Workspace-Id", &offsite.workspace)
+        .await
+        .assert_status(StatusCode::FORBIDDEN);
+
+    // The owner's history records who made the change
+    let history: TodoHistoryListResponse = server
+        .get(&format!("{}/history", offsite.todo_url()))
+        .add_header("Authorization", format!("Bearer {}", offsite.owner))
+        .await
+        .json();
+    assert_eq!(history.total, 2);
+    assert_ne!(history.entries[0].actor_id, history.entries[1].actor_id);
+
+    // Others see the change
+    let shared = shared_with(&server, &offsite, &offsite.viewer).await;
+    assert_eq!(
+        shared.todos[0].todo.description.as_deref(),
+        Some("Booked the venue")
+    );
+
+    common::cleanup_test_data(&pool).await;
+}
+
+#[tokio::test]
+async fn test_list_shared_todos() {
+    let (server, pool) = common::create_test_server().await;
+    let offsite = offsite(&server, "share_list").await;
+    share_with_both(&server, &offsite).await;
+    common::create_todo(&server, &offsite.owner, "Not shared").await;
+
+    // Each user sees the todos shared with them, with their permission
+    let shared = shared_with(&server, &offsite, &offsite.viewer).await;
+    assert_eq!(shared.total, 1);
+    assert_eq!(shared.todos[0].todo.id, offsite.todo.id);
+    assert_eq!(shared.todos[0].permission, SharePermission::Viewer);
+    let shared = shared_with(&server, &offsite, &offsite.editor).await;
+    assert_eq!(shared.todos[0].permission, SharePermission::Editor);
+
+    common::cleanup_test_data(&pool).await;
+}
+
+#[tokio::test]
+async fn test_revoke_share() {
+    let (server, pool) = common::create_test_server().await;
+    let offsite = offsite(&server, "share_revoke").await;
+    let shares = share_with_both(&server, &offsite).await;
+    let viewer_id = shares.shares[0].user_id;
+    let editor_id = shares.shares[1].user_id;
+
+    // Only the owner revokes other users' shares
+    server
+        .delete(&format!("{}/{}", offsite.shares_url(), viewer_id))
+        .add_header("Authorization", format!("Bearer {}", offsite.editor))
+        .add_header("X-Workspace-Id", &offsite.workspace)
+        .await
+        .assert_status(StatusCode::FORBIDDEN);
+    server
+        .delete(&format!("{}/{}", offsite.shares_url(), viewer_id))
+        .add_header("Authorization", format!("Bearer {}", offsite.owner))
+        .await
+        .assert_status(StatusCode::NO_CONTENT);
+    server
+        .get(&offsite.todo_url())
+        .add_header("Authorization", format!("Bearer {}", offsite.viewer))
+        .add_header("X-Workspace-Id", &offsite.workspace)
+        .await
+        .assert_status(StatusCode::NOT_FOUND);
+
+    // Anyone can leave
+    server
+        .delete(&format!("{}/{}", offsite.shares_url(), editor_id))
+        .add_header("Authorization", format!("Bearer {}", offsite.editor))
+        .add_header("X-Workspace-Id", &offsite.workspace)
+        .await
+        .assert_status(StatusCode::NO_CONTENT);
+    let shared = shared_with(&server, &offsite, &offsite.editor).await;
+    assert_eq!(shared.total, 0);
+
+    common::cleanup_test_data(&pool).await;
+}
+
+#[tokio::test]
+async fn test_next_occurrence_keeps_shares() {
+    let (server, pool) = common::create_test_server().await;
+
+    let owner = common::register_test_user(&server, "series_owner@example.com", "password123")
+        .await
+        .access_token;
+    let editor = common::register_test_user(&server, "series_editor@example.com", "password123")
+        .await
+        .access_token;
+    let workspace = common::join_workspace(&server, &owner, &editor, "series_editor@example.com")
+        .await
+        .to_string();
+
+    let todo = common::create_todo_with(
+        &server,
+        &owner,
+        serde_json::json!({
+            "title": "Water the plants",
+            "due_at": "2026-10-19T09:00:00Z",
+            "recurrence_rule": "FREQ=DAILY"
+        }),
+    )
+    .await;
+    server
+        .post(&format!("/api/v1/todos/{}/shares", todo.id))
+        .add_header("Authorization", format!("Bearer {}", owner))
+        .json(&serde_json::json!({ "email": "series_editor@example.com", "permission": "editor" }))
+        .await
+        .assert_status(StatusCode::CREATED);
+
+    server
+        .patch(&format!("/api/v1/todos/{}", todo.id))
+        .add_header("Authorization", format!("Bearer {}", editor))
+        .add_header("X-Workspace-Id", &workspace)
+        .json(&serde_json::json!({ "completed": true }))
+        .await
+        .assert_status_ok();
+
+    let shared: SharedTodoListResponse = server
+        .get("/api/v1/todos/shared")
+        .add_header("Authorization", format!("Bearer {}", editor))
+        .add_header("X-Workspace-Id", &workspace)
+        .await
+        .json();
+    assert_eq!(shared.total, 2);
+    let next = shared
+        .todos
+        .iter()
+        .find(|shared| shared.todo.id != todo.id)
+        .expect("next occurrence is shared");
+    assert!(!next.todo.completed);
+    assert_eq!(next.permission, SharePermission::Editor);
+
+    common::cleanup_test_data(&pool).await;
+}
+
+#[tokio::test]
+async fn test_share_project() {
+    let (server, pool) = common::create_test_server().await;
+    let offsite = offsite(&server, "share_project").await;
+    common::register_test_user(&server, "share_project_outsider@example.com", "password123").await;
+    let (shares_url, todo) = venue_project(&server, &offsite).await;
+    let todo_url = format!("/api/v1/todos/{}", todo.id);
+
+    // Only owners and admins share projects, and only with members
+    share_project(
+        &server,
+        &offsite,
+        &offsite.viewer,
+        &shares_url,
+        &offsite.email("editor"),
+        "editor",
+    )
+    .await
+    .assert_status(StatusCode::FORBIDDEN);
+    share_project(
+        &server,
+        &offsite,
+        &offsite.owner,
+        &shares_url,
+        "share_project_outsider@example.com",
+        "viewer",
+    )
+    .await
+    .assert_status(StatusCode::BAD_REQUEST);
+
+    let response = share_project(
+        &server,
+        &offsite,
+        &offsite.owner,
+        &shares_url,
+        &offsite.email("viewer"),
+        "viewer",
+    )
+    .await;
+    response.assert_status(StatusCode::CREATED);
+    let share: ProjectShareResponse = response.json();
+    assert_eq!(share.permission, SharePermission::Viewer);
+
+    // The project's todos are shared at the project's permission
+    server
+        .get(&todo_url)
+        .add_header("Authorization", format!("Bearer {}", offsite.viewer))
+        .add_header("X-Workspace-Id", &offsite.workspace)
+        .await
+        .assert_status_ok();
+    server
+        .patch(&todo_url)
+        .add_header("Authorization", format!("Bearer {}", offsite.viewer))
+        .add_header("X-Workspace-Id", &offsite.workspace)
+        .json(&serde_json::json!({ "completed": true }))
+        .await
+        .assert_status(StatusCode::FORBIDDEN);
+    let shared = shared_with(&server, &offsite, &offsite.viewer).await;
+    assert_eq!(shared.total, 1);
+    assert_eq!(shared.todos[0].todo.id, todo.id);
+    assert_eq!(shared.todos[0].permission, SharePermission::Viewer);
+
+    let shares: ProjectShareListResponse = server
+        .get(&shares_url)
+        .add_header("Authorization", format!("Bearer {}", offsite.owner))
+        .await
+        .json();
+    assert_eq!(shares.shares.len(), 1);
+    assert_eq!(shares.shares[0].email, offsite.email("viewer"));
+
+    common::cleanup_test_data(&pool).await;
+}
+
+#[tokio::test]
+async fn test_project_and_todo_shares_combine() {
+    let (server, pool) = common::create_test_server().await;
+    let offsite = offsite(&server, "share_combine").await;
+    let (shares_url, todo) = venue_project(&server, &offsite).await;
+    server
+        .post(&format!("/api/v1/todos/{}/shares", todo.id))
+        .add_header("Authorization", format!("Bearer {}", offsite.owner))
+        .json(&serde_json::json!({ "email": offsite.email("editor"), "permission": "viewer" }))
+        .await
+        .assert_status(StatusCode::CREATED);
+    let share: ProjectShareResponse = share_project(
+        &server,
+        &offsite,
+        &offsite.owner,
+        &shares_url,
+        &offsite.email("editor"),
+        "editor",
+    )
+    .await
+    .json();
+
+    // The stronger permission wins, and the todo is listed once
+    let shared = shared_with(&server, &offsite, &offsite.editor).await;
+    assert_eq!(shared.total, 1);
+    assert_eq!(shared.todos[0].permission, SharePermission::Editor);
+    server
+        .patch(&format!("/api/v1/todos/{}", todo.id))
+        .add_header("Authorization", format!("Bearer {}", offsite.editor))
+        .add_header("X-Workspace-Id", &offsite.workspace)
+        .json(&serde_json::json!({ "description": "Deposit paid" }))
+        .await
+        .assert_status_ok();
+
+    // Leaving the project keeps the todo's own share
+    server
+        .delete(&format!("{}/{}", shares_url, share.user_id))
+        .add_header("Authorization", format!("Bearer {}", offsite.editor))
+        .add_header("X-Workspace-Id", &offsite.workspace)
+        .await
+        .assert_status(StatusCode::NO_CONTENT);
+    let shared = shared_with(&server, &offsite, &offsite.editor).await;
+    assert_eq!(shared.total, 1);
+    assert_eq!(shared.todos[0].permission, SharePermission::Viewer);
+
+    common::cleanup_test_data(&pool).await;
+}
+
+#[tokio::test]
+async fn test_revoke_project_share() {
+    let (server, pool) = common::create_test_server().await;
+    let offsite = offsite(&server, "share_project_revoke").await;
+    let (shares_url, todo) = venue_project(&server, &offsite).await;
+    let share: ProjectShareResponse = share_project(
+        &server,
+        &offsite,
+        &offsite.owner,
+        &shares_url,
+        &offsite.email("viewer"),
+        "viewer",
+    )
+    .await
+    .json();
+    let share_url = format!("{}/{}", shares_url, share.user_id);
+
+    // Members cannot revoke other users' shares
+    server
+        .delete(&share_url)
+        .add_header("Authorization", format!("Bearer {}", offsite.editor))
+        .add_header("X-Workspace-Id", &offsite.workspace)
+        .await
+        .assert_status(StatusCode::FORBIDDEN);
+    server
+        .delete(&share_url)
+        .add_header("Authorization", format!("Bearer {}", offsite.owner))
+        .await
+        .assert_status(StatusCode::NO_CONTENT);
+    server
+        .get(&format!("/api/v1/todos/{}", todo.id))
+        .add_header("Authorization", format!("Bearer {}", offsite.viewer))
+        .add_header("X-Workspace-Id", &offsite.workspace)
+        .await
+        .assert_status(StatusCode::NOT_FOUND);
+    server
+        .delete(&share_url)
+        .add_header("Authorization", format!("Bearer {}", offsite.owner))
+        .await
+        .assert_status(StatusCode::NOT_FOUND);
+
+    common::cleanup_test_data(&pool).await;
+}
@@ -0,0 +1,480 @@
+use axum::http::StatusCode;
+use axum_test::TestServer;
+use rust_teraform_backend::application::dto::{
+    TimeEntryListResponse, TimeEntryResponse, TimeReportResponse, TodoResponse, WorkspaceResponse,
+};
+
+use crate::common;
+
+async fn add_time_entry(
+    server: &TestServer,
+    token: &str,
+    workspace: Option<&str>,
+    todo: &TodoResponse,
+    started_at: &str,
+    ended_at: &str,
+) {
+    let mut request = server
+        .post(&format!("/api/v1/todos/{}/time-entries", todo.id))
+        .add_header("Authorization", format!("Bearer {}", token));
+    if let Some(workspace) = workspace {
+        request = request.add_header("X-Workspace-Id", workspace);
+    }
+    request
+        .json(&serde_json::json!({ "started_at": started_at, "ended_at": ended_at }))
+        .await
+        .assert_status(StatusCode::CREATED);
+}
+
+/// Two hours on an Acme todo tagged billable and security, across midnight
+/// UTC, and half an hour on a personal todo tagged admin the next day.
+async fn track_time_in_two_workspaces(server: &TestServer, token: &str) {
+    let acme: WorkspaceResponse = server
+        .post("/api/v1/workspaces")
+        .add_header("Authorization", format!("Bearer {}", token))
+        .json(&serde_json::json!({ "name": "Acme" }))
+        .await
+        .json();
+    let acme_id = acme.id.to_string();
+
+    let personal = common::create_todo_with(
+        server,
+        token,
+        serde_json::json!({ "title": "Taxes", "tags": ["admin"] }),
+    )
+    .await;
+    let client: TodoResponse = server
+        .post("/api/v1/todos")
+        .add_header("Authorization", format!("Bearer {}", token))
+        .add_header("X-Workspace-Id", &acme_id)
+        .json(&serde_json::json!({ "title": "Audit", "tags": ["billable", "security"] }))
+        .await
+        .json();
+
+    // 23:00-01:00 UTC, which is 08:00-10:00 in Tokyo
+    add_time_entry(
+        server,
+        token,
+        Some(&acme_id),
+        &client,
+        "2025-03-01T23:00:00Z",
+        "2025-03-02T01:00:00Z",
+    )
+    .await;
+    add_time_entry(
+        server,
+        token,
+        None,
+        &personal,
+        "2025-03-02T12:00:00Z",
+        "2025-03-02T12:30:00Z",
+    )
+    .await;
+}
+
+async fn time_report(server: &TestServer, token: &str, query: &str) -> TimeReportResponse {
+    let response = server
+        .get(&format!("/api/v1/reports/time?{}", query))
+        .add_header("Authorization", format!("Bearer {}", token))
+        .await;
+    response.assert_status_ok();
+    response.json()
+}
+
+#[tokio::test]
+async fn test_timer() {
+    let (server, pool) = common::create_test_server().await;
+
+    let token = common::register_test_user(&server, "timer@example.com", "password123")
+        .await
+        .access_token;
+    let auth = format!("Bearer {}", token);
+    let design = common::create_todo(&server, &token, "Design").await;
+    let build = common::create_todo(&server, &token, "Build").await;
+    assert_eq!(design.tracked_seconds, 0);
+
+    let response = server
+        .post(&format!("/api/v1/todos/{}/timer/start", design.id))
+        .add_header("Authorization", &auth)
+        .await;
+    response.assert_status(StatusCode::CREATED);
+    let running: TimeEntryResponse = response.json();
+    assert!(running.running);
+    assert_eq!(running.ended_at, None);
+
+    // One running timer per user
+    server
+        .post(&format!("/api/v1/todos/{}/timer/start", build.id))
+        .add_header("Authorization", &auth)
+        .await
+        .assert_status(StatusCode::CONFLICT);
+    server
+        .post(&format!("/api/v1/todos/{}/timer/stop", build.id))
+        .add_header("Authorization", &auth)
+        .await
+        .assert_status(StatusCode::NOT_FOUND);
+
+    let response = server
+        .post(&format!("/api/v1/todos/{}/timer/stop", design.id))
+        .add_header("Authorization", &auth)
+        .await;
+    response.assert_status_ok();
+    let stopped: TimeEntryResponse = response.json();
+    assert_eq!(stopped.id, running.id);
+    assert!(!stopped.running);
+    assert!(stopped.ended_at.unwrap() > stopped.started_at);
+
+    // Another timer can start once the first has stopped
+    server
+        .post(&format!("/api/v1/todos/{}/timer/start", build.id))
+        .add_header("Authorization", &auth)
+        .await
+        .assert_status(StatusCode::CREATED);
+
+    common::cleanup_test_data(&pool).await;
+}
+
+#[tokio::test]
+async fn test_manual_time_entries() {
+    let (server, pool) = common::create_test_server().await;
+
+    let token = common::register_test_user(&server, "time_entries@example.com", "password123")
+        .await
+        .access_token;
+    let auth = format!("Bearer {}", token);
+    let design = common::create_todo(&server, &token, "Design").await;
+    let entries_url = format!("/api/v1/todos/{}/time-entries", design.id);
+
+    let response = server
+        .post(&entries_url)
+        .add_header("Authorization", &auth)
+        .json(&serde_json::json!({
+            "started_at": "2025-03-01T09:00:00Z",
+            "ended_at": "2025-03-01T10:30:00Z",
+            "note": "Wireframes with the client"
+        }))
+        .await;
+    response.assert_status(StatusCode::CREATED);
+    let manual: TimeEntryResponse = response.json();
+    assert_eq!(manual.duration_seconds, 5400);
+    assert_eq!(manual.note.as_deref(), Some("Wireframes with the client"));
+    add_time_entry(
+        &server,
+        &token,
+        None,
+        &design,
+        "2025-03-02T09:00:00Z",
+        "2025-03-02T09:30:00Z",
+    )
+    .await;
+
+    let list: TimeEntryListResponse = server
+        .get(&entries_url)
+        .add_header("Authorization", &auth)
+        .await
+        .json();
+    // Newest first
+    assert_eq!(list.entries.len(), 2);
+    assert_eq!(list.entries[1].id, manual.id);
+    assert_eq!(list.total_seconds, 7200);
+    let fetched: TodoResponse = server
+        .get(&format!("/api/v1/todos/{}", design.id))
+        .add_header("Authorization", &auth)
+        .await
+        .json();
+    assert_eq!(fetched.tracked_seconds, 7200);
+
+    server
+        .delete(&format!("{}/{}", entries_url, manual.id))
+        .add_header("Authorization", &auth)
+        .await
+        .assert_status(StatusCode::NO_CONTENT);
+    server
+        .delete(&format!("{}/{}", entries_url, manual.id))
+        .add_header("Authorization", &auth)
+        .await
+        .assert_status(StatusCode::NOT_FOUND);
+
+    common::cleanup_test_data(&pool).await;
+}
+
+#[tokio::test]
+async fn test_manual_time_entry_validation() {
+    let (server, pool) = common::create_test_server().await;
+
+    let token = common::register_test_user(&server, "time_invalid@example.com", "password123")
+        .await
+        .access_token;
+    let design = common::create_todo(&server, &token, "Design").await;
+
+    // Backwards, longer than a day, or ending in the future
+    for invalid in [
+        serde_json::json!({ "started_at": "2025-03-01T10:00:00Z", "ended_at": "2025-03-01T09:00:00Z" }),
+        serde_json::json!({ "started_at": "2025-03-01T00:00:00Z", "ended_at": "2025-03-03T00:00:00Z" }),
+        serde_json::json!({ "started_at": "2025-03-01T09:00:00Z", "ended_at": "2999-01-01T00:00:00Z" }),
+    ] {
+        server
+            .post(&format!("/api/v1/todos/{}/time-entries", design.id))
+            .add_header("Authorization", format!("Bearer {}", token))
+            .json(&invalid)
+            .await
+            .assert_status(StatusCode::BAD_REQUEST);
+    }
+
+    common::cleanup_test_data(&pool).await;
+}
+
+#[tokio::test]
+async fn test_tracked_time_bumps_version() {
+    let (server, pool) = common::create_test_server().await;
+
+    let token = common::register_test_user(&server, "timer_version@example.com", "password123")
+        .await
+        .access_token;
+    let auth = format!("Bearer {}", token);
+    let todo = common::create_todo(&server, &token, "Design").await;
+    let fetch = || async {
+        server
+            .get(&format!("/api/v1/todos/{}", todo.id))
+            .add_header("Authorization", &auth)
+            .await
+            .json::<TodoResponse>()
+    };
+
+    // A running timer isn't counted yet, so the todo is unchanged
+    server
+        .post(&format!("/api/v1/todos/{}/timer/start", todo.id))
+        .add_header("Authorization", &auth)
+        .await
+        .assert_status(StatusCode::CREATED);
+    let running = fetch().await;
+    assert_eq!(running.tracked_seconds, 0);
+    assert_eq!(running.version, todo.version);
+
+    server
+        .post(&format!("/api/v1/todos/{}/timer/stop", todo.id))
+        .add_header("Authorization", &auth)
+        .await
+        .assert_status_ok();
+    assert_eq!(fetch().await.version, todo.version + 1);
+
+    let entries_url = format!("/api/v1/todos/{}/time-entries", todo.id);
+    let manual: TimeEntryResponse = server
+        .post(&entries_url)
+        .add_header("Authorization", &auth)
+        .json(&serde_json::json!({
+            "started_at": "2025-03-01T09:00:00Z",
+            "ended_at": "2025-03-01T10:00:00Z"
+        }))
+        .await
+        .json();
+    let entered = fetch().await;
+    assert_eq!(entered.version, todo.version + 2);
+    assert!(entered.tracked_seconds >= 3600);
+
+    server
+        .delete(&format!("{}/{}", entries_url, manual.id))
+        .add_header("Authorization", &auth)
+        .await
+        .assert_status(StatusCode::NO_CONTENT);
+    let deleted = fetch().await;
+    assert_eq!(deleted.version, todo.version + 3);
+    assert!(deleted.tracked_seconds < 3600);
+
+    common::cleanup_test_data(&pool).await;
+}
+
+#[tokio::test]
+async fn test_time_report() {
+    let (server, pool) = common::create_test_server().await;
+
+    let token = common::register_test_user(&server, "time_report@example.com", "password123")
+        .await
+        .access_token;
+    track_time_in_two_workspaces(&server, &token).await;
+
+    let report = time_report(&server, &token, "from=2025-03-01&to=2025-03-02").await;
+    assert_eq!(report.time_zone, "UTC");
+    assert_eq!(report.total_seconds, 9000);
+    assert_eq!(
+        report
+            .rows
+            .iter()
+            .map(|r| (r.day.unwrap().to_string(), r.seconds))
+            .collect::<Vec<_>>(),
+        vec![
+            ("2025-03-01".to_string(), 3600),
+            ("2025-03-02".to_string(), 5400)
+        ]
+    );
+
+    // Other users' time is not reported
+    let other = common::register_test_user(&server, "time_other@example.com", "password123")
+        .await
+        .access_token;
+    let report = time_report(&server, &other, "from=2025-03-01&to=2025-03-02").await;
+    assert_eq!(report.total_seconds, 0);
+    assert!(report.rows.is_empty());
+
+    common::cleanup_test_data(&pool).await;
+}
+
+#[tokio::test]
+async fn test_time_report_by_workspace_and_tag() {
+    let (server, pool) = common::create_test_server().await;
+
+    let token = common::register_test_user(&server, "time_tags@example.com", "password123")
+        .await
+        .access_token;
+    track_time_in_two_workspaces(&server, &token).await;
+
+    // Time in every workspace of the user is reported
+    let report = time_report(
+        &server,
+        &token,
+        "from=2025-03-02&to=2025-03-02&time_zone=Asia/Tokyo&group_by=workspace,tag",
+    )
+    .await;
+    assert_eq!(report.total_seconds, 9000);
+    assert_eq!(
+        report
+            .rows
+            .iter()
+            .map(|r| (
+                r.workspace_name.clone().unwrap(),
+                r.tag.clone().unwrap(),
+                r.seconds
+            ))
+            .collect::<Vec<_>>(),
+        vec![
+            ("Acme".to_string(), "billable".to_string(), 7200),
+            ("Acme".to_string(), "security".to_string(), 7200),
+            ("Personal".to_string(), "admin".to_string(), 1800),
+        ]
+    );
+
+    common::cleanup_test_data(&pool).await;
+}
+
+#[tokio::test]
+async fn test_time_report_csv() {
+    let (server, pool) = common::create_test_server().await;
+
+    let token = common::register_test_user(&server, "time_csv@example.com", "password123")
+        .await
+        .access_token;
+    track_time_in_two_workspaces(&server, &token).await;
+
+    let response = server
+        .get("/api/v1/reports/time?from=2025-03-02&to=2025-03-02&time_zone=Asia/Tokyo&group_by=day,tag&format=csv")
+        .add_header("Authorization", format!("Bearer {}", token))
+        .await;
+    response.assert_status_ok();
+    assert_eq!(response.header("Content-Type"), "text/csv; charset=utf-8");
+    assert_eq!(
+        response.text(),
+        "day,tag,seconds,hours\r\n\
+         2025-03-02,admin,1800,0.50\r\n\
+         2025-03-02,billable,7200,2.00\r\n\
+         2025-03-02,security,7200,2.00\r\n"
+    );
+
+    common::cleanup_test_data(&pool).await;
+}
+
+#[tokio::test]
+async fn test_time_report_validation() {
+    let (server, pool) = common::create_test_server().await;
+
+    let token = common::register_test_user(&server, "time_report_bad@example.com", "password123")
+        .await
+        .access_token;
+
+    for invalid in [
+        "from=2025-03-02&to=2025-03-01",
+        "from=2024-01-01&to=2025-03-01",
+        "from=2025-03-01&to=2025-03-02&time_zone=Mars/Olympus",
+        "from=2025-03-01&to=2025-03-02&group_by=client",
+    ] {
+        server
+            .get(&format!("/api/v1/reports/time?{}", invalid))
+            .add_header("Authorization", format!("Bearer {}", token))
+            .await
+            .assert_status(StatusCode::BAD_REQUEST);
+    }
+
+    common::cleanup_test_data(&pool).await;
+}
+
+#[tokio::test]
+async fn test_time_report_by_project() {
+    let (server, pool) = common::create_test_server().await;
+
+    let token = common::register_test_user(&server, "time_project@example.com", "password123")
+        .await
+        .access_token;
+    let website = common::create_project(&server, &token, "Website").await;
+
+    let footer = common::create_todo_with(
+        &server,
+        &token,
+        serde_json::json!({ "title": "Fix the footer", "project_id": website.id }),
+    )
+    .await;
+    let taxes = common::create_todo(&server, &token, "Taxes").await;
+    add_time_entry(
+        &server,
+        &token,
+        None,
+        &footer,
+        "2025-03-02T09:00:00Z",
+        "2025-03-02T10:00:00Z",
+    )
+    .await;
+    add_time_entry(
+        &server,
+        &token,
+        None,
+        &taxes,
+        "2025-03-02T12:00:00Z",
+        "2025-03-02T12:30:00Z",
+    )
+    .await;
+
+    // Time on todos outside projects has a row of its own
+    let report = time_report(
+        &server,
+        &token,
+        "from=2025-03-02&to=2025-03-02&group_by=project",
+    )
+    .await;
+    assert_eq!(report.total_seconds, 5400);
+    assert_eq!(
+        report
+            .rows
+            .iter()
+            .map(|r| (r.project_id, r.project_name.clone(), r.seconds))
+            .collect::<Vec<_>>(),
+        vec![
+            (None, None, 1800),
+            (Some(website.id), Some("Website".to_string()), 3600),
+        ]
+    );
+
+    let response = server
+        .get("/api/v1/reports/time?from=2025-03-02&to=2025-03-02&group_by=project&format=csv")
+        .add_header("Authorization", format!("Bearer {}", token))
+        .await;
+    response.assert_status_ok();
+    assert_eq!(
+        response.text(),
+        format!(
+            "project_id,project,seconds,hours\r\n\
+             ,,1800,0.50\r\n\
+             {},Website,3600,1.00\r\n",
+            website.id
+        )
+    );
+
+    common::cleanup_test_data(&pool).await;
+}
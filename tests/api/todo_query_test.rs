@@ -0,0 +1,204 @@
+use axum::http::StatusCode;
+use axum_test::TestServer;
+use rust_teraform_backend::application::dto::TodoListResponse;
+
+use crate::common;
+
+async fn query_titles(server: &TestServer, token: &str, q: &str) -> Vec<String> {
+    let response = server
+        .get("/api/v1/todos")
+        .add_query_param("q", q)
+        .add_query_param("sort", "title")
+        .add_header("Authorization", format!("Bearer {}", token))
+        .await;
+    response.assert_status_ok();
+    let listed: TodoListResponse = response.json();
+    listed.todos.into_iter().map(|t| t.title).collect()
+}
+
+/// Work todos with various priorities and due dates, one of them assigned
+/// and one completed, plus an untagged one without a due date.
+async fn create_query_todos(server: &TestServer, token: &str) {
+    common::create_todo_with(
+        server,
+        token,
+        serde_json::json!({
+            "title": "Draft budget",
+            "tags": ["work"],
+            "priority": "high",
+            "due_at": "2026-10-25T12:00:00Z"
+        }),
+    )
+    .await;
+    let review = common::create_todo_with(
+        server,
+        token,
+        serde_json::json!({
+            "title": "Review contract",
+            "tags": ["work"],
+            "due_at": "2026-10-30T12:00:00Z"
+        }),
+    )
+    .await;
+    server
+        .put(&format!("/api/v1/todos/{}/assignee", review.id))
+        .add_header("Authorization", format!("Bearer {}", token))
+        .json(&serde_json::json!({ "assignee_id": review.user_id }))
+        .await
+        .assert_status_ok();
+    common::create_todo_with(
+        server,
+        token,
+        serde_json::json!({
+            "title": "Archive receipts",
+            "tags": ["work", "later"],
+            "priority": "high",
+            "due_at": "2026-10-20T12:00:00Z"
+        }),
+    )
+    .await;
+    common::create_todo_with(
+        server,
+        token,
+        serde_json::json!({
+            "title": "Plan budget offsite",
+            "tags": ["work"],
+            "priority": "high",
+            "due_at": "2026-11-05T12:00:00Z"
+        }),
+    )
+    .await;
+    let done = common::create_todo_with(
+        server,
+        token,
+        serde_json::json!({
+            "title": "File expenses",
+            "tags": ["work"],
+            "priority": "urgent",
+            "due_at": "2026-10-22T12:00:00Z"
+        }),
+    )
+    .await;
+    server
+        .patch(&format!("/api/v1/todos/{}", done.id))
+        .add_header("Authorization", format!("Bearer {}", token))
+        .json(&serde_json::json!({ "completed": true }))
+        .await
+        .assert_status_ok();
+    common::create_todo(server, token, "Water plants").await;
+}
+
+#[tokio::test]
+async fn test_query_fields() {
+    let (server, pool) = common::create_test_server().await;
+
+    let token = common::register_test_user(&server, "query@example.com", "password123")
+        .await
+        .access_token;
+    create_query_todos(&server, &token).await;
+
+    assert_eq!(
+        query_titles(
+            &server,
+            &token,
+            "status:open tag:work due<2026-11-01 (priority:high OR assignee:me) -tag:later"
+        )
+        .await,
+        vec!["Draft budget", "Review contract"]
+    );
+    assert_eq!(
+        query_titles(&server, &token, "status:closed").await,
+        vec!["File expenses"]
+    );
+    assert_eq!(
+        query_titles(&server, &token, "priority>high OR due:none").await,
+        vec!["File expenses", "Water plants"]
+    );
+    assert_eq!(
+        query_titles(&server, &token, "due:2026-10-25").await,
+        vec!["Draft budget"]
+    );
+    // Negation matches todos where the field is missing
+    assert_eq!(
+        query_titles(&server, &token, "NOT due<2026-11-01").await,
+        vec!["Plan budget offsite", "Water plants"]
+    );
+
+    common::cleanup_test_data(&pool).await;
+}
+
+#[tokio::test]
+async fn test_query_text() {
+    let (server, pool) = common::create_test_server().await;
+
+    let token = common::register_test_user(&server, "query_text@example.com", "password123")
+        .await
+        .access_token;
+    create_query_todos(&server, &token).await;
+
+    assert_eq!(
+        query_titles(&server, &token, "budget -offsite").await,
+        vec!["Draft budget"]
+    );
+    assert_eq!(
+        query_titles(&server, &token, "\"budget offsite\" assignee:none").await,
+        vec!["Plan budget offsite"]
+    );
+    // Quotes and wildcards in values are bound, never spliced into the SQL
+    assert!(query_titles(&server, &token, r#""x'y" OR budg'et*"#)
+        .await
+        .is_empty());
+
+    common::cleanup_test_data(&pool).await;
+}
+
+#[tokio::test]
+async fn test_query_with_other_filters() {
+    let (server, pool) = common::create_test_server().await;
+
+    let token = common::register_test_user(&server, "query_filters@example.com", "password123")
+        .await
+        .access_token;
+    create_query_todos(&server, &token).await;
+
+    let listed: TodoListResponse = server
+        .get("/api/v1/todos?q=tag:work&priority=high&completed=false")
+        .add_header("Authorization", format!("Bearer {}", token))
+        .await
+        .json();
+    assert_eq!(listed.total, Some(3));
+
+    common::cleanup_test_data(&pool).await;
+}
+
+#[tokio::test]
+async fn test_query_errors() {
+    let (server, pool) = common::create_test_server().await;
+
+    let token = common::register_test_user(&server, "query_errors@example.com", "password123")
+        .await
+        .access_token;
+
+    let response = server
+        .get("/api/v1/todos")
+        .add_query_param("q", "tag:work (priority:high")
+        .add_header("Authorization", format!("Bearer {}", token))
+        .await;
+    response.assert_status(StatusCode::BAD_REQUEST);
+    let body: serde_json::Value = response.json();
+    assert_eq!(
+        body["details"],
+        serde_json::json!({ "field": "q", "position": 9, "message": "Unclosed '('" })
+    );
+
+    let response = server
+        .get("/api/v1/todos")
+        .add_query_param("q", "due<soon")
+        .add_header("Authorization", format!("Bearer {}", token))
+        .await;
+    response.assert_status(StatusCode::BAD_REQUEST);
+    let body: serde_json::Value = response.json();
+    assert_eq!(body["details"]["position"], 4);
+
+    common::cleanup_test_data(&pool).await;
+}
@@ -85,7 +85,7 @@ async fn test_list_todos_empty() {
 
     let list: TodoListResponse = response.json();
     assert_eq!(list.todos.len(), 0);
-    assert_eq!(list.total, 0);
+    assert_eq!(list.total, Some(0));
 
     common::cleanup_test_data(&pool).await;
 }
@@ -118,8 +118,8 @@ async fn test_list_todos_with_pagination() {
 
     let list: TodoListResponse = response.json();
     assert_eq!(list.todos.len(), 2);
-    assert_eq!(list.total, 5);
-    assert_eq!(list.page, 1);
+    assert_eq!(list.total, Some(5));
+    assert_eq!(list.page, Some(1));
     assert_eq!(list.per_page, 2);
 
     // Get second page
@@ -130,7 +130,7 @@ async fn test_list_todos_with_pagination() {
 
     let list: TodoListResponse = response.json();
     assert_eq!(list.todos.len(), 2);
-    assert_eq!(list.page, 2);
+    assert_eq!(list.page, Some(2));
 
     common::cleanup_test_data(&pool).await;
 }
@@ -244,7 +244,7 @@ async fn test_update_todo_partial() {
 
     // Update only completed status
     let response = server
-        .put(&format!("/api/v1/todos/{}", created_todo.id))
+        .patch(&format!("/api/v1/todos/{}", created_todo.id))
         .add_header("Authorization", format!("Bearer {}", auth.access_token))
         .json(&serde_json::json!({
             "completed": true
@@ -255,11 +255,171 @@ async fn test_update_todo_partial() {
 
     let todo: TodoResponse = response.json();
     assert_eq!(todo.title, "Keep This Title"); // Unchanged
+    assert_eq!(todo.description, Some("Keep This Description".to_string())); // Unchanged
     assert!(todo.completed);
 
     common::cleanup_test_data(&pool).await;
 }
 
+#[tokio::test]
+async fn test_replace_todo_clears_omitted_fields() {
+    let (server, pool) = common::create_test_server().await;
+
+    let auth = common::register_test_user(&server, "replace@example.com", "password123").await;
+
+    let created: TodoResponse = server
+        .post("/api/v1/todos")
+        .add_header("Authorization", format!("Bearer {}", auth.access_token))
+        .json(&serde_json::json!({
+            "title": "Original",
+            "description": "Will be cleared",
+            "due_at": "2026-10-19T00:00:00Z",
+            "time_zone": "Asia/Tokyo"
+        }))
+        .await
+        .json();
+
+    let response = server
+        .put(&format!("/api/v1/todos/{}", created.id))
+        .add_header("Authorization", format!("Bearer {}", auth.access_token))
+        .json(&serde_json::json!({ "title": "Replaced" }))
+        .await;
+
+    response.assert_status_ok();
+    let todo: TodoResponse = response.json();
+    assert_eq!(todo.title, "Replaced");
+    assert_eq!(todo.description, None);
+    assert_eq!(todo.due_at, None);
+    assert_eq!(todo.time_zone, "UTC");
+    assert!(!todo.completed);
+
+    // The title is required
+    let response = server
+        .put(&format!("/api/v1/todos/{}", created.id))
+        .add_header("Authorization", format!("Bearer {}", auth.access_token))
+        .json(&serde_json::json!({ "completed": true }))
+        .await;
+    assert!(response.status_code().is_client_error());
+
+    common::cleanup_test_data(&pool).await;
+}
+
+#[tokio::test]
+async fn test_merge_patch_clears_fields() {
+    let (server, pool) = common::create_test_server().await;
+
+    let auth = common::register_test_user(&server, "merge_patch@example.com", "password123").await;
+
+    let created: TodoResponse = server
+        .post("/api/v1/todos")
+        .add_header("Authorization", format!("Bearer {}", auth.access_token))
+        .json(&serde_json::json!({
+            "title": "Keep",
+            "description": "Remove me",
+            "due_at": "2026-10-19T00:00:00Z"
+        }))
+        .await
+        .json();
+
+    let response = server
+        .patch(&format!("/api/v1/todos/{}", created.id))
+        .add_header("Authorization", format!("Bearer {}", auth.access_token))
+        .add_header("Content-Type", "application/merge-patch+json")
+        .bytes(r#"{"description": null, "due_at": null}"#.into())
+        .await;
+
+    response.assert_status_ok();
+    let todo: TodoResponse = response.json();
+    assert_eq!(todo.title, "Keep");
+    assert_eq!(todo.description, None);
+    assert_eq!(todo.due_at, None);
+
+    // Required fields cannot be cleared
+    let response = server
+        .patch(&format!("/api/v1/todos/{}", created.id))
+        .add_header("Authorization", format!("Bearer {}", auth.access_token))
+        .json(&serde_json::json!({ "title": null }))
+        .await;
+    response.assert_status(StatusCode::BAD_REQUEST);
+
+    // Recurring todos keep their due date
+    let recurring: TodoResponse = server
+        .post("/api/v1/todos")
+        .add_header("Authorization", format!("Bearer {}", auth.access_token))
+        .json(&serde_json::json!({
+            "title": "Daily",
+            "due_at": "2026-10-19T00:00:00Z",
+            "recurrence_rule": "FREQ=DAILY"
+        }))
+        .await
+        .json();
+    let response = server
+        .patch(&format!("/api/v1/todos/{}", recurring.id))
+        .add_header("Authorization", format!("Bearer {}", auth.access_token))
+        .json(&serde_json::json!({ "due_at": null }))
+        .await;
+    response.assert_status(StatusCode::BAD_REQUEST);
+
+    common::cleanup_test_data(&pool).await;
+}
+
+#[tokio::test]
+async fn test_json_patch_todo() {
+    let (server, pool) = common::create_test_server().await;
+
+    let auth = common::register_test_user(&server, "json_patch@example.com", "password123").await;
+
+    let created: TodoResponse = server
+        .post("/api/v1/todos")
+        .add_header("Authorization", format!("Bearer {}", auth.access_token))
+        .json(&serde_json::json!({ "title": "Draft", "description": "Notes" }))
+        .await
+        .json();
+
+    let patch = |operations: serde_json::Value| {
+        server
+            .patch(&format!("/api/v1/todos/{}", created.id))
+            .add_header("Authorization", format!("Bearer {}", auth.access_token))
+            .add_header("Content-Type", "application/json-patch+json")
+            .bytes(operations.to_string().into())
+    };
+
+    let response = patch(serde_json::json!([
+        { "op": "test", "path": "/title", "value": "Draft" },
+        { "op": "replace", "path": "/title", "value": "Final" },
+        { "op": "remove", "path": "/description" },
+        { "op": "replace", "path": "/completed", "value": true }
+    ]))
+    .await;
+
+    response.assert_status_ok();
+    let todo: TodoResponse = response.json();
+    assert_eq!(todo.title, "Final");
+    assert_eq!(todo.description, None);
+    assert!(todo.completed);
+
+    // A failed test leaves the todo unchanged
+    let response = patch(serde_json::json!([
+        { "op": "test", "path": "/title", "value": "Draft" },
+        { "op": "replace", "path": "/title", "value": "Again" }
+    ]))
+    .await;
+    response.assert_status(StatusCode::BAD_REQUEST);
+
+    // The patched document must still be a valid todo
+    let response = patch(serde_json::json!([{ "op": "remove", "path": "/title" }])).await;
+    response.assert_status(StatusCode::BAD_REQUEST);
+
+    let todo: TodoResponse = server
+        .get(&format!("/api/v1/todos/{}", created.id))
+        .add_header("Authorization", format!("Bearer {}", auth.access_token))
+        .await
+        .json();
+    assert_eq!(todo.title, "Final");
+
+    common::cleanup_test_data(&pool).await;
+}
+
 #[tokio::test]
 async fn test_delete_todo_success() {
     let (server, pool) = common::create_test_server().await;
@@ -393,7 +553,7 @@ async fn test_users_see_only_their_todos() {
         .add_header("Authorization", format!("Bearer {}", auth1.access_token))
         .await;
     let list: TodoListResponse = response.json();
-    assert_eq!(list.total, 3);
+    assert_eq!(list.total, Some(3));
     for todo in &list.todos {
         assert!(todo.title.starts_with("User1"));
     }
@@ -404,10 +564,192 @@ async fn test_users_see_only_their_todos() {
         .add_header("Authorization", format!("Bearer {}", auth2.access_token))
         .await;
     let list: TodoListResponse = response.json();
-    assert_eq!(list.total, 2);
+    assert_eq!(list.total, Some(2));
     for todo in &list.todos {
         assert!(todo.title.starts_with("User2"));
     }
 
     common::cleanup_test_data(&pool).await;
 }
+
+#[tokio::test]
+async fn test_list_todos_with_filters() {
+    let (server, pool) = common::create_test_server().await;
+
+    let auth = common::register_test_user(&server, "filters@example.com", "password123").await;
+
+    let todos = [
+        ("Due soon", "2026-11-01T09:00:00Z", true),
+        ("Due later", "2026-12-01T09:00:00Z", false),
+        ("Due much later", "2027-01-01T09:00:00Z", false),
+    ];
+    for (title, due_at, completed) in todos {
+        let created: TodoResponse = server
+            .post("/api/v1/todos")
+            .add_header("Authorization", format!("Bearer {}", auth.access_token))
+            .json(&serde_json::json!({ "title": title, "due_at": due_at }))
+            .await
+            .json();
+        if completed {
+            server
+                .patch(&format!("/api/v1/todos/{}", created.id))
+                .add_header("Authorization", format!("Bearer {}", auth.access_token))
+                .json(&serde_json::json!({ "completed": true }))
+                .await
+                .assert_status_ok();
+        }
+    }
+
+    // Open todos only
+    let response = server
+        .get("/api/v1/todos?completed=false")
+        .add_header("Authorization", format!("Bearer {}", auth.access_token))
+        .await;
+    let list: TodoListResponse = response.json();
+    assert_eq!(list.total, Some(2));
+    assert!(list.todos.iter().all(|t| !t.completed));
+
+    // Due date range combined with the completed filter
+    let response = server
+        .get("/api/v1/todos")
+        .add_query_param("completed", "false")
+        .add_query_param("due_after", "2026-11-15T00:00:00Z")
+        .add_query_param("due_before", "2026-12-31T00:00:00Z")
+        .add_header("Authorization", format!("Bearer {}", auth.access_token))
+        .await;
+    let list: TodoListResponse = response.json();
+    assert_eq!(list.total, Some(1));
+    assert_eq!(list.todos[0].title, "Due later");
+
+    // Inverted range is rejected
+    let response = server
+        .get("/api/v1/todos")
+        .add_query_param("due_after", "2026-12-31T00:00:00Z")
+        .add_query_param("due_before", "2026-11-15T00:00:00Z")
+        .add_header("Authorization", format!("Bearer {}", auth.access_token))
+        .await;
+    response.assert_status(StatusCode::BAD_REQUEST);
+
+    common::cleanup_test_data(&pool).await;
+}
+
+#[tokio::test]
+async fn test_list_todos_with_sorting() {
+    let (server, pool) = common::create_test_server().await;
+
+    let auth = common::register_test_user(&server, "sorting@example.com", "password123").await;
+
+    let todos = [
+        ("Banana", Some("2026-12-01T09:00:00Z")),
+        ("Apple", None),
+        ("Cherry", Some("2026-11-01T09:00:00Z")),
+    ];
+    for (title, due_at) in todos {
+        server
+            .post("/api/v1/todos")
+            .add_header("Authorization", format!("Bearer {}", auth.access_token))
+            .json(&serde_json::json!({ "title": title, "due_at": due_at }))
+            .await
+            .assert_status(StatusCode::CREATED);
+    }
+
+    let titles = |list: TodoListResponse| -> Vec<String> {
+        list.todos.into_iter().map(|t| t.title).collect()
+    };
+
+    let response = server
+        .get("/api/v1/todos?sort=title")
+        .add_header("Authorization", format!("Bearer {}", auth.access_token))
+        .await;
+    assert_eq!(titles(response.json()), vec!["Apple", "Banana", "Cherry"]);
+
+    let response = server
+        .get("/api/v1/todos?sort=title&order=desc")
+        .add_header("Authorization", format!("Bearer {}", auth.access_token))
+        .await;
+    assert_eq!(titles(response.json()), vec!["Cherry", "Banana", "Apple"]);
+
+    // Todos without a due date sort last
+    let response = server
+        .get("/api/v1/todos?sort=due_at")
+        .add_header("Authorization", format!("Bearer {}", auth.access_token))
+        .await;
+    assert_eq!(titles(response.json()), vec!["Cherry", "Banana", "Apple"]);
+
+    let response = server
+        .get("/api/v1/todos?sort=priority")
+        .add_header("Authorization", format!("Bearer {}", auth.access_token))
+        .await;
+    response.assert_status(StatusCode::BAD_REQUEST);
+
+    common::cleanup_test_data(&pool).await;
+}
+
+#[tokio::test]
+async fn test_list_todos_with_cursor() {
+    let (server, pool) = common::create_test_server().await;
+
+    let auth = common::register_test_user(&server, "cursor@example.com", "password123").await;
+
+    for i in 1..=5 {
+        server
+            .post("/api/v1/todos")
+            .add_header("Authorization", format!("Bearer {}", auth.access_token))
+            .json(&serde_json::json!({ "title": format!("Todo {}", i) }))
+            .await
+            .assert_status(StatusCode::CREATED);
+    }
+
+    let get_page = |cursor: Option<String>| {
+        let mut request = server
+            .get("/api/v1/todos")
+            .add_query_param("sort", "title")
+            .add_query_param("per_page", 2)
+            .add_query_param("include_total", false)
+            .add_header("Authorization", format!("Bearer {}", auth.access_token));
+        if let Some(cursor) = cursor {
+            request = request.add_query_param("cursor", cursor);
+        }
+        request
+    };
+    let titles = |list: &TodoListResponse| -> Vec<String> {
+        list.todos.iter().map(|t| t.title.clone()).collect()
+    };
+
+    // First page, without a total count
+    let first: TodoListResponse = get_page(None).await.json();
+    assert_eq!(titles(&first), vec!["Todo 1", "Todo 2"]);
+    assert_eq!(first.total, None);
+    assert!(first.prev_cursor.is_none());
+
+    // A todo inserted before the cursor does not shift later pages
+    server
+        .post("/api/v1/todos")
+        .add_header("Authorization", format!("Bearer {}", auth.access_token))
+        .json(&serde_json::json!({ "title": "Todo 0" }))
+        .await
+        .assert_status(StatusCode::CREATED);
+
+    let second: TodoListResponse = get_page(first.next_cursor).await.json();
+    assert_eq!(titles(&second), vec!["Todo 3", "Todo 4"]);
+    assert_eq!(second.page, None);
+
+    let third: TodoListResponse = get_page(second.next_cursor.clone()).await.json();
+    assert_eq!(titles(&third), vec!["Todo 5"]);
+    assert!(third.next_cursor.is_none());
+
+    // Going back from the second page
+    let back: TodoListResponse = get_page(second.prev_cursor).await.json();
+    assert_eq!(titles(&back), vec!["Todo 1", "Todo 2"]);
+    assert!(back.prev_cursor.is_some());
+
+    // A cursor issued for another sort is rejected
+    let response = server
+        .get("/api/v1/todos")
+        .add_query_param("cursor", second.next_cursor.unwrap())
+        .add_header("Authorization", format!("Bearer {}", auth.access_token))
+        .await;
+    response.assert_status(StatusCode::BAD_REQUEST);
+
+    common::cleanup_test_data(&pool).await;
+}
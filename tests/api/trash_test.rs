@@ -0,0 +1,209 @@
+use axum::http::StatusCode;
+use chrono::{Duration, Utc};
+use rust_teraform_backend::application::dto::{TodoListResponse, TodoResponse};
+use rust_teraform_backend::domain::repositories::TodoRepository;
+use rust_teraform_backend::infrastructure::persistence::postgres::{
+    PostgresTodoRepository, Tenant,
+};
+
+use crate::common;
+
+#[tokio::test]
+async fn test_delete_moves_todo_to_trash() {
+    let (server, pool) = common::create_test_server().await;
+
+    let auth = common::register_test_user(&server, "trash@example.com", "password123").await;
+    let token = auth.access_token;
+    let todo = common::create_todo(&server, &token, "Trash me").await;
+    common::create_todo(&server, &token, "Keep me").await;
+
+    let response = server
+        .delete(&format!("/api/v1/todos/{}", todo.id))
+        .add_header("Authorization", format!("Bearer {}", token))
+        .await;
+    response.assert_status(StatusCode::NO_CONTENT);
+
+    // Hidden from reads and listings
+    let response = server
+        .get(&format!("/api/v1/todos/{}", todo.id))
+        .add_header("Authorization", format!("Bearer {}", token))
+        .await;
+    response.assert_status(StatusCode::NOT_FOUND);
+
+    let list: TodoListResponse = server
+        .get("/api/v1/todos")
+        .add_header("Authorization", format!("Bearer {}", token))
+        .await
+        .json();
+    assert_eq!(list.total, Some(1));
+    assert_eq!(list.todos[0].title, "Keep me");
+
+    let search = server
+        .get("/api/v1/todos/search?q=trash")
+        .add_header("Authorization", format!("Bearer {}", token))
+        .await;
+    search.assert_status_ok();
+    assert_eq!(search.json::<serde_json::Value>()["total"], 0);
+
+    // Listed in the trash
+    let trash: TodoListResponse = server
+        .get("/api/v1/todos/trash")
+        .add_header("Authorization", format!("Bearer {}", token))
+        .await
+        .json();
+    assert_eq!(trash.total, Some(1));
+    assert_eq!(trash.todos[0].id, todo.id);
+    assert!(trash.todos[0].deleted_at.is_some());
+
+    // Trashed todos cannot be updated or trashed again
+    let response = server
+        .patch(&format!("/api/v1/todos/{}", todo.id))
+        .add_header("Authorization", format!("Bearer {}", token))
+        .json(&serde_json::json!({ "completed": true }))
+        .await;
+    response.assert_status(StatusCode::NOT_FOUND);
+
+    let response = server
+        .delete(&format!("/api/v1/todos/{}", todo.id))
+        .add_header("Authorization", format!("Bearer {}", token))
+        .await;
+    response.assert_status(StatusCode::NOT_FOUND);
+
+    common::cleanup_test_data(&pool).await;
+}
+
+#[tokio::test]
+async fn test_restore_todo() {
+    let (server, pool) = common::create_test_server().await;
+
+    let auth = common::register_test_user(&server, "restore@example.com", "password123").await;
+    let token = auth.access_token;
+    let todo = common::create_todo(&server, &token, "Bring me back").await;
+
+    // Only trashed todos can be restored
+    let response = server
+        .post(&format!("/api/v1/todos/{}/restore", todo.id))
+        .add_header("Authorization", format!("Bearer {}", token))
+        .await;
+    response.assert_status(StatusCode::NOT_FOUND);
+
+    server
+        .delete(&format!("/api/v1/todos/{}", todo.id))
+        .add_header("Authorization", format!("Bearer {}", token))
+        .await
+        .assert_status(StatusCode::NO_CONTENT);
+
+    // Other users cannot restore it
+    let other =
+        common::register_test_user(&server, "restore_other@example.com", "password123").await;
+    let response = server
+        .post(&format!("/api/v1/todos/{}/restore", todo.id))
+        .add_header("Authorization", format!("Bearer {}", other.access_token))
+        .await;
+    response.assert_status(StatusCode::NOT_FOUND);
+
+    let response = server
+        .post(&format!("/api/v1/todos/{}/restore", todo.id))
+        .add_header("Authorization", format!("Bearer {}", token))
+        .await;
+    response.assert_status_ok();
+    let restored: TodoResponse = response.json();
+    assert_eq!(restored.title, "Bring me back");
+    assert!(restored.deleted_at.is_none());
+
+    let response = server
+        .get(&format!("/api/v1/todos/{}", todo.id))
+        .add_header("Authorization", format!("Bearer {}", token))
+        .await;
+    response.assert_status_ok();
+
+    common::cleanup_test_data(&pool).await;
+}
+
+#[tokio::test]
+async fn test_permanently_delete_todo() {
+    let (server, pool) = common::create_test_server().await;
+
+    let auth = common::register_test_user(&server, "purge@example.com", "password123").await;
+    let token = auth.access_token;
+    let todo = common::create_todo(&server, &token, "Gone for good").await;
+
+    // Must be in the trash first
+    let response = server
+        .delete(&format!("/api/v1/todos/trash/{}", todo.id))
+        .add_header("Authorization", format!("Bearer {}", token))
+        .await;
+    response.assert_status(StatusCode::NOT_FOUND);
+
+    server
+        .delete(&format!("/api/v1/todos/{}", todo.id))
+        .add_header("Authorization", format!("Bearer {}", token))
+        .await
+        .assert_status(StatusCode::NO_CONTENT);
+
+    let response = server
+        .delete(&format!("/api/v1/todos/trash/{}", todo.id))
+        .add_header("Authorization", format!("Bearer {}", token))
+        .await;
+    response.assert_status(StatusCode::NO_CONTENT);
+
+    let trash: TodoListResponse = server
+        .get("/api/v1/todos/trash")
+        .add_header("Authorization", format!("Bearer {}", token))
+        .await
+        .json();
+    assert_eq!(trash.total, Some(0));
+
+    let response = server
+        .post(&format!("/api/v1/todos/{}/restore", todo.id))
+        .add_header("Authorization", format!("Bearer {}", token))
+        .await;
+    response.assert_status(StatusCode::NOT_FOUND);
+
+    common::cleanup_test_data(&pool).await;
+}
+
+#[tokio::test]
+async fn test_purge_trashed_todos_past_retention() {
+    let (server, pool) = common::create_test_server().await;
+
+    let auth = common::register_test_user(&server, "retention@example.com", "password123").await;
+    let token = auth.access_token;
+    let old = common::create_todo(&server, &token, "Old").await;
+    let recent = common::create_todo(&server, &token, "Recent").await;
+
+    for id in [old.id, recent.id] {
+        server
+            .delete(&format!("/api/v1/todos/{}", id))
+            .add_header("Authorization", format!("Bearer {}", token))
+            .await
+            .assert_status(StatusCode::NO_CONTENT);
+    }
+    // The purge job runs outside any workspace
+    let purged = Tenant::System
+        .scope(async {
+            sqlx::query("UPDATE todos SET deleted_at = NOW() - INTERVAL '31 days' WHERE id = $1")
+                .bind(old.id)
+                .execute(&pool)
+                .await
+                .unwrap();
+
+            let repository = PostgresTodoRepository::new(pool.clone());
+            repository
+                .purge_trashed_before(Utc::now() - Duration::days(30))
+                .await
+                .unwrap()
+        })
+        .await;
+    assert_eq!(purged, 1);
+
+    let trash: TodoListResponse = server
+        .get("/api/v1/todos/trash")
+        .add_header("Authorization", format!("Bearer {}", token))
+        .await
+        .json();
+    assert_eq!(trash.total, Some(1));
+    assert_eq!(trash.todos[0].id, recent.id);
+
+    common::cleanup_test_data(&pool).await;
+}
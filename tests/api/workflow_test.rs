@@ -0,0 +1,262 @@
+use axum::http::StatusCode;
+use axum_test::TestServer;
+use rust_teraform_backend::application::dto::{TodoListResponse, TodoResponse, WorkflowResponse};
+
+use crate::common;
+
+async fn patch_todo(
+    server: &TestServer,
+    token: &str,
+    todo: &TodoResponse,
+    body: serde_json::Value,
+) -> axum_test::TestResponse {
+    server
+        .patch(&format!("/api/v1/todos/{}", todo.id))
+        .add_header("Authorization", format!("Bearer {}", token))
+        .json(&body)
+        .await
+}
+
+#[tokio::test]
+async fn test_status_transitions() {
+    let (server, pool) = common::create_test_server().await;
+
+    let token = common::register_test_user(&server, "status@example.com", "password123")
+        .await
+        .access_token;
+    let todo = common::create_todo(&server, &token, "Write the changelog").await;
+    assert_eq!(todo.status, "todo");
+    assert!(!todo.completed);
+
+    // Moves must follow the workflow
+    patch_todo(
+        &server,
+        &token,
+        &todo,
+        serde_json::json!({ "status": "in_review" }),
+    )
+    .await
+    .assert_status(StatusCode::BAD_REQUEST);
+    patch_todo(
+        &server,
+        &token,
+        &todo,
+        serde_json::json!({ "status": "shipped" }),
+    )
+    .await
+    .assert_status(StatusCode::BAD_REQUEST);
+    patch_todo(
+        &server,
+        &token,
+        &todo,
+        serde_json::json!({ "status": "in_progress", "completed": true }),
+    )
+    .await
+    .assert_status(StatusCode::BAD_REQUEST);
+
+    let response = patch_todo(
+        &server,
+        &token,
+        &todo,
+        serde_json::json!({ "status": "in_progress" }),
+    )
+    .await;
+    response.assert_status_ok();
+    let in_progress: TodoResponse = response.json();
+    assert_eq!(in_progress.status, "in_progress");
+    assert!(!in_progress.completed);
+
+    let listed: TodoListResponse = server
+        .get("/api/v1/todos?status=in_progress")
+        .add_header("Authorization", format!("Bearer {}", token))
+        .await
+        .json();
+    assert_eq!(listed.total, Some(1));
+    server
+        .get("/api/v1/todos?status=In%20Progress")
+        .add_header("Authorization", format!("Bearer {}", token))
+        .await
+        .assert_status(StatusCode::BAD_REQUEST);
+
+    // Old clients still complete and reopen todos with the flag
+    let response = patch_todo(
+        &server,
+        &token,
+        &todo,
+        serde_json::json!({ "completed": true }),
+    )
+    .await;
+    response.assert_status_ok();
+    let done: TodoResponse = response.json();
+    assert_eq!(done.status, "done");
+    assert!(done.completed);
+    assert!(done.completed_at.is_some());
+
+    let response = server
+        .put(&format!("/api/v1/todos/{}", todo.id))
+        .add_header("Authorization", format!("Bearer {}", token))
+        .json(&serde_json::json!({ "title": "Write the changelog", "completed": false }))
+        .await;
+    response.assert_status_ok();
+    let reopened: TodoResponse = response.json();
+    assert_eq!(reopened.status, "todo");
+    assert!(!reopened.completed);
+    assert_eq!(reopened.completed_at, None);
+
+    let response = patch_todo(
+        &server,
+        &token,
+        &todo,
+        serde_json::json!({ "status": "cancelled" }),
+    )
+    .await;
+    response.assert_status_ok();
+    assert!(response.json::<TodoResponse>().completed);
+
+    common::cleanup_test_data(&pool).await;
+}
+
+#[tokio::test]
+async fn test_custom_workflow() {
+    let (server, pool) = common::create_test_server().await;
+
+    let owner = common::register_test_user(&server, "flow_owner@example.com", "password123")
+        .await
+        .access_token;
+    let member = common::register_test_user(&server, "flow_member@example.com", "password123")
+        .await
+        .access_token;
+    let workspace =
+        common::join_workspace(&server, &owner, &member, "flow_member@example.com").await;
+
+    let workflow: WorkflowResponse = server
+        .get("/api/v1/todos/workflow")
+        .add_header("Authorization", format!("Bearer {}", owner))
+        .await
+        .json();
+    let keys: Vec<_> = workflow.statuses.iter().map(|s| s.key.as_str()).collect();
+    assert_eq!(
+        keys,
+        vec!["todo", "in_progress", "in_review", "done", "cancelled"]
+    );
+
+    let custom = serde_json::json!({
+        "statuses": [
+            { "key": "todo", "name": "Backlog", "transitions": ["doing"] },
+            { "key": "doing", "name": "Doing", "transitions": ["todo", "shipped"] },
+            { "key": "shipped", "name": "Shipped", "terminal": true, "transitions": ["doing"] }
+        ]
+    });
+    let replace = |token: &str, body: &serde_json::Value| {
+        server
+            .put("/api/v1/todos/workflow")
+            .add_header("Authorization", format!("Bearer {}", token))
+            .add_header("X-Workspace-Id", workspace.to_string())
+            .json(body)
+    };
+
+    // Members can't change the workflow, and it has to be valid
+    replace(&member, &custom)
+        .await
+        .assert_status(StatusCode::FORBIDDEN);
+    replace(
+        &owner,
+        &serde_json::json!({ "statuses": [{ "key": "todo", "name": "To do" }] }),
+    )
+    .await
+    .assert_status(StatusCode::BAD_REQUEST);
+
+    // Statuses still in use can't be dropped
+    let todo = common::create_todo(&server, &owner, "Plan the launch").await;
+    patch_todo(
+        &server,
+        &owner,
+        &todo,
+        serde_json::json!({ "status": "in_progress" }),
+    )
+    .await
+    .assert_status_ok();
+    replace(&owner, &custom)
+        .await
+        .assert_status(StatusCode::CONFLICT);
+    patch_todo(
+        &server,
+        &owner,
+        &todo,
+        serde_json::json!({ "status": "todo" }),
+    )
+    .await
+    .assert_status_ok();
+
+    let response = replace(&owner, &custom).await;
+    response.assert_status_ok();
+    let workflow: WorkflowResponse = response.json();
+    assert_eq!(workflow.statuses[0].name, "Backlog");
+
+    // Completing follows the custom transitions
+    patch_todo(
+        &server,
+        &owner,
+        &todo,
+        serde_json::json!({ "completed": true }),
+    )
+    .await
+    .assert_status(StatusCode::BAD_REQUEST);
+    patch_todo(
+        &server,
+        &owner,
+        &todo,
+        serde_json::json!({ "status": "doing" }),
+    )
+    .await
+    .assert_status_ok();
+    let response = patch_todo(
+        &server,
+        &owner,
+        &todo,
+        serde_json::json!({ "completed": true }),
+    )
+    .await;
+    response.assert_status_ok();
+    assert_eq!(response.json::<TodoResponse>().status, "shipped");
+
+    // Other workspaces keep the default workflow
+    let workflow: WorkflowResponse = server
+        .get("/api/v1/todos/workflow")
+        .add_header("Authorization", format!("Bearer {}", member))
+        .await
+        .json();
+    assert_eq!(workflow.statuses.len(), 5);
+
+    common::cleanup_test_data(&pool).await;
+}
+
+#[tokio::test]
+async fn test_statuses_without_transitions() {
+    let (server, pool) = common::create_test_server().await;
+
+    let token = common::register_test_user(&server, "flow_final@example.com", "password123")
+        .await
+        .access_token;
+
+    // Final statuses don't have to lead anywhere
+    server
+        .put("/api/v1/todos/workflow")
+        .add_header("Authorization", format!("Bearer {}", token))
+        .json(&serde_json::json!({
+            "statuses": [
+                { "key": "todo", "name": "Backlog", "transitions": ["shipped"] },
+                { "key": "shipped", "name": "Shipped", "terminal": true }
+            ]
+        }))
+        .await
+        .assert_status_ok();
+    let workflow: WorkflowResponse = server
+        .get("/api/v1/todos/workflow")
+        .add_header("Authorization", format!("Bearer {}", token))
+        .await
+        .json();
+    assert!(workflow.statuses[1].transitions.is_empty());
+
+    common::cleanup_test_data(&pool).await;
+}
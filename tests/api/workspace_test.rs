@@ -0,0 +1,294 @@
+use axum::http::StatusCode;
+use axum_test::TestServer;
+use rust_teraform_backend::application::dto::{
+    TodoListResponse, WorkspaceInvitationListResponse, WorkspaceListResponse,
+    WorkspaceMemberListResponse, WorkspaceResponse,
+};
+use rust_teraform_backend::domain::entities::{TodoId, WorkspaceRole};
+use rust_teraform_backend::domain::repositories::TodoRepository;
+use rust_teraform_backend::infrastructure::persistence::postgres::{
+    PostgresTodoRepository, Tenant,
+};
+use uuid::Uuid;
+
+use crate::common;
+
+async fn workspaces(server: &TestServer, token: &str) -> WorkspaceListResponse {
+    server
+        .get("/api/v1/workspaces")
+        .add_header("Authorization", format!("Bearer {}", token))
+        .await
+        .json()
+}
+
+async fn create_workspace(server: &TestServer, token: &str, name: &str) -> WorkspaceResponse {
+    let response = server
+        .post("/api/v1/workspaces")
+        .add_header("Authorization", format!("Bearer {}", token))
+        .json(&serde_json::json!({ "name": name }))
+        .await;
+    response.assert_status(StatusCode::CREATED);
+    response.json()
+}
+
+async fn members(server: &TestServer, token: &str, workspace: Uuid) -> WorkspaceMemberListResponse {
+    server
+        .get(&format!("/api/v1/workspaces/{}/members", workspace))
+        .add_header("Authorization", format!("Bearer {}", token))
+        .await
+        .json()
+}
+
+#[tokio::test]
+async fn test_personal_workspace() {
+    let (server, pool) = common::create_test_server().await;
+
+    let token = common::register_test_user(&server, "ws_personal@example.com", "password123")
+        .await
+        .access_token;
+
+    // Every user starts with a personal workspace
+    let workspaces = workspaces(&server, &token).await;
+    assert_eq!(workspaces.workspaces.len(), 1);
+    assert_eq!(workspaces.workspaces[0].name, "Personal");
+    assert_eq!(workspaces.workspaces[0].role, WorkspaceRole::Owner);
+
+    common::cleanup_test_data(&pool).await;
+}
+
+#[tokio::test]
+async fn test_create_workspace() {
+    let (server, pool) = common::create_test_server().await;
+
+    let token = common::register_test_user(&server, "ws_create@example.com", "password123")
+        .await
+        .access_token;
+
+    let team = create_workspace(&server, &token, "  Team  ").await;
+    assert_eq!(team.name, "Team");
+    assert_eq!(team.role, WorkspaceRole::Owner);
+    assert_eq!(workspaces(&server, &token).await.workspaces.len(), 2);
+
+    common::cleanup_test_data(&pool).await;
+}
+
+#[tokio::test]
+async fn test_workspace_invitations() {
+    let (server, pool) = common::create_test_server().await;
+
+    let owner = common::register_test_user(&server, "ws_owner@example.com", "password123")
+        .await
+        .access_token;
+    let member = common::register_test_user(&server, "ws_member@example.com", "password123")
+        .await
+        .access_token;
+    let team = create_workspace(&server, &owner, "Team").await;
+    let invitations_url = format!("/api/v1/workspaces/{}/invitations", team.id);
+
+    // Outsiders cannot see the workspace
+    server
+        .get(&format!("/api/v1/workspaces/{}/members", team.id))
+        .add_header("Authorization", format!("Bearer {}", member))
+        .await
+        .assert_status(StatusCode::NOT_FOUND);
+
+    server
+        .post(&invitations_url)
+        .add_header("Authorization", format!("Bearer {}", owner))
+        .json(&serde_json::json!({ "email": "ws_member@example.com", "role": "member" }))
+        .await
+        .assert_status(StatusCode::CREATED);
+
+    let invitations: WorkspaceInvitationListResponse = server
+        .get("/api/v1/workspaces/invitations")
+        .add_header("Authorization", format!("Bearer {}", member))
+        .await
+        .json();
+    assert_eq!(invitations.invitations.len(), 1);
+    assert_eq!(invitations.invitations[0].workspace_name, "Team");
+
+    server
+        .post(&format!(
+            "/api/v1/workspaces/invitations/{}/accept",
+            invitations.invitations[0].id
+        ))
+        .add_header("Authorization", format!("Bearer {}", member))
+        .await
+        .assert_status_ok();
+
+    let members = members(&server, &member, team.id).await;
+    assert_eq!(members.members.len(), 2);
+    assert_eq!(members.members[1].role, WorkspaceRole::Member);
+
+    // Inviting an existing member conflicts
+    server
+        .post(&invitations_url)
+        .add_header("Authorization", format!("Bearer {}", owner))
+        .json(&serde_json::json!({ "email": "ws_member@example.com", "role": "admin" }))
+        .await
+        .assert_status(StatusCode::CONFLICT);
+
+    common::cleanup_test_data(&pool).await;
+}
+
+#[tokio::test]
+async fn test_workspace_member_roles() {
+    let (server, pool) = common::create_test_server().await;
+
+    let owner = common::register_test_user(&server, "ws_roles_owner@example.com", "password123")
+        .await
+        .access_token;
+    let member = common::register_test_user(&server, "ws_roles_member@example.com", "password123")
+        .await
+        .access_token;
+    let workspace =
+        common::join_workspace(&server, &owner, &member, "ws_roles_member@example.com").await;
+    let members_url = format!("/api/v1/workspaces/{}/members", workspace);
+    let members = members(&server, &owner, workspace).await;
+    let owner_id = members.members[0].user_id;
+    let member_id = members.members[1].user_id;
+
+    // Members cannot manage others, and the last owner cannot step down
+    server
+        .patch(&format!("{}/{}", members_url, owner_id))
+        .add_header("Authorization", format!("Bearer {}", member))
+        .json(&serde_json::json!({ "role": "member" }))
+        .await
+        .assert_status(StatusCode::FORBIDDEN);
+    server
+        .delete(&format!("{}/{}", members_url, owner_id))
+        .add_header("Authorization", format!("Bearer {}", owner))
+        .await
+        .assert_status(StatusCode::BAD_REQUEST);
+
+    // Once there is another owner, the first can leave
+    server
+        .patch(&format!("{}/{}", members_url, member_id))
+        .add_header("Authorization", format!("Bearer {}", owner))
+        .json(&serde_json::json!({ "role": "owner" }))
+        .await
+        .assert_status_ok();
+    server
+        .delete(&format!("{}/{}", members_url, owner_id))
+        .add_header("Authorization", format!("Bearer {}", owner))
+        .await
+        .assert_status(StatusCode::NO_CONTENT);
+
+    let workspaces = workspaces(&server, &owner).await;
+    assert!(workspaces.workspaces.iter().all(|w| w.id != workspace));
+
+    common::cleanup_test_data(&pool).await;
+}
+
+#[tokio::test]
+async fn test_todos_belong_to_the_selected_workspace() {
+    let (server, pool) = common::create_test_server().await;
+
+    let token = common::register_test_user(&server, "iso_owner@example.com", "password123")
+        .await
+        .access_token;
+    let team = create_workspace(&server, &token, "Team").await;
+
+    // Without the header todos go to the first workspace
+    let personal_todo = common::create_todo(&server, &token, "Renew passport").await;
+    server
+        .post("/api/v1/todos")
+        .add_header("Authorization", format!("Bearer {}", token))
+        .add_header("X-Workspace-Id", team.id.to_string())
+        .json(&serde_json::json!({ "title": "Ship the release" }))
+        .await
+        .assert_status(StatusCode::CREATED);
+
+    let todos: TodoListResponse = server
+        .get("/api/v1/todos")
+        .add_header("Authorization", format!("Bearer {}", token))
+        .add_header("X-Workspace-Id", team.id.to_string())
+        .await
+        .json();
+    let titles: Vec<_> = todos.todos.iter().map(|t| t.title.as_str()).collect();
+    assert_eq!(titles, vec!["Ship the release"]);
+
+    server
+        .get(&format!("/api/v1/todos/{}", personal_todo.id))
+        .add_header("Authorization", format!("Bearer {}", token))
+        .add_header("X-Workspace-Id", team.id.to_string())
+        .await
+        .assert_status(StatusCode::NOT_FOUND);
+
+    common::cleanup_test_data(&pool).await;
+}
+
+#[tokio::test]
+async fn test_workspace_header_is_checked() {
+    let (server, pool) = common::create_test_server().await;
+
+    let owner = common::register_test_user(&server, "iso_header_owner@example.com", "password123")
+        .await
+        .access_token;
+    let other = common::register_test_user(&server, "iso_header_other@example.com", "password123")
+        .await
+        .access_token;
+    let team = create_workspace(&server, &owner, "Team").await;
+
+    // Non-members and malformed IDs are rejected
+    server
+        .get("/api/v1/todos")
+        .add_header("Authorization", format!("Bearer {}", other))
+        .add_header("X-Workspace-Id", team.id.to_string())
+        .await
+        .assert_status(StatusCode::NOT_FOUND);
+    server
+        .get("/api/v1/todos")
+        .add_header("Authorization", format!("Bearer {}", owner))
+        .add_header("X-Workspace-Id", "team")
+        .await
+        .assert_status(StatusCode::BAD_REQUEST);
+
+    common::cleanup_test_data(&pool).await;
+}
+
+#[tokio::test]
+async fn test_database_isolates_workspaces() {
+    let (server, pool) = common::create_test_server().await;
+
+    let token = common::register_test_user(&server, "iso_rls@example.com", "password123")
+        .await
+        .access_token;
+    let personal_id = workspaces(&server, &token).await.workspaces[0].id;
+    let team = create_workspace(&server, &token, "Team").await;
+    let personal_todo = common::create_todo(&server, &token, "Renew passport").await;
+    server
+        .post("/api/v1/todos")
+        .add_header("Authorization", format!("Bearer {}", token))
+        .add_header("X-Workspace-Id", team.id.to_string())
+        .json(&serde_json::json!({ "title": "Ship the release" }))
+        .await
+        .assert_status(StatusCode::CREATED);
+
+    // The database itself hides other workspaces' rows, whoever asks
+    let owner_id = members(&server, &token, personal_id).await.members[0].user_id;
+    let repository = PostgresTodoRepository::new(pool.clone());
+    let id = TodoId(personal_todo.id);
+    let found = Tenant::Workspace(team.id)
+        .scope(repository.find_by_id(id, owner_id))
+        .await
+        .unwrap();
+    assert!(found.is_none());
+    let found = Tenant::Workspace(personal_id)
+        .scope(repository.find_by_id(id, owner_id))
+        .await
+        .unwrap();
+    assert!(found.is_some());
+
+    let visible: i64 = Tenant::Workspace(team.id)
+        .scope(async {
+            sqlx::query_scalar("SELECT COUNT(*) FROM todos")
+                .fetch_one(&pool)
+                .await
+        })
+        .await
+        .unwrap();
+    assert_eq!(visible, 1);
+
+    common::cleanup_test_data(&pool).await;
+}